@@ -37,6 +37,9 @@ struct Model {
     knob_value: f64,
     knob_value2: f64,
     knob_value3: f64,
+    knob_cc: Option<u8>,
+    knob_pan: f64,
+    knob_learning: bool,
     fader_value: f64,
     fader_value2: f64,
     fader_value3: f64,
@@ -59,12 +62,15 @@ struct Model {
 
     // Modal state
     show_modal: bool,
+    modal_size: ModalSize,
     show_confirm: bool,
     confirm_result: Option<bool>,
+    show_prompt: bool,
+    prompt_value: Option<String>,
 
     // Theme
     theme: Theme,
-    theme_index: usize, // 0: Light, 1: Dark, 2: Pastel, 3: Pastel Dark
+    theme_index: usize, // 0: Light, 1: Dark, 2: Pastel, 3: Pastel Dark, 4: High Contrast, 5: Colorblind Safe
 
     // Theme scale settings
     spacing_scale: f32,
@@ -132,6 +138,11 @@ struct Model {
     bindings_counter: i32,
     bindings_last_action: Option<&'static str>,
     bindings_rebind_mode: bool,
+    bindings_conflict: Option<String>,
+
+    // Media browser demo
+    media_path: Vec<String>,
+    media_search: String,
 
     // Context menu demo
     context_menu_last_action: Option<&'static str>,
@@ -163,17 +174,43 @@ struct Model {
     piano_notes: Vec<MidiNote>,
     piano_position: f32,
     piano_selected: Option<usize>,
+    piano_selection: Vec<usize>,
+
+    // Waveform demo
+    waveform_selection: Option<(f32, f32)>,
+
+    // Transport demo
+    transport_playing: bool,
+    transport_recording: bool,
+    transport_looping: bool,
+    transport_bpm: f32,
+    transport_started: Option<std::time::Instant>,
+
+    // StepSeq demo
+    step_pattern: Vec<Vec<StepValue>>,
+
+    // SamplePad demo
+    pads_active: Vec<usize>,
+    pads_last: Option<String>,
+
+    // BeatSync demo
+    beat_sync: SyncState,
 
     // Mixer demo
     channel_volumes: [f32; 4],
     channel_pans: [f32; 4],
     channel_mutes: [bool; 4],
     channel_solos: [bool; 4],
+    channel_eq: [f32; 3],
+    channel_send: f32,
 
     // Crossfader demo
     crossfader_value: f32,
+    rack_slot: AbSlot,
+    rack_status: String,
 
     // Timeline demo
+    timeline_regions: Vec<(f64, f64)>,
     timeline_position: f64,
 
     // WorkspaceCanvas demo
@@ -183,6 +220,7 @@ struct Model {
 
     // Color wheel demo
     wheel_color: Hsva,
+    wheel_recents: Vec<egui::Color32>,
 
     // NodeGraph demo (RefCell for interior mutability in view)
     #[cfg(feature = "snarl")]
@@ -352,6 +390,8 @@ enum Msg {
     KnobChanged(f64),
     Knob2Changed(f64),
     Knob3Changed(f64),
+    KnobLearn,
+    KnobPanChanged(f64),
 
     // Fader
     FaderChanged(f64),
@@ -384,9 +424,12 @@ enum Msg {
 
     // Modal
     OpenModal,
+    OpenModalSized(ModalSize),
     CloseModal,
     OpenConfirm,
     ConfirmResult(bool),
+    OpenPrompt,
+    PromptResult(Option<String>),
 
     // Theme
     ToggleTheme,
@@ -447,8 +490,19 @@ enum Msg {
     ShowToastSuccess,
     ShowToastWarning,
     ShowToastError,
+    ShowToastAction,
+    ToastUndo,
     DismissToast(ToastId),
 
+    // Waveform demo
+    WaveformSelect(f32, f32),
+
+    // Transport demo
+    Transport(TransportEvent),
+    Step(StepEvent),
+    Pad(PadEvent),
+    BeatSync(BeatSyncEvent),
+
     // Form demo
     FormSubmit,
 
@@ -462,6 +516,9 @@ enum Msg {
     ShortcutSave,
     ShortcutUndo,
 
+    // Media browser demo
+    Media(MediaBrowserEvent),
+
     // Dynamic bindings demo
     BindingsAction(DemoAction),
     BindingsRebind(DemoAction, DynamicShortcut),
@@ -505,6 +562,8 @@ enum Msg {
     PianoNoteMove(usize, u8, f32),
     PianoNoteDelete(usize),
     PianoNoteSelect(usize),
+    PianoNotesSelect(Vec<usize>),
+    PianoNotesMove(Vec<(usize, u8, f32)>),
     PianoSeek(f32),
 
     // Channel Strip
@@ -512,12 +571,18 @@ enum Msg {
     ChannelPan(usize, f32),
     ChannelMute(usize),
     ChannelSolo(usize),
+    ChannelEq(usize, f32),
+    ChannelSend(f32),
 
     // Crossfader
     CrossfaderChange(f32),
+    RackSwapAB,
+    RackSavePreset,
+    RackLoadPreset(String),
 
     // Timeline
     TimelineSeek(f64),
+    TimelineEdit(TimelineEvent),
 
     // WorkspaceCanvas
     WorkspaceEvent(WorkspaceEvent),
@@ -697,7 +762,9 @@ fn rebuild_theme(model: &mut Model) {
         0 => Theme::light(),
         1 => Theme::dark(),
         2 => Theme::pastel(),
-        _ => Theme::pastel_dark(),
+        3 => Theme::pastel_dark(),
+        4 => Theme::high_contrast(),
+        _ => Theme::colorblind_safe(),
     };
 
     let mut theme = base
@@ -863,7 +930,7 @@ impl App for StorybookApp {
 
                 // Swarm demo
                 sparkline_buffer: {
-                    let mut buf = SparklineBuffer::new(50);
+                    let mut buf = SparklineBuffer::with_capacity(50).with_baseline(50.0);
                     // Pre-fill with some data
                     for i in 0..50 {
                         buf.push(30.0 + 20.0 * ((i as f32) * 0.2).sin() + (i as f32 * 0.5) % 15.0);
@@ -936,6 +1003,16 @@ impl App for StorybookApp {
             Msg::Knob3Changed(v) => {
                 model.knob_value3 = v;
             }
+            Msg::KnobPanChanged(v) => {
+                model.knob_pan = v;
+            }
+            Msg::KnobLearn => {
+                // No MIDI input in the storybook: a second click "receives" the next CC
+                if model.knob_learning {
+                    model.knob_cc = Some(model.knob_cc.map_or(74, |cc| (cc + 1) % 128));
+                }
+                model.knob_learning = !model.knob_learning;
+            }
             Msg::FaderChanged(v) => {
                 model.fader_value = v;
             }
@@ -987,10 +1064,12 @@ impl App for StorybookApp {
                 // Simple password validation
                 model.password_validation = if v.is_empty() {
                     ValidationState::None
-                } else if v.len() >= 8 {
-                    ValidationState::Valid
-                } else {
+                } else if v.len() < 8 {
                     ValidationState::invalid("Password must be at least 8 characters")
+                } else if !v.chars().any(|c| c.is_ascii_digit()) {
+                    ValidationState::warning("Weak password: consider adding a digit")
+                } else {
+                    ValidationState::Valid
                 };
                 model.password_value = v;
             }
@@ -998,6 +1077,11 @@ impl App for StorybookApp {
                 model.select_value = idx;
             }
             Msg::OpenModal => {
+                model.modal_size = ModalSize::default();
+                model.show_modal = true;
+            }
+            Msg::OpenModalSized(size) => {
+                model.modal_size = size;
                 model.show_modal = true;
             }
             Msg::CloseModal => {
@@ -1010,8 +1094,17 @@ impl App for StorybookApp {
                 model.show_confirm = false;
                 model.confirm_result = Some(result);
             }
+            Msg::OpenPrompt => {
+                model.show_prompt = true;
+            }
+            Msg::PromptResult(value) => {
+                model.show_prompt = false;
+                if value.is_some() {
+                    model.prompt_value = value;
+                }
+            }
             Msg::ToggleTheme => {
-                model.theme_index = (model.theme_index + 1) % 4;
+                model.theme_index = (model.theme_index + 1) % 6;
                 rebuild_theme(model);
             }
             Msg::SetSpacingScale(v) => {
@@ -1204,6 +1297,67 @@ impl App for StorybookApp {
                     Msg::DismissToast,
                 );
             }
+            Msg::ShowToastAction => {
+                return model.toasts.action(
+                    "Item deleted",
+                    "Undo",
+                    Msg::ToastUndo,
+                    Duration::from_secs(5),
+                    Msg::DismissToast,
+                );
+            }
+            Msg::Transport(event) => match event {
+                TransportEvent::Play => {
+                    model.transport_playing = true;
+                    model
+                        .transport_started
+                        .get_or_insert_with(std::time::Instant::now);
+                }
+                TransportEvent::Pause => model.transport_playing = false,
+                TransportEvent::Stop => {
+                    model.transport_playing = false;
+                    model.transport_recording = false;
+                    model.transport_started = None;
+                }
+                TransportEvent::Record => model.transport_recording = !model.transport_recording,
+                TransportEvent::ToggleLoop => model.transport_looping = !model.transport_looping,
+                TransportEvent::BpmChange(bpm) => model.transport_bpm = bpm,
+            },
+            Msg::BeatSync(event) => match event {
+                BeatSyncEvent::TempoDetected(bpm) | BeatSyncEvent::SetBpm(bpm) => {
+                    model.beat_sync.bpm = bpm;
+                    model.beat_sync.is_synced = true;
+                }
+                BeatSyncEvent::SetDivision(division) => model.beat_sync.division = division,
+                BeatSyncEvent::Resync => model.beat_sync.resync(),
+                BeatSyncEvent::ResetTaps => model.beat_sync.reset_taps(),
+                BeatSyncEvent::Tap(time) => model.beat_sync.add_tap(time),
+                BeatSyncEvent::NudgePhase(_) => {}
+            },
+            Msg::Step(event) => {
+                if model.step_pattern.is_empty() {
+                    model.step_pattern = vec![vec![StepValue::Off; 16]; 4];
+                }
+                event.apply(&mut model.step_pattern);
+            }
+            Msg::Pad(event) => {
+                match event {
+                    PadEvent::Trigger { index, .. } => model.pads_active.push(index),
+                    PadEvent::Release { index } => model.pads_active.retain(|&i| i != index),
+                    PadEvent::Select(_) => {}
+                }
+                model.pads_last = Some(format!("{:?}", event));
+            }
+            Msg::WaveformSelect(start, end) => {
+                model.waveform_selection = Some((start, end));
+            }
+            Msg::ToastUndo => {
+                return model.toasts.success(
+                    "Restored item",
+                    Duration::from_secs(3),
+                    Msg::DismissToast,
+                );
+            }
             Msg::DismissToast(id) => {
                 model.toasts.dismiss(id);
             }
@@ -1265,8 +1419,23 @@ impl App for StorybookApp {
                     model.bindings_last_action = Some("Save");
                 }
             },
+            Msg::Media(event) => match event {
+                MediaBrowserEvent::EnterFolder(id) => {
+                    model.media_path.push(id);
+                    model.media_search.clear();
+                }
+                MediaBrowserEvent::NavigateUp => {
+                    model.media_path.pop();
+                }
+                MediaBrowserEvent::NavigateTo(depth) => model.media_path.truncate(depth),
+                MediaBrowserEvent::SearchChange(query) => model.media_search = query,
+                _ => {}
+            },
             Msg::BindingsRebind(action, shortcut) => {
-                model.bindings.rebind(&action, shortcut);
+                model.bindings_conflict = match model.bindings.try_rebind(&action, shortcut) {
+                    Ok(_) => None,
+                    Err(other) => Some(format!("{:?} already uses that shortcut", other)),
+                };
             }
             Msg::BindingsReset(action) => {
                 model.bindings.reset(&action);
@@ -1340,10 +1509,7 @@ impl App for StorybookApp {
                 };
                 model.error_console.push_with_level(msg, level);
             }
-            Msg::ErrorConsoleMsg(msg) => match msg {
-                ErrorConsoleMsg::Dismiss(i) => model.error_console.dismiss(i),
-                ErrorConsoleMsg::DismissAll => model.error_console.clear(),
-            },
+            Msg::ErrorConsoleMsg(msg) => model.error_console.handle_msg(msg),
 
             #[cfg(feature = "dock")]
             Msg::DockEvent(event) => {
@@ -1414,6 +1580,19 @@ impl App for StorybookApp {
             }
             Msg::PianoNoteSelect(idx) => {
                 model.piano_selected = Some(idx);
+                model.piano_selection.clear();
+            }
+            Msg::PianoNotesSelect(indices) => {
+                model.piano_selected = None;
+                model.piano_selection = indices;
+            }
+            Msg::PianoNotesMove(moves) => {
+                for (idx, note, start) in moves {
+                    if let Some(n) = model.piano_notes.get_mut(idx) {
+                        n.note = note;
+                        n.start = start;
+                    }
+                }
             }
             Msg::PianoSeek(pos) => {
                 model.piano_position = pos;
@@ -1438,12 +1617,41 @@ impl App for StorybookApp {
                     model.channel_solos[idx] = !model.channel_solos[idx];
                 }
             }
+            Msg::ChannelEq(band, value) => {
+                model.channel_eq[band] = value;
+            }
+            Msg::ChannelSend(value) => {
+                model.channel_send = value;
+            }
             Msg::CrossfaderChange(val) => {
                 model.crossfader_value = val;
             }
+            Msg::RackSwapAB => {
+                model.rack_slot = model.rack_slot.other();
+                model.rack_status = format!("Switched to {:?}", model.rack_slot);
+            }
+            Msg::RackSavePreset => {
+                model.rack_status = format!("Saved slot {:?}", model.rack_slot);
+            }
+            Msg::RackLoadPreset(name) => {
+                model.rack_status = format!("Loaded \"{}\"", name);
+            }
             Msg::TimelineSeek(pos) => {
                 model.timeline_position = pos;
             }
+            Msg::TimelineEdit(event) => match event {
+                TimelineEvent::Seek(pos) => model.timeline_position = pos,
+                TimelineEvent::RegionCreated { start, end } => {
+                    model.timeline_regions.push((start, end));
+                }
+                TimelineEvent::RegionResized { index, start, end }
+                | TimelineEvent::RegionMoved { index, start, end } => {
+                    if let Some(region) = model.timeline_regions.get_mut(index) {
+                        *region = (start, end);
+                    }
+                }
+                _ => {}
+            },
             Msg::WorkspaceEvent(event) => match event {
                 WorkspaceEvent::PaneMoved { id, position } => {
                     if let Some(pane) = model.workspace_panes.iter_mut().find(|p| p.id == id) {
@@ -1463,6 +1671,38 @@ impl App for StorybookApp {
                         pane.minimized = minimized;
                     }
                 }
+                WorkspaceEvent::PaneMaximized { id, maximized } => {
+                    for pane in model.workspace_panes.iter_mut() {
+                        pane.maximized = maximized && pane.id == id;
+                    }
+                }
+                WorkspaceEvent::PanesTabbed { moved, target } => {
+                    let group = model
+                        .workspace_panes
+                        .iter()
+                        .find(|p| p.id == target)
+                        .map(|p| p.tab_group.clone().unwrap_or(target.clone()));
+                    for pane in model.workspace_panes.iter_mut() {
+                        if pane.id == target || pane.id == moved {
+                            pane.tab_group = group.clone();
+                        }
+                        if group.is_some() && pane.tab_group == group {
+                            pane.tab_active = pane.id == moved;
+                        }
+                    }
+                }
+                WorkspaceEvent::PaneActivated(id) => {
+                    let group = model
+                        .workspace_panes
+                        .iter()
+                        .find(|p| p.id == id)
+                        .and_then(|p| p.tab_group.clone());
+                    for pane in model.workspace_panes.iter_mut() {
+                        if pane.tab_group.is_some() && pane.tab_group == group {
+                            pane.tab_active = pane.id == id;
+                        }
+                    }
+                }
                 WorkspaceEvent::PaneReordered { from, to } => {
                     let idx_from = model.workspace_panes.iter().position(|p| p.order == from);
                     let idx_to = model.workspace_panes.iter().position(|p| p.order == to);
@@ -1488,6 +1728,7 @@ impl App for StorybookApp {
             }
             Msg::ColorWheelChange(color) => {
                 model.wheel_color = color;
+                ColorWheel::push_recent(&mut model.wheel_recents, color.to_color32());
             }
 
             // === Swarm ===
@@ -1554,7 +1795,9 @@ impl App for StorybookApp {
     }
 
     fn view(model: &Model, ctx: &mut ViewCtx<Msg>) {
-        model.theme.apply(ctx.ui.ctx());
+        model
+            .theme
+            .apply_animated(ctx.ui.ctx(), std::time::Duration::from_millis(200));
 
         // Use sidebar_layout for clean two-panel design
         ctx.sidebar_layout(
@@ -1570,7 +1813,9 @@ impl App for StorybookApp {
                     0 => "Light > Dark",
                     1 => "Dark > Pastel",
                     2 => "Pastel > Pastel Dark",
-                    _ => "Pastel Dark > Light",
+                    3 => "Pastel Dark > High Contrast",
+                    4 => "High Contrast > Colorblind Safe",
+                    _ => "Colorblind Safe > Light",
                 };
                 Button::ghost(theme_label).on_click(ctx, Msg::ToggleTheme);
 
@@ -1646,11 +1891,21 @@ impl App for StorybookApp {
 
                 // Modals (inside main panel)
                 if model.show_modal {
-                    let close = Modal::titled("Demo Modal").show(ctx.ui, true, |ui| {
-                        ui.label("This is a modal dialog.");
-                        ui.label("You can put any content here.");
-                        ui.label("Click the X button or backdrop to close.");
-                    });
+                    let close = Modal::titled("Demo Modal").size(model.modal_size).show(
+                        ctx.ui,
+                        true,
+                        |ui| {
+                            ui.label("This is a modal dialog.");
+                            ui.label("You can put any content here.");
+                            ui.label("Click the X button or backdrop, or press Escape to close.");
+                            if model.modal_size != ModalSize::default() {
+                                ui.add_space(8.0);
+                                for i in 1..=60 {
+                                    ui.label(format!("Scrollable line {i}"));
+                                }
+                            }
+                        },
+                    );
                     if close {
                         ctx.emit(Msg::CloseModal);
                     }
@@ -1666,6 +1921,19 @@ impl App for StorybookApp {
                         ConfirmResult::None => {}
                     }
                 }
+
+                if model.show_prompt {
+                    let initial = model.prompt_value.as_deref().unwrap_or("Untitled");
+                    let result = PromptDialog::new("Rename", "New name:", initial)
+                        .required(true)
+                        .submit_text("Rename")
+                        .show(ctx.ui, true);
+                    match result {
+                        PromptResult::Submitted(name) => ctx.emit(Msg::PromptResult(Some(name))),
+                        PromptResult::Cancelled => ctx.emit(Msg::PromptResult(None)),
+                        PromptResult::None => {}
+                    }
+                }
             },
         );
 
@@ -1850,6 +2118,30 @@ fn render_core_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
                         .label("Large")
                         .show_with(ctx, model.knob_value3, Msg::Knob3Changed);
                 });
+
+                ctx.ui.add_space(16.0);
+
+                ctx.vertical(|ctx| {
+                    Knob::new(-1.0..=1.0).label("Pan").bipolar(true).show_with(
+                        ctx,
+                        model.knob_pan,
+                        Msg::KnobPanChanged,
+                    );
+                });
+
+                ctx.ui.add_space(16.0);
+
+                ctx.vertical(|ctx| {
+                    Knob::new(0.0..=1.0)
+                        .label("MIDI")
+                        .cc(model.knob_cc)
+                        .learnable(true)
+                        .learning(model.knob_learning)
+                        .show_with_events(ctx, model.knob_value2, |e| match e {
+                            KnobEvent::Change(v) => Msg::Knob2Changed(v),
+                            KnobEvent::StartLearn => Msg::KnobLearn,
+                        });
+                });
             });
 
             ctx.ui.add_space(16.0);
@@ -1867,8 +2159,12 @@ fn render_core_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
 
             ctx.ui.add_space(8.0);
             ctx.ui.label("Features:");
-            ctx.ui.label("• Drag up/down to adjust value");
-            ctx.ui.label("• Double-click to reset to center");
+            ctx.ui
+                .label("• Drag up/down to adjust value (Shift for fine)");
+            ctx.ui
+                .label("• Double-click to type a value, Alt+double-click to reset");
+            ctx.ui
+                .label("• Learnable knobs: click to start MIDI learn, shows CC number");
             ctx.ui.label("• Theme-aware styling");
         }
 
@@ -1933,8 +2229,11 @@ fn render_core_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
 
             ctx.ui.add_space(8.0);
             ctx.ui.label("Features:");
-            ctx.ui.label("• Drag to adjust, click to set");
-            ctx.ui.label("• Double-click to reset (0dB for dB scale)");
+            ctx.ui
+                .label("• Drag to adjust (Shift for fine), click to set");
+            ctx.ui.label("• Double-click to type a value");
+            ctx.ui
+                .label("• Alt+double-click to reset (0dB for dB scale)");
             ctx.ui.label("• dB scale with -∞ display");
         }
 
@@ -1959,10 +2258,12 @@ fn render_core_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
 
                 // With grid and labels
                 ctx.vertical(|ctx| {
-                    ctx.ui.label("With grid:");
+                    ctx.ui.label("With snap grid:");
                     XYPad::new()
                         .size(150.0, 150.0)
                         .grid(true)
+                        .snap_grid(4, 4)
+                        .snap_center(true)
                         .label_x("Cutoff")
                         .label_y("Q")
                         .show_with(ctx, model.xypad_value2, Msg::XYPad2Changed);
@@ -2045,10 +2346,11 @@ fn render_core_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
 
                 // Large with thick arc
                 ctx.vertical(|ctx| {
-                    ctx.ui.label("Large + Thick:");
+                    ctx.ui.label("Large + Bipolar:");
                     ArcSlider::new(-24.0..=24.0)
                         .large()
                         .thickness(1.5)
+                        .bipolar(true)
                         .suffix("dB")
                         .label("Gain")
                         .show_with(ctx, model.arc_value3, Msg::Arc3Changed);
@@ -2074,6 +2376,8 @@ fn render_core_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
             ctx.ui.label("• Double-click to reset to center");
             ctx.ui.label("• Size variants (small/medium/large)");
             ctx.ui.label("• Customizable arc thickness");
+            ctx.ui
+                .label("• Bipolar mode fills from center and snaps to 0");
             ctx.ui.label("• Optional value suffix (%, dB, etc.)");
         }
 
@@ -2164,6 +2468,17 @@ fn render_core_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
             ctx.ui.add_space(8.0);
 
             Code::new("fn main() {\n    println!(\"Hello, world!\");\n}").show(ctx.ui);
+
+            ctx.ui.add_space(16.0);
+            ctx.ui.strong("CodeBlock with line numbers and highlight:");
+            ctx.ui.add_space(4.0);
+            CodeBlock::new(
+                "fn main() {\n    let app = App::new();\n    egui_cha::run::<App>(config)\n}",
+            )
+            .language("rust")
+            .line_numbers(true)
+            .highlight_lines(&[3])
+            .show(ctx.ui);
         }
 
         "Text" => {
@@ -2501,6 +2816,16 @@ fn render_audio_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
 
             ctx.ui.label("Bars style:");
             Waveform::new(&samples).height(60.0).bars().show(ctx.ui);
+            ctx.ui.add_space(12.0);
+
+            ctx.ui.label("Drag to select (zoomed to first half):");
+            Waveform::new(&samples)
+                .height(60.0)
+                .zoom((0.0, 0.5))
+                .selection(model.waveform_selection)
+                .show_with(ctx, |WaveformEvent::SelectionChanged(start, end)| {
+                    Msg::WaveformSelect(start, end)
+                });
         }
 
         "Spectrum" => {
@@ -2519,6 +2844,17 @@ fn render_audio_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
                 .collect();
 
             Spectrum::new(&fft_bins).height(80.0).show(ctx.ui);
+
+            ctx.ui.add_space(8.0);
+            ctx.ui
+                .label("Log-frequency axis (48kHz), smoothed with peak hold");
+            Spectrum::new(&fft_bins)
+                .height(80.0)
+                .log_freq(true)
+                .sample_rate(48_000.0)
+                .smoothing(0.7)
+                .peak_hold(0.5)
+                .show(ctx.ui);
         }
 
         "LevelMeter" => {
@@ -2537,6 +2873,21 @@ fn render_audio_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
                     .show(ctx.ui, level_db - 6.0);
             });
 
+            ctx.ui.add_space(16.0);
+            ctx.ui.label("Stereo pair with shared scale and peak hold");
+            LevelMeter::new()
+                .size(16.0, 120.0)
+                .stereo_pair(level_db, level_db - 6.0)
+                .show_with(ctx);
+
+            ctx.ui.add_space(16.0);
+            ctx.ui.label("Peak + RMS with clip latch (click to reset)");
+            LevelMeter::new()
+                .size(20.0, 120.0)
+                .with_rms(level_db - 9.0)
+                .clip()
+                .show_with(ctx, level_db + 6.0);
+
             ctx.ui.ctx().request_repaint();
         }
 
@@ -2570,28 +2921,126 @@ fn render_audio_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
             ctx.ui.heading("Transport");
             ctx.ui.label("Transport controls (Play/Pause/Stop/Record)");
             ctx.ui.add_space(16.0);
-            ctx.ui.label("TODO: Implement TransportBar demo");
+
+            // Derive a bar.beat position from elapsed time while playing
+            let bpm = if model.transport_bpm > 0.0 {
+                model.transport_bpm
+            } else {
+                120.0
+            };
+            let beats = model
+                .transport_started
+                .map(|started| (started.elapsed().as_secs_f32() * bpm / 60.0) as u32)
+                .unwrap_or(0);
+
+            TransportBar::new()
+                .playing(model.transport_playing)
+                .recording(model.transport_recording)
+                .looping(model.transport_looping)
+                .show_loop(true)
+                .position(beats / 4 + 1, beats % 4 + 1)
+                .bpm(bpm)
+                .show_with(ctx, Msg::Transport);
+
+            ctx.ui.add_space(8.0);
+            TransportBar::new()
+                .compact(true)
+                .playing(model.transport_playing)
+                .show_record(false)
+                .show_bpm(false)
+                .show_with(ctx, Msg::Transport);
+
+            if model.transport_playing {
+                ctx.ui.ctx().request_repaint();
+            }
         }
 
         "BeatSync" => {
             ctx.ui.heading("BeatSync");
             ctx.ui.label("Beat synchronization and tap tempo");
+            ctx.ui.label("Tap TAP at least 3 times to detect the tempo");
             ctx.ui.add_space(16.0);
-            ctx.ui.label("TODO: Implement BeatSync demo");
+
+            // Drive the phase indicator from elapsed time
+            let mut sync = model.beat_sync.clone();
+            let beats =
+                ctx.ui.input(|i| i.time) as f32 * sync.bpm / 60.0 / sync.division.multiplier();
+            sync.phase = beats.fract();
+            sync.beat_count = beats as u32;
+
+            BeatSync::new(&sync).show_with(ctx, Msg::BeatSync);
+            ctx.ui.add_space(8.0);
+            BeatSync::new(&sync)
+                .compact(true)
+                .show_with(ctx, Msg::BeatSync);
+
+            ctx.ui.ctx().request_repaint();
         }
 
         "StepSeq" => {
             ctx.ui.heading("StepSeq");
             ctx.ui.label("Step sequencer for pattern creation");
+            ctx.ui
+                .label("Click to toggle, Shift+click to cycle accent, scroll/drag for velocity");
             ctx.ui.add_space(16.0);
-            ctx.ui.label("TODO: Implement StepSeq demo");
+
+            let pattern = if model.step_pattern.is_empty() {
+                vec![vec![StepValue::Off; 16]; 4]
+            } else {
+                model.step_pattern.clone()
+            };
+            let step = (ctx.ui.input(|i| i.time) * 8.0) as usize % 16;
+
+            StepSeq::new(4, 16)
+                .track_labels(&["Kick", "Snare", "HiHat", "Clap"])
+                .position(step)
+                .accent_every(4)
+                .show_with(ctx, &pattern, Msg::Step);
         }
 
         "SamplePad" => {
             ctx.ui.heading("SamplePad");
-            ctx.ui.label("Sample trigger pads");
+            ctx.ui
+                .label("MPC-style trigger pads with velocity and choke groups");
+            ctx.ui.add_space(8.0);
+
+            Code::new(
+                "SamplePad::new(&pads)\n    .columns(4)\n    .active(&model.playing)\n    .velocity_sensitive(true)\n    .show_with(ctx, Msg::Pad);"
+            ).show(ctx.ui);
+
             ctx.ui.add_space(16.0);
-            ctx.ui.label("TODO: Implement SamplePad demo");
+            ctx.ui.separator();
+            ctx.ui.add_space(8.0);
+
+            let pads = [
+                PadCell::new("Kick").with_color(egui::Color32::from_rgb(220, 80, 60)),
+                PadCell::new("Snare").with_color(egui::Color32::from_rgb(230, 160, 50)),
+                PadCell::new("HH Closed")
+                    .with_color(egui::Color32::from_rgb(80, 180, 220))
+                    .with_choke_group(1),
+                PadCell::new("HH Open")
+                    .with_color(egui::Color32::from_rgb(80, 140, 220))
+                    .with_choke_group(1),
+                PadCell::new("Clap"),
+                PadCell::new("Rim"),
+                PadCell::new("Tom Lo"),
+                PadCell::new("Tom Hi"),
+            ];
+
+            SamplePad::new(&pads)
+                .columns(4)
+                .active(&model.pads_active)
+                .velocity_sensitive(true)
+                .show_index(true)
+                .show_with(ctx, Msg::Pad);
+
+            ctx.ui.add_space(8.0);
+            ctx.ui.label(format!(
+                "Last event: {}",
+                model.pads_last.as_deref().unwrap_or("-")
+            ));
+            ctx.ui.label("• Press lower on a pad for higher velocity");
+            ctx.ui.label("• HH Closed and HH Open share a choke group");
         }
 
         _ => {
@@ -2616,12 +3065,17 @@ fn render_midi_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
             ctx.ui.separator();
             ctx.ui.add_space(8.0);
 
-            ctx.ui.strong("2 Octaves (click to trigger):");
+            ctx.ui
+                .strong("2 Octaves (hold to play, click height sets velocity):");
+            ctx.ui
+                .label("Click the keyboard, then play with A W S E D … (Z/X: octave)");
             ctx.ui.add_space(8.0);
 
             MidiKeyboard::new()
                 .octaves(2)
                 .start_octave(4)
+                .velocity_from_position(true)
+                .computer_keys(true)
                 .active_notes(&model.keyboard_notes)
                 .show_with(ctx, |event| match event {
                     KeyboardEvent::NoteOn(note, vel) => Msg::KeyboardNoteOn(note, vel),
@@ -2630,13 +3084,15 @@ fn render_midi_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
 
             ctx.ui.add_space(16.0);
 
-            ctx.ui.strong("3 Octaves, compact:");
+            ctx.ui
+                .strong("3 Octaves, compact, sustain (click to latch):");
             ctx.ui.add_space(8.0);
 
             MidiKeyboard::new()
                 .octaves(3)
                 .start_octave(3)
                 .key_size(18.0, 60.0)
+                .sustain(true)
                 .active_notes(&model.keyboard_notes)
                 .show_with(ctx, |event| match event {
                     KeyboardEvent::NoteOn(note, vel) => Msg::KeyboardNoteOn(note, vel),
@@ -2774,7 +3230,7 @@ fn render_midi_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
             ctx.ui.add_space(8.0);
 
             Code::new(
-                "PianoRoll::new()\n    .notes(&model.notes)\n    .position(model.playhead)\n    .bars(4)\n    .show_with(ctx, |event| match event {\n        PianoRollEvent::NoteAdd(n, s, d) => Msg::AddNote(n, s, d),\n        PianoRollEvent::Seek(pos) => Msg::Seek(pos),\n        _ => Msg::Noop,\n    });"
+                "PianoRoll::new()\n    .notes(&model.notes)\n    .position(model.playhead)\n    .bars(4)\n    .snap(Fraction::new(1, 4))\n    .show_with(ctx, |event| match event {\n        PianoRollEvent::NoteAdd(n, s, d) => Msg::AddNote(n, s, d),\n        PianoRollEvent::Seek(pos) => Msg::Seek(pos),\n        _ => Msg::Noop,\n    });"
             ).show(ctx.ui);
 
             ctx.ui.add_space(16.0);
@@ -2809,11 +3265,15 @@ fn render_midi_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
                 .bars(2)
                 .note_range(48, 84)
                 .selected(model.piano_selected)
+                .selection(&model.piano_selection)
+                .snap(Fraction::new(1, 4))
                 .show_with(ctx, |event| match event {
                     PianoRollEvent::NoteAdd(n, s, d) => Msg::PianoNoteAdd(n, s, d),
                     PianoRollEvent::NoteMove(i, n, s) => Msg::PianoNoteMove(i, n, s),
                     PianoRollEvent::NoteDelete(i) => Msg::PianoNoteDelete(i),
                     PianoRollEvent::NoteSelect(i) => Msg::PianoNoteSelect(i),
+                    PianoRollEvent::NotesSelect(v) => Msg::PianoNotesSelect(v),
+                    PianoRollEvent::NotesMove(m) => Msg::PianoNotesMove(m),
                     PianoRollEvent::Seek(pos) => Msg::PianoSeek(pos),
                     _ => Msg::PianoSeek(0.0),
                 });
@@ -2821,6 +3281,10 @@ fn render_midi_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
             ctx.ui.add_space(8.0);
             ctx.ui.label("• Double-click to add notes");
             ctx.ui.label("• Drag to move notes");
+            ctx.ui
+                .label("• Hold Alt while dragging to disable snapping");
+            ctx.ui
+                .label("• Drag on empty space to box-select, Shift-click to toggle");
             ctx.ui.label("• Right-click to delete");
             ctx.ui.label("• Click grid to seek");
         }
@@ -2889,6 +3353,23 @@ fn render_mixer_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
                     ctx.ui.add_space(4.0);
                 }
             });
+
+            ctx.ui.add_space(16.0);
+            ctx.ui.strong("With EQ and aux send:");
+            ctx.ui.add_space(8.0);
+
+            let [low, mid, high] = model.channel_eq;
+            ChannelStrip::new("Vocal")
+                .volume(model.channel_volumes[0])
+                .level(levels[0])
+                .with_eq(low, mid, high)
+                .with_send(model.channel_send)
+                .show_with(ctx, |e| match e {
+                    ChannelEvent::VolumeChange(v) => Msg::ChannelVolume(0, v),
+                    ChannelEvent::EqChange { band, value } => Msg::ChannelEq(band, value),
+                    ChannelEvent::SendChange(v) => Msg::ChannelSend(v),
+                    _ => Msg::ButtonClicked,
+                });
         }
 
         "CrossFader" => {
@@ -2970,11 +3451,25 @@ fn render_mixer_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
                     .with_param(EffectParam::new("Decay", 0.7)),
             ];
 
-            EffectRack::new(&effects).show(ctx.ui);
+            let presets = vec!["Warm Room".to_string(), "Big Hall".to_string()];
+            EffectRack::new(&effects)
+                .presets(&presets)
+                .active_slot(model.rack_slot)
+                .show_with(ctx, |e| match e {
+                    RackEvent::SwapAB => Msg::RackSwapAB,
+                    RackEvent::SavePreset => Msg::RackSavePreset,
+                    RackEvent::LoadPreset(name) => Msg::RackLoadPreset(name),
+                    _ => Msg::ButtonClicked,
+                });
 
             ctx.ui.add_space(8.0);
+            if !model.rack_status.is_empty() {
+                ctx.ui.label(&model.rack_status);
+            }
             ctx.ui.label("• Click effect to select");
             ctx.ui.label("• Toggle bypass with power button");
+            ctx.ui
+                .label("• A/B flips between snapshots, SAVE / Presets are app-handled");
         }
 
         "EnvelopeEditor" => {
@@ -3201,7 +3696,7 @@ fn render_visual_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
             ctx.ui.add_space(8.0);
 
             Code::new(
-                "ClipGrid::new(&clips, 4)\n    .current(model.playing_clip)\n    .queued(&model.queued)\n    .show_with(ctx, |idx| Msg::QueueClip(idx));"
+                "ClipGrid::new(&clips, 4)\n    .current(model.playing_clip)\n    .queued(&model.queued)\n    .scene_labels(&[\"Scene A\", \"Scene B\"])\n    .with_legend(true)\n    .show_with(ctx, |idx| Msg::QueueClip(idx));"
             ).show(ctx.ui);
 
             ctx.ui.add_space(16.0);
@@ -3231,11 +3726,10 @@ fn render_visual_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
                 .queued(&[5])
                 .cell_size(80.0, 50.0)
                 .show_index(true)
-                .show(ctx.ui);
-
-            ctx.ui.add_space(8.0);
-            ctx.ui.label("• Green border = Playing");
-            ctx.ui.label("• Pulsing = Queued");
+                .scene_labels(&["Scene A", "Scene B"])
+                .with_legend(true)
+                .launch_controls(true)
+                .show_events(ctx.ui);
         }
 
         "Timeline" => {
@@ -3306,6 +3800,59 @@ fn render_visual_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
                     TimelineEvent::Seek(p) => Msg::TimelineSeek(p),
                     _ => Msg::TimelineSeek(0.0),
                 });
+
+            ctx.ui.add_space(16.0);
+
+            ctx.ui.strong("Editable regions:");
+            ctx.ui
+                .label("Drag empty track to create, drag edges to resize, drag body to move");
+            ctx.ui.add_space(8.0);
+
+            let editable: Vec<TimelineRegion> = model
+                .timeline_regions
+                .iter()
+                .map(|&(start, end)| {
+                    TimelineRegion::new(
+                        start,
+                        end,
+                        egui::Color32::from_rgba_unmultiplied(100, 150, 255, 50),
+                    )
+                })
+                .collect();
+            Timeline::new(120.0)
+                .position(model.timeline_position)
+                .regions(&editable)
+                .editable_regions(true)
+                .height(36.0)
+                .show_with(ctx, Msg::TimelineEdit);
+
+            ctx.ui.add_space(16.0);
+
+            ctx.ui.strong("Time formats:");
+            ctx.ui.add_space(8.0);
+
+            for (name, format) in [
+                ("SMPTE 30fps", TimeFormat::Smpte { fps: 30 }),
+                (
+                    "Bars:Beats 120 BPM 4/4",
+                    TimeFormat::BarsBeats {
+                        bpm: 120.0,
+                        sig: (4, 4),
+                    },
+                ),
+                ("Seconds", TimeFormat::Seconds),
+            ] {
+                ctx.ui.label(name);
+                Timeline::new(120.0)
+                    .position(position)
+                    .format(format)
+                    .height(32.0)
+                    .show_with(ctx, |e| match e {
+                        TimelineEvent::Seek(p) => Msg::TimelineSeek(p),
+                        _ => Msg::TimelineSeek(0.0),
+                    });
+                ctx.ui.add_space(4.0);
+            }
         }
 
         "Preview" => {
@@ -3394,22 +3941,26 @@ fn render_visual_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
             ctx.ui.separator();
             ctx.ui.add_space(8.0);
 
+            // Top layer first
             let layers = vec![
-                Layer::new("Background").with_color(egui::Color32::from_rgb(100, 150, 200)),
-                Layer::new("Video 1")
-                    .with_opacity(0.8)
-                    .with_blend_mode(BlendMode::Normal)
-                    .with_color(egui::Color32::from_rgb(200, 150, 100)),
+                Layer::new("Text")
+                    .with_blend_mode(BlendMode::Screen)
+                    .with_color(egui::Color32::from_rgb(200, 100, 150)),
                 Layer::new("Overlay")
                     .with_opacity(0.6)
                     .with_blend_mode(BlendMode::Add)
                     .with_color(egui::Color32::from_rgb(150, 200, 100)),
-                Layer::new("Text")
-                    .with_blend_mode(BlendMode::Screen)
-                    .with_color(egui::Color32::from_rgb(200, 100, 150)),
+                Layer::new("Video 1")
+                    .with_opacity(0.8)
+                    .with_blend_mode(BlendMode::Normal)
+                    .with_color(egui::Color32::from_rgb(200, 150, 100)),
+                Layer::new("Background").with_color(egui::Color32::from_rgb(100, 150, 200)),
             ];
 
-            LayerStack::new(&layers).selected(Some(1)).show(ctx.ui);
+            LayerStack::new(&layers)
+                .selected(Some(2))
+                .with_composite_preview(true)
+                .show(ctx.ui);
 
             ctx.ui.add_space(8.0);
             ctx.ui.label("• Drag to reorder layers");
@@ -3422,7 +3973,7 @@ fn render_visual_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
             ctx.ui.add_space(8.0);
 
             Code::new(
-                "ColorWheel::new()\n    .show_alpha(true)\n    .show_with(ctx, model.color, Msg::SetColor);"
+                "ColorWheel::new()\n    .show_alpha(true)\n    .with_inputs(true)\n    .show_with(ctx, model.color, Msg::SetColor);"
             ).show(ctx.ui);
 
             ctx.ui.add_space(16.0);
@@ -3450,6 +4001,9 @@ fn render_visual_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
                 ColorWheel::new()
                     .style(WheelStyle::Square)
                     .size(160.0)
+                    .with_inputs(true)
+                    .with_recents(&model.wheel_recents)
+                    .with_eyedropper(true)
                     .show_with(ctx, current_color, |c| {
                         Msg::ColorWheelChange(Hsva::from_color32(c))
                     });
@@ -3540,9 +4094,24 @@ fn render_visual_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
 
             MaskEditor::new(&mask).size(200.0, 200.0).show(ctx.ui);
 
+            ctx.ui.add_space(16.0);
+            ctx.ui.strong("Feathered ellipse minus a rectangle:");
+            ctx.ui.add_space(8.0);
+
+            let masks = [
+                Mask::ellipse().with_feather(0.15),
+                Mask::rectangle().with_combine(CombineMode::Subtract),
+            ];
+            MaskEditor::multi(&masks)
+                .active(1)
+                .size(300.0, 200.0)
+                .show(ctx.ui);
+
             ctx.ui.add_space(8.0);
             ctx.ui.label("• Drag points to reshape");
             ctx.ui.label("• Click edge to add points");
+            ctx.ui
+                .label("• Pick a shape tab and its Add/Sub/Int combine mode");
         }
 
         "TransformGizmo" => {
@@ -3568,9 +4137,13 @@ fn render_visual_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
             TransformGizmo::new()
                 .size(300.0, 200.0)
                 .mode(TransformMode::All)
+                .show_readout(true)
+                .snap_rotation(15.0)
                 .show(ctx.ui, &transform);
 
             ctx.ui.add_space(8.0);
+            ctx.ui
+                .label("• Click to focus, then nudge with arrow keys (Shift: ×10)");
             ctx.ui.label("• Drag center to move");
             ctx.ui.label("• Drag corners to scale");
             ctx.ui.label("• Drag outside to rotate");
@@ -3617,6 +4190,43 @@ fn render_visual_atom(model: &Model, ctx: &mut ViewCtx<Msg>) {
                 .view_mode(BrowserViewMode::List)
                 .size(350.0, 120.0)
                 .show(ctx.ui);
+
+            ctx.ui.add_space(16.0);
+
+            ctx.ui.strong("Folders and search:");
+            ctx.ui.add_space(8.0);
+
+            let library = vec![
+                MediaItem::folder(
+                    "Clips",
+                    vec![
+                        MediaItem::new("c1", "intro.mov").with_type(MediaType::Video),
+                        MediaItem::new("c2", "outro.mov").with_type(MediaType::Video),
+                        MediaItem::folder(
+                            "Loops",
+                            vec![
+                                MediaItem::new("l1", "tunnel_loop.mov").with_type(MediaType::Video)
+                            ],
+                        ),
+                    ],
+                ),
+                MediaItem::folder(
+                    "Audio",
+                    vec![MediaItem::new("a1", "loop_beat.wav").with_type(MediaType::Audio)],
+                ),
+                MediaItem::new("bg", "background.jpg").with_type(MediaType::Image),
+            ];
+
+            MediaBrowser::new(&library)
+                .path(&model.media_path)
+                .search(&model.media_search)
+                .size(400.0, 150.0)
+                .show_with(ctx, Msg::Media);
+
+            ctx.ui.add_space(8.0);
+            ctx.ui.label("• Double-click a folder to enter it");
+            ctx.ui
+                .label("• Search matches names in the current folder and below");
         }
 
         "OutputRouter" => {
@@ -3756,6 +4366,17 @@ fn render_swarm_component(model: &Model, ctx: &mut ViewCtx<Msg>) {
                 .bounds(0.0, 100.0)
                 .show(ctx.ui);
 
+            ctx.ui.add_space(16.0);
+            ctx.ui.strong("Buffer Render (rolling window)");
+            let buf = &model.sparkline_buffer;
+            ctx.ui.label(format!(
+                "min {:.0} / max {:.0} / avg {:.1}",
+                buf.min().unwrap_or(0.0),
+                buf.max().unwrap_or(0.0),
+                buf.avg().unwrap_or(0.0)
+            ));
+            buf.render(ctx.ui, egui::vec2(240.0, 32.0));
+
             ctx.ui.add_space(16.0);
             ctx.ui.strong("Show Current Value");
             Sparkline::new(&data)
@@ -3879,6 +4500,33 @@ fn render_swarm_component(model: &Model, ctx: &mut ViewCtx<Msg>) {
                 .show_percentage()
                 .show(ctx.ui);
 
+            ctx.ui.add_space(16.0);
+            ctx.ui.strong("Ring and Segments");
+            ctx.ui.add_space(4.0);
+
+            ctx.horizontal(|ctx| {
+                CapacityGauge::new(0.45)
+                    .ring()
+                    .show_percentage()
+                    .show(ctx.ui);
+                CapacityGauge::new(0.78)
+                    .ring()
+                    .show_percentage()
+                    .show(ctx.ui);
+                CapacityGauge::new(0.93)
+                    .ring()
+                    .show_percentage()
+                    .show(ctx.ui);
+            });
+            ctx.ui.add_space(4.0);
+            CapacityGauge::new(0.65)
+                .segments(12)
+                .warn_at(0.6)
+                .danger_at(0.85)
+                .width(240.0)
+                .label("Buffer")
+                .show(ctx.ui);
+
             ctx.ui.add_space(16.0);
             ctx.ui.strong("With Animation");
             ctx.ui.add_space(4.0);
@@ -4295,6 +4943,41 @@ fn render_semantics(model: &Model, ctx: &mut ViewCtx<Msg>) {
             ctx.ui.separator();
             ctx.ui.add_space(8.0);
 
+            ctx.ui.strong("Timestamp & Source:");
+            ctx.ui.add_space(8.0);
+
+            let now = std::time::Instant::now();
+            SeverityLog::info("Connected to server")
+                .at(now)
+                .with_source("net")
+                .show(ctx.ui);
+            ctx.ui.add_space(4.0);
+            SeverityLog::warn("Cache miss rate high")
+                .at(now)
+                .with_source("cache")
+                .show_framed(ctx.ui);
+            ctx.ui.add_space(4.0);
+            SeverityLog::error("Connection lost")
+                .at(now)
+                .with_source("net")
+                .show_framed(ctx.ui);
+
+            ctx.ui.add_space(16.0);
+            ctx.ui.separator();
+            ctx.ui.add_space(8.0);
+
+            ctx.ui.strong("With Details:");
+            ctx.ui.add_space(8.0);
+
+            SeverityLog::critical("Worker thread panicked")
+                .with_source("jobs")
+                .with_details("thread 'worker-2' panicked at src/jobs.rs:88:14\nindex out of bounds: the len is 3 but the index is 5")
+                .show_framed(ctx.ui);
+
+            ctx.ui.add_space(16.0);
+            ctx.ui.separator();
+            ctx.ui.add_space(8.0);
+
             ctx.ui.strong("Icon Only (no label):");
             ctx.ui.add_space(8.0);
 
@@ -4330,6 +5013,27 @@ fn render_molecule(model: &Model, ctx: &mut ViewCtx<Msg>) {
             Card::new().show_ctx(ctx, |ctx| {
                 ctx.ui.label("Card without title");
             });
+
+            ctx.ui.add_space(8.0);
+
+            Card::titled("Collapsible Card")
+                .collapsible(true)
+                .show_ctx(ctx, |ctx| {
+                    ctx.ui.label("Click the header to collapse.");
+                });
+
+            ctx.ui.add_space(8.0);
+
+            Card::titled("Card with Footer")
+                .with_footer(|ctx: &mut ViewCtx<Msg>| {
+                    ctx.horizontal(|ctx| {
+                        Button::primary("Save").on_click(ctx, Msg::OpenConfirm);
+                        Button::ghost("Cancel").on_click(ctx, Msg::CloseModal);
+                    });
+                })
+                .show_ctx(ctx, |ctx| {
+                    ctx.ui.label("Footer actions are pinned below the body.");
+                });
         }
 
         "Tabs" => {
@@ -4427,6 +5131,24 @@ fn render_molecule(model: &Model, ctx: &mut ViewCtx<Msg>) {
             ctx.horizontal(|ctx| {
                 Button::primary("Open Modal").on_click(ctx, Msg::OpenModal);
                 Button::secondary("Open Confirm").on_click(ctx, Msg::OpenConfirm);
+                Button::outline("Open Prompt").on_click(ctx, Msg::OpenPrompt);
+            });
+
+            if let Some(name) = &model.prompt_value {
+                ctx.ui.add_space(8.0);
+                ctx.ui.label(format!("Prompt result: {}", name));
+            }
+
+            ctx.ui.add_space(8.0);
+            ctx.ui.label("Size presets (tall content scrolls):");
+            ctx.horizontal(|ctx| {
+                for (label, size) in [
+                    ("Small", ModalSize::Small),
+                    ("Large", ModalSize::Large),
+                    ("Fullscreen", ModalSize::Fullscreen),
+                ] {
+                    Button::outline(label).on_click(ctx, Msg::OpenModalSized(size));
+                }
             });
 
             if let Some(result) = model.confirm_result {
@@ -4594,6 +5316,10 @@ Strip::horizontal()
                 Button::warning("Warning").on_click(ctx, Msg::ShowToastWarning);
                 Button::danger("Error").on_click(ctx, Msg::ShowToastError);
             });
+            ctx.ui.add_space(4.0);
+            ctx.horizontal(|ctx| {
+                Button::outline("With Action").on_click(ctx, Msg::ShowToastAction);
+            });
 
             ctx.ui.add_space(16.0);
             ctx.ui
@@ -4608,6 +5334,9 @@ Strip::horizontal()
             ctx.ui.label("- 4 variants: Info, Success, Warning, Error");
             ctx.ui.label("- Auto-dismiss with configurable duration");
             ctx.ui.label("- Manual dismiss via close button");
+            ctx.ui.label("- Inline action button (e.g. Undo)");
+            ctx.ui
+                .label("- Duplicate toasts coalesce with a count badge");
             ctx.ui
                 .label("- Position: TopRight (default), BottomRight, etc.");
             ctx.ui.label("- Multiple toasts stack vertically");
@@ -5011,6 +5740,11 @@ NodeGraphArea::new(&mut model.graph)
             }
             ctx.ui.add_space(8.0);
 
+            if ctx.ui.button("Fit to nodes").clicked() {
+                model.node_graph.borrow_mut().fit_to_nodes();
+            }
+            ctx.ui.add_space(8.0);
+
             // Use fixed-size area for node graph
             let available = ctx.ui.available_size();
             let graph_height = 350.0_f32.min(available.y - 50.0).max(200.0);
@@ -5025,6 +5759,7 @@ NodeGraphArea::new(&mut model.graph)
                     ui.set_min_size(egui::vec2(available.x - 20.0, graph_height));
 
                     NodeGraphArea::new(&mut model.node_graph.borrow_mut())
+                        .with_minimap(true)
                         .show(ui, &mut DemoNodeViewer);
                 });
 
@@ -5034,6 +5769,8 @@ NodeGraphArea::new(&mut model.graph)
             ctx.ui.label("• Theme-aware styling via NodeGraphStyle");
             ctx.ui.label("• Custom context menu actions via MenuAction");
             ctx.ui.label("• TEA-style events via NodeGraphEvent");
+            ctx.ui
+                .label("• Minimap overview (drag to pan) and fit-to-view");
             ctx.ui
                 .label("• Preset pin types for VJ/DAW (Audio, Video, MIDI, etc.)");
         }
@@ -6272,6 +7009,13 @@ fn render_framework(model: &Model, ctx: &mut ViewCtx<Msg>) {
                 }
             });
 
+            ctx.horizontal(|ctx| {
+                ctx.ui.label("Record Save:");
+                if let Some(shortcut) = egui_cha::bindings::capture_shortcut(ctx.ui) {
+                    ctx.emit(Msg::BindingsRebind(DemoAction::Save, shortcut));
+                }
+            });
+
             ctx.horizontal(|ctx| {
                 if ctx.ui.button("Save -> Ctrl+Shift+S").clicked() {
                     ctx.emit(Msg::BindingsRebind(
@@ -6279,9 +7023,20 @@ fn render_framework(model: &Model, ctx: &mut ViewCtx<Msg>) {
                         DynamicShortcut::new(Modifiers::CTRL.plus(Modifiers::SHIFT), Key::S),
                     ));
                 }
+                if ctx.ui.button("Reset -> W").clicked() {
+                    ctx.emit(Msg::BindingsRebind(
+                        DemoAction::Reset,
+                        DynamicShortcut::new(Modifiers::NONE, Key::W),
+                    ));
+                }
                 Button::secondary("Reset All to Defaults").on_click(ctx, Msg::BindingsResetAll);
             });
 
+            if let Some(conflict) = &model.bindings_conflict {
+                ctx.ui.add_space(4.0);
+                Badge::error(conflict).show(ctx.ui);
+            }
+
             ctx.ui.add_space(16.0);
 
             // Counter display
@@ -6498,7 +7253,9 @@ fn render_theme(model: &Model, ctx: &mut ViewCtx<'_, Msg>) {
                 0 => "Light",
                 1 => "Dark",
                 2 => "Pastel",
-                _ => "Pastel Dark",
+                3 => "Pastel Dark",
+                4 => "High Contrast",
+                _ => "Colorblind Safe",
             };
             ctx.ui.horizontal(|ui| {
                 let theme = Theme::current(ui.ctx());
@@ -6512,7 +7269,8 @@ fn render_theme(model: &Model, ctx: &mut ViewCtx<'_, Msg>) {
                     0 => Badge::new(theme_name).show(ui), // Light - use default (gray)
                     1 => Badge::info(theme_name).show(ui), // Dark - use info (blue)
                     2 => Badge::success(theme_name).show(ui), // Pastel - use success (green)
-                    _ => Badge::warning(theme_name).show(ui), // Pastel Dark - use warning (amber)
+                    3 => Badge::warning(theme_name).show(ui), // Pastel Dark - use warning (amber)
+                    _ => Badge::error(theme_name).show(ui), // Accessibility presets - use error
                 };
             });
             ctx.ui.add_space(16.0);
@@ -6690,6 +7448,21 @@ fn render_theme(model: &Model, ctx: &mut ViewCtx<'_, Msg>) {
                 });
             });
 
+            ctx.ui.add_space(16.0);
+            ctx.ui.strong("Scoped Override:");
+            ctx.horizontal(|ctx| {
+                Button::primary("Base").on_click(ctx, Msg::ButtonClicked);
+                ctx.with_theme_override(
+                    |t| {
+                        t.primary = t.state_danger;
+                        t.primary_hover = t.state_danger_hover;
+                    },
+                    |ctx| {
+                        Button::primary("Overridden").on_click(ctx, Msg::ButtonClicked);
+                    },
+                );
+            });
+
             ctx.ui.add_space(16.0);
             ctx.ui.strong("Input:");
             let mut sample = String::from("Sample text");
@@ -6940,6 +7713,44 @@ let theme = BrandTheme.to_theme();"#;
         .label("• Hover states auto-generated (lighten/darken)");
     ctx.ui.label("• Secondary/muted text derived with alpha");
     ctx.ui.label("• Border colors derived from background");
+
+    ctx.ui.add_space(16.0);
+    ctx.ui.separator();
+    ctx.ui.add_space(8.0);
+
+    // Single-accent palettes
+    ctx.ui.strong("Theme::from_accent:");
+    ctx.ui.add_space(8.0);
+    let accents = [
+        egui::Color32::from_rgb(139, 92, 246),
+        egui::Color32::from_rgb(250, 204, 21),
+        egui::Color32::from_rgb(6, 182, 212),
+    ];
+    ctx.ui.horizontal_wrapped(|ui| {
+        for variant in [
+            egui_cha_ds::ThemeVariant::Light,
+            egui_cha_ds::ThemeVariant::Dark,
+        ] {
+            for accent in accents {
+                let theme = Theme::from_accent(accent, variant);
+                egui::Frame::new()
+                    .fill(theme.bg_primary)
+                    .stroke(egui::Stroke::new(1.0, theme.border))
+                    .inner_margin(8.0)
+                    .corner_radius(8.0)
+                    .show(ui, |ui| {
+                        egui::Frame::new()
+                            .fill(theme.primary)
+                            .inner_margin(6.0)
+                            .corner_radius(4.0)
+                            .show(ui, |ui| {
+                                ui.colored_label(theme.primary_text, "Aa");
+                            });
+                        ui.colored_label(theme.text_secondary, "text");
+                    });
+            }
+        }
+    });
 }
 
 /// Demo for Glass & Titlebar components (vibrancy feature)
@@ -2,6 +2,25 @@
 //!
 //! A component for BPM input via tap tempo and visual beat sync indicator.
 //! Supports manual BPM entry, tap detection, and beat phase visualization.
+//!
+//! # Tap tempo
+//! Tap history lives in [`SyncState::tap_tempo`]. Every TAP press emits
+//! [`BeatSyncEvent::Tap`], which the app feeds to [`SyncState::add_tap`].
+//! Once the history (including this tap) holds enough taps, the press also
+//! emits [`BeatSyncEvent::TempoDetected`] with the rolling average of the last
+//! few intervals. A tap much later than the running median (more than 2×)
+//! starts a new measurement.
+//!
+//! # Example
+//! ```ignore
+//! BeatSync::new(&model.sync).show_with(ctx, |event| match event {
+//!     BeatSyncEvent::Tap(time) => Msg::Tap(time), // model.sync.add_tap(time)
+//!     BeatSyncEvent::TempoDetected(bpm) | BeatSyncEvent::SetBpm(bpm) => Msg::SetBpm(bpm),
+//!     BeatSyncEvent::SetDivision(div) => Msg::SetDivision(div),
+//!     BeatSyncEvent::Resync => Msg::Resync,
+//!     _ => Msg::Noop,
+//! });
+//! ```
 
 use crate::Theme;
 use egui::{Pos2, Rect, Sense, Stroke, Ui, Vec2};
@@ -54,6 +73,129 @@ impl BeatDivision {
     }
 }
 
+/// Number of intervals averaged for tap tempo
+const TAP_WINDOW: usize = 4;
+
+/// Taps needed before a tempo is reported
+const TAP_MIN_TAPS: usize = 3;
+
+/// Taps are considered unrelated beyond this gap (seconds, = 20 BPM)
+const MAX_TAP_GAP: f64 = 3.0;
+
+/// Tap tempo detector
+///
+/// Averages the last [`window`](Self::window) intervals. An interval more than
+/// twice the median of the current intervals (or longer than 3s) resets the
+/// averaging, so a pause between phrases doesn't drag the tempo down.
+#[derive(Debug, Clone)]
+pub struct TapTempo {
+    taps: Vec<f64>,
+    window: usize,
+    min_taps: usize,
+}
+
+impl Default for TapTempo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TapTempo {
+    /// Create a detector averaging 4 intervals, reporting after 3 taps
+    pub fn new() -> Self {
+        Self {
+            taps: Vec::new(),
+            window: TAP_WINDOW,
+            min_taps: TAP_MIN_TAPS,
+        }
+    }
+
+    /// Set the number of intervals averaged
+    pub fn window(mut self, intervals: usize) -> Self {
+        self.window = intervals.max(1);
+        self
+    }
+
+    /// Set the number of taps needed before a tempo is reported
+    pub fn min_taps(mut self, taps: usize) -> Self {
+        self.min_taps = taps.max(2);
+        self
+    }
+
+    /// Record a tap at `time` (seconds), returning the BPM once enough taps are in
+    pub fn tap(&mut self, time: f64) -> Option<f32> {
+        push_tap(&mut self.taps, time, self.window + 1);
+        self.bpm()
+    }
+
+    /// BPM of the current measurement, once enough taps are in
+    pub fn bpm(&self) -> Option<f32> {
+        if self.taps.len() >= self.min_taps {
+            average_bpm(&self.taps)
+        } else {
+            None
+        }
+    }
+
+    /// Number of taps in the current measurement
+    pub fn len(&self) -> usize {
+        self.taps.len()
+    }
+
+    /// Whether no taps are recorded
+    pub fn is_empty(&self) -> bool {
+        self.taps.is_empty()
+    }
+
+    /// Forget all taps
+    pub fn reset(&mut self) {
+        self.taps.clear();
+    }
+}
+
+/// Push a tap, restarting on outliers and keeping at most `max_taps`
+fn push_tap(taps: &mut Vec<f64>, time: f64, max_taps: usize) {
+    if let Some(&last) = taps.last() {
+        let interval = time - last;
+        let intervals = tap_intervals(taps);
+        let outlier = interval <= 0.0
+            || interval > MAX_TAP_GAP
+            || (!intervals.is_empty() && interval > 2.0 * median(&intervals));
+        if outlier {
+            taps.clear();
+        }
+    }
+    taps.push(time);
+    if taps.len() > max_taps {
+        taps.drain(..taps.len() - max_taps);
+    }
+}
+
+fn tap_intervals(taps: &[f64]) -> Vec<f64> {
+    taps.windows(2).map(|w| w[1] - w[0]).collect()
+}
+
+fn median(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// BPM from the average interval between taps
+fn average_bpm(taps: &[f64]) -> Option<f32> {
+    let intervals = tap_intervals(taps);
+    if intervals.is_empty() {
+        return None;
+    }
+    let avg_interval = intervals.iter().sum::<f64>() / intervals.len() as f64;
+    (avg_interval > 0.0).then(|| (60.0 / avg_interval as f32).clamp(20.0, 300.0))
+}
+
 /// Sync state data
 #[derive(Debug, Clone)]
 pub struct SyncState {
@@ -62,7 +204,7 @@ pub struct SyncState {
     pub beat_count: u32,
     pub division: BeatDivision,
     pub is_synced: bool,
-    pub tap_times: Vec<f64>,
+    pub tap_tempo: TapTempo,
 }
 
 impl SyncState {
@@ -73,7 +215,7 @@ impl SyncState {
             beat_count: 0,
             division: BeatDivision::Quarter,
             is_synced: false,
-            tap_times: Vec::new(),
+            tap_tempo: TapTempo::new(),
         }
     }
 
@@ -90,33 +232,21 @@ impl SyncState {
         }
     }
 
+    /// Record a tap in [`tap_tempo`](Self::tap_tempo) and update the BPM
     pub fn add_tap(&mut self, time: f64) {
-        self.tap_times.push(time);
-        if self.tap_times.len() > 8 {
-            self.tap_times.remove(0);
-        }
-        if self.tap_times.len() >= 2 {
-            self.calculate_bpm_from_taps();
+        if let Some(bpm) = self.tap_tempo.tap(time) {
+            self.bpm = bpm;
+            self.is_synced = true;
         }
     }
 
-    fn calculate_bpm_from_taps(&mut self) {
-        if self.tap_times.len() < 2 {
-            return;
-        }
-        let mut intervals: Vec<f64> = Vec::new();
-        for i in 1..self.tap_times.len() {
-            intervals.push(self.tap_times[i] - self.tap_times[i - 1]);
-        }
-        let avg_interval = intervals.iter().sum::<f64>() / intervals.len() as f64;
-        if avg_interval > 0.0 {
-            self.bpm = (60.0 / avg_interval as f32).clamp(20.0, 300.0);
-            self.is_synced = true;
-        }
+    /// BPM measured from the tap history, once enough taps are in
+    pub fn tapped_bpm(&self) -> Option<f32> {
+        self.tap_tempo.bpm()
     }
 
     pub fn reset_taps(&mut self) {
-        self.tap_times.clear();
+        self.tap_tempo.reset();
         self.is_synced = false;
     }
 
@@ -135,7 +265,10 @@ impl Default for SyncState {
 /// Events emitted by BeatSync
 #[derive(Debug, Clone)]
 pub enum BeatSyncEvent {
+    /// TAP pressed (time in seconds); feed it to [`SyncState::add_tap`]
     Tap(f64),
+    /// The tap history, including the tap emitted just before, measures this BPM
+    TempoDetected(f32),
     SetBpm(f32),
     SetDivision(BeatDivision),
     Resync,
@@ -205,23 +338,31 @@ impl<'a> BeatSync<'a> {
         ctx: &mut ViewCtx<'_, Msg>,
         on_event: impl Fn(BeatSyncEvent) -> Msg,
     ) {
-        if let Some(e) = self.show_internal(ctx.ui) {
+        for e in self.show_internal(ctx.ui) {
             ctx.emit(on_event(e));
         }
     }
 
+    /// Show and return the first event (a TAP press returns [`BeatSyncEvent::Tap`])
+    ///
+    /// Use [`show_events`](Self::show_events) to also receive `TempoDetected`.
     pub fn show(self, ui: &mut Ui) -> Option<BeatSyncEvent> {
+        self.show_internal(ui).into_iter().next()
+    }
+
+    /// Show and return all events of this frame
+    pub fn show_events(self, ui: &mut Ui) -> Vec<BeatSyncEvent> {
         self.show_internal(ui)
     }
 
-    fn show_internal(self, ui: &mut Ui) -> Option<BeatSyncEvent> {
+    fn show_internal(self, ui: &mut Ui) -> Vec<BeatSyncEvent> {
         let theme = Theme::current(ui.ctx());
         let mut event: Option<BeatSyncEvent> = None;
 
         let (rect, _response) = ui.allocate_exact_size(self.size, Sense::hover());
 
         if !ui.is_rect_visible(rect) {
-            return None;
+            return Vec::new();
         }
 
         if self.compact {
@@ -369,7 +510,7 @@ impl<'a> BeatSync<'a> {
             );
 
             // Tap count indicator
-            let tap_count = self.state.tap_times.len();
+            let tap_count = self.state.tap_tempo.len();
             if tap_count > 0 {
                 let dots_y = tap_rect.max.y - theme.spacing_xs;
                 for i in 0..tap_count.min(8) {
@@ -539,8 +680,7 @@ impl<'a> BeatSync<'a> {
 
         // Process events
         if interactions.tap_clicked {
-            let time = ui.input(|i| i.time);
-            event = Some(BeatSyncEvent::Tap(time));
+            return self.tap_events(ui.input(|i| i.time));
         } else if interactions.resync_clicked {
             event = Some(BeatSyncEvent::Resync);
         } else if interactions.minus_clicked {
@@ -555,12 +695,10 @@ impl<'a> BeatSync<'a> {
             event = Some(BeatSyncEvent::NudgePhase(0.05));
         }
 
-        event
+        event.into_iter().collect()
     }
 
-    fn show_compact(self, ui: &mut Ui, rect: Rect, theme: &Theme) -> Option<BeatSyncEvent> {
-        let mut event: Option<BeatSyncEvent> = None;
-
+    fn show_compact(self, ui: &mut Ui, rect: Rect, theme: &Theme) -> Vec<BeatSyncEvent> {
         // Tap area (left half)
         let tap_rect = Rect::from_min_size(rect.min, Vec2::new(rect.width() * 0.4, rect.height()));
         let tap_resp = ui.allocate_rect(tap_rect, Sense::click());
@@ -619,10 +757,106 @@ impl<'a> BeatSync<'a> {
         );
 
         if tap_resp.clicked() {
-            let time = ui.input(|i| i.time);
-            event = Some(BeatSyncEvent::Tap(time));
+            return self.tap_events(ui.input(|i| i.time));
         }
 
-        event
+        Vec::new()
+    }
+
+    /// `Tap`, followed by `TempoDetected` if the model's history with this tap measures a tempo
+    fn tap_events(&self, time: f64) -> Vec<BeatSyncEvent> {
+        let mut next = self.state.clone();
+        next.add_tap(time);
+        let mut events = vec![BeatSyncEvent::Tap(time)];
+        events.extend(next.tapped_bpm().map(BeatSyncEvent::TempoDetected));
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tap_tempo_detects_120_bpm() {
+        let mut tempo = TapTempo::new();
+        let mut detected = None;
+        for i in 0..6 {
+            detected = tempo.tap(i as f64 * 0.5);
+        }
+        let bpm = detected.expect("tempo detected");
+        assert!((bpm - 120.0).abs() < 0.5, "got {bpm}");
+    }
+
+    #[test]
+    fn test_tap_tempo_needs_min_taps() {
+        let mut tempo = TapTempo::new();
+        assert_eq!(tempo.tap(0.0), None);
+        assert_eq!(tempo.tap(0.5), None);
+        assert!(tempo.tap(1.0).is_some());
+    }
+
+    #[test]
+    fn test_outlier_resets_averaging() {
+        let mut tempo = TapTempo::new();
+        for i in 0..4 {
+            tempo.tap(i as f64 * 0.5);
+        }
+        // Long pause (> 2x median) starts a new measurement
+        assert_eq!(tempo.tap(1.5 + 1.2), None);
+        assert_eq!(tempo.len(), 1);
+
+        // New tempo at 100 BPM is not averaged with the old one
+        let mut detected = None;
+        for i in 1..5 {
+            detected = tempo.tap(2.7 + i as f64 * 0.6);
+        }
+        assert!((detected.unwrap() - 100.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_sync_state_averages_tap_window() {
+        let mut state = SyncState::new(90.0);
+
+        // 100 BPM, then 120 BPM: only the last 4 intervals are averaged
+        let mut time = 0.0;
+        for _ in 0..4 {
+            state.add_tap(time);
+            time += 0.6;
+        }
+        time -= 0.1;
+        for _ in 0..TAP_WINDOW {
+            state.add_tap(time);
+            time += 0.5;
+        }
+
+        assert!((state.bpm - 120.0).abs() < 0.5, "got {}", state.bpm);
+        assert!(state.is_synced);
+        assert_eq!(state.tap_tempo.len(), TAP_WINDOW + 1);
+    }
+
+    #[test]
+    fn test_tap_events_follow_model_history() {
+        let mut state = SyncState::new(100.0);
+        let mut detected = Vec::new();
+        for i in 0..4 {
+            let time = i as f64 * 0.5;
+            let events = BeatSync::new(&state).tap_events(time);
+            assert!(matches!(events[0], BeatSyncEvent::Tap(t) if t == time));
+            detected.push(events.get(1).cloned());
+
+            // The app feeds every Tap back into the model
+            state.add_tap(time);
+        }
+
+        assert!(detected[0].is_none() && detected[1].is_none());
+        for event in &detected[2..] {
+            let Some(BeatSyncEvent::TempoDetected(bpm)) = event else {
+                panic!("expected TempoDetected, got {event:?}");
+            };
+            assert!((bpm - 120.0).abs() < 0.5, "got {bpm}");
+        }
+        assert_eq!(state.tap_tempo.len(), 4);
+        assert!((state.bpm - 120.0).abs() < 0.5);
     }
 }
@@ -10,10 +10,24 @@
 //! LevelMeter::new()
 //!     .stereo(true)
 //!     .show_stereo_with_peak(ui, left_db, right_db, left_peak, right_peak);
+//!
+//! // Peak + RMS with a clip latch (click the meter to reset)
+//! LevelMeter::new()
+//!     .with_rms(rms_db)
+//!     .clip()
+//!     .id_salt("master")
+//!     .show(ui, peak_db);
+//!
+//! // L/R pair sharing one scale, with decaying peak-hold lines
+//! LevelMeter::new()
+//!     .size(16.0, 120.0)
+//!     .hold_time(1.5)
+//!     .stereo_pair(left_db, right_db)
+//!     .show(ui);
 //! ```
 
 use crate::Theme;
-use egui::{Color32, Rect, Response, Sense, Stroke, Ui, Vec2};
+use egui::{Color32, Id, Rect, Response, Sense, Stroke, Ui, Vec2};
 use egui_cha::ViewCtx;
 
 /// Level meter display mode
@@ -26,6 +40,8 @@ pub enum MeterMode {
     Peak,
     /// RMS meter showing average level
     RMS,
+    /// Peak bar with a narrower RMS bar inside
+    PeakRms,
 }
 
 /// Level meter orientation
@@ -57,6 +73,15 @@ pub struct LevelMeter {
     yellow_threshold: f32,
     /// Threshold for red zone (dB)
     red_threshold: f32,
+    /// Seconds a peak is held before decaying
+    hold_time: f32,
+    /// Peak decay rate after hold (dB per second)
+    peak_decay: f32,
+    /// RMS levels (left, right) for `MeterMode::PeakRms`
+    rms: Option<(f32, f32)>,
+    /// Latch a clip indicator when a level reaches 0 dBFS
+    clip: bool,
+    id_salt: Option<Id>,
 }
 
 impl Default for LevelMeter {
@@ -73,6 +98,11 @@ impl Default for LevelMeter {
             max_db: 6.0,
             yellow_threshold: -12.0,
             red_threshold: -3.0,
+            hold_time: 1.5,
+            peak_decay: 20.0,
+            rms: None,
+            clip: false,
+            id_salt: None,
         }
     }
 }
@@ -139,6 +169,69 @@ impl LevelMeter {
         self
     }
 
+    /// Set how long a peak is held before it starts to decay (seconds)
+    pub fn hold_time(mut self, seconds: f32) -> Self {
+        self.hold_time = seconds.max(0.0);
+        self
+    }
+
+    /// Set the peak decay rate after the hold time (dB per second)
+    pub fn peak_decay(mut self, db_per_sec: f32) -> Self {
+        self.peak_decay = db_per_sec.max(0.0);
+        self
+    }
+
+    /// Show an RMS bar inside the peak bar (switches to [`MeterMode::PeakRms`])
+    pub fn with_rms(self, rms_db: f32) -> Self {
+        self.with_rms_stereo(rms_db, rms_db)
+    }
+
+    /// Show per-channel RMS bars inside the peak bars (stereo mode)
+    pub fn with_rms_stereo(mut self, left_rms_db: f32, right_rms_db: f32) -> Self {
+        self.rms = Some((left_rms_db, right_rms_db));
+        self.mode = MeterMode::PeakRms;
+        self
+    }
+
+    /// Enable the clip indicator
+    ///
+    /// Once a level reaches 0 dBFS, a red segment lights at the top of the
+    /// meter and stays lit until the meter is clicked or
+    /// [`reset_clip`](Self::reset_clip) is called.
+    pub fn clip(mut self) -> Self {
+        self.clip = true;
+        self
+    }
+
+    /// Set the id used to store the clip latch (defaults to an auto id)
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// Whether the clip latch for the meter with `id_salt` is lit
+    pub fn is_clipped(ctx: &egui::Context, id_salt: impl std::hash::Hash) -> bool {
+        ctx.data(|d| d.get_temp(clip_id(Id::new(id_salt))).unwrap_or(false))
+    }
+
+    /// Clear the clip latch for the meter with `id_salt`
+    pub fn reset_clip(ctx: &egui::Context, id_salt: impl std::hash::Hash) {
+        ctx.data_mut(|d| d.insert_temp(clip_id(Id::new(id_salt)), false));
+    }
+
+    /// Build an L/R meter pair sharing a single dB scale
+    ///
+    /// Each channel uses this meter's size; peak hold is tracked
+    /// internally using [`hold_time`](Self::hold_time) and
+    /// [`peak_decay`](Self::peak_decay).
+    pub fn stereo_pair(self, left_db: f32, right_db: f32) -> StereoMeter {
+        StereoMeter {
+            meter: self,
+            left_db,
+            right_db,
+        }
+    }
+
     /// Convert dB to normalized 0.0-1.0 value
     fn db_to_normalized(&self, db: f32) -> f32 {
         let clamped = db.clamp(self.min_db, self.max_db);
@@ -206,8 +299,26 @@ impl LevelMeter {
         let scale_width = if self.show_scale { 24.0 } else { 0.0 };
         let total_width = self.width + scale_width;
 
-        let (rect, response) =
-            ui.allocate_exact_size(Vec2::new(total_width, self.height), Sense::hover());
+        let sense = if self.clip {
+            Sense::click()
+        } else {
+            Sense::hover()
+        };
+        let (rect, response) = ui.allocate_exact_size(Vec2::new(total_width, self.height), sense);
+
+        // Clip latch
+        let clipped = self.clip && {
+            let id = clip_id(self.id_salt.unwrap_or(response.id));
+            let mut latched = ui.ctx().data(|d| d.get_temp(id).unwrap_or(false));
+            if response.clicked() {
+                latched = false;
+            }
+            if left_db >= 0.0 || right_db >= 0.0 {
+                latched = true;
+            }
+            ui.ctx().data_mut(|d| d.insert_temp(id, latched));
+            latched
+        };
 
         if !ui.is_rect_visible(rect) {
             return response;
@@ -225,14 +336,7 @@ impl LevelMeter {
             rect
         };
 
-        // Background
-        painter.rect_filled(meter_rect, 2.0, theme.bg_tertiary);
-        painter.rect_stroke(
-            meter_rect,
-            2.0,
-            Stroke::new(1.0, theme.border),
-            egui::StrokeKind::Outside,
-        );
+        draw_background(painter, meter_rect, &theme);
 
         // Draw scale if enabled
         if self.show_scale {
@@ -249,16 +353,51 @@ impl LevelMeter {
             // Left channel
             let left_rect =
                 Rect::from_min_size(inner_rect.min, Vec2::new(bar_width, inner_rect.height()));
-            self.draw_meter_bar(ui, left_rect, left_db, peaks.map(|(l, _)| l), &theme);
+            self.draw_meter_bar(
+                ui,
+                left_rect,
+                left_db,
+                peaks.map(|(l, _)| l),
+                self.rms.map(|(l, _)| l),
+                &theme,
+            );
 
             // Right channel
             let right_rect = Rect::from_min_size(
                 inner_rect.min + Vec2::new(bar_width + 2.0, 0.0),
                 Vec2::new(bar_width, inner_rect.height()),
             );
-            self.draw_meter_bar(ui, right_rect, right_db, peaks.map(|(_, r)| r), &theme);
+            self.draw_meter_bar(
+                ui,
+                right_rect,
+                right_db,
+                peaks.map(|(_, r)| r),
+                self.rms.map(|(_, r)| r),
+                &theme,
+            );
         } else {
-            self.draw_meter_bar(ui, inner_rect, left_db, peaks.map(|(l, _)| l), &theme);
+            self.draw_meter_bar(
+                ui,
+                inner_rect,
+                left_db,
+                peaks.map(|(l, _)| l),
+                self.rms.map(|(l, _)| l),
+                &theme,
+            );
+        }
+
+        if clipped {
+            let clip_rect = match self.orientation {
+                MeterOrientation::Vertical => Rect::from_min_size(
+                    inner_rect.min,
+                    Vec2::new(inner_rect.width(), CLIP_SEGMENT_SIZE),
+                ),
+                MeterOrientation::Horizontal => Rect::from_min_max(
+                    egui::pos2(inner_rect.max.x - CLIP_SEGMENT_SIZE, inner_rect.min.y),
+                    inner_rect.max,
+                ),
+            };
+            ui.painter().rect_filled(clip_rect, 1.0, theme.state_danger);
         }
 
         response
@@ -270,6 +409,7 @@ impl LevelMeter {
         rect: Rect,
         level_db: f32,
         peak_db: Option<f32>,
+        rms_db: Option<f32>,
         theme: &Theme,
     ) {
         let painter = ui.painter();
@@ -354,6 +494,40 @@ impl LevelMeter {
                 }
             }
         }
+
+        // RMS inner bar
+        if self.mode == MeterMode::PeakRms {
+            if let Some(rms) = rms_db {
+                painter.rect_filled(self.rms_rect(rect, rms), 1.0, theme.text_primary);
+            }
+        }
+    }
+
+    /// Inner bar for an RMS level, narrower than the peak bar
+    fn rms_rect(&self, rect: Rect, rms_db: f32) -> Rect {
+        let normalized = self.db_to_normalized(rms_db);
+        match self.orientation {
+            MeterOrientation::Vertical => {
+                let width = rect.width() * RMS_BAR_RATIO;
+                Rect::from_min_max(
+                    egui::pos2(
+                        rect.center().x - width / 2.0,
+                        rect.max.y - normalized * rect.height(),
+                    ),
+                    egui::pos2(rect.center().x + width / 2.0, rect.max.y),
+                )
+            }
+            MeterOrientation::Horizontal => {
+                let height = rect.height() * RMS_BAR_RATIO;
+                Rect::from_min_max(
+                    egui::pos2(rect.min.x, rect.center().y - height / 2.0),
+                    egui::pos2(
+                        rect.min.x + normalized * rect.width(),
+                        rect.center().y + height / 2.0,
+                    ),
+                )
+            }
+        }
     }
 
     fn draw_scale(&self, ui: &mut Ui, rect: Rect, theme: &Theme) {
@@ -393,3 +567,293 @@ impl LevelMeter {
         }
     }
 }
+
+/// Width of the RMS bar relative to the peak bar
+const RMS_BAR_RATIO: f32 = 0.4;
+
+/// Thickness of the clip indicator segment
+const CLIP_SEGMENT_SIZE: f32 = 4.0;
+
+fn clip_id(id: Id) -> Id {
+    id.with("level_meter_clip")
+}
+
+/// Tick marks for the shared stereo scale (`min_db` is labelled -∞)
+const STEREO_SCALE_MARKS: [f32; 4] = [0.0, -6.0, -12.0, -24.0];
+
+/// Space reserved between the two channels for the shared scale
+const STEREO_SCALE_SIZE: f32 = 24.0;
+
+/// Peak-hold tracker for one channel
+///
+/// Holds the highest level for a while, then decays linearly
+/// towards the current level.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PeakHold {
+    peak_db: f32,
+    held_at: f64,
+}
+
+impl PeakHold {
+    /// Create a tracker starting at `db`
+    pub fn new(db: f32) -> Self {
+        Self {
+            peak_db: db,
+            held_at: 0.0,
+        }
+    }
+
+    /// Feed the current level at time `now` (seconds), returning the displayed peak
+    pub fn update(&mut self, level_db: f32, now: f64, hold_time: f32, decay: f32) -> f32 {
+        let current = self.value(now, hold_time, decay);
+        if level_db >= current {
+            self.peak_db = level_db;
+            self.held_at = now;
+            return level_db;
+        }
+        current
+    }
+
+    /// Displayed peak at time `now` without feeding a new level
+    pub fn value(&self, now: f64, hold_time: f32, decay: f32) -> f32 {
+        let elapsed = (now - self.held_at) as f32;
+        if elapsed <= hold_time {
+            self.peak_db
+        } else {
+            self.peak_db - (elapsed - hold_time) * decay
+        }
+    }
+}
+
+/// L/R level meter pair with a shared scale (see [`LevelMeter::stereo_pair`])
+#[derive(Debug, Clone)]
+pub struct StereoMeter {
+    meter: LevelMeter,
+    left_db: f32,
+    right_db: f32,
+}
+
+impl StereoMeter {
+    /// TEA-style: Show the stereo pair
+    pub fn show_with<Msg>(&self, ctx: &mut ViewCtx<'_, Msg>) -> Response {
+        self.show(ctx.ui)
+    }
+
+    /// Display the stereo pair
+    pub fn show(&self, ui: &mut Ui) -> Response {
+        let theme = Theme::current(ui.ctx());
+        let meter = &self.meter;
+        let scale = if meter.show_scale {
+            STEREO_SCALE_SIZE
+        } else {
+            2.0
+        };
+
+        let (channel_size, step, total) = match meter.orientation {
+            MeterOrientation::Vertical => (
+                Vec2::new(meter.width, meter.height),
+                Vec2::new(meter.width + scale, 0.0),
+                Vec2::new(meter.width * 2.0 + scale, meter.height),
+            ),
+            MeterOrientation::Horizontal => (
+                Vec2::new(meter.width, meter.height),
+                Vec2::new(0.0, meter.height + scale),
+                Vec2::new(meter.width, meter.height * 2.0 + scale),
+            ),
+        };
+
+        let (rect, response) = ui.allocate_exact_size(total, Sense::hover());
+
+        // Peak hold state
+        let now = ui.input(|i| i.time);
+        let hold_id = response.id.with("peak_hold");
+        let (mut left_hold, mut right_hold) = ui.ctx().data_mut(|d| {
+            d.get_temp(hold_id)
+                .unwrap_or((PeakHold::new(meter.min_db), PeakHold::new(meter.min_db)))
+        });
+        let left_peak = left_hold.update(self.left_db, now, meter.hold_time, meter.peak_decay);
+        let right_peak = right_hold.update(self.right_db, now, meter.hold_time, meter.peak_decay);
+        ui.ctx()
+            .data_mut(|d| d.insert_temp(hold_id, (left_hold, right_hold)));
+
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+
+        let left_rect = Rect::from_min_size(rect.min, channel_size);
+        let right_rect = Rect::from_min_size(rect.min + step, channel_size);
+
+        for (channel_rect, level, peak, rms) in [
+            (
+                left_rect,
+                self.left_db,
+                left_peak,
+                meter.rms.map(|(l, _)| l),
+            ),
+            (
+                right_rect,
+                self.right_db,
+                right_peak,
+                meter.rms.map(|(_, r)| r),
+            ),
+        ] {
+            draw_background(ui.painter(), channel_rect, &theme);
+            meter.draw_meter_bar(ui, channel_rect.shrink(2.0), level, Some(peak), rms, &theme);
+        }
+
+        if meter.show_scale {
+            self.draw_shared_scale(ui, left_rect, right_rect, &theme);
+        }
+
+        response
+    }
+
+    fn draw_shared_scale(&self, ui: &mut Ui, left: Rect, right: Rect, theme: &Theme) {
+        let meter = &self.meter;
+        let painter = ui.painter();
+        let stroke = Stroke::new(1.0, theme.text_secondary);
+        let font = egui::FontId::proportional(9.0);
+
+        let marks = STEREO_SCALE_MARKS
+            .iter()
+            .filter(|&&db| db > meter.min_db && db <= meter.max_db)
+            .map(|&db| (db, format!("{}", db as i32)))
+            .chain(std::iter::once((meter.min_db, "-∞".to_string())));
+
+        for (db, label) in marks {
+            let t = meter.db_to_normalized(db);
+            match meter.orientation {
+                MeterOrientation::Vertical => {
+                    let y = left.max.y - t * left.height();
+                    painter.hline(left.max.x + 1.0..=left.max.x + 4.0, y, stroke);
+                    painter.hline(right.min.x - 4.0..=right.min.x - 1.0, y, stroke);
+                    painter.text(
+                        egui::pos2((left.max.x + right.min.x) / 2.0, y),
+                        egui::Align2::CENTER_CENTER,
+                        label,
+                        font.clone(),
+                        theme.text_secondary,
+                    );
+                }
+                MeterOrientation::Horizontal => {
+                    let x = left.min.x + t * left.width();
+                    painter.vline(x, left.max.y + 1.0..=left.max.y + 4.0, stroke);
+                    painter.vline(x, right.min.y - 4.0..=right.min.y - 1.0, stroke);
+                    painter.text(
+                        egui::pos2(x, (left.max.y + right.min.y) / 2.0),
+                        egui::Align2::CENTER_CENTER,
+                        label,
+                        font.clone(),
+                        theme.text_secondary,
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn draw_background(painter: &egui::Painter, rect: Rect, theme: &Theme) {
+    painter.rect_filled(rect, 2.0, theme.bg_tertiary);
+    painter.rect_stroke(
+        rect,
+        2.0,
+        Stroke::new(1.0, theme.border),
+        egui::StrokeKind::Outside,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{run_ui, test_context};
+
+    #[test]
+    fn test_stereo_pair_reserves_two_channels() {
+        let ctx = test_context();
+        let mut width = 0.0;
+
+        run_ui(&ctx, |ui| {
+            width = LevelMeter::new()
+                .size(16.0, 100.0)
+                .stereo_pair(-12.0, -6.0)
+                .show(ui)
+                .rect
+                .width();
+        });
+
+        assert_eq!(width, 16.0 * 2.0 + STEREO_SCALE_SIZE);
+    }
+
+    #[test]
+    fn test_zero_db_latches_clip() {
+        let ctx = test_context();
+        let meter = LevelMeter::new().clip().id_salt("master");
+
+        run_ui(&ctx, |ui| {
+            meter.show(ui, -12.0);
+        });
+        assert!(!LevelMeter::is_clipped(&ctx, "master"));
+
+        run_ui(&ctx, |ui| {
+            meter.show(ui, 0.0);
+        });
+        run_ui(&ctx, |ui| {
+            meter.show(ui, -20.0);
+        });
+        assert!(LevelMeter::is_clipped(&ctx, "master"));
+
+        LevelMeter::reset_clip(&ctx, "master");
+        assert!(!LevelMeter::is_clipped(&ctx, "master"));
+    }
+
+    #[test]
+    fn test_rms_renders_below_peak() {
+        let ctx = test_context();
+        let output = run_ui(&ctx, |ui| {
+            LevelMeter::new().with_rms(-18.0).show(ui, -6.0);
+        });
+
+        let theme = Theme::current(&ctx);
+        let rms_top = output
+            .shapes
+            .iter()
+            .find_map(|clipped| match &clipped.shape {
+                egui::Shape::Rect(r) if r.fill == theme.text_primary => Some(r.rect.top()),
+                _ => None,
+            })
+            .expect("RMS bar is painted");
+
+        let lit_segment_tops: Vec<f32> = output
+            .shapes
+            .iter()
+            .filter_map(|clipped| match &clipped.shape {
+                egui::Shape::Rect(r)
+                    if r.fill == theme.state_success || r.fill == theme.state_warning =>
+                {
+                    Some(r.rect.top())
+                }
+                _ => None,
+            })
+            .collect();
+        let peak_top = lit_segment_tops.iter().cloned().fold(f32::MAX, f32::min);
+
+        assert!(rms_top > peak_top, "RMS bar should end below the peak bar");
+    }
+
+    #[test]
+    fn test_peak_hold_persists_for_hold_time() {
+        let mut hold = PeakHold::new(-60.0);
+        assert_eq!(hold.update(0.0, 0.0, 1.0, 20.0), 0.0);
+
+        // Transient is over, but the peak is still held
+        assert_eq!(hold.update(-40.0, 0.5, 1.0, 20.0), 0.0);
+        assert_eq!(hold.update(-40.0, 1.0, 1.0, 20.0), 0.0);
+
+        // Decays after the hold time
+        let decayed = hold.update(-40.0, 1.5, 1.0, 20.0);
+        assert!((decayed - -10.0).abs() < 1e-4);
+
+        // Never drops below the live level
+        assert_eq!(hold.update(-40.0, 10.0, 1.0, 20.0), -40.0);
+    }
+}
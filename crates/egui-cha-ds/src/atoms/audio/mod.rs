@@ -13,12 +13,12 @@ mod step_seq;
 mod transport;
 mod waveform;
 
-pub use beat_sync::{BeatDivision, BeatSync, BeatSyncEvent, SyncState};
+pub use beat_sync::{BeatDivision, BeatSync, BeatSyncEvent, SyncState, TapTempo};
 pub use bpm_display::{BpmDisplay, DisplaySize, DisplayStyle};
-pub use level_meter::{LevelMeter, MeterMode, MeterOrientation};
+pub use level_meter::{LevelMeter, MeterMode, MeterOrientation, PeakHold, StereoMeter};
 pub use oscilloscope::{Oscilloscope, ScopeMode, TriggerMode};
 pub use sample_pad::{PadCell, PadEvent, SamplePad};
 pub use spectrum::{Spectrum, SpectrumColorMode};
 pub use step_seq::{StepEvent, StepSeq, StepValue};
 pub use transport::{BeatIndicator, TransportBar, TransportEvent};
-pub use waveform::{Waveform, WaveformStyle};
+pub use waveform::{Waveform, WaveformEvent, WaveformStyle};
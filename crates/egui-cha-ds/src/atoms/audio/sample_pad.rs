@@ -4,24 +4,40 @@
 //!
 //! # Example
 //! ```ignore
-//! SamplePad::new(4, 4)  // 4x4 grid
-//!     .labels(&["Kick", "Snare", "HiHat", "Clap", ...])
-//!     .active(&[0, 5])  // Currently playing pads
+//! let pads = [
+//!     PadCell::new("Kick").with_color(Color32::RED),
+//!     PadCell::new("Snare"),
+//!     PadCell::new("HH Closed").with_choke_group(1),
+//!     PadCell::new("HH Open").with_choke_group(1),
+//! ];
+//!
+//! SamplePad::new(&pads)
+//!     .columns(4)
+//!     .active(&model.playing)  // Currently playing pads
+//!     .velocity_sensitive(true)
 //!     .show_with(ctx, |event| match event {
-//!         PadEvent::Trigger(idx) => Msg::TriggerSample(idx),
+//!         PadEvent::Trigger { index, velocity } => Msg::Play(index, velocity),
+//!         PadEvent::Release { index } => Msg::Stop(index),
 //!         PadEvent::Select(idx) => Msg::SelectPad(idx),
 //!     });
 //! ```
+//!
+//! # Playing
+//! Pressing a pad emits `Trigger` and letting go emits `Release`. Triggering
+//! a pad first releases any active pad sharing its choke group, so an open
+//! hi-hat is cut off by the closed one.
 
 use crate::Theme;
-use egui::{Color32, Rect, Sense, Stroke, Ui, Vec2};
+use egui::{Color32, Id, Rect, Sense, Stroke, Ui, Vec2};
 use egui_cha::ViewCtx;
 
 /// Events from pad interactions
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum PadEvent {
-    /// Pad triggered (primary click)
-    Trigger(usize),
+    /// Pad pressed, with velocity (0.0 - 1.0)
+    Trigger { index: usize, velocity: f32 },
+    /// Pad let go, or choked by another pad in its group
+    Release { index: usize },
     /// Pad selected (secondary click or shift+click)
     Select(usize),
 }
@@ -37,6 +53,8 @@ pub struct PadCell {
     pub velocity: f32,
     /// Whether this pad is assigned/has content
     pub assigned: bool,
+    /// Pads in the same choke group cut each other off
+    pub choke_group: Option<u8>,
 }
 
 impl PadCell {
@@ -52,6 +70,7 @@ impl PadCell {
             color: None,
             velocity: 1.0,
             assigned: true,
+            choke_group: None,
         }
     }
 
@@ -66,14 +85,19 @@ impl PadCell {
         self.velocity = velocity.clamp(0.0, 1.0);
         self
     }
+
+    /// Put the pad in a choke group
+    pub fn with_choke_group(mut self, group: u8) -> Self {
+        self.choke_group = Some(group);
+        self
+    }
 }
 
 /// MPC-style sample trigger pad grid
 pub struct SamplePad<'a> {
     cols: usize,
-    rows: usize,
-    pads: Option<&'a [PadCell]>,
-    labels: Option<&'a [&'a str]>,
+    rows: Option<usize>,
+    pads: &'a [PadCell],
     active: &'a [usize],
     selected: Option<usize>,
     pad_size: f32,
@@ -83,13 +107,12 @@ pub struct SamplePad<'a> {
 }
 
 impl<'a> SamplePad<'a> {
-    /// Create a new pad grid
-    pub fn new(cols: usize, rows: usize) -> Self {
+    /// Create a pad grid for the given pads (4 columns by default)
+    pub fn new(pads: &'a [PadCell]) -> Self {
         Self {
-            cols: cols.max(1),
-            rows: rows.max(1),
-            pads: None,
-            labels: None,
+            cols: 4,
+            rows: None,
+            pads,
             active: &[],
             selected: None,
             pad_size: 60.0,
@@ -99,15 +122,16 @@ impl<'a> SamplePad<'a> {
         }
     }
 
-    /// Provide pad cell data
-    pub fn pads(mut self, pads: &'a [PadCell]) -> Self {
-        self.pads = Some(pads);
+    /// Set the number of columns
+    pub fn columns(mut self, cols: usize) -> Self {
+        self.cols = cols.max(1);
         self
     }
 
-    /// Simple labels (creates basic PadCells internally)
-    pub fn labels(mut self, labels: &'a [&'a str]) -> Self {
-        self.labels = Some(labels);
+    /// Set a fixed grid size; cells without a pad are drawn empty
+    pub fn grid(mut self, cols: usize, rows: usize) -> Self {
+        self.cols = cols.max(1);
+        self.rows = Some(rows.max(1));
         self
     }
 
@@ -141,40 +165,55 @@ impl<'a> SamplePad<'a> {
         self
     }
 
-    /// Enable velocity sensitivity (visual feedback based on click position)
+    /// Derive velocity from the press height on the pad (top = soft,
+    /// bottom = hard) instead of each pad's own velocity
     pub fn velocity_sensitive(mut self, enabled: bool) -> Self {
         self.velocity_sensitive = enabled;
         self
     }
 
+    /// Velocity for a press at `y` on `pad`
+    fn velocity_at(&self, pad: &PadCell, pad_rect: Rect, y: f32) -> f32 {
+        if !self.velocity_sensitive {
+            return pad.velocity;
+        }
+        let t = ((y - pad_rect.min.y) / pad_rect.height()).clamp(0.0, 1.0);
+        0.1 + t * 0.9
+    }
+
     /// TEA-style: Show pad grid and emit events
     pub fn show_with<Msg>(self, ctx: &mut ViewCtx<'_, Msg>, on_event: impl Fn(PadEvent) -> Msg) {
-        if let Some(event) = self.render(ctx.ui) {
+        for event in self.render(ctx.ui) {
             ctx.emit(on_event(event));
         }
     }
 
-    /// Show pad grid, returns event if any
-    pub fn show(self, ui: &mut Ui) -> Option<PadEvent> {
+    /// Show pad grid, returns the events of this frame
+    pub fn show(self, ui: &mut Ui) -> Vec<PadEvent> {
         self.render(ui)
     }
 
-    fn render(self, ui: &mut Ui) -> Option<PadEvent> {
+    fn render(self, ui: &mut Ui) -> Vec<PadEvent> {
         let theme = Theme::current(ui.ctx());
         let time = ui.input(|i| i.time) as f32;
-        let mut event = None;
+        let mut events = Vec::new();
 
-        let total_pads = self.cols * self.rows;
+        let rows = self
+            .rows
+            .unwrap_or_else(|| self.pads.len().div_ceil(self.cols))
+            .max(1);
+        let total_pads = self.cols * rows;
         let total_width =
             self.cols as f32 * self.pad_size + (self.cols.saturating_sub(1)) as f32 * self.spacing;
         let total_height =
-            self.rows as f32 * self.pad_size + (self.rows.saturating_sub(1)) as f32 * self.spacing;
+            rows as f32 * self.pad_size + (rows.saturating_sub(1)) as f32 * self.spacing;
 
-        let (rect, _) =
+        let (rect, response) =
             ui.allocate_exact_size(Vec2::new(total_width, total_height), Sense::hover());
+        let held_id = response.id.with("held_pad");
 
         if !ui.is_rect_visible(rect) {
-            return None;
+            return events;
         }
 
         // First pass: collect pad info and handle interactions
@@ -189,11 +228,12 @@ impl<'a> SamplePad<'a> {
         }
 
         let mut pads_info: Vec<PadInfo> = Vec::with_capacity(total_pads);
+        let mut pressed: Option<(usize, f32)> = None;
 
         for idx in 0..total_pads {
             let col = idx % self.cols;
             // MPC-style: bottom-left is pad 1, so invert row order
-            let display_row = self.rows - 1 - (idx / self.cols);
+            let display_row = rows - 1 - (idx / self.cols);
 
             let pad_x = rect.min.x + col as f32 * (self.pad_size + self.spacing);
             let pad_y = rect.min.y + display_row as f32 * (self.pad_size + self.spacing);
@@ -201,17 +241,7 @@ impl<'a> SamplePad<'a> {
                 Rect::from_min_size(egui::pos2(pad_x, pad_y), Vec2::splat(self.pad_size));
 
             // Get pad data
-            let pad_data = if let Some(pads) = self.pads {
-                pads.get(idx).cloned().unwrap_or_default()
-            } else if let Some(labels) = self.labels {
-                if let Some(label) = labels.get(idx) {
-                    PadCell::new(*label)
-                } else {
-                    PadCell::empty()
-                }
-            } else {
-                PadCell::empty()
-            };
+            let pad_data = self.pads.get(idx).cloned().unwrap_or_default();
 
             let base_color = pad_data.color.unwrap_or(theme.primary);
             let is_active = self.active.contains(&idx);
@@ -220,14 +250,17 @@ impl<'a> SamplePad<'a> {
             // Allocate interactive area
             let response = ui.allocate_rect(pad_rect, Sense::click());
 
-            // Handle click events
-            if response.clicked() {
-                event = Some(PadEvent::Trigger(idx));
-            }
-            if response.secondary_clicked()
-                || (response.clicked() && ui.input(|i| i.modifiers.shift))
-            {
-                event = Some(PadEvent::Select(idx));
+            // Handle press/select
+            let shift = ui.input(|i| i.modifiers.shift);
+            if response.secondary_clicked() || (response.clicked() && shift) {
+                events.push(PadEvent::Select(idx));
+            } else if response.is_pointer_button_down_on() && !shift && pad_data.assigned {
+                let velocity = response
+                    .interact_pointer_pos()
+                    .map_or(pad_data.velocity, |pos| {
+                        self.velocity_at(&pad_data, pad_rect, pos.y)
+                    });
+                pressed = Some((idx, velocity));
             }
 
             pads_info.push(PadInfo {
@@ -241,6 +274,8 @@ impl<'a> SamplePad<'a> {
             });
         }
 
+        events.extend(self.press_events(ui, held_id, pressed));
+
         // Second pass: draw all pads
         let painter = ui.painter();
 
@@ -361,6 +396,114 @@ impl<'a> SamplePad<'a> {
             ui.ctx().request_repaint();
         }
 
-        event
+        events
+    }
+
+    /// Trigger when a pad is pressed (choking its group), Release once the
+    /// pointer lets go of it
+    fn press_events(&self, ui: &Ui, held_id: Id, pressed: Option<(usize, f32)>) -> Vec<PadEvent> {
+        let held: Option<usize> = ui.ctx().data(|d| d.get_temp(held_id)).flatten();
+        let mut events = Vec::new();
+        let next = match (held, pressed) {
+            (Some(index), Some((pressed_index, _))) if index == pressed_index => held,
+            (Some(index), _) => {
+                events.push(PadEvent::Release { index });
+                None
+            }
+            (None, Some((index, velocity))) => {
+                events.extend(
+                    self.choked_by(index)
+                        .map(|choked| PadEvent::Release { index: choked }),
+                );
+                events.push(PadEvent::Trigger { index, velocity });
+                Some(index)
+            }
+            (None, None) => None,
+        };
+        ui.ctx().data_mut(|d| d.insert_temp(held_id, next));
+        events
+    }
+
+    /// Active pads that share a choke group with `index`
+    fn choked_by(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        let group = self.pads.get(index).and_then(|p| p.choke_group);
+        self.active.iter().copied().filter(move |&other| {
+            other != index
+                && group.is_some()
+                && self.pads.get(other).and_then(|p| p.choke_group) == group
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, run_ui, test_context};
+
+    fn hihats() -> Vec<PadCell> {
+        vec![
+            PadCell::new("Kick"),
+            PadCell::new("HH Open").with_choke_group(1),
+            PadCell::new("HH Closed").with_choke_group(1),
+            PadCell::new("Snare"),
+        ]
+    }
+
+    /// Click the center of pad `index` in a single-row grid
+    fn click_pad(pads: &[PadCell], active: &[usize], index: usize) -> Vec<PadEvent> {
+        let ctx = test_context();
+        let mut rect = Rect::NOTHING;
+        run_ui(&ctx, |ui| {
+            rect = ui.scope(|ui| SamplePad::new(pads).show(ui)).response.rect;
+        });
+
+        let pos = rect.left_center() + Vec2::new(4.0 + 30.0 + index as f32 * 64.0, 0.0);
+        let mut events = Vec::new();
+        click_at(&ctx, pos, |ui| {
+            events.extend(SamplePad::new(pads).active(active).show(ui));
+        });
+        events
+    }
+
+    #[test]
+    fn test_trigger_and_release() {
+        let pads = hihats();
+        let events = click_pad(&pads, &[], 3);
+        assert_eq!(
+            events,
+            vec![
+                PadEvent::Trigger {
+                    index: 3,
+                    velocity: 1.0
+                },
+                PadEvent::Release { index: 3 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_choke_group_releases_active_pad() {
+        let pads = hihats();
+        // Kick (no group) and open hi-hat are playing
+        let events = click_pad(&pads, &[0, 1], 2);
+        assert_eq!(events[0], PadEvent::Release { index: 1 });
+        assert!(matches!(events[1], PadEvent::Trigger { index: 2, .. }));
+        assert!(!events.contains(&PadEvent::Release { index: 0 }));
+    }
+
+    #[test]
+    fn test_velocity_from_position() {
+        let pads = hihats();
+        let pad = SamplePad::new(&pads).velocity_sensitive(true);
+        let rect = Rect::from_min_size(egui::Pos2::ZERO, Vec2::splat(60.0));
+        let soft = pad.velocity_at(&pads[0], rect, 5.0);
+        let hard = pad.velocity_at(&pads[0], rect, 55.0);
+        assert!(hard > soft);
+
+        let fixed = SamplePad::new(&pads);
+        assert_eq!(
+            fixed.velocity_at(&pads[0].clone().with_velocity(0.5), rect, 5.0),
+            0.5
+        );
     }
 }
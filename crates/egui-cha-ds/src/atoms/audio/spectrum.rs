@@ -5,9 +5,11 @@
 //!
 //! # Features
 //! - Vertical bar display with configurable band count
-//! - Peak hold indicators
+//! - Peak hold indicators with decay
+//! - Frame-to-frame smoothing
 //! - Multiple color modes (solid, gradient, rainbow)
 //! - Mirrored mode for symmetric display
+//! - Logarithmic frequency axis with octave gridlines
 //!
 //! # Example
 //! ```ignore
@@ -15,11 +17,18 @@
 //! Spectrum::new(&fft_bins)
 //!     .show(ctx.ui);
 //!
-//! // With peak hold and gradient
+//! // Smoothed, with peak caps falling at 0.5 per second
 //! Spectrum::new(&fft_bins)
 //!     .bands(32)
-//!     .peak_hold(true)
-//!     .gradient(true)
+//!     .smoothing(0.8)
+//!     .peak_hold(0.5)
+//!     .gradient()
+//!     .show(ctx.ui);
+//!
+//! // Log-frequency axis with 100Hz / 1k / 10k labels
+//! Spectrum::new(&fft_bins)
+//!     .log_freq(true)
+//!     .sample_rate(48_000.0)
 //!     .show(ctx.ui);
 //!
 //! // Mirrored (symmetric) display
@@ -29,7 +38,11 @@
 //! ```
 
 use crate::Theme;
-use egui::{Color32, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget};
+use egui::{Color32, Id, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget};
+use std::ops::Range;
+
+/// Labelled frequencies on the log axis (Hz)
+const FREQ_LABELS: [(f32, &str); 3] = [(100.0, "100Hz"), (1_000.0, "1k"), (10_000.0, "10k")];
 
 /// Color mode for spectrum bars
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -49,10 +62,15 @@ pub struct Spectrum<'a> {
     bands: usize,
     height: Option<f32>,
     color_mode: SpectrumColorMode,
-    peak_hold: bool,
+    /// Peak cap fall rate (per second), `None` disables peak hold
+    peak_decay: Option<f32>,
     peaks: Option<&'a [f32]>,
+    smoothing: f32,
+    id_salt: Option<Id>,
     mirrored: bool,
     bar_gap: f32,
+    log_freq: bool,
+    sample_rate: Option<f32>,
 }
 
 impl<'a> Spectrum<'a> {
@@ -65,10 +83,14 @@ impl<'a> Spectrum<'a> {
             bands: 32,
             height: None,
             color_mode: SpectrumColorMode::default(),
-            peak_hold: false,
+            peak_decay: None,
             peaks: None,
+            smoothing: 0.0,
+            id_salt: None,
             mirrored: false,
             bar_gap: 2.0,
+            log_freq: false,
+            sample_rate: None,
         }
     }
 
@@ -102,16 +124,31 @@ impl<'a> Spectrum<'a> {
         self
     }
 
-    /// Enable peak hold indicators
-    pub fn peak_hold(mut self, enabled: bool) -> Self {
-        self.peak_hold = enabled;
+    /// Enable peak hold caps that fall at `decay_per_sec` (normalized units)
+    ///
+    /// Peaks are tracked across frames in egui memory.
+    pub fn peak_hold(mut self, decay_per_sec: f32) -> Self {
+        self.peak_decay = Some(decay_per_sec.max(0.0));
         self
     }
 
-    /// Provide external peak values (for smooth decay)
+    /// Provide external peak values (overrides internal peak tracking)
     pub fn peaks(mut self, peaks: &'a [f32]) -> Self {
         self.peaks = Some(peaks);
-        self.peak_hold = true;
+        self
+    }
+
+    /// Exponential smoothing across frames (0.0 = none, default)
+    ///
+    /// Each band moves `1.0 - factor` of the way towards the new value per frame.
+    pub fn smoothing(mut self, factor: f32) -> Self {
+        self.smoothing = factor.clamp(0.0, 0.99);
+        self
+    }
+
+    /// Set the id used to store smoothing and peak state (defaults to an auto id)
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
         self
     }
 
@@ -127,6 +164,93 @@ impl<'a> Spectrum<'a> {
         self
     }
 
+    /// Map bins onto a logarithmic frequency axis (default: linear)
+    pub fn log_freq(mut self, enabled: bool) -> Self {
+        self.log_freq = enabled;
+        self
+    }
+
+    /// Set the sample rate the bins were computed at
+    ///
+    /// Bins are assumed to span 0 Hz to Nyquist. With [`log_freq`](Self::log_freq)
+    /// enabled this draws octave gridlines and frequency labels.
+    pub fn sample_rate(mut self, sample_rate: f32) -> Self {
+        self.sample_rate = Some(sample_rate);
+        self
+    }
+
+    /// Range of bins aggregated into band `index` of `bands`
+    fn band_bins(&self, index: usize, bands: usize) -> Range<usize> {
+        let len = self.bins.len();
+        if self.log_freq && len > 1 {
+            // Bin 0 (DC) has no place on a log axis; spread bins 1..len
+            let edge = |i: usize| (len as f32).powf(i as f32 / bands as f32) as usize;
+            let start = edge(index).clamp(1, len - 1);
+            let end = edge(index + 1).clamp(start + 1, len);
+            start..end
+        } else {
+            let bins_per_band = len / bands.max(1);
+            let start = (index * bins_per_band).min(len);
+            let end = ((index + 1) * bins_per_band).min(len);
+            start..end
+        }
+    }
+
+    /// Horizontal position of (fractional) bin `bin` as a 0.0..1.0 fraction of the width
+    fn bin_position(&self, bin: f32) -> f32 {
+        let len = self.bins.len() as f32;
+        if self.log_freq && len > 1.0 {
+            bin.max(1.0).ln() / len.ln()
+        } else {
+            bin / len.max(1.0)
+        }
+    }
+
+    /// Fractional bin for a frequency (requires a sample rate)
+    fn freq_to_bin(&self, freq: f32) -> Option<f32> {
+        let nyquist = self.sample_rate? / 2.0;
+        Some(freq / nyquist * self.bins.len() as f32)
+    }
+
+    /// Draw octave gridlines and frequency labels (log axis only)
+    fn draw_freq_grid(&self, painter: &egui::Painter, rect: Rect, theme: &Theme) {
+        let Some(sample_rate) = self.sample_rate else {
+            return;
+        };
+        let nyquist = sample_rate / 2.0;
+        let lowest = nyquist / self.bins.len().max(1) as f32;
+        let grid_stroke = Stroke::new(theme.border_width, theme.border.gamma_multiply(0.5));
+
+        // Octaves around 1kHz
+        let mut freq = 1_000.0;
+        while freq / 2.0 > lowest {
+            freq /= 2.0;
+        }
+        while freq < nyquist {
+            if let Some(bin) = self.freq_to_bin(freq) {
+                let x = rect.min.x + self.bin_position(bin) * rect.width();
+                painter.vline(x, rect.y_range(), grid_stroke);
+            }
+            freq *= 2.0;
+        }
+
+        for (freq, label) in FREQ_LABELS {
+            if freq <= lowest || freq >= nyquist {
+                continue;
+            }
+            if let Some(bin) = self.freq_to_bin(freq) {
+                let x = rect.min.x + self.bin_position(bin) * rect.width();
+                painter.text(
+                    Pos2::new(x + 2.0, rect.min.y + 2.0),
+                    egui::Align2::LEFT_TOP,
+                    label,
+                    egui::FontId::proportional(theme.font_size_xs),
+                    theme.text_muted,
+                );
+            }
+        }
+    }
+
     /// Show the spectrum
     pub fn show(self, ui: &mut Ui) -> Response {
         let theme = Theme::current(ui.ctx());
@@ -137,19 +261,42 @@ impl<'a> Spectrum<'a> {
 
         let (rect, response) = ui.allocate_exact_size(Vec2::new(width, height), Sense::hover());
 
+        // Calculate bands
+        let display_bands = if self.mirrored {
+            self.bands / 2
+        } else {
+            self.bands
+        };
+
+        let mut values: Vec<f32> = (0..display_bands)
+            .map(|i| {
+                // Use max for more responsive display
+                self.bins[self.band_bins(i, display_bands)]
+                    .iter()
+                    .cloned()
+                    .fold(0.0_f32, f32::max)
+            })
+            .collect();
+        let mut held_peaks: Option<Vec<f32>> = None;
+
+        // Smoothing and peak hold carry state across frames
+        if self.smoothing > 0.0 || self.peak_decay.is_some() {
+            let id = state_id(self.id_salt.unwrap_or(response.id));
+            let now = ui.input(|i| i.time);
+            let mut state: SpectrumState = ui.ctx().data(|d| d.get_temp(id).unwrap_or_default());
+            state.update(&mut values, now, self.smoothing, self.peak_decay);
+            if self.peak_decay.is_some() {
+                held_peaks = Some(state.peaks.clone());
+            }
+            ui.ctx().data_mut(|d| d.insert_temp(id, state));
+        }
+
         if ui.is_rect_visible(rect) {
             let painter = ui.painter();
 
             // Background
             painter.rect_filled(rect, theme.radius_sm, theme.bg_secondary);
 
-            // Calculate bands
-            let display_bands = if self.mirrored {
-                self.bands / 2
-            } else {
-                self.bands
-            };
-
             let total_gap = self.bar_gap * (display_bands.saturating_sub(1)) as f32;
             let bar_width = if self.mirrored {
                 (width - total_gap) / display_bands as f32 / 2.0 - self.bar_gap / 2.0
@@ -157,22 +304,19 @@ impl<'a> Spectrum<'a> {
                 (width - total_gap) / display_bands as f32
             };
 
-            let bins_per_band = self.bins.len() / display_bands.max(1);
+            if self.log_freq && !self.mirrored {
+                self.draw_freq_grid(painter, rect, &theme);
+            }
 
-            // Draw bars
-            for i in 0..display_bands {
-                // Average bins for this band
-                let start = i * bins_per_band;
-                let end = ((i + 1) * bins_per_band).min(self.bins.len());
-                let slice = &self.bins[start..end];
-
-                let value = if slice.is_empty() {
-                    0.0
-                } else {
-                    // Use max for more responsive display
-                    slice.iter().cloned().fold(0.0_f32, f32::max)
-                };
+            let peak_at = |i: usize| -> Option<f32> {
+                match self.peaks {
+                    Some(peaks) => peaks.get(i).cloned(),
+                    None => held_peaks.as_ref().and_then(|p| p.get(i).cloned()),
+                }
+            };
 
+            // Draw bars
+            for (i, &value) in values.iter().enumerate() {
                 let bar_height = value.clamp(0.0, 1.0) * (height - theme.spacing_xs * 2.0);
 
                 // Get color for this band
@@ -201,9 +345,7 @@ impl<'a> Spectrum<'a> {
                     painter.rect_filled(bar_rect_left, theme.radius_sm * 0.5, color);
 
                     // Peak indicators
-                    if self.peak_hold {
-                        let peak_value =
-                            self.peaks.and_then(|p| p.get(i).cloned()).unwrap_or(value);
+                    if let Some(peak_value) = peak_at(i) {
                         let peak_y = rect.max.y
                             - theme.spacing_xs
                             - peak_value.clamp(0.0, 1.0) * (height - theme.spacing_xs * 2.0);
@@ -235,9 +377,7 @@ impl<'a> Spectrum<'a> {
                     painter.rect_filled(bar_rect, theme.radius_sm * 0.5, color);
 
                     // Peak indicator
-                    if self.peak_hold {
-                        let peak_value =
-                            self.peaks.and_then(|p| p.get(i).cloned()).unwrap_or(value);
+                    if let Some(peak_value) = peak_at(i) {
                         let peak_y = rect.max.y
                             - theme.spacing_xs
                             - peak_value.clamp(0.0, 1.0) * (height - theme.spacing_xs * 2.0);
@@ -289,6 +429,40 @@ impl Widget for Spectrum<'_> {
     }
 }
 
+/// Per-band state kept across frames for smoothing and peak hold
+#[derive(Clone, Debug, Default)]
+struct SpectrumState {
+    values: Vec<f32>,
+    peaks: Vec<f32>,
+    time: f64,
+}
+
+impl SpectrumState {
+    /// Smooth `values` in place and advance the falling peaks
+    fn update(&mut self, values: &mut [f32], now: f64, smoothing: f32, peak_decay: Option<f32>) {
+        let dt = (now - self.time).max(0.0) as f32;
+        self.time = now;
+        self.values.resize(values.len(), 0.0);
+        self.peaks.resize(values.len(), 0.0);
+
+        for (i, value) in values.iter_mut().enumerate() {
+            if smoothing > 0.0 {
+                *value = self.values[i] * smoothing + *value * (1.0 - smoothing);
+            }
+            self.values[i] = *value;
+
+            if let Some(decay) = peak_decay {
+                let fallen = self.peaks[i] - decay * dt;
+                self.peaks[i] = fallen.max(*value);
+            }
+        }
+    }
+}
+
+fn state_id(id: Id) -> Id {
+    id.with("spectrum_state")
+}
+
 // Helper functions
 fn lerp_u8(a: u8, b: u8, t: f32) -> u8 {
     (a as f32 + (b as f32 - a as f32) * t) as u8
@@ -314,3 +488,87 @@ fn hsv_to_rgb(h: f32, s: f32, v: f32) -> Color32 {
         ((b + m) * 255.0) as u8,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{run_ui, test_context};
+
+    #[test]
+    fn test_smoothing_rises_gradually() {
+        let ctx = test_context();
+        let silent = vec![0.0; 4];
+        let step = vec![1.0; 4];
+        let read = |ctx: &egui::Context| {
+            ctx.data(|d| d.get_temp::<SpectrumState>(state_id(Id::new("spectrum"))))
+                .unwrap()
+                .values[0]
+        };
+
+        run_ui(&ctx, |ui| {
+            Spectrum::new(&silent)
+                .bands(4)
+                .smoothing(0.9)
+                .id_salt("spectrum")
+                .show(ui);
+        });
+        assert_eq!(read(&ctx), 0.0);
+
+        let mut previous = 0.0;
+        for _ in 0..5 {
+            run_ui(&ctx, |ui| {
+                Spectrum::new(&step)
+                    .bands(4)
+                    .smoothing(0.9)
+                    .id_salt("spectrum")
+                    .show(ui);
+            });
+            let value = read(&ctx);
+            assert!(value > previous && value < 1.0);
+            previous = value;
+        }
+        assert!((read(&ctx) - (1.0 - 0.9_f32.powi(5))).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_peak_falls_at_decay_rate() {
+        let mut state = SpectrumState::default();
+        let mut values = [1.0];
+        state.update(&mut values, 0.0, 0.0, Some(0.5));
+
+        let mut values = [0.0];
+        state.update(&mut values, 1.0, 0.0, Some(0.5));
+        assert!((state.peaks[0] - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_log_scaling_moves_1khz_bin() {
+        let bins = vec![0.0; 512];
+        let linear = Spectrum::new(&bins).sample_rate(48_000.0);
+        let log = Spectrum::new(&bins).sample_rate(48_000.0).log_freq(true);
+
+        let bin = linear.freq_to_bin(1_000.0).unwrap();
+        let width = 400.0;
+        let linear_x = linear.bin_position(bin) * width;
+        let log_x = log.bin_position(bin) * width;
+
+        // 1kHz sits near the left edge linearly, mid-way on a log axis
+        assert!(linear_x < width * 0.1);
+        assert!(log_x > width * 0.4);
+        assert!((log_x - linear_x).abs() > 1.0);
+    }
+
+    #[test]
+    fn test_log_bands_cover_all_bins() {
+        let bins = vec![0.0; 256];
+        let spectrum = Spectrum::new(&bins).log_freq(true);
+
+        let first = spectrum.band_bins(0, 16);
+        let last = spectrum.band_bins(15, 16);
+        assert_eq!(first.start, 1);
+        assert_eq!(last.end, 256);
+        for i in 0..16 {
+            assert!(!spectrum.band_bins(i, 16).is_empty());
+        }
+    }
+}
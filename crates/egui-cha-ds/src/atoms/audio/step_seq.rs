@@ -6,16 +6,28 @@
 //! ```ignore
 //! StepSeq::new(4, 16)  // 4 tracks x 16 steps
 //!     .track_labels(&["Kick", "Snare", "HiHat", "Clap"])
-//!     .playhead(model.current_step)
+//!     .position(model.current_step)
 //!     .accent_every(4)  // Mark every 4th step as accent
 //!     .show_with(ctx, &model.pattern, |event| match event {
 //!         StepEvent::Toggle { track, step } => Msg::ToggleStep(track, step),
 //!         StepEvent::SetValue { track, step, value } => Msg::SetStepValue(track, step, value),
+//!         StepEvent::SetVelocity { track, step, velocity } => {
+//!             Msg::SetStepValue(track, step, StepValue::Velocity(velocity))
+//!         }
 //!     });
 //! ```
+//!
+//! # Interaction
+//! - Click a cell to toggle it, Shift+click to cycle Off → On → Accent
+//! - Scroll or drag vertically over an active cell to change its velocity
+//!
+//! Apply events to the pattern with [`StepEvent::apply`]:
+//! ```ignore
+//! Msg::Step(event) => event.apply(&mut model.pattern),
+//! ```
 
 use crate::Theme;
-use egui::{Color32, Rect, Sense, Stroke, Ui, Vec2};
+use egui::{Color32, Pos2, Rect, Sense, Stroke, Ui, Vec2};
 use egui_cha::ViewCtx;
 
 /// Step value representing the state of a single step
@@ -46,6 +58,16 @@ impl StepValue {
         }
     }
 
+    /// Velocity of the step (0.0 when off)
+    pub fn velocity(&self) -> f32 {
+        match self {
+            StepValue::Off => 0.0,
+            StepValue::On => 0.8,
+            StepValue::Accent => 1.0,
+            StepValue::Velocity(v) => *v,
+        }
+    }
+
     /// Cycle through Off -> On -> Accent -> Off
     pub fn cycle(self) -> Self {
         match self {
@@ -68,8 +90,38 @@ pub enum StepEvent {
         step: usize,
         value: StepValue,
     },
+    /// Change the velocity of an active step (0.0 - 1.0)
+    SetVelocity {
+        track: usize,
+        step: usize,
+        velocity: f32,
+    },
+}
+
+impl StepEvent {
+    /// Apply the event to a pattern (out-of-range cells are ignored)
+    pub fn apply(&self, pattern: &mut Pattern) {
+        let (track, step, value) = match *self {
+            StepEvent::Toggle { track, step } => {
+                let current = pattern.get(track).and_then(|t| t.get(step)).copied();
+                (track, step, current.unwrap_or_default().toggle())
+            }
+            StepEvent::SetValue { track, step, value } => (track, step, value),
+            StepEvent::SetVelocity {
+                track,
+                step,
+                velocity,
+            } => (track, step, StepValue::Velocity(velocity)),
+        };
+        if let Some(cell) = pattern.get_mut(track).and_then(|t| t.get_mut(step)) {
+            *cell = value;
+        }
+    }
 }
 
+/// Velocity change per scroll point / dragged pixel
+const VELOCITY_PER_POINT: f32 = 0.005;
+
 /// Pattern data type (tracks x steps)
 pub type Pattern = Vec<Vec<StepValue>>;
 
@@ -116,6 +168,11 @@ impl<'a> StepSeq<'a> {
         self
     }
 
+    /// Highlight the playhead column at `step` (0-indexed)
+    pub fn position(self, step: usize) -> Self {
+        self.playhead(Some(step))
+    }
+
     /// Mark every Nth step as an accent/downbeat
     pub fn accent_every(mut self, n: usize) -> Self {
         self.accent_every = Some(n);
@@ -169,6 +226,22 @@ impl<'a> StepSeq<'a> {
         self.render(ui, pattern)
     }
 
+    /// Top-left of the step grid for a widget placed at `min`
+    fn grid_origin(&self, min: Pos2, theme: &Theme) -> Pos2 {
+        let header_height = if self.show_beat_numbers { 16.0 } else { 0.0 };
+        Pos2::new(
+            min.x + self.label_width + theme.spacing_sm,
+            min.y + header_height,
+        )
+    }
+
+    /// Rect of the cell at (`track`, `step`)
+    fn cell_rect(&self, grid_origin: Pos2, track: usize, step: usize) -> Rect {
+        let x = grid_origin.x + step as f32 * (self.step_size.x + self.spacing);
+        let y = grid_origin.y + track as f32 * (self.step_size.y + self.spacing);
+        Rect::from_min_size(Pos2::new(x, y), self.step_size)
+    }
+
     fn render(self, ui: &mut Ui, pattern: &Pattern) -> Option<StepEvent> {
         let theme = Theme::current(ui.ctx());
         let time = ui.input(|i| i.time) as f32;
@@ -191,8 +264,9 @@ impl<'a> StepSeq<'a> {
             return None;
         }
 
-        let grid_start_x = rect.min.x + self.label_width + theme.spacing_sm;
-        let grid_start_y = rect.min.y + header_height;
+        let grid_origin = self.grid_origin(rect.min, &theme);
+        let grid_start_x = grid_origin.x;
+        let grid_start_y = grid_origin.y;
 
         // First pass: collect step info and handle interactions
         struct StepInfo {
@@ -209,7 +283,6 @@ impl<'a> StepSeq<'a> {
         let mut steps_info: Vec<StepInfo> = Vec::with_capacity(self.tracks * self.steps);
 
         for track in 0..self.tracks {
-            let track_y = grid_start_y + track as f32 * (self.step_size.y + self.spacing);
             let track_color = self
                 .colors
                 .and_then(|c| c.get(track))
@@ -217,8 +290,7 @@ impl<'a> StepSeq<'a> {
                 .unwrap_or(theme.primary);
 
             for step in 0..self.steps {
-                let step_x = grid_start_x + step as f32 * (self.step_size.x + self.spacing);
-                let step_rect = Rect::from_min_size(egui::pos2(step_x, track_y), self.step_size);
+                let step_rect = self.cell_rect(grid_origin, track, step);
 
                 let step_value = pattern
                     .get(track)
@@ -230,7 +302,7 @@ impl<'a> StepSeq<'a> {
                 let is_accent_step = self.accent_every.map(|n| step % n == 0).unwrap_or(false);
 
                 // Allocate interactive area
-                let response = ui.allocate_rect(step_rect, Sense::click());
+                let response = ui.allocate_rect(step_rect, Sense::click_and_drag());
 
                 // Handle interactions
                 if response.clicked() {
@@ -243,6 +315,24 @@ impl<'a> StepSeq<'a> {
                     } else {
                         event = Some(StepEvent::Toggle { track, step });
                     }
+                } else if step_value.is_on() {
+                    // Scroll or vertical drag adjusts velocity
+                    let delta = if response.dragged() {
+                        -response.drag_delta().y
+                    } else if response.hovered() {
+                        ui.input(|i| i.smooth_scroll_delta.y)
+                    } else {
+                        0.0
+                    };
+                    if delta != 0.0 {
+                        let velocity =
+                            (step_value.velocity() + delta * VELOCITY_PER_POINT).clamp(0.0, 1.0);
+                        event = Some(StepEvent::SetVelocity {
+                            track,
+                            step,
+                            velocity,
+                        });
+                    }
                 }
 
                 steps_info.push(StepInfo {
@@ -404,3 +494,51 @@ impl<'a> StepSeq<'a> {
         event
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, run_ui, test_context};
+
+    #[test]
+    fn test_click_cell_emits_toggle() {
+        let ctx = test_context();
+        let pattern: Pattern = vec![vec![StepValue::Off; 8]; 4];
+        let mut cell = Rect::NOTHING;
+
+        run_ui(&ctx, |ui| {
+            let rect = ui
+                .scope(|ui| StepSeq::new(4, 8).show(ui, &pattern))
+                .response
+                .rect;
+            let seq = StepSeq::new(4, 8);
+            cell = seq.cell_rect(seq.grid_origin(rect.min, &Theme::current(ui.ctx())), 1, 3);
+        });
+
+        let mut events = Vec::new();
+        click_at(&ctx, cell.center(), |ui| {
+            events.extend(ui.scope(|ui| StepSeq::new(4, 8).show(ui, &pattern)).inner);
+        });
+
+        assert_eq!(events, vec![StepEvent::Toggle { track: 1, step: 3 }]);
+    }
+
+    #[test]
+    fn test_apply_events() {
+        let mut pattern: Pattern = vec![vec![StepValue::Off; 4]; 2];
+
+        StepEvent::Toggle { track: 1, step: 2 }.apply(&mut pattern);
+        assert_eq!(pattern[1][2], StepValue::On);
+
+        StepEvent::SetVelocity {
+            track: 1,
+            step: 2,
+            velocity: 0.3,
+        }
+        .apply(&mut pattern);
+        assert_eq!(pattern[1][2], StepValue::Velocity(0.3));
+
+        // Out of range is ignored
+        StepEvent::Toggle { track: 5, step: 0 }.apply(&mut pattern);
+    }
+}
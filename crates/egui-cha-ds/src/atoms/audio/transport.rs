@@ -1,23 +1,32 @@
 //! Transport controls for audio/video playback
 //!
-//! Provides Play/Stop/Record buttons and BPM display for DAW-style applications.
+//! Provides Play/Pause/Stop/Record buttons, a bar.beat position readout,
+//! loop toggle and BPM display for DAW-style applications. Buttons are the
+//! semantic media buttons, so they match the rest of the app.
 //!
 //! # Example
 //! ```ignore
 //! TransportBar::new()
 //!     .playing(model.is_playing)
 //!     .recording(model.is_recording)
+//!     .position(model.bar, model.beat)
+//!     .looping(model.looping)
+//!     .show_loop(true)
 //!     .bpm(model.bpm)
 //!     .show_with(ctx, |event| match event {
 //!         TransportEvent::Play => Msg::Play,
+//!         TransportEvent::Pause => Msg::Pause,
 //!         TransportEvent::Stop => Msg::Stop,
 //!         TransportEvent::Record => Msg::ToggleRecord,
+//!         TransportEvent::ToggleLoop => Msg::ToggleLoop,
 //!         TransportEvent::BpmChange(bpm) => Msg::SetBpm(bpm),
 //!     });
 //! ```
 
+use crate::atoms::icons;
+use crate::semantics::{self, ButtonStyle};
 use crate::Theme;
-use egui::{CornerRadius, Rect, Sense, Stroke, Ui, Vec2};
+use egui::{RichText, Sense, Stroke, Ui, Vec2};
 use egui_cha::ViewCtx;
 
 /// Transport control events
@@ -25,10 +34,14 @@ use egui_cha::ViewCtx;
 pub enum TransportEvent {
     /// Play button pressed
     Play,
+    /// Pause button pressed (shown in place of Play while playing)
+    Pause,
     /// Stop button pressed
     Stop,
     /// Record button toggled
     Record,
+    /// Loop button toggled
+    ToggleLoop,
     /// BPM changed (via drag or input)
     BpmChange(f32),
 }
@@ -37,8 +50,11 @@ pub enum TransportEvent {
 pub struct TransportBar {
     playing: bool,
     recording: bool,
+    looping: bool,
     bpm: f32,
+    position: Option<(u32, u32, Option<u32>)>,
     show_record: bool,
+    show_loop: bool,
     show_bpm: bool,
     compact: bool,
 }
@@ -55,44 +71,71 @@ impl TransportBar {
         Self {
             playing: false,
             recording: false,
+            looping: false,
             bpm: 120.0,
+            position: None,
             show_record: true,
+            show_loop: false,
             show_bpm: true,
             compact: false,
         }
     }
 
-    /// Set playing state
+    /// Set playing state (Play becomes Pause while playing)
     pub fn playing(mut self, playing: bool) -> Self {
         self.playing = playing;
         self
     }
 
-    /// Set recording state
+    /// Set recording state (the record button is filled while recording)
     pub fn recording(mut self, recording: bool) -> Self {
         self.recording = recording;
         self
     }
 
+    /// Set loop state (shown when [`show_loop`](Self::show_loop) is enabled)
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
     /// Set BPM value
     pub fn bpm(mut self, bpm: f32) -> Self {
         self.bpm = bpm;
         self
     }
 
+    /// Show a `bar.beat` position readout (1-indexed)
+    pub fn position(mut self, bar: u32, beat: u32) -> Self {
+        self.position = Some((bar, beat, None));
+        self
+    }
+
+    /// Show a `bar.beat.tick` position readout
+    pub fn position_ticks(mut self, bar: u32, beat: u32, tick: u32) -> Self {
+        self.position = Some((bar, beat, Some(tick)));
+        self
+    }
+
     /// Show/hide record button
     pub fn show_record(mut self, show: bool) -> Self {
         self.show_record = show;
         self
     }
 
+    /// Show/hide loop toggle
+    pub fn show_loop(mut self, show: bool) -> Self {
+        self.show_loop = show;
+        self
+    }
+
     /// Show/hide BPM display
     pub fn show_bpm(mut self, show: bool) -> Self {
         self.show_bpm = show;
         self
     }
 
-    /// Compact mode (smaller buttons)
+    /// Compact mode (icon-only buttons)
     pub fn compact(mut self, compact: bool) -> Self {
         self.compact = compact;
         self
@@ -116,7 +159,11 @@ impl TransportBar {
 
     fn render(self, ui: &mut Ui) -> Option<TransportEvent> {
         let theme = Theme::current(ui.ctx());
-        let button_size = if self.compact { 28.0 } else { 36.0 };
+        let style = if self.compact {
+            ButtonStyle::Icon
+        } else {
+            ButtonStyle::Both
+        };
         let spacing = theme.spacing_xs;
 
         let mut event = None;
@@ -124,23 +171,46 @@ impl TransportBar {
         ui.horizontal(|ui| {
             ui.spacing_mut().item_spacing.x = spacing;
 
-            // Stop button (square)
-            if self.render_stop_button(ui, button_size, &theme) {
+            if semantics::stop(style).show(ui) {
                 event = Some(TransportEvent::Stop);
             }
 
-            // Play button (triangle)
-            if self.render_play_button(ui, button_size, &theme) {
+            if self.playing {
+                if semantics::pause(style).show(ui) {
+                    event = Some(TransportEvent::Pause);
+                }
+            } else if semantics::play(style).show(ui) {
                 event = Some(TransportEvent::Play);
             }
 
-            // Record button (circle)
             if self.show_record {
-                if self.render_record_button(ui, button_size, &theme) {
+                if semantics::record(style).active(self.recording).show(ui) {
                     event = Some(TransportEvent::Record);
                 }
             }
 
+            if self.show_loop {
+                let icon =
+                    RichText::new(icons::REPEAT).family(egui::FontFamily::Name("icons".into()));
+                if ui
+                    .selectable_label(self.looping, icon)
+                    .on_hover_text("Loop")
+                    .clicked()
+                {
+                    event = Some(TransportEvent::ToggleLoop);
+                }
+            }
+
+            if let Some((bar, beat, tick)) = self.position {
+                ui.add_space(spacing * 2.0);
+                ui.label(
+                    RichText::new(format_position(bar, beat, tick))
+                        .monospace()
+                        .size(theme.font_size_lg)
+                        .color(theme.text_primary),
+                );
+            }
+
             // BPM display/control
             if self.show_bpm {
                 ui.add_space(spacing * 2.0);
@@ -153,104 +223,6 @@ impl TransportBar {
         event
     }
 
-    fn render_stop_button(&self, ui: &mut Ui, size: f32, theme: &Theme) -> bool {
-        let (rect, response) = ui.allocate_exact_size(Vec2::splat(size), Sense::click());
-
-        if ui.is_rect_visible(rect) {
-            let visuals = ui.style().interact(&response);
-            let bg_color = if !self.playing {
-                theme.primary
-            } else {
-                visuals.bg_fill
-            };
-
-            // Background
-            ui.painter()
-                .rect_filled(rect, CornerRadius::same(4), bg_color);
-
-            // Square icon
-            let icon_size = size * 0.35;
-            let icon_rect = Rect::from_center_size(rect.center(), Vec2::splat(icon_size));
-            let icon_color = if !self.playing {
-                theme.primary_text
-            } else {
-                visuals.fg_stroke.color
-            };
-            ui.painter()
-                .rect_filled(icon_rect, CornerRadius::ZERO, icon_color);
-        }
-
-        response.clicked()
-    }
-
-    fn render_play_button(&self, ui: &mut Ui, size: f32, theme: &Theme) -> bool {
-        let (rect, response) = ui.allocate_exact_size(Vec2::splat(size), Sense::click());
-
-        if ui.is_rect_visible(rect) {
-            let visuals = ui.style().interact(&response);
-            let bg_color = if self.playing {
-                theme.state_success
-            } else {
-                visuals.bg_fill
-            };
-
-            // Background
-            ui.painter()
-                .rect_filled(rect, CornerRadius::same(4), bg_color);
-
-            // Triangle icon (play)
-            let icon_size = size * 0.4;
-            let center = rect.center();
-            let icon_color = if self.playing {
-                theme.state_success_text
-            } else {
-                visuals.fg_stroke.color
-            };
-
-            let points = vec![
-                egui::pos2(center.x - icon_size * 0.4, center.y - icon_size * 0.5),
-                egui::pos2(center.x + icon_size * 0.5, center.y),
-                egui::pos2(center.x - icon_size * 0.4, center.y + icon_size * 0.5),
-            ];
-            ui.painter().add(egui::Shape::convex_polygon(
-                points,
-                icon_color,
-                Stroke::NONE,
-            ));
-        }
-
-        response.clicked()
-    }
-
-    fn render_record_button(&self, ui: &mut Ui, size: f32, theme: &Theme) -> bool {
-        let (rect, response) = ui.allocate_exact_size(Vec2::splat(size), Sense::click());
-
-        if ui.is_rect_visible(rect) {
-            let visuals = ui.style().interact(&response);
-            let bg_color = if self.recording {
-                theme.state_danger
-            } else {
-                visuals.bg_fill
-            };
-
-            // Background
-            ui.painter()
-                .rect_filled(rect, CornerRadius::same(4), bg_color);
-
-            // Circle icon (record)
-            let icon_radius = size * 0.2;
-            let icon_color = if self.recording {
-                theme.state_danger_text
-            } else {
-                theme.state_danger
-            };
-            ui.painter()
-                .circle_filled(rect.center(), icon_radius, icon_color);
-        }
-
-        response.clicked()
-    }
-
     fn render_bpm(&self, ui: &mut Ui, theme: &Theme) -> Option<f32> {
         let mut new_bpm = None;
 
@@ -289,6 +261,14 @@ impl TransportBar {
     }
 }
 
+/// Format a transport position as `bar.beat` or `bar.beat.tick`
+fn format_position(bar: u32, beat: u32, tick: Option<u32>) -> String {
+    match tick {
+        Some(tick) => format!("{bar}.{beat}.{tick:03}"),
+        None => format!("{bar}.{beat}"),
+    }
+}
+
 /// Compact beat indicator showing current beat position
 pub struct BeatIndicator {
     beats: usize,
@@ -365,3 +345,34 @@ impl BeatIndicator {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, run_ui, test_context};
+
+    #[test]
+    fn test_format_position() {
+        assert_eq!(format_position(2, 3, None), "2.3");
+        assert_eq!(format_position(12, 4, Some(96)), "12.4.096");
+    }
+
+    #[test]
+    fn test_click_play_emits_play() {
+        let ctx = test_context();
+        let bar = || TransportBar::new().show_record(false).show_bpm(false);
+        let mut row = egui::Rect::NOTHING;
+
+        run_ui(&ctx, |ui| {
+            row = ui.horizontal(|ui| bar().show(ui)).response.rect;
+        });
+
+        // Play is the last button in the row
+        let mut events = Vec::new();
+        click_at(&ctx, row.right_center() - Vec2::new(6.0, 0.0), |ui| {
+            events.extend(ui.horizontal(|ui| bar().show(ui)).inner);
+        });
+
+        assert_eq!(events, vec![TransportEvent::Play]);
+    }
+}
@@ -9,6 +9,7 @@
 //! - Configurable height and color
 //! - Theme-aware styling
 //! - Supports mono and stereo display
+//! - Drag-to-select range and zoom for trimming
 //!
 //! # Example
 //! ```ignore
@@ -26,10 +27,19 @@
 //! // Stereo waveform
 //! Waveform::stereo(&left_samples, &right_samples)
 //!     .show(ui);
+//!
+//! // Trimming: drag to select, zoomed into the first half
+//! Waveform::new(&samples)
+//!     .zoom((0.0, 0.5))
+//!     .selection(model.selection)
+//!     .show_with(ctx, |WaveformEvent::SelectionChanged(start, end)| {
+//!         Msg::SetSelection(start, end)
+//!     });
 //! ```
 
 use crate::Theme;
 use egui::{Color32, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget};
+use egui_cha::ViewCtx;
 
 #[cfg(feature = "plot")]
 use egui_plot::{Line, Plot, PlotPoints};
@@ -46,6 +56,13 @@ pub enum WaveformStyle {
     Bars,
 }
 
+/// Events emitted by an interactive waveform
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WaveformEvent {
+    /// Selection dragged to a new (start, end), normalized over all samples
+    SelectionChanged(f32, f32),
+}
+
 /// A waveform visualization component
 pub struct Waveform<'a> {
     samples: &'a [f32],
@@ -57,6 +74,8 @@ pub struct Waveform<'a> {
     show_center_line: bool,
     show_grid: bool,
     line_width: f32,
+    selection: Option<(f32, f32)>,
+    zoom: (f32, f32),
 }
 
 impl<'a> Waveform<'a> {
@@ -74,6 +93,8 @@ impl<'a> Waveform<'a> {
             show_center_line: true,
             show_grid: false,
             line_width: 1.5,
+            selection: None,
+            zoom: (0.0, 1.0),
         }
     }
 
@@ -89,6 +110,8 @@ impl<'a> Waveform<'a> {
             show_center_line: true,
             show_grid: false,
             line_width: 1.5,
+            selection: None,
+            zoom: (0.0, 1.0),
         }
     }
 
@@ -146,8 +169,42 @@ impl<'a> Waveform<'a> {
         self
     }
 
+    /// Highlight a selected region (normalized start/end over all samples)
+    pub fn selection(mut self, selection: Option<(f32, f32)>) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Only draw the samples within `range` (normalized start/end, default: 0.0..1.0)
+    pub fn zoom(mut self, range: (f32, f32)) -> Self {
+        let start = range.0.clamp(0.0, 1.0);
+        let end = range.1.clamp(start, 1.0);
+        self.zoom = (start, end);
+        self
+    }
+
+    /// TEA-style: Show the waveform with drag-to-select, emitting selection changes
+    pub fn show_with<Msg>(
+        self,
+        ctx: &mut ViewCtx<'_, Msg>,
+        on_event: impl Fn(WaveformEvent) -> Msg,
+    ) {
+        if let Some(event) = self.show_events(ctx.ui) {
+            ctx.emit(on_event(event));
+        }
+    }
+
+    /// Show the waveform with drag-to-select, returning any selection change
+    pub fn show_events(self, ui: &mut Ui) -> Option<WaveformEvent> {
+        self.show_internal(ui, true).1
+    }
+
     /// Show the waveform
     pub fn show(self, ui: &mut Ui) -> Response {
+        self.show_internal(ui, false).0
+    }
+
+    fn show_internal(self, ui: &mut Ui, interactive: bool) -> (Response, Option<WaveformEvent>) {
         let theme = Theme::current(ui.ctx());
 
         // Calculate dimensions
@@ -192,7 +249,68 @@ impl<'a> Waveform<'a> {
             }
         }
 
-        response
+        // Drag-to-select (interacted after drawing so it sits above the plot)
+        let mut event = None;
+        if interactive {
+            let drag = ui
+                .interact(rect, response.id.with("select"), Sense::drag())
+                .on_hover_cursor(egui::CursorIcon::Text);
+            let anchor_id = response.id.with("select_anchor");
+            if let Some(pos) = drag.interact_pointer_pos() {
+                let at = self.x_to_normalized(rect, pos.x);
+                if drag.drag_started() {
+                    ui.ctx().data_mut(|d| d.insert_temp(anchor_id, at));
+                }
+                if drag.dragged() {
+                    let anchor = ui.ctx().data(|d| d.get_temp(anchor_id)).unwrap_or(at);
+                    let selection = (anchor.min(at), anchor.max(at));
+                    if self.selection != Some(selection) {
+                        event = Some(WaveformEvent::SelectionChanged(selection.0, selection.1));
+                    }
+                }
+            }
+        }
+
+        // Selection band (latest drag wins over the stored selection)
+        let selection = match event {
+            Some(WaveformEvent::SelectionChanged(start, end)) => Some((start, end)),
+            None => self.selection,
+        };
+        if let Some((start, end)) = selection {
+            if ui.is_rect_visible(rect) {
+                let x0 = self.normalized_to_x(rect, start);
+                let x1 = self.normalized_to_x(rect, end);
+                let band = Rect::from_x_y_ranges(x0.min(x1)..=x0.max(x1), rect.y_range());
+                let painter = ui.painter();
+                painter.rect_filled(band, 0.0, theme.primary.gamma_multiply(0.2));
+                let edge = Stroke::new(theme.stroke_width, theme.primary);
+                painter.vline(band.min.x, rect.y_range(), edge);
+                painter.vline(band.max.x, rect.y_range(), edge);
+            }
+        }
+
+        (response, event)
+    }
+
+    /// Convert a screen x position to a normalized position over all samples
+    fn x_to_normalized(&self, rect: Rect, x: f32) -> f32 {
+        let t = ((x - rect.min.x) / rect.width().max(1.0)).clamp(0.0, 1.0);
+        self.zoom.0 + t * (self.zoom.1 - self.zoom.0)
+    }
+
+    /// Convert a normalized position over all samples to a screen x (clamped to the view)
+    fn normalized_to_x(&self, rect: Rect, at: f32) -> f32 {
+        let span = (self.zoom.1 - self.zoom.0).max(f32::EPSILON);
+        let t = ((at - self.zoom.0) / span).clamp(0.0, 1.0);
+        rect.min.x + t * rect.width()
+    }
+
+    /// The samples within the zoom range
+    fn zoomed<'s>(&self, samples: &'s [f32]) -> &'s [f32] {
+        let len = samples.len();
+        let start = ((self.zoom.0 * len as f32).floor() as usize).min(len);
+        let end = ((self.zoom.1 * len as f32).ceil() as usize).clamp(start, len);
+        &samples[start..end]
     }
 
     fn draw_waveform_in_rect(
@@ -203,6 +321,7 @@ impl<'a> Waveform<'a> {
         color: Color32,
         theme: &Theme,
     ) {
+        let samples = self.zoomed(samples);
         if samples.is_empty() {
             return;
         }
@@ -437,3 +556,47 @@ impl Widget for Waveform<'_> {
         self.show(ui)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{drag, run_ui, test_context};
+
+    #[test]
+    fn test_drag_emits_clamped_selection() {
+        let ctx = test_context();
+        let samples: Vec<f32> = (0..128).map(|i| (i as f32 * 0.1).sin()).collect();
+        let mut rect = Rect::NOTHING;
+
+        run_ui(&ctx, |ui| {
+            rect = Waveform::new(&samples).height(60.0).show(ui).rect;
+        });
+
+        let mut events = Vec::new();
+        let from = rect.left_center() + Vec2::new(rect.width() * 0.25, 0.0);
+        let to = rect.right_center() + Vec2::new(50.0, 0.0);
+        drag(&ctx, from, to, |ui| {
+            events.extend(Waveform::new(&samples).height(60.0).show_events(ui));
+        });
+
+        let Some(&WaveformEvent::SelectionChanged(start, end)) = events.last() else {
+            panic!("no selection emitted");
+        };
+        assert!(start < end);
+        assert!((0.0..=1.0).contains(&start));
+        assert_eq!(end, 1.0);
+    }
+
+    #[test]
+    fn test_zoom_maps_positions_into_range() {
+        let samples = [0.0; 100];
+        let waveform = Waveform::new(&samples).zoom((0.5, 1.0));
+        let rect = Rect::from_min_size(Pos2::ZERO, Vec2::new(200.0, 50.0));
+
+        assert_eq!(waveform.zoomed(&samples).len(), 50);
+        assert_eq!(waveform.x_to_normalized(rect, 0.0), 0.5);
+        assert_eq!(waveform.x_to_normalized(rect, 100.0), 0.75);
+        // Positions outside the zoom clamp to the view edge
+        assert_eq!(waveform.normalized_to_x(rect, 0.25), 0.0);
+    }
+}
@@ -13,8 +13,15 @@
 //! ArcSlider::new(0.0..=1.0)
 //!     .label("Dry/Wet")
 //!     .show_with(ctx, model.mix, Msg::SetMix);
+//!
+//! // Bipolar gain: fills from the center, snaps to 0
+//! ArcSlider::new(-24.0..=24.0)
+//!     .bipolar(true)
+//!     .suffix("dB")
+//!     .show_with(ctx, model.gain, Msg::SetGain);
 //! ```
 
+use super::bipolar;
 use crate::Theme;
 use egui::{Response, Sense, Ui, Vec2};
 use egui_cha::ViewCtx;
@@ -58,6 +65,7 @@ pub struct ArcSlider<'a> {
     disabled: bool,
     /// Arc thickness multiplier (1.0 = default)
     thickness: f32,
+    bipolar: bool,
 }
 
 impl<'a> ArcSlider<'a> {
@@ -72,6 +80,7 @@ impl<'a> ArcSlider<'a> {
             value_suffix: None,
             disabled: false,
             thickness: 1.0,
+            bipolar: false,
         }
     }
 
@@ -129,13 +138,21 @@ impl<'a> ArcSlider<'a> {
         self
     }
 
-    /// Get arc angles based on style
+    /// Bipolar mode: the arc fills outward from its center (the range
+    /// center) and values near the center snap to it
+    pub fn bipolar(mut self, bipolar: bool) -> Self {
+        self.bipolar = bipolar;
+        self
+    }
+
+    /// Get arc angles based on style (0 = 12 o'clock, clockwise).
+    /// Every style is symmetric around 12 o'clock.
     fn get_arc_angles(&self) -> (f32, f32) {
         match self.style {
-            ArcStyle::Standard => (0.75 * PI, 2.25 * PI), // 270° arc with bottom gap
-            ArcStyle::Full => (0.0, 2.0 * PI),            // Full circle
-            ArcStyle::Half => (PI, 2.0 * PI),             // Bottom half
-            ArcStyle::Quarter => (1.25 * PI, 1.75 * PI),  // Bottom quarter
+            ArcStyle::Standard => (-0.75 * PI, 0.75 * PI), // 270° arc with bottom gap
+            ArcStyle::Full => (-PI, PI),                   // Full circle
+            ArcStyle::Half => (-0.5 * PI, 0.5 * PI),       // Top half
+            ArcStyle::Quarter => (-0.25 * PI, 0.25 * PI),  // Top quarter
         }
     }
 
@@ -193,7 +210,11 @@ impl<'a> ArcSlider<'a> {
             // Horizontal drag also works: right = increase, left = decrease
             let sensitivity = 0.003 * (self.range.end() - self.range.start());
             let change = (-delta.y + delta.x * 0.5) as f64 * sensitivity;
-            *value = (*value + change).clamp(*self.range.start(), *self.range.end());
+            *value = if self.bipolar {
+                bipolar::drag_with_detent(ui, &response, *value, change, &self.range)
+            } else {
+                (*value + change).clamp(*self.range.start(), *self.range.end())
+            };
             response.mark_changed();
         }
 
@@ -234,14 +255,16 @@ impl<'a> ArcSlider<'a> {
             let normalized =
                 (*value - *self.range.start()) / (*self.range.end() - *self.range.start());
             let value_angle = arc_start + (arc_end - arc_start) * normalized as f32;
+            let (fill_start, fill_end) =
+                bipolar::fill_angles(normalized as f32, arc_start, arc_end, self.bipolar);
 
-            if normalized > 0.001 {
+            if fill_end - fill_start > 0.001 {
                 self.draw_arc(
                     painter,
                     center,
                     radius - stroke_width / 2.0,
-                    arc_start,
-                    value_angle,
+                    fill_start,
+                    fill_end,
                     egui::Stroke::new(stroke_width, arc_color),
                 );
             }
@@ -0,0 +1,107 @@
+//! Shared bipolar (center-detent) helpers for rotary controls
+//!
+//! Used by `Knob` and `ArcSlider`. Angles follow their drawing convention:
+//! 0 is 12 o'clock and angles grow clockwise.
+
+use egui::{Response, Ui};
+use std::ops::RangeInclusive;
+
+/// Detent width around the center, as a fraction of the range
+pub(crate) const CENTER_DETENT: f64 = 0.02;
+
+/// Center value of a range (where a bipolar control rests)
+pub(crate) fn center(range: &RangeInclusive<f64>) -> f64 {
+    (*range.start() + *range.end()) / 2.0
+}
+
+/// Snap `value` to the exact center when it is within the detent
+pub(crate) fn detent(value: f64, range: &RangeInclusive<f64>) -> f64 {
+    let center = center(range);
+    let width = (*range.end() - *range.start()).abs() * CENTER_DETENT;
+    if (value - center).abs() <= width {
+        center
+    } else {
+        value
+    }
+}
+
+/// Apply a drag `change` with a center detent. The unsnapped value is kept
+/// for the duration of the drag so slow drags can still leave the detent.
+pub(crate) fn drag_with_detent(
+    ui: &Ui,
+    response: &Response,
+    value: f64,
+    change: f64,
+    range: &RangeInclusive<f64>,
+) -> f64 {
+    let raw_id = response.id.with("detent_raw");
+    let raw = if response.drag_started() {
+        value
+    } else {
+        ui.ctx().data(|d| d.get_temp(raw_id)).unwrap_or(value)
+    };
+    let raw = (raw + change).clamp(*range.start(), *range.end());
+    ui.ctx().data_mut(|d| d.insert_temp(raw_id, raw));
+    detent(raw, range)
+}
+
+/// Start/end angles of the filled part of the arc. Unipolar fills from the
+/// arc start; bipolar fills outward from the arc's midpoint.
+pub(crate) fn fill_angles(
+    normalized: f32,
+    arc_start: f32,
+    arc_end: f32,
+    bipolar: bool,
+) -> (f32, f32) {
+    let value_angle = arc_start + (arc_end - arc_start) * normalized;
+    if bipolar {
+        let mid = (arc_start + arc_end) / 2.0;
+        (mid.min(value_angle), mid.max(value_angle))
+    } else {
+        (arc_start, value_angle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    const KNOB_ARC: (f32, f32) = (-0.75 * PI, 0.75 * PI);
+
+    fn normalized(value: f64) -> f32 {
+        ((value + 24.0) / 48.0) as f32
+    }
+
+    #[test]
+    fn bipolar_fill_is_symmetric_around_top() {
+        let (start, end) = KNOB_ARC;
+
+        // At zero the fill collapses onto 12 o'clock
+        assert_eq!(fill_angles(normalized(0.0), start, end, true), (0.0, 0.0));
+
+        // Mirrored values fill mirrored arcs
+        let (a0, a1) = fill_angles(normalized(12.0), start, end, true);
+        let (b0, b1) = fill_angles(normalized(-12.0), start, end, true);
+        assert_eq!(a0, 0.0);
+        assert_eq!(b1, 0.0);
+        assert!((a1 + b0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn unipolar_fill_starts_at_arc_start() {
+        let (start, end) = KNOB_ARC;
+        assert_eq!(
+            fill_angles(normalized(0.0), start, end, false),
+            (start, 0.0)
+        );
+    }
+
+    #[test]
+    fn values_near_center_snap_to_zero() {
+        let range = -24.0..=24.0;
+        assert_eq!(detent(0.5, &range), 0.0);
+        assert_eq!(detent(-0.9, &range), 0.0);
+        assert_eq!(detent(3.0, &range), 3.0);
+    }
+}
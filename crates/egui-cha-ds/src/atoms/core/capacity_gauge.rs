@@ -2,6 +2,7 @@
 //!
 //! A progress bar that automatically changes color based on utilization thresholds.
 //! Useful for showing resource capacity, worker pools, memory usage, etc.
+//! Can also be drawn as a circular ring or a segmented LED-style meter.
 //!
 //! # Example
 //! ```ignore
@@ -16,6 +17,10 @@
 //!     .show_fraction(799, 1000)
 //!     .show(ui);
 //!
+//! // Dashboard ring and LED-style meter
+//! CapacityGauge::new(0.62).ring().show_percentage().show(ui);
+//! CapacityGauge::new(0.62).segments(10).warn_at(0.6).show(ui);
+//!
 //! // TEA style
 //! CapacityGauge::new(model.cpu_usage)
 //!     .show_percentage()
@@ -23,7 +28,7 @@
 //! ```
 
 use crate::Theme;
-use egui::{Response, Ui, Widget};
+use egui::{Color32, Pos2, Rect, Response, Sense, Shape, Stroke, Ui, Vec2, Widget};
 use egui_cha::ViewCtx;
 
 /// Capacity gauge with threshold-based coloring
@@ -44,6 +49,19 @@ pub struct CapacityGauge {
     height: Option<f32>,
     /// Whether to animate when not full
     animate: bool,
+    /// Visual style
+    style: GaugeStyle,
+}
+
+/// Visual style of the gauge
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum GaugeStyle {
+    /// Horizontal progress bar
+    Bar,
+    /// Circular ring, filled clockwise from 12 o'clock
+    Ring,
+    /// Row of LED-style segments
+    Segments(usize),
 }
 
 /// How to display the value on the gauge
@@ -71,6 +89,7 @@ impl CapacityGauge {
             width: None,
             height: None,
             animate: false,
+            style: GaugeStyle::Bar,
         }
     }
 
@@ -95,6 +114,33 @@ impl CapacityGauge {
         self
     }
 
+    /// Set the warning threshold (0.0 - 1.0, default: 0.7)
+    pub fn warn_at(mut self, warning: f32) -> Self {
+        self.warning_threshold = warning.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the danger threshold (0.0 - 1.0, default: 0.9)
+    pub fn danger_at(mut self, danger: f32) -> Self {
+        self.danger_threshold = danger.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Draw as a circular ring gauge
+    ///
+    /// Size is taken from `width` (or `height`), default: theme.spacing_xl * 1.5.
+    /// The display text is drawn in the center.
+    pub fn ring(mut self) -> Self {
+        self.style = GaugeStyle::Ring;
+        self
+    }
+
+    /// Draw as `n` LED-style segments; a segment lights once its share is reached
+    pub fn segments(mut self, n: usize) -> Self {
+        self.style = GaugeStyle::Segments(n.max(1));
+        self
+    }
+
     /// Set a label to display before the gauge
     pub fn label(mut self, label: impl Into<String>) -> Self {
         self.label = Some(label.into());
@@ -131,7 +177,7 @@ impl CapacityGauge {
         self
     }
 
-    /// Enable animation when progress < 1.0
+    /// Enable animation when progress < 1.0 (bar style only)
     pub fn animate(mut self, animate: bool) -> Self {
         self.animate = animate;
         self
@@ -150,27 +196,51 @@ impl CapacityGauge {
         }
     }
 
-    fn show_internal(self, ui: &mut Ui) -> Response {
+    fn show_internal(mut self, ui: &mut Ui) -> Response {
         let theme = Theme::current(ui.ctx());
-
-        // Determine color based on thresholds
-        let fill_color = if self.progress >= self.danger_threshold {
-            theme.state_danger
-        } else if self.progress >= self.warning_threshold {
-            theme.state_warning
-        } else {
-            theme.state_success
-        };
+        let fill_color = self.fill_color(&theme);
 
         // Build display text
-        let text = match self.display {
+        let text = match std::mem::replace(&mut self.display, DisplayMode::None) {
             DisplayMode::None => None,
             DisplayMode::Percentage => Some(format!("{:.0}%", self.progress * 100.0)),
             DisplayMode::Fraction { current, total } => Some(format!("{} / {}", current, total)),
             DisplayMode::Custom(s) => Some(s),
         };
 
+        let label = self.label.take();
+        let gauge = |ui: &mut Ui| match self.style {
+            GaugeStyle::Bar => self.show_bar(ui, fill_color, text),
+            GaugeStyle::Ring => self.show_ring(ui, &theme, fill_color, text),
+            GaugeStyle::Segments(n) => self.show_segments(ui, &theme, fill_color, n, text),
+        };
+
+        // Build the gauge with optional label
+        if let Some(label_text) = label {
+            ui.horizontal(|ui| {
+                ui.label(&label_text);
+                gauge(ui)
+            })
+            .inner
+        } else {
+            gauge(ui)
+        }
+    }
+
+    /// Color for the current progress based on thresholds
+    fn fill_color(&self, theme: &Theme) -> Color32 {
+        if self.progress >= self.danger_threshold {
+            theme.state_danger
+        } else if self.progress >= self.warning_threshold {
+            theme.state_warning
+        } else {
+            theme.state_success
+        }
+    }
+
+    fn show_bar(&self, ui: &mut Ui, fill_color: Color32, text: Option<String>) -> Response {
         // Get height from theme if not specified
+        let theme = Theme::current(ui.ctx());
         let height = self.height.unwrap_or(theme.spacing_md);
 
         // Build the progress bar
@@ -187,19 +257,103 @@ impl CapacityGauge {
             bar = bar.text(t);
         }
 
-        // Build the gauge with optional label
-        if let Some(label_text) = self.label {
-            ui.horizontal(|ui| {
-                ui.label(&label_text);
-                ui.add(bar)
-            })
-            .inner
-        } else {
-            ui.add(bar)
+        ui.add(bar)
+    }
+
+    fn show_ring(
+        &self,
+        ui: &mut Ui,
+        theme: &Theme,
+        fill_color: Color32,
+        text: Option<String>,
+    ) -> Response {
+        let size = self.width.or(self.height).unwrap_or(theme.spacing_xl * 1.5);
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(size), Sense::click());
+
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter();
+            let thickness = (size * 0.12).max(2.0);
+            let center = rect.center();
+            let radius = (size - thickness) / 2.0;
+
+            painter.circle_stroke(center, radius, Stroke::new(thickness, theme.bg_tertiary));
+
+            if self.progress > 0.0 {
+                let end = std::f32::consts::TAU * self.progress;
+                let steps = ((64.0 * self.progress).ceil() as usize).max(2);
+                let points: Vec<Pos2> = (0..=steps)
+                    .map(|i| {
+                        let angle = end * i as f32 / steps as f32;
+                        center + Vec2::new(angle.sin(), -angle.cos()) * radius
+                    })
+                    .collect();
+                painter.add(Shape::line(points, Stroke::new(thickness, fill_color)));
+            }
+
+            if let Some(t) = text {
+                painter.text(
+                    center,
+                    egui::Align2::CENTER_CENTER,
+                    t,
+                    egui::FontId::proportional(theme.font_size_sm),
+                    theme.text_primary,
+                );
+            }
+        }
+
+        response
+    }
+
+    fn show_segments(
+        &self,
+        ui: &mut Ui,
+        theme: &Theme,
+        fill_color: Color32,
+        count: usize,
+        text: Option<String>,
+    ) -> Response {
+        let height = self.height.unwrap_or(theme.spacing_md);
+        let width = self.width.unwrap_or_else(|| ui.available_width());
+        let (rect, response) = ui.allocate_exact_size(Vec2::new(width, height), Sense::click());
+
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter();
+            let gap = theme.spacing_xs / 3.0;
+            let segment_width = (width - gap * (count - 1) as f32) / count as f32;
+            let lit = lit_segments(self.progress, count);
+
+            for i in 0..count {
+                let x = rect.min.x + i as f32 * (segment_width + gap);
+                let segment =
+                    Rect::from_min_size(Pos2::new(x, rect.min.y), Vec2::new(segment_width, height));
+                let color = if i < lit {
+                    fill_color
+                } else {
+                    theme.bg_tertiary
+                };
+                painter.rect_filled(segment, theme.radius_sm, color);
+            }
+
+            if let Some(t) = text {
+                painter.text(
+                    rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    t,
+                    egui::FontId::proportional(theme.font_size_sm),
+                    theme.text_primary,
+                );
+            }
         }
+
+        response
     }
 }
 
+/// Number of segments lit for `progress` (a segment lights once fully reached)
+fn lit_segments(progress: f32, count: usize) -> usize {
+    ((progress * count as f32 + 1e-4).floor() as usize).min(count)
+}
+
 impl Widget for CapacityGauge {
     fn ui(self, ui: &mut Ui) -> Response {
         self.show(ui)
@@ -209,6 +363,7 @@ impl Widget for CapacityGauge {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::{run_ui, test_context};
 
     #[test]
     fn test_from_fraction() {
@@ -222,6 +377,40 @@ mod tests {
         assert_eq!(gauge.progress, 0.0);
     }
 
+    #[test]
+    fn test_danger_color_above_threshold() {
+        let theme = Theme::default();
+        let gauge = CapacityGauge::new(0.95).warn_at(0.5).danger_at(0.8);
+        assert_eq!(gauge.fill_color(&theme), theme.state_danger);
+
+        let gauge = CapacityGauge::new(0.6).warn_at(0.5).danger_at(0.8);
+        assert_eq!(gauge.fill_color(&theme), theme.state_warning);
+    }
+
+    #[test]
+    fn test_segments_light_up_to_progress() {
+        let ctx = test_context();
+        let theme = Theme::current(&ctx);
+        let output = run_ui(&ctx, |ui| {
+            CapacityGauge::new(0.65)
+                .segments(10)
+                .width(200.0)
+                .danger_at(0.6)
+                .show(ui);
+        });
+
+        let mut fills = Vec::new();
+        for clipped in &output.shapes {
+            if let Shape::Rect(rect) = &clipped.shape {
+                fills.push(rect.fill);
+            }
+        }
+        let lit = fills.iter().filter(|c| **c == theme.state_danger).count();
+        assert_eq!(lit, 6);
+        assert_eq!(lit_segments(0.7, 10), 7);
+        assert_eq!(lit_segments(1.0, 8), 8);
+    }
+
     #[test]
     fn test_progress_clamping() {
         let gauge = CapacityGauge::new(1.5);
@@ -3,7 +3,7 @@
 //! Theme-aware code display components with proper scaling support.
 
 use crate::theme::Theme;
-use egui::{FontId, RichText, Ui};
+use egui::{Color32, FontId, RichText, Shape, Ui};
 
 /// Inline code styling
 ///
@@ -45,6 +45,8 @@ impl<'a> Code<'a> {
 pub struct CodeBlock<'a> {
     code: &'a str,
     language: Option<&'a str>,
+    line_numbers: bool,
+    highlight_lines: &'a [usize],
 }
 
 impl<'a> CodeBlock<'a> {
@@ -52,6 +54,8 @@ impl<'a> CodeBlock<'a> {
         Self {
             code,
             language: None,
+            line_numbers: false,
+            highlight_lines: &[],
         }
     }
 
@@ -60,6 +64,23 @@ impl<'a> CodeBlock<'a> {
         self
     }
 
+    /// Show a muted line-number gutter
+    pub fn line_numbers(mut self, show: bool) -> Self {
+        self.line_numbers = show;
+        self
+    }
+
+    /// Tint the given lines (1-based) with a subtle primary background
+    pub fn highlight_lines(mut self, lines: &'a [usize]) -> Self {
+        self.highlight_lines = lines;
+        self
+    }
+
+    /// Background tint of highlighted lines
+    fn highlight_color(theme: &Theme) -> Color32 {
+        theme.primary.gamma_multiply(0.15)
+    }
+
     pub fn show(self, ui: &mut Ui) {
         let theme = Theme::current(ui.ctx());
 
@@ -87,11 +108,108 @@ impl<'a> CodeBlock<'a> {
                 }
 
                 // Code content
+                let font = FontId::monospace(theme.font_size_sm);
+                if !self.line_numbers && self.highlight_lines.is_empty() {
+                    ui.label(RichText::new(self.code).color(fg).font(font));
+                } else {
+                    self.show_lines(ui, &theme, font);
+                }
+            });
+    }
+
+    /// One row per line, with optional gutter and highlight
+    fn show_lines(&self, ui: &mut Ui, theme: &Theme, font: FontId) {
+        let lines: Vec<&str> = self.code.lines().collect();
+        let digits = lines.len().max(1).to_string().len();
+        let gutter_width = ui.fonts_mut(|f| f.glyph_width(&font, '0')) * digits as f32;
+        let tint = Self::highlight_color(theme);
+
+        ui.spacing_mut().item_spacing.y = 0.0;
+        let mut highlighted = Vec::new();
+        for (index, line) in lines.iter().enumerate() {
+            let number = index + 1;
+            // Reserve the background slot so the tint is painted under the text
+            let background = ui.painter().add(Shape::Noop);
+            let row = ui.horizontal(|ui| {
+                if self.line_numbers {
+                    ui.allocate_ui_with_layout(
+                        egui::vec2(gutter_width, 0.0),
+                        egui::Layout::right_to_left(egui::Align::Center),
+                        |ui| {
+                            ui.label(
+                                RichText::new(number.to_string())
+                                    .color(theme.text_muted)
+                                    .font(font.clone()),
+                            );
+                        },
+                    );
+                    ui.add_space(theme.spacing_sm);
+                }
                 ui.label(
-                    RichText::new(self.code)
-                        .color(fg)
-                        .font(FontId::monospace(theme.font_size_sm)),
+                    RichText::new(*line)
+                        .color(theme.text_primary)
+                        .font(font.clone()),
                 );
             });
+            if self.highlight_lines.contains(&number) {
+                highlighted.push((background, row.response.rect));
+            }
+        }
+
+        // Tints span the widest line once all rows are laid out
+        let right = ui.min_rect().right();
+        for (background, rect) in highlighted {
+            let rect = rect
+                .with_max_x(right)
+                .expand2(egui::vec2(theme.spacing_xs, 0.0));
+            ui.painter()
+                .set(background, Shape::rect_filled(rect, theme.radius_sm, tint));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{painted_text_rect, painted_texts, run_ui, test_context};
+
+    const SNIPPET: &str = "let a = 1;\nlet b = a + 1;\nprintln!(\"{b}\");";
+
+    #[test]
+    fn line_numbers_render_gutter() {
+        let ctx = test_context();
+        let output = run_ui(&ctx, |ui| {
+            CodeBlock::new(SNIPPET).line_numbers(true).show(ui)
+        });
+
+        let texts = painted_texts(&output);
+        for number in ["1", "2", "3"] {
+            assert!(texts.iter().any(|t| t == number), "{texts:?}");
+        }
+        assert!(!texts.iter().any(|t| t == "4"));
+    }
+
+    #[test]
+    fn highlighted_line_gets_tint() {
+        let ctx = test_context();
+        let output = run_ui(&ctx, |ui| {
+            CodeBlock::new(SNIPPET).highlight_lines(&[2]).show(ui)
+        });
+
+        let tint = CodeBlock::highlight_color(&Theme::current(&ctx));
+        let tinted: Vec<_> = output
+            .shapes
+            .iter()
+            .filter_map(|clipped| match &clipped.shape {
+                Shape::Rect(rect) if rect.fill == tint => Some(rect.rect),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(tinted.len(), 1);
+
+        let second = painted_text_rect(&output, "let b = a + 1;").unwrap();
+        assert!(tinted[0].contains(second.center()));
+        let first = painted_text_rect(&output, "let a = 1;").unwrap();
+        assert!(!tinted[0].contains(first.center()));
     }
 }
@@ -3,7 +3,8 @@
 //! A vertical fader/slider commonly used in mixers, DAWs, and audio software.
 //!
 //! # Features
-//! - Vertical drag control
+//! - Vertical drag control (Shift for fine adjustment)
+//! - Double-click to type a value, Alt+double-click to reset
 //! - Optional label and value display
 //! - dB scale support
 //! - Multiple size variants
@@ -27,6 +28,7 @@
 //!     .show_with(ctx, model.ch1, Msg::SetCh1);
 //! ```
 
+use super::value_entry::{drag_scale, entry_rect, reset_modifier, ValueEntry};
 use crate::Theme;
 use egui::{Response, Sense, Ui, Vec2, Widget};
 use egui_cha::ViewCtx;
@@ -166,7 +168,7 @@ impl<'a> Fader<'a> {
             let delta = response.drag_delta();
             // Vertical drag: up = increase, down = decrease
             let range_size = *self.range.end() - *self.range.start();
-            let sensitivity = range_size / (height - theme.spacing_md) as f64;
+            let sensitivity = range_size / (height - theme.spacing_md) as f64 * drag_scale(ui);
             *value = (*value - delta.y as f64 * sensitivity)
                 .clamp(*self.range.start(), *self.range.end());
         }
@@ -181,13 +183,18 @@ impl<'a> Fader<'a> {
             }
         }
 
-        // Double-click to reset
+        // Alt+double-click resets, double-click types a value
+        let entry = ValueEntry::new(response.id);
         if response.double_clicked() && !self.disabled {
-            // Reset to 0 for dB scale, or center for linear
-            if self.db_scale {
-                *value = 0.0_f64.clamp(*self.range.start(), *self.range.end());
+            if reset_modifier(ui) {
+                // Reset to 0 for dB scale, or center for linear
+                if self.db_scale {
+                    *value = 0.0_f64.clamp(*self.range.start(), *self.range.end());
+                } else {
+                    *value = (*self.range.start() + *self.range.end()) / 2.0;
+                }
             } else {
-                *value = (*self.range.start() + *self.range.end()) / 2.0;
+                entry.open(ui, *value);
             }
         }
 
@@ -311,6 +318,12 @@ impl<'a> Fader<'a> {
             }
         }
 
+        let value_center = rect.center_top() + Vec2::new(0.0, theme.font_size_xs / 2.0);
+        if let Some(typed) = entry.show(ui, entry_rect(value_center, width, &theme)) {
+            *value = typed.clamp(*self.range.start(), *self.range.end());
+            response.mark_changed();
+        }
+
         // Mark response as changed if value was modified
         if response.dragged() || response.clicked() || response.double_clicked() {
             response.mark_changed();
@@ -325,3 +338,33 @@ impl Widget for Fader<'_> {
         self.show_internal(ui, &mut dummy)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{drag_with_modifiers, run_ui, test_context};
+    use egui::Modifiers;
+
+    #[test]
+    fn shift_drag_is_finer() {
+        let drag_delta = |modifiers: Modifiers| {
+            let ctx = test_context();
+            let mut rect = egui::Rect::NOTHING;
+            run_ui(&ctx, |ui| {
+                rect = Fader::new(0.0..=1.0).show(ui, &mut 0.5).rect;
+            });
+
+            let mut value = 0.5;
+            let from = rect.center();
+            drag_with_modifiers(&ctx, from, from - Vec2::new(0.0, 40.0), modifiers, |ui| {
+                Fader::new(0.0..=1.0).show(ui, &mut value);
+            });
+            value - 0.5
+        };
+
+        let plain = drag_delta(Modifiers::NONE);
+        let fine = drag_delta(Modifiers::SHIFT);
+        assert!(plain > 0.0 && fine > 0.0);
+        assert!(fine < plain / 5.0, "plain {plain}, fine {fine}");
+    }
+}
@@ -71,6 +71,7 @@ pub mod icons {
     pub const HOUSE: &str = "\u{e2c2}";
     pub const ARROW_LEFT: &str = "\u{e058}";
     pub const ARROW_RIGHT: &str = "\u{e06c}";
+    pub const DOTS_THREE: &str = "\u{e1fe}";
 
     // Actions
     pub const PLUS: &str = "\u{e3d4}";
@@ -101,6 +102,7 @@ pub mod icons {
     pub const PAUSE: &str = "\u{e39e}";
     pub const STOP: &str = "\u{e46c}";
     pub const RECORD: &str = "\u{e3f0}";
+    pub const REPEAT: &str = "\u{e3f6}";
 
     // Misc
     pub const COPY: &str = "\u{e1ca}";
@@ -109,6 +111,7 @@ pub mod icons {
     pub const LINK_SIMPLE: &str = "\u{e2e6}";
     pub const EYE: &str = "\u{e220}";
     pub const EYE_SLASH: &str = "\u{e222}";
+    pub const EYEDROPPER: &str = "\u{e568}";
 
     // Status / Alerts
     pub const FIRE: &str = "\u{e242}";
@@ -120,6 +123,7 @@ pub mod icons {
     // Window controls
     pub const CARET_UP: &str = "\u{e13c}";
     pub const CARET_DOWN: &str = "\u{e136}";
+    pub const CARET_RIGHT: &str = "\u{e13a}";
     pub const LOCK: &str = "\u{e2ec}";
     pub const LOCK_OPEN: &str = "\u{e2ee}";
     pub const CORNERS_OUT: &str = "\u{e1ce}"; // maximize
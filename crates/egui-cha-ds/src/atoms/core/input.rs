@@ -9,17 +9,74 @@
 //!     .placeholder("Enter text...")
 //!     .desired_width(200.0)
 //!     .show(ui, &mut value);
+//!
+//! // Numeric stepper: clamps to range, emits parsed f64
+//! Input::number(0.0, 100.0, 5.0)
+//!     .show_number_with(ctx, model.volume, Msg::SetVolume);
 //! ```
 
-use crate::Theme;
-use egui::Ui;
+use crate::{icons, Theme};
+use egui::{Response, RichText, Ui};
 use egui_cha::ViewCtx;
 
+/// Default width of the text field in number mode
+const NUMBER_FIELD_WIDTH: f32 = 64.0;
+
+/// Numeric range and step for number inputs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberRange {
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+}
+
+impl NumberRange {
+    /// Create a new range (min and max are swapped if reversed)
+    pub fn new(min: f64, max: f64, step: f64) -> Self {
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        Self {
+            min,
+            max,
+            step: step.abs(),
+        }
+    }
+
+    /// Clamp a value into the range
+    pub fn clamp(&self, value: f64) -> f64 {
+        value.clamp(self.min, self.max)
+    }
+
+    /// Value one step up, clamped
+    pub fn increment(&self, value: f64) -> f64 {
+        self.clamp(value + self.step)
+    }
+
+    /// Value one step down, clamped
+    pub fn decrement(&self, value: f64) -> f64 {
+        self.clamp(value - self.step)
+    }
+
+    /// Parse typed text, falling back to `last_valid` if it is not a number
+    pub fn parse(&self, text: &str, last_valid: f64) -> f64 {
+        match text.trim().parse::<f64>() {
+            Ok(value) if value.is_finite() => self.clamp(value),
+            _ => last_valid,
+        }
+    }
+}
+
+impl Default for NumberRange {
+    fn default() -> Self {
+        Self::new(f64::MIN, f64::MAX, 1.0)
+    }
+}
+
 /// A text input component with Theme styling
 pub struct Input<'a> {
     placeholder: &'a str,
     password: bool,
     desired_width: Option<f32>,
+    number: Option<NumberRange>,
 }
 
 impl<'a> Input<'a> {
@@ -28,6 +85,15 @@ impl<'a> Input<'a> {
             placeholder: "",
             password: false,
             desired_width: None,
+            number: None,
+        }
+    }
+
+    /// Numeric stepper input with +/- buttons, clamped to `min..=max`
+    pub fn number(min: f64, max: f64, step: f64) -> Self {
+        Self {
+            number: Some(NumberRange::new(min, max, step)),
+            ..Self::new()
         }
     }
 
@@ -84,6 +150,87 @@ impl<'a> Input<'a> {
         }
     }
 
+    /// TEA-style: Show numeric stepper, emit parsed value on change
+    ///
+    /// Typed text is committed (parsed and clamped) when the field loses
+    /// focus. Non-numeric text is rejected and the last valid value kept.
+    pub fn show_number_with<Msg>(
+        self,
+        ctx: &mut ViewCtx<'_, Msg>,
+        value: f64,
+        on_change: impl FnOnce(f64) -> Msg,
+    ) {
+        let range = self.number.unwrap_or_default();
+        let theme = Theme::current(ctx.ui.ctx());
+        let id = ctx.ui.next_auto_id();
+        let mut new_value = value;
+
+        let step_button = |icon: &str| {
+            egui::Button::new(
+                RichText::new(icon)
+                    .family(egui::FontFamily::Name("icons".into()))
+                    .color(theme.text_secondary),
+            )
+            .frame(false)
+        };
+
+        egui::Frame::new()
+            .stroke(egui::Stroke::new(1.0, theme.border))
+            .corner_radius(theme.radius_sm)
+            .fill(theme.bg_primary)
+            .inner_margin(egui::Margin::symmetric(8, 6))
+            .show(ctx.ui, |ui| {
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(value > range.min, step_button(icons::MINUS))
+                        .clicked()
+                    {
+                        new_value = range.decrement(value);
+                    }
+
+                    // Keep the typed text across frames while editing
+                    let text_id = id.with("text");
+                    let mut text: String = ui
+                        .ctx()
+                        .data(|d| d.get_temp(text_id))
+                        .unwrap_or_else(|| format_number(value));
+
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut text)
+                            .id(id)
+                            .hint_text(self.placeholder)
+                            .text_color(theme.text_primary)
+                            .desired_width(self.desired_width.unwrap_or(NUMBER_FIELD_WIDTH))
+                            .frame(false),
+                    );
+
+                    if response.changed() {
+                        text.retain(|c| c.is_ascii_digit() || c == '.' || c == '-');
+                    }
+
+                    if response.has_focus() {
+                        ui.ctx().data_mut(|d| d.insert_temp(text_id, text));
+                    } else {
+                        if response.lost_focus() {
+                            new_value = range.parse(&text, value);
+                        }
+                        ui.ctx().data_mut(|d| d.remove::<String>(text_id));
+                    }
+
+                    if ui
+                        .add_enabled(value < range.max, step_button(icons::PLUS))
+                        .clicked()
+                    {
+                        new_value = range.increment(value);
+                    }
+                });
+            });
+
+        if new_value != value {
+            ctx.emit(on_change(new_value));
+        }
+    }
+
     /// Legacy: Show input and emit msg on change (requires &mut)
     pub fn on_change<Msg, F>(self, ctx: &mut ViewCtx<'_, Msg>, value: &mut String, to_msg: F)
     where
@@ -96,8 +243,8 @@ impl<'a> Input<'a> {
         }
     }
 
-    /// Show the input (modifies value in place)
-    pub fn show(self, ui: &mut Ui, value: &mut String) {
+    /// Show the input (modifies value in place), returning the text field's response
+    pub fn show(self, ui: &mut Ui, value: &mut String) -> Response {
         let theme = Theme::current(ui.ctx());
 
         let mut edit = egui::TextEdit::singleline(value)
@@ -119,9 +266,8 @@ impl<'a> Input<'a> {
             .corner_radius(theme.radius_sm)
             .fill(theme.bg_primary)
             .inner_margin(egui::Margin::symmetric(8, 6))
-            .show(ui, |ui| {
-                ui.add(edit);
-            });
+            .show(ui, |ui| ui.add(edit))
+            .inner
     }
 }
 
@@ -130,3 +276,48 @@ impl<'a> Default for Input<'a> {
         Self::new()
     }
 }
+
+/// Format a number without a trailing `.0` for whole values
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        format!("{}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamps_above_max() {
+        let range = NumberRange::new(0.0, 10.0, 1.0);
+        assert_eq!(range.parse("42", 5.0), 10.0);
+        assert_eq!(range.clamp(-3.0), 0.0);
+    }
+
+    #[test]
+    fn test_steps_by_step() {
+        let range = NumberRange::new(0.0, 10.0, 2.5);
+        assert_eq!(range.increment(5.0), 7.5);
+        assert_eq!(range.decrement(5.0), 2.5);
+        assert_eq!(range.increment(9.0), 10.0);
+        assert_eq!(range.decrement(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_rejects_non_numeric() {
+        let range = NumberRange::new(0.0, 10.0, 1.0);
+        assert_eq!(range.parse("abc", 3.0), 3.0);
+        assert_eq!(range.parse("", 3.0), 3.0);
+        assert_eq!(range.parse("NaN", 3.0), 3.0);
+        assert_eq!(range.parse(" 4.5 ", 3.0), 4.5);
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(3.0), "3");
+        assert_eq!(format_number(2.5), "2.5");
+    }
+}
@@ -4,10 +4,13 @@
 //!
 //! # Features
 //! - Circular knob with arc indicator
-//! - Drag to adjust value
+//! - Drag to adjust value (Shift for fine adjustment)
+//! - Double-click to type a value, Alt+double-click to reset
 //! - Optional label and value display
 //! - Theme-aware styling
 //! - Multiple size variants
+//! - Optional MIDI CC label and learn mode
+//! - Bipolar mode for pan/gain (fills from center, snaps to center)
 //!
 //! # Example
 //! ```ignore
@@ -24,14 +27,35 @@
 //! Knob::new(0.0..=1.0)
 //!     .compact()
 //!     .show_with(ctx, model.pan, Msg::SetPan);
+//!
+//! // MIDI-mappable: shows "CC74", click starts learn
+//! Knob::new(0.0..=1.0)
+//!     .cc(model.cutoff_cc)
+//!     .learnable(true)
+//!     .learning(model.learning == Some(Param::Cutoff))
+//!     .show_with_events(ctx, model.cutoff, |e| match e {
+//!         KnobEvent::Change(v) => Msg::SetCutoff(v),
+//!         KnobEvent::StartLearn => Msg::LearnCutoff,
+//!     });
 //! ```
 
+use super::bipolar;
+use super::value_entry::{drag_scale, entry_rect, reset_modifier, ValueEntry};
 use crate::Theme;
 use egui::{Response, Sense, Ui, Vec2, Widget};
 use egui_cha::ViewCtx;
 use std::f32::consts::PI;
 use std::ops::RangeInclusive;
 
+/// Knob events (see [`Knob::show_with_events`])
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KnobEvent {
+    /// Value changed
+    Change(f64),
+    /// Clicked while learnable: start MIDI learn for this knob
+    StartLearn,
+}
+
 /// Size variant for Knob
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum KnobSize {
@@ -51,6 +75,10 @@ pub struct Knob<'a> {
     size: KnobSize,
     show_value: bool,
     disabled: bool,
+    cc: Option<u8>,
+    learnable: bool,
+    learning: bool,
+    bipolar: bool,
     /// Arc start angle (radians from bottom, clockwise)
     arc_start: f32,
     /// Arc end angle (radians from bottom, clockwise)
@@ -66,6 +94,10 @@ impl<'a> Knob<'a> {
             size: KnobSize::default(),
             show_value: true,
             disabled: false,
+            cc: None,
+            learnable: false,
+            learning: false,
+            bipolar: false,
             // Default arc: 270 degrees, starting from bottom-left
             arc_start: -0.75 * PI, // -135 degrees
             arc_end: 0.75 * PI,    // +135 degrees
@@ -102,6 +134,32 @@ impl<'a> Knob<'a> {
         self
     }
 
+    /// Show the assigned MIDI CC number (e.g. "CC74") under the knob
+    pub fn cc(mut self, cc: Option<u8>) -> Self {
+        self.cc = cc;
+        self
+    }
+
+    /// Make a single click request MIDI learn ([`KnobEvent::StartLearn`])
+    /// instead of doing nothing. Use with [`Knob::show_with_events`].
+    pub fn learnable(mut self, learnable: bool) -> Self {
+        self.learnable = learnable;
+        self
+    }
+
+    /// Highlight the knob while it is waiting for a MIDI CC
+    pub fn learning(mut self, learning: bool) -> Self {
+        self.learning = learning;
+        self
+    }
+
+    /// Bipolar mode: the arc fills outward from 12 o'clock (the range
+    /// center) and values near the center snap to it
+    pub fn bipolar(mut self, bipolar: bool) -> Self {
+        self.bipolar = bipolar;
+        self
+    }
+
     /// TEA-style: Show knob with immutable value, emit Msg on change
     pub fn show_with<Msg>(
         self,
@@ -110,29 +168,54 @@ impl<'a> Knob<'a> {
         on_change: impl FnOnce(f64) -> Msg,
     ) {
         let mut current = value;
-        let response = self.show_internal(ctx.ui, &mut current);
+        let (response, _) = self.show_internal(ctx.ui, &mut current);
 
         if response.changed() {
             ctx.emit(on_change(current));
         }
     }
 
+    /// TEA-style: Show knob and emit value changes and learn requests
+    pub fn show_with_events<Msg>(
+        self,
+        ctx: &mut ViewCtx<'_, Msg>,
+        value: f64,
+        on_event: impl FnOnce(KnobEvent) -> Msg,
+    ) {
+        let mut current = value;
+        if let Some(event) = self.show_events(ctx.ui, &mut current) {
+            ctx.emit(on_event(event));
+        }
+    }
+
     /// Show knob (modifies value in place)
     pub fn show(self, ui: &mut Ui, value: &mut f64) -> Response {
-        self.show_internal(ui, value)
+        self.show_internal(ui, value).0
     }
 
-    fn show_internal(self, ui: &mut Ui, value: &mut f64) -> Response {
-        let theme = Theme::current(ui.ctx());
+    /// Show knob (modifies value in place), returns event if any
+    pub fn show_events(self, ui: &mut Ui, value: &mut f64) -> Option<KnobEvent> {
+        let (response, learn) = self.show_internal(ui, value);
+        if learn {
+            Some(KnobEvent::StartLearn)
+        } else if response.changed() {
+            Some(KnobEvent::Change(*value))
+        } else {
+            None
+        }
+    }
 
-        // Calculate size based on variant
-        let diameter = match self.size {
+    /// Knob diameter for the current size variant
+    fn diameter(&self, theme: &Theme) -> f32 {
+        match self.size {
             KnobSize::Compact => theme.spacing_lg + theme.spacing_md, // ~40px
             KnobSize::Medium => theme.spacing_xl + theme.spacing_md,  // ~48px
             KnobSize::Large => theme.spacing_xl + theme.spacing_lg,   // ~56px
-        };
+        }
+    }
 
-        // Total height including label
+    /// Total size the knob allocates, including label and value rows
+    pub(crate) fn layout_size(&self, theme: &Theme) -> Vec2 {
         let label_height = if self.label.is_some() {
             theme.font_size_xs + theme.spacing_xs
         } else {
@@ -143,10 +226,29 @@ impl<'a> Knob<'a> {
         } else {
             0.0
         };
-        let total_height = diameter + label_height + value_height;
+        let diameter = self.diameter(theme);
+        Vec2::new(
+            diameter,
+            diameter + label_height + value_height + self.cc_height(theme),
+        )
+    }
+
+    /// Height of the CC label row
+    fn cc_height(&self, theme: &Theme) -> f32 {
+        if self.cc.is_some() || self.learning {
+            theme.font_size_xs * 0.8 + theme.spacing_xs * 0.5
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the response and whether a learn request was made
+    fn show_internal(self, ui: &mut Ui, value: &mut f64) -> (Response, bool) {
+        let theme = Theme::current(ui.ctx());
+        let diameter = self.diameter(&theme);
 
         let (rect, mut response) = ui.allocate_exact_size(
-            Vec2::new(diameter, total_height),
+            self.layout_size(&theme),
             if self.disabled {
                 Sense::hover()
             } else {
@@ -158,16 +260,30 @@ impl<'a> Knob<'a> {
         if response.dragged() && !self.disabled {
             let delta = response.drag_delta();
             // Vertical drag: up = increase, down = decrease
-            let sensitivity = 0.005 * (self.range.end() - self.range.start());
-            *value = (*value - delta.y as f64 * sensitivity)
-                .clamp(*self.range.start(), *self.range.end());
+            let sensitivity = 0.005 * (self.range.end() - self.range.start()) * drag_scale(ui);
+            let change = -delta.y as f64 * sensitivity;
+            *value = if self.bipolar {
+                bipolar::drag_with_detent(ui, &response, *value, change, &self.range)
+            } else {
+                (*value + change).clamp(*self.range.start(), *self.range.end())
+            };
         }
 
-        // Double-click to reset to center
+        // Alt+double-click resets to center, double-click types a value
+        let entry = ValueEntry::new(response.id);
         if response.double_clicked() && !self.disabled {
-            *value = (*self.range.start() + *self.range.end()) / 2.0;
+            if reset_modifier(ui) {
+                *value = (*self.range.start() + *self.range.end()) / 2.0;
+                response.mark_changed();
+            } else {
+                entry.open(ui, *value);
+            }
         }
 
+        // Single click requests MIDI learn
+        let learn =
+            self.learnable && !self.disabled && response.clicked() && !response.double_clicked();
+
         if ui.is_rect_visible(rect) {
             let painter = ui.painter();
             let knob_center = rect.center_top() + Vec2::new(0.0, diameter / 2.0);
@@ -176,6 +292,8 @@ impl<'a> Knob<'a> {
             // Colors
             let (bg_color, track_color, arc_color) = if self.disabled {
                 (theme.bg_tertiary, theme.border, theme.text_muted)
+            } else if self.learning {
+                (theme.bg_tertiary, theme.border, theme.state_warning)
             } else if response.hovered() || response.dragged() {
                 (theme.bg_tertiary, theme.border, theme.primary_hover)
             } else {
@@ -200,18 +318,33 @@ impl<'a> Knob<'a> {
             let normalized =
                 (*value - *self.range.start()) / (*self.range.end() - *self.range.start());
             let value_angle = self.arc_start + (self.arc_end - self.arc_start) * normalized as f32;
+            let (fill_start, fill_end) = bipolar::fill_angles(
+                normalized as f32,
+                self.arc_start,
+                self.arc_end,
+                self.bipolar,
+            );
 
-            if normalized > 0.001 {
+            if fill_end - fill_start > 0.001 {
                 self.draw_arc(
                     painter,
                     knob_center,
                     radius - stroke_width,
-                    self.arc_start,
-                    value_angle,
+                    fill_start,
+                    fill_end,
                     egui::Stroke::new(stroke_width, arc_color),
                 );
             }
 
+            // Center detent tick
+            if self.bipolar {
+                let top = knob_center - Vec2::new(0.0, radius);
+                painter.line_segment(
+                    [top, top + Vec2::new(0.0, stroke_width)],
+                    egui::Stroke::new(theme.stroke_width, theme.text_muted),
+                );
+            }
+
             // Indicator dot
             let dot_radius = theme.spacing_xs / 2.0;
             let dot_distance = radius - stroke_width * 2.5;
@@ -242,9 +375,39 @@ impl<'a> Knob<'a> {
                 );
             }
 
+            // Learn ring
+            if self.learning {
+                painter.circle_stroke(
+                    knob_center,
+                    radius + 1.0,
+                    egui::Stroke::new(theme.stroke_width, theme.state_warning),
+                );
+            }
+
+            // CC label at the very bottom
+            let cc_height = self.cc_height(&theme);
+            if cc_height > 0.0 {
+                let cc_text = match self.cc {
+                    Some(cc) => format!("CC{}", cc),
+                    None => "LEARN".to_string(),
+                };
+                painter.text(
+                    rect.center_bottom() - Vec2::new(0.0, cc_height / 2.0),
+                    egui::Align2::CENTER_CENTER,
+                    cc_text,
+                    egui::FontId::monospace(theme.font_size_xs * 0.8),
+                    if self.learning {
+                        theme.state_warning
+                    } else {
+                        theme.text_muted
+                    },
+                );
+            }
+
             // Label below knob
             if let Some(label) = self.label {
-                let label_pos = rect.center_bottom() - Vec2::new(0.0, theme.font_size_xs / 2.0);
+                let label_pos =
+                    rect.center_bottom() - Vec2::new(0.0, cc_height + theme.font_size_xs / 2.0);
                 painter.text(
                     label_pos,
                     egui::Align2::CENTER_CENTER,
@@ -259,11 +422,17 @@ impl<'a> Knob<'a> {
             }
         }
 
+        let knob_center = rect.center_top() + Vec2::new(0.0, diameter / 2.0);
+        if let Some(typed) = entry.show(ui, entry_rect(knob_center, diameter, &theme)) {
+            *value = typed.clamp(*self.range.start(), *self.range.end());
+            response.mark_changed();
+        }
+
         // Mark response as changed if value was modified
-        if response.dragged() || response.double_clicked() {
+        if response.dragged() {
             response.mark_changed();
         }
-        response
+        (response, learn)
     }
 
     /// Draw an arc using line segments
@@ -295,6 +464,154 @@ impl<'a> Knob<'a> {
 impl Widget for Knob<'_> {
     fn ui(self, ui: &mut Ui) -> Response {
         let mut dummy = 0.5; // Default middle value
-        self.show_internal(ui, &mut dummy)
+        self.show_internal(ui, &mut dummy).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        click_at, drag_with_modifiers, painted_texts, run_ui, run_ui_with_events, test_context,
+    };
+    use egui::Modifiers;
+
+    #[test]
+    fn cc_label_is_rendered() {
+        let ctx = test_context();
+        let output = run_ui(&ctx, |ui| {
+            Knob::new(0.0..=1.0).cc(Some(74)).show(ui, &mut 0.5);
+        });
+        assert!(painted_texts(&output).iter().any(|t| t == "CC74"));
+
+        let output = run_ui(&ctx, |ui| {
+            Knob::new(0.0..=1.0).show(ui, &mut 0.5);
+        });
+        assert!(!painted_texts(&output).iter().any(|t| t.starts_with("CC")));
+    }
+
+    #[test]
+    fn learnable_click_starts_learn() {
+        let click = |learnable: bool| {
+            let ctx = test_context();
+            let mut rect = egui::Rect::NOTHING;
+            run_ui(&ctx, |ui| {
+                rect = Knob::new(0.0..=1.0).show(ui, &mut 0.5).rect;
+            });
+
+            let mut events = Vec::new();
+            click_at(&ctx, rect.center(), |ui| {
+                events.extend(
+                    Knob::new(0.0..=1.0)
+                        .learnable(learnable)
+                        .show_events(ui, &mut 0.5),
+                )
+            });
+            events
+        };
+
+        assert_eq!(click(true), vec![KnobEvent::StartLearn]);
+        assert!(click(false).is_empty());
+    }
+
+    #[test]
+    fn shift_drag_is_finer() {
+        let drag_delta = |modifiers: Modifiers| {
+            let ctx = test_context();
+            let mut rect = egui::Rect::NOTHING;
+            run_ui(&ctx, |ui| {
+                rect = Knob::new(0.0..=1.0).show(ui, &mut 0.5).rect;
+            });
+
+            let mut value = 0.5;
+            let from = rect.center();
+            drag_with_modifiers(&ctx, from, from - Vec2::new(0.0, 40.0), modifiers, |ui| {
+                Knob::new(0.0..=1.0).show(ui, &mut value);
+            });
+            value - 0.5
+        };
+
+        let plain = drag_delta(Modifiers::NONE);
+        let fine = drag_delta(Modifiers::SHIFT);
+        assert!(plain > 0.0 && fine > 0.0);
+        assert!(fine < plain / 5.0, "plain {plain}, fine {fine}");
+    }
+
+    #[test]
+    fn double_click_types_value() {
+        let ctx = test_context();
+        let mut value = 0.5;
+        let mut rect = egui::Rect::NOTHING;
+        run_ui(&ctx, |ui| {
+            rect = Knob::new(0.0..=1.0).show(ui, &mut value).rect;
+        });
+
+        let mut show = |events| {
+            run_ui_with_events(&ctx, events, |ui| {
+                Knob::new(0.0..=1.0).show(ui, &mut value);
+            });
+        };
+        let button = |pressed| egui::Event::PointerButton {
+            pos: rect.center(),
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+        show(vec![egui::Event::PointerMoved(rect.center())]);
+        for pressed in [true, false, true, false] {
+            show(vec![button(pressed)]);
+        }
+        // Entry is focused on the next frame
+        show(vec![]);
+        show(vec![
+            egui::Event::Key {
+                key: egui::Key::A,
+                physical_key: None,
+                pressed: true,
+                repeat: false,
+                modifiers: Modifiers::COMMAND,
+            },
+            egui::Event::Text("0.25".into()),
+        ]);
+        show(vec![egui::Event::Key {
+            key: egui::Key::Enter,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        }]);
+
+        assert_eq!(value, 0.25);
+    }
+
+    #[test]
+    fn bipolar_drag_snaps_to_center() {
+        let drag_to_near_zero = |bipolar: bool| {
+            let ctx = test_context();
+            let mut rect = egui::Rect::NOTHING;
+            run_ui(&ctx, |ui| {
+                rect = Knob::new(-24.0..=24.0).show(ui, &mut 0.0).rect;
+            });
+
+            // 0.24 per point, so the drag ends within the ±0.96 detent
+            let mut value = 1.5;
+            let from = rect.center();
+            drag_with_modifiers(
+                &ctx,
+                from,
+                from + Vec2::new(0.0, 10.0),
+                Modifiers::NONE,
+                |ui| {
+                    Knob::new(-24.0..=24.0)
+                        .bipolar(bipolar)
+                        .show(ui, &mut value);
+                },
+            );
+            value
+        };
+
+        let free = drag_to_near_zero(false);
+        assert!(free != 0.0 && free.abs() < 0.96, "{free}");
+        assert_eq!(drag_to_near_zero(true), 0.0);
     }
 }
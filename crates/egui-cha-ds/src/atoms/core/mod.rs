@@ -4,6 +4,7 @@
 
 mod arc_slider;
 mod badge;
+mod bipolar;
 mod button;
 mod button_group;
 mod capacity_gauge;
@@ -24,6 +25,7 @@ mod text;
 mod toggle;
 mod tooltip;
 mod validated_input;
+mod value_entry;
 mod xypad;
 
 pub use arc_slider::{ArcSlider, ArcSliderSize, ArcStyle};
@@ -36,8 +38,8 @@ pub use code::{Code, CodeBlock};
 pub use context_menu::{ContextMenuExt, ContextMenuItem};
 pub use fader::{Fader, FaderSize};
 pub use icon::{icons, Icon};
-pub use input::Input;
-pub use knob::{Knob, KnobSize};
+pub use input::{Input, NumberRange};
+pub use knob::{Knob, KnobEvent, KnobSize};
 pub use link::Link;
 pub use list_item::{ListItem, ListItemSize};
 pub use select::Select;
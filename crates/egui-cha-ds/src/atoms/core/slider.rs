@@ -1,5 +1,10 @@
 //! Slider atom
+//!
+//! Wraps `egui::Slider` with Shift-drag fine adjustment and
+//! double-click value entry.
 
+use super::value_entry::{drag_scale, entry_rect, ValueEntry};
+use crate::Theme;
 use egui::Ui;
 use egui_cha::ViewCtx;
 use std::ops::RangeInclusive;
@@ -57,24 +62,7 @@ impl<'a> Slider<'a> {
         on_change: impl FnOnce(f64) -> Msg,
     ) {
         let mut current = value;
-
-        let mut slider = egui::Slider::new(&mut current, self.range.clone());
-
-        if let Some(label) = self.label {
-            slider = slider.text(label);
-        }
-
-        if let Some(step) = self.step {
-            slider = slider.step_by(step);
-        }
-
-        if !self.show_value {
-            slider = slider.show_value(false);
-        }
-
-        let response = ctx.ui.add_enabled(!self.disabled, slider);
-
-        if response.changed() {
+        if self.show_internal(ctx.ui, &mut current) {
             ctx.emit(on_change(current));
         }
     }
@@ -91,6 +79,14 @@ impl<'a> Slider<'a> {
 
     /// Show slider (modifies value in place)
     pub fn show(self, ui: &mut Ui, value: &mut f64) -> bool {
+        self.show_internal(ui, value)
+    }
+
+    fn show_internal(self, ui: &mut Ui, value: &mut f64) -> bool {
+        let theme = Theme::current(ui.ctx());
+        let (min, max) = (*self.range.start(), *self.range.end());
+        let before = *value;
+
         let mut slider = egui::Slider::new(value, self.range.clone());
 
         if let Some(label) = self.label {
@@ -105,7 +101,72 @@ impl<'a> Slider<'a> {
             slider = slider.show_value(false);
         }
 
+        let rail_width = ui.spacing().slider_width;
         let response = ui.add_enabled(!self.disabled, slider);
-        response.changed()
+        let mut changed = response.changed();
+
+        // Shift-drag: move relative to the previous value at reduced speed
+        // instead of jumping to the pointer (egui also sets it on release)
+        if (response.dragged() || response.drag_stopped()) && drag_scale(ui) < 1.0 {
+            let delta = response.drag_delta().x as f64 / rail_width as f64;
+            let mut fine = before + delta * (max - min) * drag_scale(ui);
+            if let Some(step) = self.step {
+                fine = min + ((fine - min) / step).round() * step;
+            }
+            *value = fine.clamp(min, max);
+            changed = *value != before;
+        }
+
+        // Double-click on the rail types a value (the slider itself has no reset)
+        let entry = ValueEntry::new(response.id);
+        if response.double_clicked() && !self.disabled {
+            entry.open(ui, before);
+        }
+        let rail = egui::Rect::from_min_size(
+            response.rect.min,
+            egui::vec2(rail_width, response.rect.height()),
+        );
+        if let Some(typed) = entry.show(ui, entry_rect(rail.center(), rail_width * 0.5, &theme)) {
+            *value = typed.clamp(min, max);
+            changed = true;
+        }
+
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{drag_with_modifiers, run_ui, test_context};
+    use egui::Modifiers;
+
+    #[test]
+    fn shift_drag_is_finer() {
+        let drag_delta = |modifiers: Modifiers| {
+            let ctx = test_context();
+            let mut rect = egui::Rect::NOTHING;
+            let mut rail_width = 0.0;
+            run_ui(&ctx, |ui| {
+                rail_width = ui.spacing().slider_width;
+                rect = ui
+                    .scope(|ui| Slider::new(0.0..=1.0).show(ui, &mut 0.5))
+                    .response
+                    .rect;
+            });
+
+            // Start on the handle so pressing doesn't move the value
+            let mut value = 0.5;
+            let from = egui::pos2(rect.min.x + rail_width / 2.0, rect.center().y);
+            drag_with_modifiers(&ctx, from, from + egui::vec2(30.0, 0.0), modifiers, |ui| {
+                Slider::new(0.0..=1.0).show(ui, &mut value);
+            });
+            value - 0.5
+        };
+
+        let plain = drag_delta(Modifiers::NONE);
+        let fine = drag_delta(Modifiers::SHIFT);
+        assert!(plain > 0.0 && fine > 0.0, "plain {plain}, fine {fine}");
+        assert!(fine < plain / 5.0, "plain {plain}, fine {fine}");
     }
 }
@@ -13,8 +13,15 @@
 //!
 //! // Use with Sparkline
 //! Sparkline::new(&buffer.as_vec()).show(ui);
+//!
+//! // Or render the trend directly (scaled to the buffer's own min/max)
+//! let mut latency = SparklineBuffer::with_capacity(60).with_baseline(50.0);
+//! latency.push(48.0);
+//! latency.render(ui, egui::vec2(120.0, 24.0));
 //! ```
 
+use crate::Theme;
+use egui::{Pos2, Response, Sense, Shape, Stroke, Ui, Vec2};
 use std::collections::VecDeque;
 
 /// Fixed-capacity ring buffer for time-series data
@@ -22,6 +29,7 @@ use std::collections::VecDeque;
 pub struct SparklineBuffer {
     data: VecDeque<f32>,
     capacity: usize,
+    baseline: Option<f32>,
 }
 
 impl SparklineBuffer {
@@ -30,18 +38,37 @@ impl SparklineBuffer {
         Self {
             data: VecDeque::with_capacity(capacity),
             capacity,
+            baseline: None,
         }
     }
 
+    /// Create a rolling window keeping the last `capacity` samples
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::new(capacity)
+    }
+
+    /// Draw a reference line at `value` when rendering (e.g. a target or threshold)
+    pub fn with_baseline(mut self, value: f32) -> Self {
+        self.baseline = Some(value);
+        self
+    }
+
     /// Create a buffer pre-filled with a default value
     pub fn filled(capacity: usize, value: f32) -> Self {
         let mut data = VecDeque::with_capacity(capacity);
         data.resize(capacity, value);
-        Self { data, capacity }
+        Self {
+            data,
+            capacity,
+            baseline: None,
+        }
     }
 
     /// Push a new value, removing the oldest if at capacity
     pub fn push(&mut self, value: f32) {
+        if self.capacity == 0 {
+            return;
+        }
         if self.data.len() >= self.capacity {
             self.data.pop_front();
         }
@@ -109,6 +136,21 @@ impl SparklineBuffer {
         Some((min, max))
     }
 
+    /// Smallest value in the window
+    pub fn min(&self) -> Option<f32> {
+        self.min_max().map(|(min, _)| min)
+    }
+
+    /// Largest value in the window
+    pub fn max(&self) -> Option<f32> {
+        self.min_max().map(|(_, max)| max)
+    }
+
+    /// Arithmetic mean of the window (alias of [`average`](Self::average))
+    pub fn avg(&self) -> Option<f32> {
+        self.average()
+    }
+
     /// Calculate average of all values
     pub fn average(&self) -> Option<f32> {
         if self.data.is_empty() {
@@ -122,6 +164,51 @@ impl SparklineBuffer {
     pub fn iter(&self) -> impl Iterator<Item = f32> + '_ {
         self.data.iter().copied()
     }
+
+    /// Draw the trend line into `size`, scaled to the window's own min/max.
+    /// The baseline is drawn when it falls inside that range.
+    pub fn render(&self, ui: &mut Ui, size: Vec2) -> Response {
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        let Some((min, max)) = self.min_max() else {
+            return response;
+        };
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+
+        let theme = Theme::current(ui.ctx());
+        let painter = ui.painter();
+        let range = max - min;
+        let to_y = |v: f32| {
+            if range > f32::EPSILON {
+                rect.max.y - (v - min) / range * rect.height()
+            } else {
+                rect.center().y
+            }
+        };
+
+        if let Some(baseline) = self.baseline.filter(|b| (min..=max).contains(b)) {
+            let y = to_y(baseline);
+            painter.line_segment(
+                [Pos2::new(rect.min.x, y), Pos2::new(rect.max.x, y)],
+                Stroke::new(theme.stroke_width, theme.text_muted),
+            );
+        }
+
+        let step = rect.width() / (self.data.len().max(2) - 1) as f32;
+        let points: Vec<Pos2> = self
+            .data
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| Pos2::new(rect.min.x + i as f32 * step, to_y(v)))
+            .collect();
+        painter.add(Shape::line(
+            points,
+            Stroke::new(theme.stroke_width * 1.5, theme.primary),
+        ));
+
+        response
+    }
 }
 
 impl Default for SparklineBuffer {
@@ -147,6 +234,26 @@ mod tests {
         assert_eq!(buf.as_vec(), vec![2.0, 3.0, 4.0]);
     }
 
+    #[test]
+    fn test_rolling_window_keeps_last_n() {
+        let mut buf = SparklineBuffer::with_capacity(5);
+        buf.extend((0..6).map(|i| i as f32));
+        assert_eq!(buf.len(), 5);
+        assert_eq!(buf.as_vec(), vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(buf.min(), Some(1.0));
+        assert_eq!(buf.max(), Some(5.0));
+    }
+
+    #[test]
+    fn test_avg_is_arithmetic_mean() {
+        let samples = [3.5, 7.0, 1.25, 9.0, 4.25];
+        let mut buf = SparklineBuffer::with_capacity(samples.len());
+        buf.extend(samples);
+        let mean = samples.iter().sum::<f32>() / samples.len() as f32;
+        assert_eq!(buf.avg(), Some(mean));
+        assert_eq!(SparklineBuffer::with_capacity(3).avg(), None);
+    }
+
     #[test]
     fn test_min_max() {
         let mut buf = SparklineBuffer::new(5);
@@ -1,6 +1,8 @@
 //! Status indicator with animated states
 //!
 //! Visual indicator showing agent/system state with optional pulse/blink animations.
+//! Animations follow wall-clock time (`ui.input(|i| i.time)`) and schedule their
+//! own repaints, so no continuous repaint is needed from the app.
 //!
 //! # Example
 //! ```ignore
@@ -23,6 +25,10 @@
 use crate::Theme;
 use egui::{Color32, Response, Sense, Ui, Vec2};
 use egui_cha::ViewCtx;
+use std::time::Duration;
+
+/// Repaint interval for smooth (pulse) animations (~30 fps)
+const PULSE_FRAME: Duration = Duration::from_millis(33);
 
 /// Status state with predefined colors and animations
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -54,6 +60,43 @@ pub enum Animation {
     Blink { speed: f32 },
 }
 
+impl Animation {
+    /// Alpha multiplier at wall-clock `time` in seconds
+    pub fn alpha(&self, time: f64) -> f32 {
+        match *self {
+            Animation::None => 1.0,
+            Animation::Pulse { speed } => {
+                // Smooth sine wave: 0.5 to 1.0
+                let phase = (time * speed as f64).fract() as f32;
+                0.75 + 0.25 * (phase * std::f32::consts::TAU).sin()
+            }
+            Animation::Blink { speed } => {
+                // Sharp on/off: 1.0 or 0.3
+                if (time * speed as f64).fract() < 0.5 {
+                    1.0
+                } else {
+                    0.3
+                }
+            }
+        }
+    }
+
+    /// Delay from `time` until the next visible animation step, or `None` if static
+    pub fn next_step(&self, time: f64) -> Option<Duration> {
+        match *self {
+            Animation::None => None,
+            Animation::Pulse { speed } if speed > 0.0 => Some(PULSE_FRAME),
+            Animation::Blink { speed } if speed > 0.0 => {
+                // Wake up exactly at the next on/off edge
+                let phase = (time * speed as f64).fract();
+                let edge = if phase < 0.5 { 0.5 } else { 1.0 };
+                Some(Duration::from_secs_f64((edge - phase) / speed as f64))
+            }
+            _ => None,
+        }
+    }
+}
+
 /// Visual status indicator with optional animation
 pub struct StatusIndicator {
     status: Status,
@@ -182,7 +225,7 @@ impl StatusIndicator {
 
     fn show_internal(self, ui: &mut Ui) -> Response {
         let theme = Theme::current(ui.ctx());
-        let time = ui.input(|i| i.time) as f32;
+        let time = ui.input(|i| i.time);
 
         // Get size from builder or use theme default
         let size = self.size.unwrap_or(theme.spacing_sm);
@@ -201,21 +244,7 @@ impl StatusIndicator {
         let animation = self.custom_animation.unwrap_or(default_animation);
 
         // Calculate animation multiplier
-        let alpha_multiplier = match animation {
-            Animation::None => 1.0,
-            Animation::Pulse { speed } => {
-                // Smooth sine wave: 0.5 to 1.0
-                0.5 + 0.5 * (time * speed * std::f32::consts::TAU).sin()
-            }
-            Animation::Blink { speed } => {
-                // Sharp on/off: 1.0 or 0.3
-                if (time * speed).fract() < 0.5 {
-                    1.0
-                } else {
-                    0.3
-                }
-            }
-        };
+        let alpha_multiplier = animation.alpha(time);
 
         let animated_color = color.gamma_multiply(alpha_multiplier);
 
@@ -294,11 +323,40 @@ impl StatusIndicator {
             response
         };
 
-        // Request repaint if animating
-        if !matches!(animation, Animation::None) {
-            ui.ctx().request_repaint();
+        // Schedule the next animation step
+        if let Some(delay) = animation.next_step(time) {
+            ui.ctx().request_repaint_after(delay);
         }
 
         response
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pulse_alpha_follows_time() {
+        let pulse = Animation::Pulse { speed: 1.0 };
+        assert_ne!(pulse.alpha(0.0), pulse.alpha(0.25));
+        assert!((0.5..=1.0).contains(&pulse.alpha(0.75)));
+    }
+
+    #[test]
+    fn blink_wakes_at_next_edge() {
+        let blink = Animation::Blink { speed: 2.0 };
+        assert_eq!(blink.alpha(0.1), 1.0);
+        assert_eq!(blink.alpha(0.3), 0.3);
+
+        // Period 0.5s: toggles at 0.25s
+        let delay = blink.next_step(0.1).unwrap().as_secs_f64();
+        assert!((delay - 0.15).abs() < 1e-9);
+    }
+
+    #[test]
+    fn static_has_no_repaint() {
+        assert_eq!(Animation::None.next_step(1.0), None);
+        assert_eq!(Animation::None.alpha(1.0), 1.0);
+    }
+}
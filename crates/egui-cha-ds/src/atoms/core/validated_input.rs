@@ -9,20 +9,54 @@
 //!     .placeholder("user@example.com")
 //!     .show(&mut model.email, &model.email_validation, ctx);
 //! ```
+//!
+//! # Async validation
+//!
+//! Set [`ValidationState::Pending`] while a validation `Cmd` is in flight,
+//! then replace it with the result when the response message arrives:
+//!
+//! ```ignore
+//! Msg::UsernameChanged(name) => {
+//!     model.username_validation = ValidationState::pending();
+//!     Cmd::task(async move { Msg::UsernameChecked(api::is_available(&name).await) })
+//! }
+//! Msg::UsernameChecked(available) => {
+//!     model.username_validation = if available {
+//!         ValidationState::valid()
+//!     } else {
+//!         ValidationState::invalid("Username is taken")
+//!     };
+//!     Cmd::none()
+//! }
+//! ```
 
-use egui::{RichText, Ui};
+use egui::{Color32, RichText, Ui};
 use egui_cha::ViewCtx;
 
 use crate::{icons, Theme};
 
+/// Height reserved for the indicator row below the input
+const INDICATOR_HEIGHT: f32 = 18.0;
+
+/// Leading visual of the indicator row
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Indicator {
+    Icon(&'static str),
+    Spinner,
+}
+
 /// Validation state for form inputs
 #[derive(Debug, Clone, Default)]
 pub enum ValidationState {
     /// Not yet validated
     #[default]
     None,
+    /// Validation in flight (e.g. waiting on an async `Cmd`)
+    Pending,
     /// Validation passed
     Valid,
+    /// Validation passed with a non-blocking warning message
+    Warning(String),
     /// Validation failed with error message
     Invalid(String),
 }
@@ -33,11 +67,21 @@ impl ValidationState {
         Self::Valid
     }
 
+    /// Create a pending state (async validation in flight)
+    pub fn pending() -> Self {
+        Self::Pending
+    }
+
     /// Create an invalid state with message
     pub fn invalid(message: impl Into<String>) -> Self {
         Self::Invalid(message.into())
     }
 
+    /// Create a warning state with message (non-blocking)
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::Warning(message.into())
+    }
+
     /// Check if state is valid
     pub fn is_valid(&self) -> bool {
         matches!(self, Self::Valid)
@@ -48,6 +92,23 @@ impl ValidationState {
         matches!(self, Self::Invalid(_))
     }
 
+    /// Check if validation is still pending
+    pub fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending)
+    }
+
+    /// Check if state is a warning
+    pub fn is_warning(&self) -> bool {
+        matches!(self, Self::Warning(_))
+    }
+
+    /// Check if this state should block form submission
+    ///
+    /// Warnings are passable; invalid and pending states block.
+    pub fn is_blocking(&self) -> bool {
+        matches!(self, Self::Invalid(_) | Self::Pending)
+    }
+
     /// Get error message if invalid
     pub fn error_message(&self) -> Option<&str> {
         match self {
@@ -55,6 +116,75 @@ impl ValidationState {
             _ => None,
         }
     }
+
+    /// Get warning message if warning
+    pub fn warning_message(&self) -> Option<&str> {
+        match self {
+            Self::Warning(msg) => Some(msg),
+            _ => None,
+        }
+    }
+
+    /// Border color for this state
+    pub(crate) fn border_color(&self, theme: &Theme) -> Color32 {
+        match self {
+            Self::None | Self::Pending => theme.border,
+            Self::Valid => theme.state_success,
+            Self::Warning(_) => theme.state_warning,
+            Self::Invalid(_) => theme.state_danger,
+        }
+    }
+
+    /// Indicator shown below the input: (icon, message, color)
+    fn indicator(&self, theme: &Theme) -> Option<(Indicator, &str, Color32)> {
+        match self {
+            Self::None => None,
+            Self::Pending => Some((Indicator::Spinner, "Validating…", theme.text_muted)),
+            Self::Valid => Some((Indicator::Icon(icons::CHECK), "Valid", theme.state_success)),
+            Self::Warning(msg) => Some((
+                Indicator::Icon(icons::WARNING),
+                msg.as_str(),
+                theme.state_warning,
+            )),
+            Self::Invalid(msg) => Some((
+                Indicator::Icon(icons::WARNING),
+                msg.as_str(),
+                theme.state_danger,
+            )),
+        }
+    }
+
+    /// Show the validation indicator (icon + message) below an input
+    ///
+    /// Always reserves a row of the same height so the layout does not
+    /// jump when the state changes (e.g. None → Pending → Valid).
+    pub(crate) fn show_indicator(&self, theme: &Theme, ui: &mut Ui) {
+        let size = egui::vec2(ui.available_width(), INDICATOR_HEIGHT);
+        let layout = egui::Layout::left_to_right(egui::Align::Center);
+
+        ui.allocate_ui_with_layout(size, layout, |ui| {
+            ui.set_min_height(INDICATOR_HEIGHT);
+
+            let Some((indicator, message, color)) = self.indicator(theme) else {
+                return;
+            };
+
+            match indicator {
+                Indicator::Spinner => {
+                    ui.add(egui::Spinner::new().size(12.0).color(color));
+                }
+                Indicator::Icon(icon) => {
+                    ui.label(
+                        RichText::new(icon)
+                            .family(egui::FontFamily::Name("icons".into()))
+                            .color(color)
+                            .size(14.0),
+                    );
+                }
+            }
+            ui.label(RichText::new(message).color(color).small());
+        });
+    }
 }
 
 /// A text input with validation state
@@ -102,11 +232,7 @@ impl<'a> ValidatedInput<'a> {
         ui.label(self.label);
 
         // Input with border color based on state
-        let border_color = match state {
-            ValidationState::None => theme.border,
-            ValidationState::Valid => theme.state_success,
-            ValidationState::Invalid(_) => theme.state_danger,
-        };
+        let border_color = state.border_color(&theme);
 
         let mut edit = egui::TextEdit::singleline(value)
             .hint_text(self.placeholder)
@@ -132,31 +258,7 @@ impl<'a> ValidatedInput<'a> {
             });
 
         // Validation indicator and message
-        match state {
-            ValidationState::Valid => {
-                ui.horizontal(|ui| {
-                    ui.label(
-                        RichText::new(icons::CHECK)
-                            .family(egui::FontFamily::Name("icons".into()))
-                            .color(theme.state_success)
-                            .size(14.0),
-                    );
-                    ui.label(RichText::new("Valid").color(theme.state_success).small());
-                });
-            }
-            ValidationState::Invalid(msg) => {
-                ui.horizontal(|ui| {
-                    ui.label(
-                        RichText::new(icons::WARNING)
-                            .family(egui::FontFamily::Name("icons".into()))
-                            .color(theme.state_danger)
-                            .size(14.0),
-                    );
-                    ui.label(RichText::new(msg).color(theme.state_danger).small());
-                });
-            }
-            ValidationState::None => {}
-        }
+        state.show_indicator(&theme, ui);
     }
 
     /// TEA-style: Show with immutable value, emit on change
@@ -174,11 +276,7 @@ impl<'a> ValidatedInput<'a> {
         ctx.ui.label(self.label);
 
         // Border color based on state
-        let border_color = match state {
-            ValidationState::None => theme.border,
-            ValidationState::Valid => theme.state_success,
-            ValidationState::Invalid(_) => theme.state_danger,
-        };
+        let border_color = state.border_color(&theme);
 
         let mut edit = egui::TextEdit::singleline(&mut current)
             .hint_text(self.placeholder)
@@ -209,30 +307,80 @@ impl<'a> ValidatedInput<'a> {
         }
 
         // Validation indicator
-        match state {
-            ValidationState::Valid => {
-                ctx.ui.horizontal(|ui| {
-                    ui.label(
-                        RichText::new(icons::CHECK)
-                            .family(egui::FontFamily::Name("icons".into()))
-                            .color(theme.state_success)
-                            .size(14.0),
-                    );
-                    ui.label(RichText::new("Valid").color(theme.state_success).small());
-                });
-            }
-            ValidationState::Invalid(msg) => {
-                ctx.ui.horizontal(|ui| {
-                    ui.label(
-                        RichText::new(icons::WARNING)
-                            .family(egui::FontFamily::Name("icons".into()))
-                            .color(theme.state_danger)
-                            .size(14.0),
-                    );
-                    ui.label(RichText::new(msg).color(theme.state_danger).small());
-                });
-            }
-            ValidationState::None => {}
-        }
+        state.show_indicator(&theme, ctx.ui);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warning_is_distinct_from_invalid() {
+        let warning = ValidationState::warning("Password is weak");
+        let invalid = ValidationState::invalid("Password is too short");
+
+        assert!(warning.is_warning());
+        assert!(!warning.is_invalid());
+        assert!(!warning.is_blocking());
+        assert_eq!(warning.warning_message(), Some("Password is weak"));
+        assert_eq!(warning.error_message(), None);
+
+        assert!(invalid.is_blocking());
+        assert!(!invalid.is_warning());
+    }
+
+    #[test]
+    fn test_warning_uses_warning_color() {
+        let theme = Theme::dark();
+        let warning = ValidationState::warning("Password is weak");
+
+        assert_eq!(warning.border_color(&theme), theme.state_warning);
+        assert_ne!(
+            warning.border_color(&theme),
+            ValidationState::invalid("x").border_color(&theme)
+        );
+    }
+
+    #[test]
+    fn test_pending_renders_spinner_and_blocks() {
+        let theme = Theme::dark();
+        let pending = ValidationState::pending();
+
+        assert!(pending.is_pending());
+        assert!(pending.is_blocking());
+
+        let (indicator, message, _) = pending.indicator(&theme).unwrap();
+        assert_eq!(indicator, Indicator::Spinner);
+        assert_eq!(message, "Validating…");
+    }
+
+    #[test]
+    fn test_indicator_row_height_is_stable() {
+        let ctx = crate::test_utils::test_context();
+        let theme = Theme::dark();
+
+        let height = |state: ValidationState| {
+            let mut height = 0.0;
+            crate::test_utils::run_ui(&ctx, |ui| {
+                height = ui
+                    .scope(|ui| state.show_indicator(&theme, ui))
+                    .response
+                    .rect
+                    .height();
+            });
+            height
+        };
+
+        let none = height(ValidationState::None);
+        assert!(none >= INDICATOR_HEIGHT);
+        assert_eq!(height(ValidationState::pending()), none);
+        assert_eq!(height(ValidationState::valid()), none);
+    }
+
+    #[test]
+    fn test_none_and_valid_are_not_blocking() {
+        assert!(!ValidationState::None.is_blocking());
+        assert!(!ValidationState::valid().is_blocking());
     }
 }
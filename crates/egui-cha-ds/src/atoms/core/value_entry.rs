@@ -0,0 +1,111 @@
+//! Shared fine-adjust and inline value entry for value controls
+//!
+//! Used by `Knob`, `Fader`, and `Slider`:
+//! - Shift while dragging scales sensitivity down by [`FINE_ADJUST`]
+//! - Double-click opens an inline edit box that commits on Enter
+//! - Alt+double-click resets to the default value
+
+use crate::Theme;
+use egui::{Id, Key, Rect, TextEdit, Ui};
+
+/// Drag sensitivity multiplier while Shift is held
+pub(crate) const FINE_ADJUST: f64 = 0.1;
+
+/// Drag sensitivity multiplier for the current modifiers
+pub(crate) fn drag_scale(ui: &Ui) -> f64 {
+    if ui.input(|i| i.modifiers.shift) {
+        FINE_ADJUST
+    } else {
+        1.0
+    }
+}
+
+/// Whether a double-click should reset (Alt held) instead of opening value entry
+pub(crate) fn reset_modifier(ui: &Ui) -> bool {
+    ui.input(|i| i.modifiers.alt)
+}
+
+/// Edit box state kept in temp memory while open
+#[derive(Clone, Debug, Default)]
+struct EntryState {
+    text: String,
+    focused: bool,
+}
+
+/// Inline numeric edit box, keyed by the owning widget's id
+pub(crate) struct ValueEntry {
+    id: Id,
+}
+
+impl ValueEntry {
+    pub(crate) fn new(widget_id: Id) -> Self {
+        Self {
+            id: widget_id.with("value_entry"),
+        }
+    }
+
+    /// Open the edit box pre-filled with `value`
+    pub(crate) fn open(&self, ui: &Ui, value: f64) {
+        let state = EntryState {
+            text: format_entry(value),
+            focused: false,
+        };
+        ui.ctx().data_mut(|d| d.insert_temp(self.id, state));
+    }
+
+    /// Show the edit box over `rect` if open. Returns the typed value when
+    /// committed with Enter; losing focus or a parse error cancels.
+    pub(crate) fn show(&self, ui: &mut Ui, rect: Rect) -> Option<f64> {
+        let mut state = ui.ctx().data(|d| d.get_temp::<EntryState>(self.id))?;
+        let theme = Theme::current(ui.ctx());
+
+        let response = ui.put(
+            rect,
+            TextEdit::singleline(&mut state.text)
+                .id(self.id.with("edit"))
+                .font(egui::FontId::monospace(theme.font_size_xs))
+                .horizontal_align(egui::Align::Center)
+                .margin(egui::vec2(2.0, 2.0)),
+        );
+
+        if !state.focused {
+            response.request_focus();
+            state.focused = true;
+        } else if response.lost_focus() {
+            ui.ctx().data_mut(|d| d.remove_temp::<EntryState>(self.id));
+            if ui.input(|i| i.key_pressed(Key::Enter)) {
+                return state.text.trim().parse().ok();
+            }
+            return None;
+        }
+
+        ui.ctx().data_mut(|d| d.insert_temp(self.id, state));
+        None
+    }
+}
+
+/// Format a value for editing without trailing zeros
+fn format_entry(value: f64) -> String {
+    let text = format!("{:.3}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Rect for an edit box centered at `center`
+pub(crate) fn entry_rect(center: egui::Pos2, min_width: f32, theme: &Theme) -> Rect {
+    Rect::from_center_size(
+        center,
+        egui::vec2(min_width.max(48.0), theme.font_size_xs + 8.0),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_entry_trims_zeros() {
+        assert_eq!(format_entry(0.5), "0.5");
+        assert_eq!(format_entry(12.0), "12");
+        assert_eq!(format_entry(-6.125), "-6.125");
+    }
+}
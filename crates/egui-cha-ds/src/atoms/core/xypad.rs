@@ -8,6 +8,7 @@
 //! - Optional axis labels
 //! - Crosshair cursor display
 //! - Optional grid overlay
+//! - Optional snapping to grid intersections and center
 //! - Theme-aware styling
 //!
 //! # Example
@@ -23,6 +24,12 @@
 //!     .grid(true)
 //!     .show_with(ctx, (model.cutoff, model.reso), Msg::SetFilter);
 //!
+//! // Snap to a 4x4 grid and the center
+//! XYPad::new()
+//!     .snap_grid(4, 4)
+//!     .snap_center(true)
+//!     .show_with(ctx, (model.x, model.y), |(x, y)| Msg::SetXY(x, y));
+//!
 //! // Custom size
 //! XYPad::new()
 //!     .size(200.0, 200.0)
@@ -33,6 +40,9 @@ use crate::Theme;
 use egui::{Color32, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget};
 use egui_cha::ViewCtx;
 
+/// Snap radius around a snap point, in normalized units
+const SNAP_RADIUS: f64 = 0.04;
+
 /// A 2D pad control for X/Y parameter adjustment
 pub struct XYPad<'a> {
     width: Option<f32>,
@@ -41,6 +51,8 @@ pub struct XYPad<'a> {
     label_y: Option<&'a str>,
     show_grid: bool,
     show_crosshair: bool,
+    snap_grid: Option<(u32, u32)>,
+    snap_center: bool,
     disabled: bool,
 }
 
@@ -54,6 +66,8 @@ impl<'a> XYPad<'a> {
             label_y: None,
             show_grid: false,
             show_crosshair: true,
+            snap_grid: None,
+            snap_center: false,
             disabled: false,
         }
     }
@@ -89,6 +103,19 @@ impl<'a> XYPad<'a> {
         self
     }
 
+    /// Snap to the intersections of a `cols` x `rows` grid when close to one.
+    /// The grid overlay (if shown) follows these divisions.
+    pub fn snap_grid(mut self, cols: u32, rows: u32) -> Self {
+        self.snap_grid = Some((cols.max(1), rows.max(1)));
+        self
+    }
+
+    /// Snap to the center (0.5, 0.5) when close to it
+    pub fn snap_center(mut self, snap: bool) -> Self {
+        self.snap_center = snap;
+        self
+    }
+
     /// Set disabled state
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -167,8 +194,11 @@ impl<'a> XYPad<'a> {
                 let x = ((clamped_pos.x - pad_rect.min.x) / pad_width) as f64;
                 let y = (1.0 - (clamped_pos.y - pad_rect.min.y) / pad_height) as f64; // Invert Y
 
-                value.0 = x.clamp(0.0, 1.0);
-                value.1 = y.clamp(0.0, 1.0);
+                *value = snap_point(
+                    (x.clamp(0.0, 1.0), y.clamp(0.0, 1.0)),
+                    self.snap_grid,
+                    self.snap_center,
+                );
             }
         }
 
@@ -211,11 +241,11 @@ impl<'a> XYPad<'a> {
             // Grid
             if self.show_grid {
                 let grid_stroke = Stroke::new(theme.stroke_width * 0.5, grid_color);
-                let grid_divisions = 4;
+                let (cols, rows) = self.snap_grid.unwrap_or((4, 4));
 
                 // Vertical lines
-                for i in 1..grid_divisions {
-                    let x = pad_rect.min.x + pad_width * (i as f32 / grid_divisions as f32);
+                for i in 1..cols {
+                    let x = pad_rect.min.x + pad_width * (i as f32 / cols as f32);
                     painter.line_segment(
                         [Pos2::new(x, pad_rect.min.y), Pos2::new(x, pad_rect.max.y)],
                         grid_stroke,
@@ -223,8 +253,8 @@ impl<'a> XYPad<'a> {
                 }
 
                 // Horizontal lines
-                for i in 1..grid_divisions {
-                    let y = pad_rect.min.y + pad_height * (i as f32 / grid_divisions as f32);
+                for i in 1..rows {
+                    let y = pad_rect.min.y + pad_height * (i as f32 / rows as f32);
                     painter.line_segment(
                         [Pos2::new(pad_rect.min.x, y), Pos2::new(pad_rect.max.x, y)],
                         grid_stroke,
@@ -232,6 +262,19 @@ impl<'a> XYPad<'a> {
                 }
             }
 
+            // Snap notches
+            if let Some((cols, rows)) = self.snap_grid {
+                for i in 0..=cols {
+                    for j in 0..=rows {
+                        let notch = Pos2::new(
+                            pad_rect.min.x + pad_width * (i as f32 / cols as f32),
+                            pad_rect.min.y + pad_height * (j as f32 / rows as f32),
+                        );
+                        painter.circle_filled(notch, theme.stroke_width * 1.5, theme.text_muted);
+                    }
+                }
+            }
+
             // Center cross (light)
             let center_stroke = Stroke::new(theme.stroke_width * 0.5, theme.text_muted);
             painter.line_segment(
@@ -357,6 +400,26 @@ impl<'a> XYPad<'a> {
     }
 }
 
+/// Snap a normalized point to the nearest grid intersection or the center
+/// if it lies within [`SNAP_RADIUS`]
+fn snap_point(point: (f64, f64), grid: Option<(u32, u32)>, center: bool) -> (f64, f64) {
+    let mut candidates = Vec::new();
+    if let Some((cols, rows)) = grid {
+        let nearest = |v: f64, divisions: u32| (v * divisions as f64).round() / divisions as f64;
+        candidates.push((nearest(point.0, cols), nearest(point.1, rows)));
+    }
+    if center {
+        candidates.push((0.5, 0.5));
+    }
+
+    let distance = |c: &(f64, f64)| (c.0 - point.0).hypot(c.1 - point.1);
+    candidates
+        .into_iter()
+        .filter(|c| distance(c) <= SNAP_RADIUS)
+        .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+        .unwrap_or(point)
+}
+
 impl Default for XYPad<'_> {
     fn default() -> Self {
         Self::new()
@@ -369,3 +432,37 @@ impl Widget for XYPad<'_> {
         self.show_internal(ui, &mut dummy)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, run_ui, test_context};
+
+    #[test]
+    fn click_near_intersection_snaps() {
+        let ctx = test_context();
+        let mut rect = Rect::NOTHING;
+        run_ui(&ctx, |ui| {
+            rect = XYPad::new().show(ui, &mut (0.5, 0.5)).rect;
+        });
+
+        // (0.26, 0.24) in pad coordinates (Y up)
+        let pos = Pos2::new(
+            rect.min.x + rect.width() * 0.26,
+            rect.max.y - rect.height() * 0.24,
+        );
+        let mut value = (0.5, 0.5);
+        click_at(&ctx, pos, |ui| {
+            XYPad::new().snap_grid(4, 4).show(ui, &mut value);
+        });
+
+        assert_eq!(value, (0.25, 0.25));
+    }
+
+    #[test]
+    fn snap_point_respects_radius() {
+        assert_eq!(snap_point((0.3, 0.3), Some((4, 4)), false), (0.3, 0.3));
+        assert_eq!(snap_point((0.52, 0.49), None, true), (0.5, 0.5));
+        assert_eq!(snap_point((0.52, 0.49), None, false), (0.52, 0.49));
+    }
+}
@@ -13,9 +13,19 @@
 //!         KeyboardEvent::NoteOff(note) => Msg::NoteOff(note),
 //!     });
 //! ```
+//!
+//! # Playing
+//! By default a key sounds while the mouse button is held (NoteOn on press,
+//! NoteOff on release). With [`MidiKeyboard::sustain`] a click latches the note
+//! until the key is clicked again. [`MidiKeyboard::velocity_from_position`]
+//! maps the click height on the key to velocity (top = soft, bottom = hard).
+//!
+//! With [`MidiKeyboard::computer_keys`], the QWERTY rows play notes while the
+//! keyboard has focus (click it first): `A W S E D F T G Y H U J K O L P ;`
+//! map to C..E an octave up from `start_octave`, and `Z` / `X` shift octaves.
 
 use crate::Theme;
-use egui::{Color32, Rect, Sense, Stroke, Ui, Vec2};
+use egui::{Color32, Event, Id, Key, Rect, Sense, Stroke, Ui, Vec2};
 use egui_cha::ViewCtx;
 
 /// Keyboard events
@@ -61,6 +71,37 @@ impl From<(u8, u8)> for ActiveNote {
     }
 }
 
+/// Velocity used when velocity isn't derived from the click position
+const DEFAULT_VELOCITY: u8 = 100;
+
+/// Computer keys in semitone order from the tonic (DAW-style layout)
+const COMPUTER_KEYS: [Key; 17] = [
+    Key::A,
+    Key::W,
+    Key::S,
+    Key::E,
+    Key::D,
+    Key::F,
+    Key::T,
+    Key::G,
+    Key::Y,
+    Key::H,
+    Key::U,
+    Key::J,
+    Key::K,
+    Key::O,
+    Key::L,
+    Key::P,
+    Key::Semicolon,
+];
+
+/// Notes held via the computer keyboard, and the current octave shift
+#[derive(Clone, Debug, Default)]
+struct ComputerKeyState {
+    held: Vec<(Key, u8)>,
+    octave_shift: i8,
+}
+
 /// Piano keyboard display
 pub struct MidiKeyboard<'a> {
     octaves: u8,
@@ -72,6 +113,9 @@ pub struct MidiKeyboard<'a> {
     show_velocity: bool,
     clickable: bool,
     highlight_color: Option<Color32>,
+    velocity_from_position: bool,
+    sustain: bool,
+    computer_keys: bool,
 }
 
 impl<'a> MidiKeyboard<'a> {
@@ -87,6 +131,9 @@ impl<'a> MidiKeyboard<'a> {
             show_velocity: true,
             clickable: true,
             highlight_color: None,
+            velocity_from_position: false,
+            sustain: false,
+            computer_keys: false,
         }
     }
 
@@ -139,25 +186,60 @@ impl<'a> MidiKeyboard<'a> {
         self
     }
 
+    /// Derive velocity from the click height on the key (default: constant 100)
+    pub fn velocity_from_position(mut self, enabled: bool) -> Self {
+        self.velocity_from_position = enabled;
+        self
+    }
+
+    /// Latch notes on click until the key is clicked again (default: momentary)
+    pub fn sustain(mut self, sustain: bool) -> Self {
+        self.sustain = sustain;
+        self
+    }
+
+    /// Play notes from the QWERTY rows while the keyboard has focus
+    pub fn computer_keys(mut self, enabled: bool) -> Self {
+        self.computer_keys = enabled;
+        self
+    }
+
+    /// Velocity for a click at `y` on a key spanning `key_rect`
+    fn velocity_at(&self, key_rect: Rect, y: f32) -> u8 {
+        if !self.velocity_from_position {
+            return DEFAULT_VELOCITY;
+        }
+        let t = ((y - key_rect.min.y) / key_rect.height()).clamp(0.0, 1.0);
+        (1.0 + t * 126.0).round() as u8
+    }
+
     /// TEA-style: Show keyboard and emit events
     pub fn show_with<Msg>(
         self,
         ctx: &mut ViewCtx<'_, Msg>,
         on_event: impl Fn(KeyboardEvent) -> Msg,
     ) {
-        if let Some(event) = self.render(ctx.ui) {
+        for event in self.render(ctx.ui) {
             ctx.emit(on_event(event));
         }
     }
 
-    /// Show keyboard, returns event if any
+    /// Show keyboard, returns the first event if any
+    ///
+    /// Computer keys can produce several events per frame (chords); use
+    /// [`show_all`](Self::show_all) to receive all of them.
     pub fn show(self, ui: &mut Ui) -> Option<KeyboardEvent> {
+        self.render(ui).into_iter().next()
+    }
+
+    /// Show keyboard, returns every event from this frame
+    pub fn show_all(self, ui: &mut Ui) -> Vec<KeyboardEvent> {
         self.render(ui)
     }
 
-    fn render(self, ui: &mut Ui) -> Option<KeyboardEvent> {
+    fn render(self, ui: &mut Ui) -> Vec<KeyboardEvent> {
         let theme = Theme::current(ui.ctx());
-        let mut event = None;
+        let mut events = Vec::new();
 
         // 7 white keys per octave
         let white_keys_per_octave = 7;
@@ -167,13 +249,22 @@ impl<'a> MidiKeyboard<'a> {
         let black_key_width = self.white_key_width * 0.6;
         let black_key_height = self.white_key_height * 0.6;
 
-        let (rect, _) = ui.allocate_exact_size(
-            Vec2::new(total_width, self.white_key_height),
-            Sense::hover(),
-        );
+        let sense = if self.computer_keys {
+            Sense::focusable_noninteractive()
+        } else {
+            Sense::hover()
+        };
+        let (rect, response) =
+            ui.allocate_exact_size(Vec2::new(total_width, self.white_key_height), sense);
+        let held_id = response.id.with("held_note");
+
+        if self.computer_keys {
+            let state_id = response.id.with("computer_keys");
+            events.extend(self.computer_key_events(ui, state_id, response.has_focus()));
+        }
 
         if !ui.is_rect_visible(rect) {
-            return None;
+            return events;
         }
 
         // Key patterns: C D E F G A B (white), C# D# F# G# A# (black)
@@ -253,25 +344,42 @@ impl<'a> MidiKeyboard<'a> {
             }
         }
 
-        // Handle interactions (check black keys first as they're on top)
+        // Handle interactions (black keys allocated last so they're on top)
         if self.clickable {
-            // Sort by is_black (black keys checked first)
             let mut interaction_order: Vec<usize> = (0..keys.len()).collect();
-            interaction_order.sort_by(|&a, &b| keys[b].is_black.cmp(&keys[a].is_black));
+            interaction_order.sort_by_key(|&idx| keys[idx].is_black);
 
+            let mut pressed: Option<(u8, u8)> = None;
+            let mut event = None;
             for &idx in &interaction_order {
                 let key = &keys[idx];
                 let response = ui.allocate_rect(key.rect, Sense::click());
-
-                if response.clicked() {
-                    if key.is_active {
-                        event = Some(KeyboardEvent::NoteOff(key.note));
-                    } else {
-                        event = Some(KeyboardEvent::NoteOn(key.note, 100));
+                let velocity = response
+                    .interact_pointer_pos()
+                    .map_or(DEFAULT_VELOCITY, |pos| self.velocity_at(key.rect, pos.y));
+
+                if self.sustain {
+                    if response.clicked() {
+                        event = Some(if key.is_active {
+                            KeyboardEvent::NoteOff(key.note)
+                        } else {
+                            KeyboardEvent::NoteOn(key.note, velocity)
+                        });
                     }
-                    break;
+                } else if response.is_pointer_button_down_on() {
+                    pressed = Some((key.note, velocity));
                 }
             }
+
+            if !self.sustain {
+                event = self.momentary_event(ui, held_id, pressed);
+            }
+
+            // Clicking the keyboard focuses it for computer key input
+            if self.computer_keys && (event.is_some() || pressed.is_some()) {
+                response.request_focus();
+            }
+            events.extend(event);
         }
 
         // Second pass: draw all keys
@@ -356,6 +464,97 @@ impl<'a> MidiKeyboard<'a> {
             }
         }
 
+        events
+    }
+
+    /// NoteOn/NoteOff from QWERTY key presses, Z/X shift the octave
+    ///
+    /// Losing focus releases every held note so none get stuck.
+    fn computer_key_events(&self, ui: &Ui, state_id: Id, focused: bool) -> Vec<KeyboardEvent> {
+        let mut state: ComputerKeyState =
+            ui.ctx().data(|d| d.get_temp(state_id)).unwrap_or_default();
+        let mut events = Vec::new();
+
+        if !focused {
+            if !state.held.is_empty() {
+                events.extend(
+                    state
+                        .held
+                        .drain(..)
+                        .map(|(_, note)| KeyboardEvent::NoteOff(note)),
+                );
+                ui.ctx().data_mut(|d| d.insert_temp(state_id, state));
+            }
+            return events;
+        }
+
+        let key_events: Vec<(Key, bool, bool)> = ui.input(|i| {
+            i.events
+                .iter()
+                .filter_map(|e| match e {
+                    Event::Key {
+                        key,
+                        physical_key,
+                        pressed,
+                        repeat,
+                        ..
+                    } => Some((physical_key.unwrap_or(*key), *pressed, *repeat)),
+                    _ => None,
+                })
+                .collect()
+        });
+
+        for (key, pressed, repeat) in key_events {
+            if !pressed {
+                // Release whatever note this key started, even after an octave shift
+                if let Some(idx) = state.held.iter().position(|(k, _)| *k == key) {
+                    let (_, note) = state.held.remove(idx);
+                    events.push(KeyboardEvent::NoteOff(note));
+                }
+                continue;
+            }
+            // Held keys auto-repeat; don't retrigger
+            if repeat || state.held.iter().any(|(k, _)| *k == key) {
+                continue;
+            }
+            match key {
+                Key::Z => state.octave_shift = (state.octave_shift - 1).max(-4),
+                Key::X => state.octave_shift = (state.octave_shift + 1).min(4),
+                _ => {
+                    let Some(offset) = COMPUTER_KEYS.iter().position(|k| *k == key) else {
+                        continue;
+                    };
+                    let tonic = (self.start_octave as i32 + state.octave_shift as i32 + 1) * 12;
+                    let note = tonic + offset as i32;
+                    if (0..=127).contains(&note) {
+                        state.held.push((key, note as u8));
+                        events.push(KeyboardEvent::NoteOn(note as u8, DEFAULT_VELOCITY));
+                    }
+                }
+            }
+        }
+
+        ui.ctx().data_mut(|d| d.insert_temp(state_id, state));
+        events
+    }
+
+    /// NoteOn when a key is pressed, NoteOff once the pointer lets go of it
+    fn momentary_event(
+        &self,
+        ui: &Ui,
+        held_id: Id,
+        pressed: Option<(u8, u8)>,
+    ) -> Option<KeyboardEvent> {
+        let held: Option<u8> = ui.ctx().data(|d| d.get_temp(held_id)).flatten();
+        let (next, event) = match (held, pressed) {
+            (Some(note), Some((pressed_note, _))) if note == pressed_note => (held, None),
+            (Some(note), _) => (None, Some(KeyboardEvent::NoteOff(note))),
+            (None, Some((note, velocity))) => {
+                (Some(note), Some(KeyboardEvent::NoteOn(note, velocity)))
+            }
+            (None, None) => (None, None),
+        };
+        ui.ctx().data_mut(|d| d.insert_temp(held_id, next));
         event
     }
 
@@ -374,3 +573,101 @@ impl Default for MidiKeyboard<'_> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, run_ui, run_ui_with_events, test_context};
+
+    fn click_velocity(y_fraction: f32) -> Option<u8> {
+        let ctx = test_context();
+        let keyboard = || MidiKeyboard::new().octaves(1).velocity_from_position(true);
+        let mut rect = Rect::NOTHING;
+
+        run_ui(&ctx, |ui| {
+            rect = ui.scope(|ui| keyboard().show(ui)).response.rect;
+        });
+
+        // Middle of the C key, clear of the black keys
+        let pos = egui::pos2(rect.min.x + 10.0, rect.min.y + rect.height() * y_fraction);
+        let mut events = Vec::new();
+        click_at(&ctx, pos, |ui| {
+            events.extend(ui.scope(|ui| keyboard().show(ui)).inner);
+        });
+
+        assert_eq!(events.last(), Some(&KeyboardEvent::NoteOff(60)));
+        events.iter().find_map(|e| match e {
+            KeyboardEvent::NoteOn(60, velocity) => Some(*velocity),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn test_velocity_from_click_position() {
+        let soft = click_velocity(0.1).expect("note on near top");
+        let hard = click_velocity(0.9).expect("note on near bottom");
+        assert!(
+            hard > soft,
+            "bottom {hard} should be louder than top {soft}"
+        );
+    }
+
+    fn key_event(key: Key, pressed: bool, repeat: bool) -> Event {
+        Event::Key {
+            key,
+            physical_key: Some(key),
+            pressed,
+            repeat,
+            modifiers: egui::Modifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn test_computer_key_plays_tonic() {
+        let ctx = test_context();
+        let keyboard = || MidiKeyboard::new().octaves(1).computer_keys(true);
+        let mut rect = Rect::NOTHING;
+
+        run_ui(&ctx, |ui| {
+            rect = ui.scope(|ui| keyboard().show_all(ui)).response.rect;
+        });
+        // Click to focus
+        click_at(&ctx, rect.left_bottom() + Vec2::new(10.0, -10.0), |ui| {
+            keyboard().show_all(ui);
+        });
+
+        let run = |event: Event| {
+            let mut events = Vec::new();
+            run_ui_with_events(&ctx, vec![event], |ui| {
+                events.extend(keyboard().show_all(ui))
+            });
+            events
+        };
+
+        assert_eq!(
+            run(key_event(Key::A, true, false)),
+            vec![KeyboardEvent::NoteOn(60, DEFAULT_VELOCITY)]
+        );
+        // Auto-repeat doesn't retrigger
+        assert!(run(key_event(Key::A, true, true)).is_empty());
+        assert_eq!(
+            run(key_event(Key::A, false, false)),
+            vec![KeyboardEvent::NoteOff(60)]
+        );
+
+        // Octave up
+        run(key_event(Key::X, true, false));
+        assert_eq!(
+            run(key_event(Key::A, true, false)),
+            vec![KeyboardEvent::NoteOn(72, DEFAULT_VELOCITY)]
+        );
+    }
+
+    #[test]
+    fn test_constant_velocity_by_default() {
+        let keyboard = MidiKeyboard::new();
+        let key = Rect::from_min_size(egui::Pos2::ZERO, Vec2::new(24.0, 80.0));
+        assert_eq!(keyboard.velocity_at(key, 5.0), DEFAULT_VELOCITY);
+        assert_eq!(keyboard.velocity_at(key, 75.0), DEFAULT_VELOCITY);
+    }
+}
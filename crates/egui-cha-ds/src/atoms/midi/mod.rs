@@ -13,4 +13,4 @@ pub use midi_mapper::{
     LearnState, MappableParam, MidiMapper, MidiMapperEvent, MidiMapping, MidiMsgType,
 };
 pub use midi_monitor::{CcValue, MidiMessage, MidiMonitor, MonitorMode};
-pub use piano_roll::{MidiNote, PianoRoll, PianoRollEvent};
+pub use piano_roll::{Fraction, MidiNote, PianoRoll, PianoRollEvent};
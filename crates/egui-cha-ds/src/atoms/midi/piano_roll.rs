@@ -8,17 +8,21 @@
 //!     .notes(&model.notes)
 //!     .position(model.playhead)
 //!     .bars(4)
+//!     .snap(Fraction::new(1, 4))
 //!     .show_with(ctx, |event| match event {
 //!         PianoRollEvent::NoteAdd(note, start, dur) => Msg::AddNote(note, start, dur),
 //!         PianoRollEvent::NoteMove(idx, note, start) => Msg::MoveNote(idx, note, start),
+//!         PianoRollEvent::NotesMove(moves) => Msg::MoveNotes(moves),
 //!         PianoRollEvent::NoteResize(idx, dur) => Msg::ResizeNote(idx, dur),
 //!         PianoRollEvent::NoteDelete(idx) => Msg::DeleteNote(idx),
+//!         PianoRollEvent::NotesSelect(indices) => Msg::SelectNotes(indices),
 //!         PianoRollEvent::Seek(pos) => Msg::Seek(pos),
+//!         _ => Msg::Noop,
 //!     });
 //! ```
 
 use crate::Theme;
-use egui::{Color32, Rect, Sense, Stroke, Ui, Vec2};
+use egui::{Color32, Pos2, Rect, Sense, Stroke, Ui, Vec2};
 use egui_cha::ViewCtx;
 
 /// Piano roll events
@@ -34,10 +38,77 @@ pub enum PianoRollEvent {
     NoteDelete(usize),
     /// Note selected
     NoteSelect(usize),
+    /// Selected notes moved together: (index, new_note, new_start) per note
+    NotesMove(Vec<(usize, u8, f32)>),
+    /// Selection replaced (marquee or shift-click)
+    NotesSelect(Vec<usize>),
     /// Seek to position (beats)
     Seek(f32),
 }
 
+/// Grid resolution as a fraction of a beat (e.g. 1/4 = sixteenth notes in 4/4)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fraction {
+    /// Numerator
+    pub num: u32,
+    /// Denominator
+    pub den: u32,
+}
+
+impl Fraction {
+    /// Create a new fraction (zero terms are clamped to 1)
+    pub const fn new(num: u32, den: u32) -> Self {
+        Self {
+            num: if num == 0 { 1 } else { num },
+            den: if den == 0 { 1 } else { den },
+        }
+    }
+
+    /// Length of one grid step in beats
+    pub fn beats(self) -> f32 {
+        self.num as f32 / self.den as f32
+    }
+
+    /// Round a beat position to the nearest grid step
+    pub fn round(self, beat: f32) -> f32 {
+        let step = self.beats();
+        (beat / step).round() * step
+    }
+
+    /// Round a beat position down to the grid step containing it
+    pub fn floor(self, beat: f32) -> f32 {
+        let step = self.beats();
+        (beat / step).floor() * step
+    }
+}
+
+impl Default for Fraction {
+    fn default() -> Self {
+        Self::new(1, 4)
+    }
+}
+
+/// Where a note drag started, so snapping is relative to the original notes.
+/// `anchors` holds (index, note, start) of every note being moved.
+#[derive(Clone, Debug, Default)]
+struct NoteDragState {
+    anchors: Vec<(usize, u8, f32)>,
+    origin: Pos2,
+}
+
+/// Rubber-band selection in progress
+#[derive(Clone, Copy, Debug, Default)]
+struct MarqueeState {
+    origin: Pos2,
+    current: Pos2,
+}
+
+impl MarqueeState {
+    fn rect(&self) -> Rect {
+        Rect::from_two_pos(self.origin, self.current)
+    }
+}
+
 /// A MIDI note in the piano roll
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct MidiNote {
@@ -86,7 +157,8 @@ pub struct PianoRoll<'a> {
     beats_per_bar: u32,
     note_range: (u8, u8),
     selected: Option<usize>,
-    snap_division: u32,
+    selection: &'a [usize],
+    snap: Fraction,
     key_width: f32,
     row_height: f32,
     show_velocity: bool,
@@ -104,7 +176,8 @@ impl<'a> PianoRoll<'a> {
             beats_per_bar: 4,
             note_range: (36, 84), // C2 to C6
             selected: None,
-            snap_division: 4, // 1/4 note
+            selection: &[],
+            snap: Fraction::new(1, 4),
             key_width: 40.0,
             row_height: 12.0,
             show_velocity: true,
@@ -149,12 +222,35 @@ impl<'a> PianoRoll<'a> {
         self
     }
 
-    /// Set snap division (4 = quarter note, 8 = eighth, 16 = sixteenth)
+    /// Set multi-note selection (marquee / shift-click).
+    /// Dragging any selected note moves the whole selection.
+    pub fn selection(mut self, indices: &'a [usize]) -> Self {
+        self.selection = indices;
+        self
+    }
+
+    /// Set snap resolution in beats (e.g. `Fraction::new(1, 4)`).
+    /// Added and moved notes are quantized to this grid; hold Alt while
+    /// dragging to place notes freely. Added notes are one beat long, or
+    /// one grid step when the grid is coarser than a beat.
+    pub fn snap(mut self, snap: Fraction) -> Self {
+        self.snap = snap;
+        self
+    }
+
+    /// Set snap division as a note value (4 = quarter note, 8 = eighth, 16 = sixteenth)
+    ///
+    /// Shorthand for [`snap`](Self::snap) with `Fraction::new(4, div)` beats.
     pub fn snap_division(mut self, div: u32) -> Self {
-        self.snap_division = div.max(1);
+        self.snap = Fraction::new(4, div);
         self
     }
 
+    /// Length of a note added by double-click, in beats
+    fn new_note_length(&self) -> f32 {
+        self.snap.beats().max(1.0)
+    }
+
     /// Set keyboard width
     pub fn key_width(mut self, width: f32) -> Self {
         self.key_width = width;
@@ -211,7 +307,7 @@ impl<'a> PianoRoll<'a> {
         let grid_height = num_notes as f32 * self.row_height;
         let total_height = grid_height;
 
-        let (rect, _) = ui.allocate_exact_size(
+        let (rect, roll_response) = ui.allocate_exact_size(
             Vec2::new(ui.available_width(), total_height),
             Sense::hover(),
         );
@@ -227,10 +323,31 @@ impl<'a> PianoRoll<'a> {
         );
 
         let beat_width = grid_width / total_beats as f32;
-        let _snap_width = beat_width / (self.snap_division as f32 / self.beats_per_bar as f32);
+        let snap_enabled = !ui.input(|i| i.modifiers.alt);
+        let quantize = |beat: f32| {
+            if snap_enabled {
+                self.snap.round(beat)
+            } else {
+                beat
+            }
+        };
+
+        let mut selection: Vec<usize> = self.selection.to_vec();
+        if let Some(idx) = self.selected {
+            if !selection.contains(&idx) {
+                selection.push(idx);
+            }
+        }
+        let shift = ui.input(|i| i.modifiers.shift);
+
+        // Grid is allocated first so notes sit on top of it for hit-testing
+        let grid_response = ui.allocate_rect(grid_rect, Sense::click_and_drag());
+        let marquee_id = roll_response.id.with("marquee");
+        let mut marquee = None;
 
         // Collect interactions first
         struct NoteInfo {
+            idx: usize,
             rect: Rect,
             is_selected: bool,
             is_hovered: bool,
@@ -264,33 +381,68 @@ impl<'a> PianoRoll<'a> {
             );
 
             let response = ui.allocate_rect(clipped_rect, Sense::click_and_drag());
-            let is_selected = self.selected == Some(idx);
+            let is_selected = selection.contains(&idx);
             let is_hovered = response.hovered() || response.dragged();
 
             // Handle interactions
             if response.clicked() {
-                event = Some(PianoRollEvent::NoteSelect(idx));
+                event = Some(if shift {
+                    PianoRollEvent::NotesSelect(toggle_selection(&selection, idx))
+                } else {
+                    PianoRollEvent::NoteSelect(idx)
+                });
             }
 
-            if response.dragged() && self.editable {
-                let delta = response.drag_delta();
-                let beat_delta = delta.x / beat_width;
-                let note_delta = -(delta.y / self.row_height).round() as i8;
-
-                let new_start = (note.start + beat_delta).max(0.0);
-                let new_note = (note.note as i16 + note_delta as i16).clamp(0, 127) as u8;
-
-                // Snap to grid
-                let snapped_start =
-                    (new_start * self.snap_division as f32).round() / self.snap_division as f32;
+            let drag_id = roll_response.id.with(("note_drag", idx));
+            if response.drag_started() && self.editable {
+                let origin = ui
+                    .input(|i| i.pointer.press_origin())
+                    .or(response.interact_pointer_pos())
+                    .unwrap_or(clipped_rect.center());
+                let anchors = if is_selected && selection.len() > 1 {
+                    selection
+                        .iter()
+                        .filter_map(|&i| self.notes.get(i).map(|n| (i, n.note, n.start)))
+                        .collect()
+                } else {
+                    vec![(idx, note.note, note.start)]
+                };
+                ui.ctx()
+                    .data_mut(|d| d.insert_temp(drag_id, NoteDragState { anchors, origin }));
+            }
 
-                if beat_delta.abs() > note_delta.abs() as f32 * beat_width / self.row_height {
-                    event = Some(PianoRollEvent::NoteMove(idx, note.note, snapped_start));
-                } else if note_delta != 0 {
-                    event = Some(PianoRollEvent::NoteMove(idx, new_note, note.start));
+            if response.dragged() && self.editable {
+                let drag_state = ui.ctx().data(|d| d.get_temp::<NoteDragState>(drag_id));
+                if let (Some(state), Some(pointer)) = (drag_state, response.interact_pointer_pos())
+                {
+                    let delta = pointer - state.origin;
+                    let moves = group_move(
+                        &state.anchors,
+                        idx,
+                        -(delta.y / self.row_height).round() as i16,
+                        delta.x / beat_width,
+                        quantize,
+                    );
+                    let changed = moves.iter().any(|&(i, n, start)| {
+                        self.notes
+                            .get(i)
+                            .is_some_and(|cur| cur.note != n || cur.start != start)
+                    });
+
+                    if changed {
+                        event = Some(match moves.as_slice() {
+                            [(i, n, start)] => PianoRollEvent::NoteMove(*i, *n, *start),
+                            _ => PianoRollEvent::NotesMove(moves),
+                        });
+                    }
                 }
             }
 
+            if response.drag_stopped() {
+                ui.ctx()
+                    .data_mut(|d| d.remove_temp::<NoteDragState>(drag_id));
+            }
+
             if response.secondary_clicked() && self.editable {
                 event = Some(PianoRollEvent::NoteDelete(idx));
             }
@@ -303,6 +455,7 @@ impl<'a> PianoRoll<'a> {
             };
 
             note_infos.push(NoteInfo {
+                idx,
                 rect: clipped_rect,
                 is_selected,
                 is_hovered,
@@ -311,26 +464,64 @@ impl<'a> PianoRoll<'a> {
             });
         }
 
+        // Drag on empty space: rubber-band selection
+        if grid_response.drag_started() {
+            let origin = ui
+                .input(|i| i.pointer.press_origin())
+                .or(grid_response.interact_pointer_pos())
+                .unwrap_or(grid_rect.min);
+            ui.ctx().data_mut(|d| {
+                d.insert_temp(
+                    marquee_id,
+                    MarqueeState {
+                        origin,
+                        current: origin,
+                    },
+                )
+            });
+        }
+
+        if grid_response.dragged() {
+            if let Some(pointer) = grid_response.interact_pointer_pos() {
+                ui.ctx().data_mut(|d| {
+                    let state = d.get_temp_mut_or_default::<MarqueeState>(marquee_id);
+                    state.current = grid_rect.clamp(pointer);
+                    marquee = Some(state.rect());
+                });
+            }
+        }
+
+        if grid_response.drag_stopped() {
+            if let Some(state) = ui
+                .ctx()
+                .data_mut(|d| d.remove_temp::<MarqueeState>(marquee_id))
+            {
+                let area = state.rect();
+                let mut indices: Vec<usize> = if shift { selection.clone() } else { Vec::new() };
+                for info in &note_infos {
+                    if area.intersects(info.rect) && !indices.contains(&info.idx) {
+                        indices.push(info.idx);
+                    }
+                }
+                event = Some(PianoRollEvent::NotesSelect(indices));
+            }
+        }
+
         // Grid click to add note
-        let grid_response = ui.allocate_rect(grid_rect, Sense::click());
         if grid_response.double_clicked() && self.editable {
             if let Some(pos) = grid_response.interact_pointer_pos() {
                 let beat = (pos.x - grid_rect.min.x) / beat_width;
                 let row = (pos.y - grid_rect.min.y) / self.row_height;
                 let note = self.note_range.1 - row as u8;
 
-                // Snap
-                let snapped_beat =
-                    (beat * self.snap_division as f32).floor() / self.snap_division as f32;
-                let default_duration =
-                    1.0 / (self.snap_division as f32 / self.beats_per_bar as f32);
+                let start = if snap_enabled {
+                    self.snap.floor(beat)
+                } else {
+                    beat
+                };
 
                 if note >= self.note_range.0 && note <= self.note_range.1 {
-                    event = Some(PianoRollEvent::NoteAdd(
-                        note,
-                        snapped_beat,
-                        default_duration,
-                    ));
+                    event = Some(PianoRollEvent::NoteAdd(note, start, self.new_note_length()));
                 }
             }
         }
@@ -433,14 +624,15 @@ impl<'a> PianoRoll<'a> {
             );
         }
 
-        // Draw grid columns (beats)
-        for i in 0..=total_beats * self.snap_division / self.beats_per_bar {
-            let beat = i as f32 / (self.snap_division as f32 / self.beats_per_bar as f32);
+        // Draw grid columns (snap steps, with beats and bars emphasized)
+        let step = self.snap.beats();
+        let num_steps = (total_beats as f32 / step).round() as u32;
+        for i in 0..=num_steps {
+            let beat = i as f32 * step;
             let x = grid_rect.min.x + beat * beat_width;
 
-            let is_bar = i % (self.snap_division * self.bars / self.beats_per_bar) == 0
-                && i % self.snap_division == 0;
-            let is_beat = i % (self.snap_division / self.beats_per_bar) == 0;
+            let is_beat = (beat - beat.round()).abs() < 1e-3;
+            let is_bar = is_beat && (beat.round() as u32) % self.beats_per_bar == 0;
 
             let (stroke_width, alpha) = if is_bar {
                 (1.5, 120)
@@ -506,6 +698,17 @@ impl<'a> PianoRoll<'a> {
             }
         }
 
+        // Draw marquee
+        if let Some(area) = marquee {
+            painter.rect_filled(area, 0.0, theme.primary.gamma_multiply(0.15));
+            painter.rect_stroke(
+                area,
+                0.0,
+                Stroke::new(1.0, theme.primary),
+                egui::StrokeKind::Inside,
+            );
+        }
+
         // Draw playhead
         let playhead_x = grid_rect.min.x + self.position * beat_width;
         if playhead_x >= grid_rect.min.x && playhead_x <= grid_rect.max.x {
@@ -544,8 +747,179 @@ impl<'a> PianoRoll<'a> {
     }
 }
 
+/// Toggle `idx` in a selection (shift-click)
+fn toggle_selection(selection: &[usize], idx: usize) -> Vec<usize> {
+    if selection.contains(&idx) {
+        selection.iter().copied().filter(|&i| i != idx).collect()
+    } else {
+        let mut next = selection.to_vec();
+        next.push(idx);
+        next
+    }
+}
+
+/// Move every anchored note by the same amount. The dragged note (`lead`)
+/// is quantized and the others follow it, keeping their relative placement.
+fn group_move(
+    anchors: &[(usize, u8, f32)],
+    lead: usize,
+    note_delta: i16,
+    beat_delta: f32,
+    quantize: impl Fn(f32) -> f32,
+) -> Vec<(usize, u8, f32)> {
+    let Some(&(_, _, lead_start)) = anchors.iter().find(|(i, _, _)| *i == lead) else {
+        return Vec::new();
+    };
+
+    // Keep the whole group inside the valid note and time range
+    let min_start = anchors.iter().map(|a| a.2).fold(f32::MAX, f32::min);
+    let min_note = anchors.iter().map(|a| a.1 as i16).min().unwrap_or(0);
+    let max_note = anchors.iter().map(|a| a.1 as i16).max().unwrap_or(127);
+    let offset = (quantize(lead_start + beat_delta) - lead_start).max(-min_start);
+    let note_delta = note_delta.clamp(-min_note, 127 - max_note);
+
+    anchors
+        .iter()
+        .map(|&(i, note, start)| (i, (note as i16 + note_delta) as u8, start + offset))
+        .collect()
+}
+
 impl Default for PianoRoll<'_> {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{drag, test_context};
+
+    #[test]
+    fn fraction_rounds_to_grid() {
+        let quarter = Fraction::new(1, 4);
+        assert_eq!(quarter.beats(), 0.25);
+        assert_eq!(quarter.round(1.37), 1.25);
+        assert_eq!(quarter.round(1.40), 1.5);
+        assert_eq!(quarter.floor(1.49), 1.25);
+        assert_eq!(Fraction::new(0, 0).beats(), 1.0);
+    }
+
+    #[test]
+    fn snap_division_is_a_note_value() {
+        assert_eq!(PianoRoll::new().snap_division(4).snap.beats(), 1.0);
+        assert_eq!(PianoRoll::new().snap_division(16).snap.beats(), 0.25);
+    }
+
+    #[test]
+    fn added_notes_are_one_beat_unless_grid_is_coarser() {
+        assert_eq!(PianoRoll::new().new_note_length(), 1.0);
+        let sixteenths = PianoRoll::new().snap(Fraction::new(1, 4));
+        assert_eq!(sixteenths.new_note_length(), 1.0);
+        let half_notes = PianoRoll::new().snap(Fraction::new(2, 1));
+        assert_eq!(half_notes.new_note_length(), 2.0);
+    }
+
+    #[test]
+    fn drag_snaps_note_start_to_grid() {
+        let ctx = test_context();
+        let notes = [MidiNote::new(60, 1.0, 1.0)];
+        let roll = || {
+            PianoRoll::new()
+                .notes(&notes)
+                .bars(1)
+                .key_width(40.0)
+                .snap(Fraction::new(1, 4))
+        };
+
+        // First frame to learn the grid geometry
+        let mut grid = Rect::NOTHING;
+        crate::test_utils::run_ui(&ctx, |ui| {
+            grid = ui.scope(|ui| roll().show(ui)).response.rect;
+        });
+        let beat_width = (grid.width() - 40.0) / 4.0;
+        let row = (84 - 60) as f32 * 12.0 + 6.0;
+        let beat_x = |beat: f32| grid.min.x + 40.0 + beat * beat_width;
+
+        let from = egui::pos2(beat_x(1.1), grid.min.y + row);
+        let to = egui::pos2(beat_x(1.47), grid.min.y + row);
+        let mut events = Vec::new();
+        drag(&ctx, from, to, |ui| events.extend(roll().show(ui)));
+
+        assert_eq!(
+            events.last(),
+            Some(&PianoRollEvent::NoteMove(0, 60, 1.25)),
+            "{events:?}"
+        );
+    }
+
+    #[test]
+    fn marquee_selects_and_group_moves() {
+        let ctx = test_context();
+        let notes = [
+            MidiNote::new(60, 0.5, 0.5),
+            MidiNote::new(62, 1.25, 0.5),
+            MidiNote::new(72, 3.0, 1.0),
+        ];
+        let roll = |selection: &[usize], ui: &mut Ui| {
+            PianoRoll::new()
+                .notes(&notes)
+                .bars(1)
+                .key_width(40.0)
+                .selection(selection)
+                .show(ui)
+        };
+
+        let mut grid = Rect::NOTHING;
+        crate::test_utils::run_ui(&ctx, |ui| {
+            grid = ui
+                .scope(|ui| PianoRoll::new().bars(1).show(ui))
+                .response
+                .rect;
+        });
+        let beat_width = (grid.width() - 40.0) / 4.0;
+        let at = |beat: f32, note: u8| {
+            egui::pos2(
+                grid.min.x + 40.0 + beat * beat_width,
+                grid.min.y + (84 - note) as f32 * 12.0 + 6.0,
+            )
+        };
+
+        // Marquee from empty space around the first two notes
+        let mut events = Vec::new();
+        drag(&ctx, at(2.75, 58), at(0.25, 64), |ui| {
+            events.extend(roll(&[], ui))
+        });
+        assert_eq!(
+            events.last(),
+            Some(&PianoRollEvent::NotesSelect(vec![0, 1]))
+        );
+
+        // Dragging one selected note moves both by a full beat
+        events.clear();
+        drag(&ctx, at(0.75, 60), at(1.75, 60), |ui| {
+            events.extend(roll(&[0, 1], ui))
+        });
+        assert_eq!(
+            events.last(),
+            Some(&PianoRollEvent::NotesMove(vec![
+                (0, 60, 1.5),
+                (1, 62, 2.25)
+            ])),
+            "{events:?}"
+        );
+    }
+
+    #[test]
+    fn shift_click_toggles_selection() {
+        assert_eq!(toggle_selection(&[1, 3], 2), vec![1, 3, 2]);
+        assert_eq!(toggle_selection(&[1, 3], 3), vec![1]);
+    }
+
+    #[test]
+    fn group_move_stays_in_range() {
+        let anchors = [(0, 60, 0.5), (1, 126, 2.0)];
+        let moves = group_move(&anchors, 1, 5, -4.0, |b| b);
+        assert_eq!(moves, vec![(0, 61, 0.0), (1, 127, 1.5)]);
+    }
+}
@@ -11,17 +11,24 @@
 //!     .level(model.peak_level)
 //!     .mute(model.muted)
 //!     .solo(model.soloed)
+//!     .with_eq(model.eq_low, model.eq_mid, model.eq_high) // dB
+//!     .with_send(model.send)
 //!     .show_with(ctx, |event| match event {
 //!         ChannelEvent::VolumeChange(v) => Msg::SetVolume(v),
 //!         ChannelEvent::PanChange(p) => Msg::SetPan(p),
 //!         ChannelEvent::Mute => Msg::ToggleMute,
 //!         ChannelEvent::Solo => Msg::ToggleSolo,
+//!         ChannelEvent::EqChange { band, value } => Msg::SetEq(band, value),
+//!         ChannelEvent::SendChange(v) => Msg::SetSend(v),
+//!         ChannelEvent::Select => Msg::Select,
 //!     });
 //! ```
 
+use crate::atoms::Knob;
 use crate::Theme;
 use egui::{Color32, Rect, Sense, Stroke, Ui, Vec2};
 use egui_cha::ViewCtx;
+use std::ops::RangeInclusive;
 
 /// Channel strip events
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -36,8 +43,18 @@ pub enum ChannelEvent {
     Solo,
     /// Channel selected
     Select,
+    /// EQ gain changed (band: 0 = low, 1 = mid, 2 = high; value in dB)
+    EqChange { band: usize, value: f32 },
+    /// Aux send level changed (0.0 - 1.0)
+    SendChange(f32),
 }
 
+/// EQ gain range in dB (symmetric around 0)
+pub const EQ_RANGE_DB: f32 = 12.0;
+
+/// Knob rows drawn top to bottom, console style (high band first)
+const EQ_ROWS: [(usize, &str); 3] = [(2, "HI"), (1, "MID"), (0, "LO")];
+
 /// Channel strip component
 pub struct ChannelStrip<'a> {
     label: &'a str,
@@ -45,6 +62,8 @@ pub struct ChannelStrip<'a> {
     pan: f32,
     level: f32,
     level_right: Option<f32>,
+    eq: Option<[f32; 3]>,
+    send: Option<f32>,
     muted: bool,
     soloed: bool,
     selected: bool,
@@ -64,6 +83,8 @@ impl<'a> ChannelStrip<'a> {
             pan: 0.0,
             level: 0.0,
             level_right: None,
+            eq: None,
+            send: None,
             muted: false,
             soloed: false,
             selected: false,
@@ -100,6 +121,19 @@ impl<'a> ChannelStrip<'a> {
         self
     }
 
+    /// Show a 3-band EQ section with gains in dB (±[`EQ_RANGE_DB`])
+    pub fn with_eq(mut self, low: f32, mid: f32, high: f32) -> Self {
+        let clamp = |v: f32| v.clamp(-EQ_RANGE_DB, EQ_RANGE_DB);
+        self.eq = Some([clamp(low), clamp(mid), clamp(high)]);
+        self
+    }
+
+    /// Show an aux send knob (0.0 - 1.0)
+    pub fn with_send(mut self, send: f32) -> Self {
+        self.send = Some(send.clamp(0.0, 1.0));
+        self
+    }
+
     /// Set mute state
     pub fn mute(mut self, muted: bool) -> Self {
         self.muted = muted;
@@ -142,7 +176,7 @@ impl<'a> ChannelStrip<'a> {
         self
     }
 
-    /// Compact mode
+    /// Compact mode (hides the EQ and send knobs)
     pub fn compact(mut self, compact: bool) -> Self {
         self.compact = compact;
         self
@@ -177,9 +211,13 @@ impl<'a> ChannelStrip<'a> {
         let fader_height = if self.compact { 100.0 } else { 140.0 };
         let meter_width = if self.show_meter { 8.0 } else { 0.0 };
         let spacing = theme.spacing_xs;
+        let knob_height = section_knob("", 0.0..=1.0).layout_size(&theme).y;
+        let knob_rows = self.knob_rows();
+        let knobs_height = knob_rows.len() as f32 * (knob_height + spacing);
 
         let total_height = label_height
             + spacing
+            + knobs_height
             + button_height * 2.0
             + spacing * 2.0
             + pan_height
@@ -209,6 +247,19 @@ impl<'a> ChannelStrip<'a> {
         );
         y += label_height + spacing;
 
+        let knob_rects: Vec<(KnobRow, Rect)> = knob_rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let r = Rect::from_min_size(
+                    egui::pos2(rect.min.x, y + i as f32 * (knob_height + spacing)),
+                    Vec2::new(self.width, knob_height),
+                );
+                (*row, r)
+            })
+            .collect();
+        y += knobs_height;
+
         let mute_rect = Rect::from_min_size(
             egui::pos2(rect.min.x + 4.0, y),
             Vec2::new(self.width - 8.0, button_height),
@@ -282,7 +333,7 @@ impl<'a> ChannelStrip<'a> {
         }
 
         // Second pass: draw everything
-        let painter = ui.painter();
+        let painter = ui.painter().clone();
 
         // Background
         let bg_color = if self.selected {
@@ -297,6 +348,35 @@ impl<'a> ChannelStrip<'a> {
         };
         painter.rect_filled(rect, theme.radius_sm, bg_color);
 
+        // EQ / send knobs (drawn by Knob itself, on top of the background)
+        for (row, knob_rect) in knob_rects {
+            let (label, value, range) = match row {
+                KnobRow::Eq(band, label) => (
+                    label,
+                    self.eq.map(|eq| eq[band]).unwrap_or(0.0),
+                    -EQ_RANGE_DB..=EQ_RANGE_DB,
+                ),
+                KnobRow::Send => ("SEND", self.send.unwrap_or(0.0), 0.0..=1.0),
+            };
+            let mut current = value as f64;
+            let changed = ui
+                .scope_builder(
+                    egui::UiBuilder::new()
+                        .max_rect(knob_rect)
+                        .id_salt(row)
+                        .layout(egui::Layout::top_down(egui::Align::Center)),
+                    |ui| section_knob(label, range).show(ui, &mut current).changed(),
+                )
+                .inner;
+            if changed {
+                let value = current as f32;
+                event = Some(match row {
+                    KnobRow::Eq(band, _) => ChannelEvent::EqChange { band, value },
+                    KnobRow::Send => ChannelEvent::SendChange(value),
+                });
+            }
+        }
+
         // Top label with color indicator
         let color_bar = Rect::from_min_size(label_rect.min, Vec2::new(self.width, 3.0));
         painter.rect_filled(color_bar, theme.radius_sm, channel_color);
@@ -398,14 +478,14 @@ impl<'a> ChannelStrip<'a> {
                 egui::pos2(meter_x, fader_area_rect.min.y),
                 Vec2::new(meter_width, fader_height),
             );
-            self.draw_meter(painter, meter_rect, self.level, &theme);
+            self.draw_meter(&painter, meter_rect, self.level, &theme);
 
             if let Some(right) = self.level_right {
                 let right_rect = Rect::from_min_size(
                     egui::pos2(meter_x + meter_width + 2.0, fader_area_rect.min.y),
                     Vec2::new(meter_width, fader_height),
                 );
-                self.draw_meter(painter, right_rect, right, &theme);
+                self.draw_meter(&painter, right_rect, right, &theme);
             }
         }
 
@@ -496,6 +576,25 @@ impl<'a> ChannelStrip<'a> {
         event
     }
 
+    /// Knob rows to show, hidden entirely in compact mode
+    fn knob_rows(&self) -> Vec<KnobRow> {
+        if self.compact {
+            return Vec::new();
+        }
+        let mut rows = Vec::new();
+        if self.eq.is_some() {
+            rows.extend(
+                EQ_ROWS
+                    .iter()
+                    .map(|&(band, label)| KnobRow::Eq(band, label)),
+            );
+        }
+        if self.send.is_some() {
+            rows.push(KnobRow::Send);
+        }
+        rows
+    }
+
     fn draw_meter(&self, painter: &egui::Painter, rect: Rect, level: f32, theme: &Theme) {
         // Background
         painter.rect_filled(rect, 2.0, theme.bg_primary);
@@ -529,3 +628,62 @@ impl<'a> ChannelStrip<'a> {
         }
     }
 }
+
+/// A knob row in the EQ/send section
+#[derive(Clone, Copy, Debug, Hash)]
+enum KnobRow {
+    Eq(usize, &'static str),
+    Send,
+}
+
+/// Knob style used for the EQ/send section
+fn section_knob(label: &str, range: RangeInclusive<f32>) -> Knob<'_> {
+    Knob::new(*range.start() as f64..=*range.end() as f64)
+        .compact()
+        .label(label)
+        .show_value(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{drag, run_ui, test_context};
+
+    #[test]
+    fn compact_hides_eq_and_send() {
+        let strip = ChannelStrip::new("Ch")
+            .with_eq(0.0, 0.0, 0.0)
+            .with_send(0.5);
+        assert_eq!(strip.knob_rows().len(), 4);
+        assert!(strip.compact(true).knob_rows().is_empty());
+    }
+
+    #[test]
+    fn turning_low_eq_emits_band_zero() {
+        let ctx = test_context();
+        let strip = || ChannelStrip::new("Ch").with_eq(0.0, 0.0, 0.0);
+
+        let mut rect = Rect::NOTHING;
+        run_ui(&ctx, |ui| {
+            rect = ui.scope(|ui| strip().show(ui)).response.rect;
+        });
+
+        // LO is the third knob row, below the top label
+        let theme = Theme::current(&ctx);
+        let knob = section_knob("LO", 0.0..=1.0).layout_size(&theme);
+        let lo_center = egui::pos2(
+            rect.center().x,
+            rect.min.y + 20.0 + theme.spacing_xs * 3.0 + knob.y * 2.0 + knob.x / 2.0,
+        );
+
+        let mut events = Vec::new();
+        drag(&ctx, lo_center, lo_center - Vec2::new(0.0, 30.0), |ui| {
+            events.extend(strip().show(ui))
+        });
+
+        match events.last() {
+            Some(ChannelEvent::EqChange { band: 0, value }) => assert!(*value > 0.0),
+            other => panic!("expected low EQ change, got {other:?}"),
+        }
+    }
+}
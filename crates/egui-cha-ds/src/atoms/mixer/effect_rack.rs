@@ -5,34 +5,33 @@
 //! # Example
 //! ```ignore
 //! EffectRack::new(&model.effects)
+//!     .preset_bar(true)
+//!     .presets(&model.preset_names)
+//!     .active_slot(model.ab_slot)
 //!     .show_with(ctx, |event| match event {
 //!         RackEvent::Toggle(idx) => Msg::ToggleEffect(idx),
 //!         RackEvent::Reorder(from, to) => Msg::ReorderEffects(from, to),
 //!         RackEvent::Remove(idx) => Msg::RemoveEffect(idx),
 //!         RackEvent::Select(idx) => Msg::SelectEffect(idx),
 //!         RackEvent::ParamChange(idx, param, val) => Msg::SetParam(idx, param, val),
+//!         RackEvent::AddEffect => Msg::AddEffect,
+//!         RackEvent::SavePreset => Msg::SavePreset,
+//!         RackEvent::LoadPreset(name) => Msg::LoadPreset(name),
+//!         RackEvent::SwapAB => Msg::SwapAB,
 //!     });
 //! ```
 
 use crate::Theme;
-use egui::{Color32, Id, Pos2, Rect, Sense, Stroke, Ui, Vec2};
+use egui::{Color32, Id, Rect, Sense, Stroke, Ui, Vec2};
+use egui_cha::drag_drop::{Reorder, ReorderDrag};
 use egui_cha::ViewCtx;
 
-/// Drag state for reordering effects
-#[derive(Clone, Debug, Default)]
-struct EffectDragState {
-    /// Index of effect being dragged
-    dragging: Option<usize>,
-    /// Current drop target index
-    drop_target: Option<usize>,
-}
-
 /// Effect rack events
 #[derive(Clone, Debug, PartialEq)]
 pub enum RackEvent {
     /// Effect enabled/disabled toggle
     Toggle(usize),
-    /// Effect reordered (from_index, to_index)
+    /// Effect dragged to a new position (from_index, final index once moved)
     Reorder(usize, usize),
     /// Effect removed
     Remove(usize),
@@ -42,8 +41,37 @@ pub enum RackEvent {
     ParamChange(usize, usize, f32),
     /// Add effect button clicked
     AddEffect,
+    /// Save button clicked (storing the chain is up to the app)
+    SavePreset,
+    /// Preset picked from the preset menu
+    LoadPreset(String),
+    /// A/B toggle clicked
+    SwapAB,
+}
+
+/// Which of the two A/B snapshots is active
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum AbSlot {
+    /// Snapshot A
+    #[default]
+    A,
+    /// Snapshot B
+    B,
+}
+
+impl AbSlot {
+    /// The other slot
+    pub fn other(self) -> Self {
+        match self {
+            AbSlot::A => AbSlot::B,
+            AbSlot::B => AbSlot::A,
+        }
+    }
 }
 
+/// Height of the preset bar above the rack
+const PRESET_BAR_HEIGHT: f32 = 22.0;
+
 /// Effect type category
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum EffectCategory {
@@ -212,6 +240,9 @@ pub struct EffectRack<'a> {
     draggable: bool,
     effect_height: f32,
     effect_width: f32,
+    preset_bar: bool,
+    presets: &'a [String],
+    active_slot: AbSlot,
 }
 
 impl<'a> EffectRack<'a> {
@@ -227,6 +258,9 @@ impl<'a> EffectRack<'a> {
             draggable: true,
             effect_height: 64.0,
             effect_width: 120.0,
+            preset_bar: false,
+            presets: &[],
+            active_slot: AbSlot::A,
         }
     }
 
@@ -275,6 +309,25 @@ impl<'a> EffectRack<'a> {
         self
     }
 
+    /// Show the preset bar (A/B toggle, save, preset menu) above the rack
+    pub fn preset_bar(mut self, show: bool) -> Self {
+        self.preset_bar = show;
+        self
+    }
+
+    /// Set preset names listed in the preset menu (enables the preset bar)
+    pub fn presets(mut self, presets: &'a [String]) -> Self {
+        self.presets = presets;
+        self.preset_bar = true;
+        self
+    }
+
+    /// Set the active A/B slot
+    pub fn active_slot(mut self, slot: AbSlot) -> Self {
+        self.active_slot = slot;
+        self
+    }
+
     /// TEA-style: Show rack and emit events
     pub fn show_with<Msg>(self, ctx: &mut ViewCtx<'_, Msg>, on_event: impl Fn(RackEvent) -> Msg) {
         if let Some(event) = self.render(ctx.ui) {
@@ -294,11 +347,8 @@ impl<'a> EffectRack<'a> {
         let spacing = theme.spacing_sm;
         let add_button_size = 32.0;
 
-        // Drag state management
-        let drag_id = Id::new("effect_rack_drag");
-        let mut drag_state: EffectDragState = ui
-            .ctx()
-            .data_mut(|d| d.get_temp(drag_id).unwrap_or_default());
+        let mut drag = ReorderDrag::new(ui, Id::new("effect_rack_drag"))
+            .horizontal(self.orientation == RackOrientation::Horizontal);
 
         // Calculate total size
         let (total_width, total_height) = match self.orientation {
@@ -318,20 +368,63 @@ impl<'a> EffectRack<'a> {
             }
         };
 
-        let (rect, _) =
-            ui.allocate_exact_size(Vec2::new(total_width, total_height), Sense::hover());
+        let bar_height = if self.preset_bar {
+            PRESET_BAR_HEIGHT + spacing
+        } else {
+            0.0
+        };
+        let (outer_rect, _) = ui.allocate_exact_size(
+            Vec2::new(total_width, total_height + bar_height),
+            Sense::hover(),
+        );
 
-        if !ui.is_rect_visible(rect) {
+        if !ui.is_rect_visible(outer_rect) {
             return None;
         }
 
+        // Preset bar on top, effects below
+        let rect = Rect::from_min_max(
+            egui::pos2(outer_rect.min.x, outer_rect.min.y + bar_height),
+            outer_rect.max,
+        );
+        let bar = self
+            .preset_bar
+            .then(|| preset_bar_layout(outer_rect, spacing, !self.presets.is_empty()));
+        let bar_responses = bar.as_ref().map(|bar| {
+            let ab = ui.allocate_rect(bar.ab, Sense::click());
+            let save = ui.allocate_rect(bar.save, Sense::click());
+            let load = bar.load.map(|r| ui.allocate_rect(r, Sense::click()));
+            (ab, save, load)
+        });
+
+        if let Some((ab, save, load)) = &bar_responses {
+            if ab.clicked() {
+                event = Some(RackEvent::SwapAB);
+            }
+            if save.clicked() {
+                event = Some(RackEvent::SavePreset);
+            }
+            if let Some(load) = load {
+                let picked = egui::Popup::menu(load)
+                    .show(|ui| {
+                        self.presets
+                            .iter()
+                            .find(|name| ui.button(name.as_str()).clicked())
+                            .cloned()
+                    })
+                    .and_then(|inner| inner.inner);
+                if let Some(name) = picked {
+                    event = Some(RackEvent::LoadPreset(name));
+                }
+            }
+        }
+
         // First pass: collect all interactions
         struct EffectInfo {
             effect_rect: Rect,
             is_selected: bool,
             is_hovered: bool,
             is_enabled: bool,
-            drag_started: bool,
             toggle_rect: Rect,
             toggle_hovered: bool,
             content_rect: Rect,
@@ -368,7 +461,7 @@ impl<'a> EffectRack<'a> {
             let is_selected = self.selected == Some(idx);
             let is_hovered = response.hovered();
             let is_enabled = effect.enabled && !effect.bypassed;
-            let drag_started = response.drag_started() && self.draggable;
+            drag.item(effect_rect, response.drag_started() && self.draggable);
 
             let bg_color = if is_selected {
                 theme.bg_tertiary
@@ -431,7 +524,6 @@ impl<'a> EffectRack<'a> {
                 is_selected,
                 is_hovered,
                 is_enabled,
-                drag_started,
                 toggle_rect,
                 toggle_hovered: toggle_response.hovered(),
                 content_rect,
@@ -443,49 +535,8 @@ impl<'a> EffectRack<'a> {
             });
         }
 
-        // Handle drag-to-reorder
-        let pointer_pos = ui.input(|i| i.pointer.hover_pos());
-        for info in &effect_infos {
-            // Start drag
-            if info.drag_started && drag_state.dragging.is_none() {
-                drag_state.dragging = Some(info.effect_idx);
-            }
-
-            // Update drop target during drag
-            if drag_state.dragging.is_some() && info.is_hovered {
-                if let Some(pos) = pointer_pos {
-                    match self.orientation {
-                        RackOrientation::Vertical => {
-                            let mid_y = info.effect_rect.center().y;
-                            if pos.y < mid_y {
-                                drag_state.drop_target = Some(info.effect_idx);
-                            } else {
-                                drag_state.drop_target = Some(info.effect_idx + 1);
-                            }
-                        }
-                        RackOrientation::Horizontal => {
-                            let mid_x = info.effect_rect.center().x;
-                            if pos.x < mid_x {
-                                drag_state.drop_target = Some(info.effect_idx);
-                            } else {
-                                drag_state.drop_target = Some(info.effect_idx + 1);
-                            }
-                        }
-                    }
-                }
-            }
-        }
-
-        // Complete drag on release
-        if !ui.input(|i| i.pointer.any_down()) {
-            if let (Some(from), Some(to)) = (drag_state.dragging, drag_state.drop_target) {
-                // Only emit if actually moved
-                if from != to && from + 1 != to {
-                    event = Some(RackEvent::Reorder(from, to));
-                }
-            }
-            drag_state.dragging = None;
-            drag_state.drop_target = None;
+        if let Some(Reorder { from, to }) = drag.update(ui) {
+            event = Some(RackEvent::Reorder(from, to));
         }
 
         // Add button interaction
@@ -523,7 +574,58 @@ impl<'a> EffectRack<'a> {
         let painter = ui.painter();
 
         // Background
-        painter.rect_filled(rect, theme.radius_md, theme.bg_primary);
+        painter.rect_filled(outer_rect, theme.radius_md, theme.bg_primary);
+
+        // Preset bar
+        if let (Some(bar), Some((ab, save, load))) = (&bar, &bar_responses) {
+            let font = egui::FontId::proportional(theme.font_size_xs);
+
+            // A/B toggle: two halves, active one highlighted
+            let (a_rect, b_rect) = bar.ab.split_left_right_at_fraction(0.5);
+            for (slot, half, label) in [(AbSlot::A, a_rect, "A"), (AbSlot::B, b_rect, "B")] {
+                let active = self.active_slot == slot;
+                let fill = if active {
+                    theme.primary
+                } else if ab.hovered() {
+                    theme.bg_tertiary
+                } else {
+                    theme.bg_secondary
+                };
+                let text_color = if active {
+                    theme.primary_text
+                } else {
+                    theme.text_secondary
+                };
+                painter.rect_filled(half, theme.radius_sm * 0.5, fill);
+                painter.text(
+                    half.center(),
+                    egui::Align2::CENTER_CENTER,
+                    label,
+                    font.clone(),
+                    text_color,
+                );
+            }
+
+            let bar_button = |r: Rect, hovered: bool, label: &str| {
+                let fill = if hovered {
+                    theme.bg_tertiary
+                } else {
+                    theme.bg_secondary
+                };
+                painter.rect_filled(r, theme.radius_sm * 0.5, fill);
+                painter.text(
+                    r.center(),
+                    egui::Align2::CENTER_CENTER,
+                    label,
+                    font.clone(),
+                    theme.text_secondary,
+                );
+            };
+            bar_button(bar.save, save.hovered(), "SAVE");
+            if let (Some(r), Some(load)) = (bar.load, load) {
+                bar_button(r, load.hovered(), "Presets \u{25BE}");
+            }
+        }
 
         // Draw chain connection line
         if self.show_chain_line && !self.effects.is_empty() {
@@ -693,7 +795,7 @@ impl<'a> EffectRack<'a> {
             }
 
             // Drag overlay for dragged effect
-            if drag_state.dragging == Some(info.effect_idx) {
+            if drag.dragging() == Some(info.effect_idx) {
                 painter.rect_filled(
                     info.effect_rect,
                     theme.radius_sm,
@@ -711,48 +813,10 @@ impl<'a> EffectRack<'a> {
                     egui::StrokeKind::Inside,
                 );
             }
-
-            // Drop indicator line
-            if let Some(drop_idx) = drag_state.drop_target {
-                let draw_line = |start: Pos2, end: Pos2| {
-                    painter.line_segment([start, end], Stroke::new(3.0, theme.primary));
-                };
-
-                match self.orientation {
-                    RackOrientation::Vertical => {
-                        if drop_idx == info.effect_idx {
-                            draw_line(
-                                Pos2::new(info.effect_rect.min.x, info.effect_rect.min.y),
-                                Pos2::new(info.effect_rect.max.x, info.effect_rect.min.y),
-                            );
-                        } else if drop_idx == info.effect_idx + 1
-                            && info.effect_idx == self.effects.len() - 1
-                        {
-                            draw_line(
-                                Pos2::new(info.effect_rect.min.x, info.effect_rect.max.y),
-                                Pos2::new(info.effect_rect.max.x, info.effect_rect.max.y),
-                            );
-                        }
-                    }
-                    RackOrientation::Horizontal => {
-                        if drop_idx == info.effect_idx {
-                            draw_line(
-                                Pos2::new(info.effect_rect.min.x, info.effect_rect.min.y),
-                                Pos2::new(info.effect_rect.min.x, info.effect_rect.max.y),
-                            );
-                        } else if drop_idx == info.effect_idx + 1
-                            && info.effect_idx == self.effects.len() - 1
-                        {
-                            draw_line(
-                                Pos2::new(info.effect_rect.max.x, info.effect_rect.min.y),
-                                Pos2::new(info.effect_rect.max.x, info.effect_rect.max.y),
-                            );
-                        }
-                    }
-                }
-            }
         }
 
+        drag.paint_drop_marker(painter, Stroke::new(3.0, theme.primary));
+
         // Draw add button
         let add_bg_color = if add_hovered {
             theme.bg_tertiary
@@ -801,15 +865,118 @@ impl<'a> EffectRack<'a> {
 
         // Border
         painter.rect_stroke(
-            rect,
+            outer_rect,
             theme.radius_md,
             Stroke::new(theme.border_width, theme.border),
             egui::StrokeKind::Inside,
         );
 
-        // Save drag state
-        ui.ctx().data_mut(|d| d.insert_temp(drag_id, drag_state));
-
         event
     }
 }
+
+/// Button rects inside the preset bar
+struct PresetBarLayout {
+    ab: Rect,
+    save: Rect,
+    load: Option<Rect>,
+}
+
+/// Lay out the preset bar along the top edge of the rack
+fn preset_bar_layout(outer: Rect, spacing: f32, with_presets: bool) -> PresetBarLayout {
+    let button_width = 36.0;
+    let top = outer.min.y + spacing;
+    let button = |x: f32, width: f32| {
+        Rect::from_min_size(egui::pos2(x, top), Vec2::new(width, PRESET_BAR_HEIGHT))
+    };
+
+    let ab = button(outer.min.x + spacing, button_width);
+    let save = button(ab.max.x + spacing, button_width);
+    let load_width = outer.max.x - spacing - (save.max.x + spacing);
+    let load = (with_presets && load_width > button_width)
+        .then(|| button(save.max.x + spacing, load_width));
+
+    PresetBarLayout { ab, save, load }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, drag, run_ui, test_context};
+
+    fn click_bar_button(pick: impl Fn(&PresetBarLayout) -> Rect) -> Vec<RackEvent> {
+        let ctx = test_context();
+        let effects = [Effect::new("Reverb", EffectCategory::Time)];
+        let presets = vec!["Warm".to_string()];
+        let rack = |ui: &mut Ui| {
+            EffectRack::new(&effects)
+                .presets(&presets)
+                .active_slot(AbSlot::B)
+                .show(ui)
+        };
+
+        let mut outer = Rect::NOTHING;
+        run_ui(&ctx, |ui| {
+            outer = ui.scope(|ui| rack(ui)).response.rect;
+        });
+        let spacing = Theme::current(&ctx).spacing_sm;
+        let target = pick(&preset_bar_layout(outer, spacing, true)).center();
+
+        let mut events = Vec::new();
+        click_at(&ctx, target, |ui| events.extend(rack(ui)));
+        events
+    }
+
+    #[test]
+    fn ab_toggle_emits_swap() {
+        assert_eq!(click_bar_button(|bar| bar.ab), vec![RackEvent::SwapAB]);
+    }
+
+    #[test]
+    fn save_button_emits_save_preset() {
+        assert_eq!(
+            click_bar_button(|bar| bar.save),
+            vec![RackEvent::SavePreset]
+        );
+    }
+
+    #[test]
+    fn drag_reports_final_index() {
+        let ctx = test_context();
+        let effects = [
+            Effect::new("Comp", EffectCategory::Dynamics),
+            Effect::new("EQ", EffectCategory::EQ),
+            Effect::new("Reverb", EffectCategory::Time),
+        ];
+        let rack = |ui: &mut Ui| {
+            EffectRack::new(&effects)
+                .orientation(RackOrientation::Horizontal)
+                .effect_size(80.0, 60.0)
+                .show(ui)
+        };
+
+        let mut outer = Rect::NOTHING;
+        run_ui(&ctx, |ui| {
+            outer = ui.scope(|ui| rack(ui)).response.rect;
+        });
+        let spacing = Theme::current(&ctx).spacing_sm;
+        let slot_center =
+            |i: f32| outer.min + Vec2::new(spacing + i * (80.0 + spacing) + 40.0, spacing + 30.0);
+
+        // Past the middle of the last effect: the first one ends up last
+        let mut events = Vec::new();
+        drag(
+            &ctx,
+            slot_center(0.0),
+            slot_center(2.0) + Vec2::new(10.0, 0.0),
+            |ui| events.extend(rack(ui)),
+        );
+        assert_eq!(events, vec![RackEvent::Reorder(0, 2)]);
+    }
+
+    #[test]
+    fn ab_slot_other() {
+        assert_eq!(AbSlot::A.other(), AbSlot::B);
+        assert_eq!(AbSlot::B.other(), AbSlot::A);
+    }
+}
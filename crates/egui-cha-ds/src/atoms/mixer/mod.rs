@@ -10,9 +10,9 @@ mod effect_rack;
 mod envelope_editor;
 
 pub use automation_lane::{AutomationCurve, AutomationEvent, AutomationLane, AutomationPoint};
-pub use channel_strip::{ChannelEvent, ChannelStrip};
+pub use channel_strip::{ChannelEvent, ChannelStrip, EQ_RANGE_DB};
 pub use crossfader::{CrossFader, CrossfaderCurve, CrossfaderOrientation};
 pub use effect_rack::{
-    Effect, EffectCategory, EffectParam, EffectRack, RackEvent, RackOrientation,
+    AbSlot, Effect, EffectCategory, EffectParam, EffectRack, RackEvent, RackOrientation,
 };
 pub use envelope_editor::{CurveType, EnvelopeEditor, EnvelopeEvent, EnvelopePoint};
@@ -8,7 +8,14 @@
 //! ClipGrid::new(&clips, 4) // 4 columns
 //!     .current(model.current_clip)
 //!     .queued(&model.queue)
+//!     .scene_labels(&["Intro", "Verse", "Drop"]) // one per row
+//!     .with_legend(true)
 //!     .show_with(ctx, |idx| Msg::QueueClip(idx));
+//!
+//! // With scene-launch and stop controls
+//! ClipGrid::new(&clips, 4)
+//!     .launch_controls(true)
+//!     .show_events_with(ctx, Msg::ClipGrid);
 //! ```
 
 use crate::Theme;
@@ -29,6 +36,22 @@ pub enum ClipState {
     Selected,
 }
 
+/// Events emitted by [`ClipGrid`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipGridEvent {
+    /// A clip cell was clicked
+    Trigger(usize),
+    /// The launch control of a scene (row) was clicked
+    LaunchScene(usize),
+    /// The stop control of a track (column) was clicked
+    StopTrack(usize),
+    /// The stop-all control was clicked
+    StopAll,
+}
+
+/// Size of the scene-launch column and the stop row
+const CONTROL_SIZE: f32 = 24.0;
+
 /// A single clip/phrase cell data
 #[derive(Debug, Clone)]
 pub struct ClipCell {
@@ -66,6 +89,9 @@ pub struct ClipGrid<'a> {
     current: Option<usize>,
     queued: &'a [usize],
     show_index: bool,
+    scene_labels: &'a [&'a str],
+    legend: bool,
+    launch_controls: bool,
 }
 
 impl<'a> ClipGrid<'a> {
@@ -79,6 +105,9 @@ impl<'a> ClipGrid<'a> {
             current: None,
             queued: &[],
             show_index: false,
+            scene_labels: &[],
+            legend: false,
+            launch_controls: false,
         }
     }
 
@@ -112,37 +141,117 @@ impl<'a> ClipGrid<'a> {
         self
     }
 
+    /// Label each row (scene) in a column left of the grid
+    pub fn scene_labels(mut self, labels: &'a [&'a str]) -> Self {
+        self.scene_labels = labels;
+        self
+    }
+
+    /// Show a key for the Idle/Queued/Playing colors below the grid
+    pub fn with_legend(mut self, show: bool) -> Self {
+        self.legend = show;
+        self
+    }
+
+    /// Show a launch button per scene (row) and a stop button per track (column)
+    pub fn launch_controls(mut self, show: bool) -> Self {
+        self.launch_controls = show;
+        self
+    }
+
+    /// Width of the scene label column (0 without labels)
+    fn label_column_width(&self, ui: &Ui, theme: &Theme) -> f32 {
+        if self.scene_labels.is_empty() {
+            return 0.0;
+        }
+        let font = egui::FontId::proportional(theme.font_size_sm);
+        let widest = self
+            .scene_labels
+            .iter()
+            .map(|label| {
+                ui.painter()
+                    .layout_no_wrap(label.to_string(), font.clone(), theme.text_secondary)
+                    .size()
+                    .x
+            })
+            .fold(0.0, f32::max);
+        widest.max(40.0) + theme.spacing_sm * 2.0
+    }
+
     /// TEA-style: Show grid, emit Msg when clip is clicked
     pub fn show_with<Msg>(self, ctx: &mut ViewCtx<'_, Msg>, on_click: impl Fn(usize) -> Msg) {
-        let clicked = self.show_internal(ctx.ui);
-        if let Some(idx) = clicked {
+        if let Some(ClipGridEvent::Trigger(idx)) = self.render(ctx.ui) {
             ctx.emit(on_click(idx));
         }
     }
 
+    /// TEA-style: Show grid and emit every [`ClipGridEvent`]
+    pub fn show_events_with<Msg>(
+        self,
+        ctx: &mut ViewCtx<'_, Msg>,
+        on_event: impl Fn(ClipGridEvent) -> Msg,
+    ) {
+        if let Some(event) = self.render(ctx.ui) {
+            ctx.emit(on_event(event));
+        }
+    }
+
     /// Show grid and return clicked clip index
     pub fn show(self, ui: &mut Ui) -> Option<usize> {
-        self.show_internal(ui)
+        match self.render(ui) {
+            Some(ClipGridEvent::Trigger(idx)) => Some(idx),
+            _ => None,
+        }
+    }
+
+    /// Show grid and return the event if any
+    pub fn show_events(self, ui: &mut Ui) -> Option<ClipGridEvent> {
+        self.render(ui)
     }
 
-    fn show_internal(self, ui: &mut Ui) -> Option<usize> {
+    fn render(self, ui: &mut Ui) -> Option<ClipGridEvent> {
         let theme = Theme::current(ui.ctx());
         let time = ui.input(|i| i.time) as f32;
-        let mut clicked_idx: Option<usize> = None;
-
-        let rows = (self.clips.len() + self.columns - 1) / self.columns;
-        let total_width =
+        let mut event: Option<ClipGridEvent> = None;
+
+        let rows = self
+            .clips
+            .len()
+            .div_ceil(self.columns)
+            .max(self.scene_labels.len());
+        let label_width = self.label_column_width(ui, &theme);
+        let legend_height = if self.legend {
+            theme.spacing_sm + theme.spacing_lg
+        } else {
+            0.0
+        };
+        let grid_width =
             self.columns as f32 * self.cell_size.x + (self.columns - 1) as f32 * self.spacing;
-        let total_height =
+        let grid_height =
             rows as f32 * self.cell_size.y + (rows.saturating_sub(1)) as f32 * self.spacing;
-
-        let (rect, _response) =
-            ui.allocate_exact_size(Vec2::new(total_width, total_height), Sense::hover());
-
-        if !ui.is_rect_visible(rect) {
+        let controls_extent = if self.launch_controls {
+            self.spacing + CONTROL_SIZE
+        } else {
+            0.0
+        };
+
+        let (outer, _response) = ui.allocate_exact_size(
+            Vec2::new(
+                label_width + grid_width + controls_extent,
+                grid_height + controls_extent + legend_height,
+            ),
+            Sense::hover(),
+        );
+
+        if !ui.is_rect_visible(outer) {
             return None;
         }
 
+        let rect = egui::Rect::from_min_size(
+            outer.min + Vec2::new(label_width, 0.0),
+            Vec2::new(grid_width, grid_height),
+        );
+
         // First pass: collect cell info and handle interactions
         struct CellInfo {
             rect: egui::Rect,
@@ -176,7 +285,7 @@ impl<'a> ClipGrid<'a> {
             let cell_response = ui.allocate_rect(cell_rect, Sense::click());
 
             if cell_response.clicked() {
-                clicked_idx = Some(idx);
+                event = Some(ClipGridEvent::Trigger(idx));
             }
 
             cells.push(CellInfo {
@@ -189,9 +298,147 @@ impl<'a> ClipGrid<'a> {
             });
         }
 
+        // Scene-launch column and stop row
+        struct ControlInfo {
+            rect: egui::Rect,
+            hovered: bool,
+            event: ClipGridEvent,
+        }
+
+        let mut controls: Vec<ControlInfo> = Vec::new();
+        if self.launch_controls {
+            let launch_x = rect.max.x + self.spacing;
+            let stop_y = rect.max.y + self.spacing;
+            let launch = (0..rows).map(|row| {
+                let y = rect.min.y + row as f32 * (self.cell_size.y + self.spacing);
+                (
+                    egui::Rect::from_min_size(
+                        egui::pos2(launch_x, y),
+                        Vec2::new(CONTROL_SIZE, self.cell_size.y),
+                    ),
+                    ClipGridEvent::LaunchScene(row),
+                )
+            });
+            let stop = (0..self.columns).map(|col| {
+                let x = rect.min.x + col as f32 * (self.cell_size.x + self.spacing);
+                (
+                    egui::Rect::from_min_size(
+                        egui::pos2(x, stop_y),
+                        Vec2::new(self.cell_size.x, CONTROL_SIZE),
+                    ),
+                    ClipGridEvent::StopTrack(col),
+                )
+            });
+            let stop_all = std::iter::once((
+                egui::Rect::from_min_size(egui::pos2(launch_x, stop_y), Vec2::splat(CONTROL_SIZE)),
+                ClipGridEvent::StopAll,
+            ));
+
+            for (control_rect, control_event) in launch.chain(stop).chain(stop_all) {
+                let response = ui.allocate_rect(control_rect, Sense::click());
+                if response.clicked() {
+                    event = Some(control_event);
+                }
+                controls.push(ControlInfo {
+                    rect: control_rect,
+                    hovered: response.hovered(),
+                    event: control_event,
+                });
+            }
+        }
+
         // Second pass: draw all cells
         let painter = ui.painter();
 
+        for control in &controls {
+            let bg = if control.hovered {
+                theme.bg_tertiary
+            } else {
+                theme.bg_secondary
+            };
+            painter.rect_filled(control.rect, theme.radius_sm, bg);
+            if matches!(control.event, ClipGridEvent::StopAll) {
+                painter.rect_stroke(
+                    control.rect,
+                    theme.radius_sm,
+                    egui::Stroke::new(theme.border_width, theme.border),
+                    egui::StrokeKind::Inside,
+                );
+            }
+
+            let icon = CONTROL_SIZE * 0.35;
+            let center = control.rect.center();
+            if matches!(control.event, ClipGridEvent::LaunchScene(_)) {
+                let color = if control.hovered {
+                    theme.state_success
+                } else {
+                    theme.text_secondary
+                };
+                painter.add(egui::Shape::convex_polygon(
+                    vec![
+                        center + Vec2::new(-icon / 2.0, -icon / 2.0),
+                        center + Vec2::new(-icon / 2.0, icon / 2.0),
+                        center + Vec2::new(icon / 2.0, 0.0),
+                    ],
+                    color,
+                    egui::Stroke::NONE,
+                ));
+            } else {
+                let color = if control.hovered {
+                    theme.state_danger
+                } else {
+                    theme.text_secondary
+                };
+                painter.rect_filled(
+                    egui::Rect::from_center_size(center, Vec2::splat(icon)),
+                    1.0,
+                    color,
+                );
+            }
+        }
+
+        // Scene labels, one per row
+        for (row, label) in self.scene_labels.iter().enumerate() {
+            let y = rect.min.y + row as f32 * (self.cell_size.y + self.spacing);
+            painter.text(
+                egui::pos2(outer.min.x + theme.spacing_sm, y + self.cell_size.y / 2.0),
+                egui::Align2::LEFT_CENTER,
+                *label,
+                egui::FontId::proportional(theme.font_size_sm),
+                theme.text_secondary,
+            );
+        }
+
+        if self.legend {
+            let y = rect.max.y + controls_extent + theme.spacing_sm + theme.spacing_lg / 2.0;
+            let swatch = Vec2::splat(theme.spacing_md);
+            let mut x = rect.min.x;
+            for (state, label) in Self::legend_entries() {
+                let (fill, stroke) = match state {
+                    ClipState::Playing => (theme.primary, theme.state_success),
+                    ClipState::Queued => (theme.primary.gamma_multiply(0.7), theme.state_warning),
+                    _ => (theme.bg_secondary, theme.border),
+                };
+                let swatch_rect =
+                    egui::Rect::from_center_size(egui::pos2(x + swatch.x / 2.0, y), swatch);
+                painter.rect_filled(swatch_rect, theme.radius_sm, fill);
+                painter.rect_stroke(
+                    swatch_rect,
+                    theme.radius_sm,
+                    egui::Stroke::new(2.0, stroke),
+                    egui::StrokeKind::Inside,
+                );
+                let text_rect = painter.text(
+                    egui::pos2(swatch_rect.max.x + theme.spacing_xs, y),
+                    egui::Align2::LEFT_CENTER,
+                    label,
+                    egui::FontId::proportional(theme.font_size_xs),
+                    theme.text_muted,
+                );
+                x = text_rect.max.x + theme.spacing_md;
+            }
+        }
+
         for cell in &cells {
             // Colors based on state
             let (bg_color, border_color, text_color) = match cell.state {
@@ -309,6 +556,131 @@ impl<'a> ClipGrid<'a> {
             ui.ctx().request_repaint();
         }
 
-        clicked_idx
+        event
+    }
+
+    /// States shown in the legend, in order
+    fn legend_entries() -> [(ClipState, &'static str); 3] {
+        [
+            (ClipState::Idle, "Idle"),
+            (ClipState::Queued, "Queued"),
+            (ClipState::Playing, "Playing"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, painted_texts, run_ui, test_context};
+
+    fn clips() -> Vec<ClipCell> {
+        (0..8).map(|i| ClipCell::new(format!("Clip {i}"))).collect()
+    }
+
+    fn grid_rect(grid: impl Fn() -> ClipGrid<'static> + Clone) -> (egui::Rect, Vec<String>) {
+        let ctx = test_context();
+        let mut rect = egui::Rect::NOTHING;
+        let output = run_ui(&ctx, |ui| {
+            rect = ui.scope(|ui| grid().show(ui)).response.rect;
+        });
+        (rect, painted_texts(&output))
+    }
+
+    #[test]
+    fn test_scene_labels_reserve_column() {
+        let clips: &'static [ClipCell] = Vec::leak(clips());
+        let (plain, _) = grid_rect(|| ClipGrid::new(clips, 4));
+        let (labelled, texts) =
+            grid_rect(|| ClipGrid::new(clips, 4).scene_labels(&["Intro", "Drop", "Outro"]));
+
+        assert!(labelled.width() >= plain.width() + 40.0);
+        // A third label adds a row even with only two rows of clips
+        assert!(labelled.height() > plain.height());
+        for label in ["Intro", "Drop", "Outro"] {
+            assert!(texts.iter().any(|t| t == label));
+        }
+    }
+
+    #[test]
+    fn test_legend_shows_three_states() {
+        let clips: &'static [ClipCell] = Vec::leak(clips());
+        let (plain, texts) = grid_rect(|| ClipGrid::new(clips, 4));
+        assert!(!texts.iter().any(|t| t == "Queued"));
+
+        let (with_legend, texts) = grid_rect(|| ClipGrid::new(clips, 4).with_legend(true));
+        assert!(with_legend.height() > plain.height());
+        for label in ["Idle", "Queued", "Playing"] {
+            assert!(texts.iter().any(|t| t == label), "missing {label}");
+        }
+    }
+
+    fn click_control(
+        clips: &[ClipCell],
+        pos: impl Fn(egui::Rect) -> egui::Pos2,
+    ) -> Vec<ClipGridEvent> {
+        let ctx = test_context();
+        let mut rect = egui::Rect::NOTHING;
+        run_ui(&ctx, |ui| {
+            rect = ui
+                .scope(|ui| ClipGrid::new(clips, 4).launch_controls(true).show(ui))
+                .response
+                .rect;
+        });
+        let mut events = Vec::new();
+        click_at(&ctx, pos(rect), |ui| {
+            events.extend(
+                ClipGrid::new(clips, 4)
+                    .launch_controls(true)
+                    .show_events(ui),
+            );
+        });
+        events
+    }
+
+    #[test]
+    fn test_scene_launch_emits_row() {
+        let clips = clips();
+        // Second row of the launch column, right of the grid
+        let events = click_control(&clips, |rect| {
+            egui::pos2(
+                rect.max.x - CONTROL_SIZE / 2.0,
+                rect.min.y + 60.0 + 4.0 + 30.0,
+            )
+        });
+        assert_eq!(events, vec![ClipGridEvent::LaunchScene(1)]);
+    }
+
+    #[test]
+    fn test_stop_controls() {
+        let clips = clips();
+        let events = click_control(&clips, |rect| {
+            egui::pos2(
+                rect.min.x + 80.0 + 4.0 + 40.0,
+                rect.max.y - CONTROL_SIZE / 2.0,
+            )
+        });
+        assert_eq!(events, vec![ClipGridEvent::StopTrack(1)]);
+
+        let events = click_control(&clips, |rect| rect.max - Vec2::splat(CONTROL_SIZE / 2.0));
+        assert_eq!(events, vec![ClipGridEvent::StopAll]);
+    }
+
+    #[test]
+    fn test_show_keeps_cell_index() {
+        let clips = clips();
+        let ctx = test_context();
+        let mut rect = egui::Rect::NOTHING;
+        run_ui(&ctx, |ui| {
+            rect = ui
+                .scope(|ui| ClipGrid::new(&clips, 4).show(ui))
+                .response
+                .rect;
+        });
+        let mut clicked = None;
+        click_at(&ctx, rect.min + Vec2::new(40.0, 30.0), |ui| {
+            clicked = clicked.or(ClipGrid::new(&clips, 4).show(ui));
+        });
+        assert_eq!(clicked, Some(0));
     }
 }
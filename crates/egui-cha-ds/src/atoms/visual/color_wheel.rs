@@ -9,6 +9,8 @@
 //! - Optional alpha slider
 //! - Preview swatch
 //! - Hex/RGB value display
+//! - Optional editable hex and H/S/V fields
+//! - Optional recent-colors strip and screen eyedropper
 //! - Theme-aware styling
 //!
 //! # Example
@@ -20,10 +22,27 @@
 //! ColorWheel::new()
 //!     .show_alpha(true)
 //!     .show_with(ctx, model.color, Msg::SetColor);
+//!
+//! // With hex and H/S/V input fields
+//! ColorWheel::new()
+//!     .with_inputs(true)
+//!     .show_with(ctx, model.color, Msg::SetColor);
+//!
+//! // Recent colors and eyedropper
+//! ColorWheel::new()
+//!     .with_recents(&model.recents)
+//!     .with_eyedropper(true)
+//!     .show_with(ctx, model.color, Msg::SetColor);
+//!
+//! // In update
+//! Msg::SetColor(color) => {
+//!     model.color = color;
+//!     ColorWheel::push_recent(&mut model.recents, color);
+//! }
 //! ```
 
-use crate::Theme;
-use egui::{Color32, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2};
+use crate::{icons, Theme};
+use egui::{Color32, Id, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2};
 use egui_cha::ViewCtx;
 use std::f32::consts::PI;
 
@@ -96,16 +115,34 @@ impl Hsva {
         };
 
         Color32::from_rgba_unmultiplied(
-            ((r + m) * 255.0) as u8,
-            ((g + m) * 255.0) as u8,
-            ((b + m) * 255.0) as u8,
-            (self.a * 255.0) as u8,
+            ((r + m) * 255.0).round() as u8,
+            ((g + m) * 255.0).round() as u8,
+            ((b + m) * 255.0).round() as u8,
+            (self.a * 255.0).round() as u8,
         )
     }
 
     pub fn hue_color(&self) -> Color32 {
         Self::new(self.h, 1.0, 1.0).to_color32()
     }
+
+    /// Parse `#RRGGBB` or `#RRGGBBAA` (the `#` is optional)
+    pub fn from_hex(text: &str) -> Option<Self> {
+        let (r, g, b, a) = parse_hex(text)?;
+        let alpha = a.map_or(1.0, |a| a as f32 / 255.0);
+        Some(Self::from_color32(Color32::from_rgb(r, g, b)).with_alpha(alpha))
+    }
+
+    /// Format as `#RRGGBB`, or `#RRGGBBAA` with `alpha`
+    pub fn to_hex(&self, alpha: bool) -> String {
+        let color = self.with_alpha(1.0).to_color32();
+        let hex = format!("#{:02X}{:02X}{:02X}", color.r(), color.g(), color.b());
+        if alpha {
+            format!("{hex}{:02X}", (self.a * 255.0).round() as u8)
+        } else {
+            hex
+        }
+    }
 }
 
 impl Default for Hsva {
@@ -134,17 +171,34 @@ pub enum WheelStyle {
     Square,
 }
 
+/// Eyedropper progress, kept in temp memory
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum PickState {
+    #[default]
+    Idle,
+    /// Waiting for the click to sample
+    Armed,
+    /// Screenshot requested, sample at this position when it arrives
+    Waiting(Pos2),
+}
+
 /// HSV Color wheel picker
-pub struct ColorWheel {
+pub struct ColorWheel<'a> {
     size: f32,
     ring_width: f32,
     style: WheelStyle,
     show_alpha: bool,
     show_preview: bool,
     show_values: bool,
+    show_inputs: bool,
+    recents: &'a [Color32],
+    eyedropper: bool,
 }
 
-impl ColorWheel {
+impl<'a> ColorWheel<'a> {
+    /// Maximum number of colors kept by [`Self::push_recent`] and shown in the strip
+    pub const MAX_RECENTS: usize = 8;
+
     pub fn new() -> Self {
         Self {
             size: 200.0,
@@ -153,6 +207,9 @@ impl ColorWheel {
             show_alpha: false,
             show_preview: true,
             show_values: true,
+            show_inputs: false,
+            recents: &[],
+            eyedropper: false,
         }
     }
 
@@ -186,6 +243,31 @@ impl ColorWheel {
         self
     }
 
+    /// Show editable hex (`#RRGGBB[AA]`) and H/S/V fields below the wheel
+    pub fn with_inputs(mut self, show: bool) -> Self {
+        self.show_inputs = show;
+        self
+    }
+
+    /// Show a clickable strip of recent colors (at most [`Self::MAX_RECENTS`])
+    pub fn with_recents(mut self, recents: &'a [Color32]) -> Self {
+        self.recents = recents;
+        self
+    }
+
+    /// Show an eyedropper button that samples the screen pixel under the next click
+    pub fn with_eyedropper(mut self, show: bool) -> Self {
+        self.eyedropper = show;
+        self
+    }
+
+    /// Move `color` to the front of `recents`, keeping at most [`Self::MAX_RECENTS`]
+    pub fn push_recent(recents: &mut Vec<Color32>, color: Color32) {
+        recents.retain(|c| *c != color);
+        recents.insert(0, color);
+        recents.truncate(Self::MAX_RECENTS);
+    }
+
     pub fn show_with<Msg>(
         self,
         ctx: &mut ViewCtx<'_, Msg>,
@@ -232,8 +314,23 @@ impl ColorWheel {
         } else {
             0.0
         };
-        let total_height =
-            wheel_size + alpha_height + preview_height + values_height + theme.spacing_sm * 3.0;
+        let inputs_height = if self.show_inputs {
+            ui.spacing().interact_size.y * 2.0 + theme.spacing_xs + theme.spacing_sm
+        } else {
+            0.0
+        };
+        let recents_height = if !self.recents.is_empty() || self.eyedropper {
+            theme.spacing_lg + theme.spacing_sm
+        } else {
+            0.0
+        };
+        let total_height = wheel_size
+            + alpha_height
+            + preview_height
+            + values_height
+            + inputs_height
+            + recents_height
+            + theme.spacing_sm * 3.0;
 
         let (rect, mut response) =
             ui.allocate_exact_size(Vec2::new(wheel_size, total_height), Sense::hover());
@@ -646,16 +743,304 @@ impl ColorWheel {
             );
         }
 
+        // Editable fields
+        let inputs_bottom = rect.max.y - recents_height;
+        if self.show_inputs {
+            let inputs_rect = Rect::from_min_size(
+                Pos2::new(rect.min.x, inputs_bottom - inputs_height + theme.spacing_sm),
+                Vec2::new(wheel_size, inputs_height - theme.spacing_sm),
+            );
+            if self.show_input_fields(ui, inputs_rect, response.id, hsva) {
+                response.mark_changed();
+            }
+        }
+
+        // Eyedropper and recent colors
+        if recents_height > 0.0 {
+            let row_rect = Rect::from_min_size(
+                Pos2::new(rect.min.x, inputs_bottom + theme.spacing_sm),
+                Vec2::new(wheel_size, theme.spacing_lg),
+            );
+            if self.show_recents_row(ui, row_rect, response.id, hsva) {
+                response.mark_changed();
+            }
+        }
+
         response
     }
+
+    /// Eyedropper button followed by recent swatches. Returns true when `hsva` was set.
+    fn show_recents_row(&self, ui: &mut Ui, rect: Rect, id: Id, hsva: &mut Hsva) -> bool {
+        let theme = Theme::current(ui.ctx());
+        let size = rect.height();
+        let mut x = rect.min.x;
+        let mut picked = None;
+
+        if self.eyedropper {
+            let button_rect = Rect::from_min_size(rect.min, Vec2::splat(size));
+            x += size + theme.spacing_xs;
+            let pick_id = id.with("eyedropper");
+            let (state, sampled) = self.eyedropper(ui, button_rect, pick_id);
+            picked = sampled;
+            if let Some(state) = state {
+                ui.ctx().data_mut(|d| d.insert_temp(pick_id, state));
+            }
+        }
+
+        let mut swatches = Vec::new();
+        for &color in self.recents.iter().take(Self::MAX_RECENTS) {
+            let swatch_rect = Rect::from_min_size(Pos2::new(x, rect.min.y), Vec2::splat(size));
+            if swatch_rect.max.x > rect.max.x {
+                break;
+            }
+            x += size + theme.spacing_xs;
+            let swatch = ui
+                .allocate_rect(swatch_rect, Sense::click())
+                .on_hover_cursor(egui::CursorIcon::PointingHand);
+            if swatch.clicked() {
+                picked = Some(Hsva::from_color32(color));
+            }
+            swatches.push((swatch_rect, color, swatch.hovered()));
+        }
+
+        let current = hsva.to_color32();
+        let painter = ui.painter();
+        for (swatch_rect, color, hovered) in swatches {
+            painter.rect_filled(swatch_rect, theme.radius_sm, color);
+            let border = if hovered || color == current {
+                theme.border_focus
+            } else {
+                theme.border
+            };
+            painter.rect_stroke(
+                swatch_rect,
+                theme.radius_sm,
+                Stroke::new(theme.border_width, border),
+                egui::StrokeKind::Inside,
+            );
+        }
+
+        match picked {
+            Some(color) if color != *hsva => {
+                *hsva = color;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Eyedropper button and pick flow: arm on click, request a screenshot on the
+    /// next click anywhere, sample it when the reply arrives. Returns the new state
+    /// (if it changed) and the sampled color.
+    fn eyedropper(
+        &self,
+        ui: &mut Ui,
+        button_rect: Rect,
+        pick_id: Id,
+    ) -> (Option<PickState>, Option<Hsva>) {
+        let theme = Theme::current(ui.ctx());
+        let state = ui
+            .ctx()
+            .data(|d| d.get_temp::<PickState>(pick_id))
+            .unwrap_or_default();
+        let button = ui
+            .allocate_rect(button_rect, Sense::click())
+            .on_hover_text("Pick a color from the screen");
+
+        let mut next = None;
+        let mut sampled = None;
+        match state {
+            _ if button.clicked() => {
+                next = Some(if state == PickState::Armed {
+                    PickState::Idle
+                } else {
+                    PickState::Armed
+                });
+            }
+            PickState::Armed => {
+                ui.ctx().set_cursor_icon(egui::CursorIcon::Crosshair);
+                let (clicked, escape, pos) = ui.input(|i| {
+                    (
+                        i.pointer.primary_clicked(),
+                        i.key_pressed(egui::Key::Escape),
+                        i.pointer.interact_pos(),
+                    )
+                });
+                if escape {
+                    next = Some(PickState::Idle);
+                } else if let (true, Some(pos)) = (clicked, pos) {
+                    ui.ctx()
+                        .send_viewport_cmd(egui::ViewportCommand::Screenshot(egui::UserData::new(
+                            pick_id,
+                        )));
+                    next = Some(PickState::Waiting(pos));
+                }
+            }
+            PickState::Waiting(pos) => {
+                let pixel = ui.input(|i| {
+                    i.raw.events.iter().find_map(|event| match event {
+                        egui::Event::Screenshot {
+                            user_data, image, ..
+                        } if user_data
+                            .data
+                            .as_ref()
+                            .and_then(|data| data.downcast_ref::<Id>())
+                            == Some(&pick_id) =>
+                        {
+                            let [w, h] = image.size;
+                            let px = pos * i.pixels_per_point;
+                            (w > 0 && h > 0).then(|| {
+                                image[(
+                                    (px.x.max(0.0) as usize).min(w - 1),
+                                    (px.y.max(0.0) as usize).min(h - 1),
+                                )]
+                            })
+                        }
+                        _ => None,
+                    })
+                });
+                if let Some(pixel) = pixel {
+                    let [r, g, b, _] = pixel.to_srgba_unmultiplied();
+                    sampled = Some(Hsva::from_color32(Color32::from_rgb(r, g, b)));
+                    next = Some(PickState::Idle);
+                }
+            }
+            PickState::Idle => {}
+        }
+
+        let armed = next.unwrap_or(state) != PickState::Idle;
+        let painter = ui.painter();
+        let bg = if armed {
+            theme.primary
+        } else if button.hovered() {
+            theme.bg_tertiary
+        } else {
+            theme.bg_secondary
+        };
+        painter.rect_filled(button_rect, theme.radius_sm, bg);
+        painter.text(
+            button_rect.center(),
+            egui::Align2::CENTER_CENTER,
+            icons::EYEDROPPER,
+            egui::FontId::new(
+                button_rect.height() * 0.75,
+                egui::FontFamily::Name("icons".into()),
+            ),
+            if armed {
+                theme.primary_text
+            } else {
+                theme.text_secondary
+            },
+        );
+
+        (next, sampled)
+    }
+
+    /// Hex row and H/S/V row. Returns true when `hsva` was edited.
+    fn show_input_fields(&self, ui: &mut Ui, rect: Rect, id: egui::Id, hsva: &mut Hsva) -> bool {
+        let theme = Theme::current(ui.ctx());
+        let hex_id = id.with("hex_input");
+        let mut changed = false;
+
+        ui.scope_builder(egui::UiBuilder::new().max_rect(rect), |ui| {
+            ui.spacing_mut().item_spacing = Vec2::new(theme.spacing_xs, theme.spacing_xs);
+            let label = |ui: &mut Ui, text: &str| {
+                ui.label(
+                    egui::RichText::new(text)
+                        .size(theme.font_size_xs)
+                        .color(theme.text_muted),
+                );
+            };
+
+            // Hex: edits go to a buffer and are applied when focus leaves
+            ui.horizontal(|ui| {
+                label(ui, "Hex");
+                let mut text = ui
+                    .ctx()
+                    .data(|d| d.get_temp::<String>(hex_id))
+                    .unwrap_or_else(|| hsva.to_hex(self.show_alpha));
+                let output = egui::TextEdit::singleline(&mut text)
+                    .id(hex_id)
+                    .font(egui::FontId::monospace(theme.font_size_sm))
+                    .desired_width(f32::INFINITY)
+                    .show(ui);
+
+                if output.response.gained_focus() {
+                    // Select everything so a pasted value replaces it
+                    let mut state = output.state;
+                    state
+                        .cursor
+                        .set_char_range(Some(egui::text::CCursorRange::two(
+                            egui::text::CCursor::new(0),
+                            egui::text::CCursor::new(text.chars().count()),
+                        )));
+                    state.store(ui.ctx(), hex_id);
+                }
+
+                if output.response.lost_focus() {
+                    ui.ctx().data_mut(|d| d.remove_temp::<String>(hex_id));
+                    if let Some((r, g, b, a)) = parse_hex(&text) {
+                        let alpha = a.map_or(hsva.a, |a| a as f32 / 255.0);
+                        let parsed =
+                            Hsva::from_color32(Color32::from_rgb(r, g, b)).with_alpha(alpha);
+                        if parsed != *hsva {
+                            *hsva = parsed;
+                            changed = true;
+                        }
+                    }
+                } else if output.response.has_focus() {
+                    ui.ctx().data_mut(|d| d.insert_temp(hex_id, text));
+                }
+            });
+
+            ui.horizontal(|ui| {
+                let mut field = |ui: &mut Ui, name: &str, value: &mut f32, max: f32, suffix| {
+                    label(ui, name);
+                    let mut scaled = *value * max;
+                    let edited = ui
+                        .add(
+                            egui::DragValue::new(&mut scaled)
+                                .range(0.0..=max)
+                                .max_decimals(0)
+                                .suffix(suffix),
+                        )
+                        .changed();
+                    if edited {
+                        *value = (scaled / max).clamp(0.0, 1.0);
+                        changed = true;
+                    }
+                };
+                field(ui, "H", &mut hsva.h, 360.0, "°");
+                field(ui, "S", &mut hsva.s, 100.0, "%");
+                field(ui, "V", &mut hsva.v, 100.0, "%");
+            });
+        });
+
+        changed
+    }
 }
 
-impl Default for ColorWheel {
+impl Default for ColorWheel<'_> {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Parse `#RRGGBB` or `#RRGGBBAA` into channels, alpha only when given
+fn parse_hex(text: &str) -> Option<(u8, u8, u8, Option<u8>)> {
+    let digits = text.trim().trim_start_matches('#');
+    if !matches!(digits.len(), 6 | 8) || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).ok();
+    let alpha = if digits.len() == 8 {
+        Some(channel(6)?)
+    } else {
+        None
+    };
+    Some((channel(0)?, channel(2)?, channel(4)?, alpha))
+}
+
 fn point_to_sv_triangle(p: Pos2, v0: Pos2, v1: Pos2, v2: Pos2) -> Option<(f32, f32)> {
     let v0v1 = v1 - v0;
     let v0v2 = v2 - v0;
@@ -696,3 +1081,187 @@ fn sv_to_triangle_point(s: f32, v: f32, v0: Pos2, v1: Pos2, v2: Pos2) -> Pos2 {
         t * v0.y + u * v1.y + w * v2.y,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, run_ui, run_ui_with_events, test_context};
+    use egui::{Event, Key, Modifiers};
+
+    fn key(key: Key) -> Event {
+        Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    /// Click the hex field, type `text` and press Enter
+    fn type_hex(start: Hsva, text: &str) -> Hsva {
+        let ctx = test_context();
+        let mut hsva = start;
+        let mut rect = Rect::NOTHING;
+        run_ui(&ctx, |ui| {
+            rect = ColorWheel::new()
+                .with_inputs(true)
+                .show_hsva(ui, &mut hsva)
+                .rect;
+        });
+
+        let row = ctx.style().spacing.interact_size.y;
+        let hex_field = Pos2::new(rect.center().x, rect.max.y - row * 1.5);
+        let mut show = |ui: &mut Ui| {
+            ColorWheel::new().with_inputs(true).show_hsva(ui, &mut hsva);
+        };
+        click_at(&ctx, hex_field, &mut show);
+        run_ui_with_events(
+            &ctx,
+            vec![Event::Text(text.to_string()), key(Key::Enter)],
+            &mut show,
+        );
+        run_ui(&ctx, &mut show);
+        hsva
+    }
+
+    fn recents_row(rect: Rect) -> f32 {
+        let theme = Theme::default();
+        rect.max.y - theme.spacing_lg / 2.0
+    }
+
+    #[test]
+    fn test_recent_swatch_emits_color() {
+        let recents = [
+            Color32::from_rgb(10, 20, 30),
+            Color32::from_rgb(200, 100, 50),
+            Color32::from_rgb(0, 128, 255),
+        ];
+        let ctx = test_context();
+        let mut color = Color32::WHITE;
+        let mut rect = Rect::NOTHING;
+        run_ui(&ctx, |ui| {
+            rect = ColorWheel::new()
+                .with_recents(&recents)
+                .show(ui, &mut color)
+                .rect;
+        });
+        let size = Theme::default().spacing_lg;
+        let second = Pos2::new(
+            rect.min.x + size * 1.5 + Theme::default().spacing_xs,
+            recents_row(rect),
+        );
+        click_at(&ctx, second, |ui| {
+            ColorWheel::new()
+                .with_recents(&recents)
+                .show(ui, &mut color);
+        });
+        assert_eq!(color, recents[1]);
+    }
+
+    #[test]
+    fn test_recents_are_bounded() {
+        let mut recents = Vec::new();
+        for i in 0..12 {
+            ColorWheel::push_recent(&mut recents, Color32::from_gray(i));
+        }
+        assert_eq!(recents.len(), ColorWheel::MAX_RECENTS);
+        assert_eq!(recents[0], Color32::from_gray(11));
+
+        // Re-picking a color moves it to the front without duplicating
+        ColorWheel::push_recent(&mut recents, Color32::from_gray(8));
+        assert_eq!(recents.len(), ColorWheel::MAX_RECENTS);
+        assert_eq!(recents[0], Color32::from_gray(8));
+        assert_eq!(
+            recents
+                .iter()
+                .filter(|c| **c == Color32::from_gray(8))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_eyedropper_samples_screenshot() {
+        let ctx = test_context();
+        let mut hsva = Hsva::default();
+        let mut rect = Rect::NOTHING;
+        run_ui(&ctx, |ui| {
+            rect = ColorWheel::new()
+                .with_eyedropper(true)
+                .show_hsva(ui, &mut hsva)
+                .rect;
+        });
+        let mut show = |ui: &mut Ui| {
+            ColorWheel::new()
+                .with_eyedropper(true)
+                .show_hsva(ui, &mut hsva);
+        };
+        let button = Pos2::new(
+            rect.min.x + Theme::default().spacing_lg / 2.0,
+            recents_row(rect),
+        );
+        click_at(&ctx, button, &mut show);
+
+        // The next click anywhere requests a screenshot tagged for this wheel
+        let target = Pos2::new(5.0, 5.0);
+        let press = |pressed| Event::PointerButton {
+            pos: target,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+        let mut user_data = None;
+        for events in [
+            vec![Event::PointerMoved(target)],
+            vec![press(true)],
+            vec![press(false)],
+        ] {
+            let output = run_ui_with_events(&ctx, events, &mut show);
+            for (_, viewport) in output.viewport_output {
+                for command in viewport.commands {
+                    if let egui::ViewportCommand::Screenshot(data) = command {
+                        user_data = Some(data);
+                    }
+                }
+            }
+        }
+        let user_data = user_data.expect("screenshot requested");
+
+        let green = Color32::from_rgb(0, 255, 0);
+        let image = egui::ColorImage::new([16, 16], vec![green; 256]);
+        run_ui_with_events(
+            &ctx,
+            vec![Event::Screenshot {
+                viewport_id: egui::ViewportId::ROOT,
+                user_data,
+                image: std::sync::Arc::new(image),
+            }],
+            &mut show,
+        );
+        assert_eq!(hsva.to_color32(), green);
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let hsva = Hsva::from_hex("#3366CC80").unwrap();
+        assert_eq!(hsva.to_hex(true), "#3366CC80");
+        assert_eq!(hsva.to_hex(false), "#3366CC");
+        assert!(Hsva::from_hex("#12345").is_none());
+        assert!(Hsva::from_hex("#GG0000").is_none());
+    }
+
+    #[test]
+    fn test_typing_hex_sets_color() {
+        let hsva = type_hex(Hsva::new(0.6, 0.5, 0.5), "#FF0000");
+        assert_eq!(hsva.h, 0.0);
+        assert_eq!(hsva.s, 1.0);
+        assert_eq!(hsva.v, 1.0);
+    }
+
+    #[test]
+    fn test_invalid_hex_leaves_color() {
+        let start = Hsva::new(0.6, 0.5, 0.5);
+        assert_eq!(type_hex(start, "#XYZ"), start);
+    }
+}
@@ -7,8 +7,10 @@
 //! - Add/remove color stops
 //! - Drag stops to reposition
 //! - Double-click to edit stop color
+//! - Right-click a stop for its interpolation (Linear/Smooth/Step) or removal
 //! - Gradient preview
 //! - Linear/Radial mode indicators
+//! - CSS `linear-gradient(...)` import/export and JSON export
 //! - Theme-aware styling
 //!
 //! # Example
@@ -26,11 +28,56 @@ use crate::Theme;
 use egui::{Color32, Pos2, Rect, Sense, Stroke, Ui, Vec2};
 use egui_cha::ViewCtx;
 
+/// How colors blend from one stop to the next
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interp {
+    /// Straight blend
+    #[default]
+    Linear,
+    /// Eased blend (smoothstep), flat at both stops
+    Smooth,
+    /// Hold the stop's color until the next stop
+    Step,
+}
+
+impl Interp {
+    /// Map a linear blend factor (`0.0..=1.0`) through this interpolation
+    pub fn ease(&self, t: f32) -> f32 {
+        match self {
+            Interp::Linear => t,
+            Interp::Smooth => t * t * (3.0 - 2.0 * t),
+            Interp::Step => {
+                if t >= 1.0 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+
+    /// Display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Interp::Linear => "Linear",
+            Interp::Smooth => "Smooth",
+            Interp::Step => "Step",
+        }
+    }
+
+    /// Get all interpolation modes
+    pub fn all() -> &'static [Interp] {
+        &[Interp::Linear, Interp::Smooth, Interp::Step]
+    }
+}
+
 /// A color stop in the gradient
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct GradientStop {
     pub position: f32,
     pub color: Color32,
+    /// Interpolation from this stop to the next one
+    pub interp: Interp,
 }
 
 impl GradientStop {
@@ -38,8 +85,14 @@ impl GradientStop {
         Self {
             position: position.clamp(0.0, 1.0),
             color,
+            interp: Interp::Linear,
         }
     }
+
+    pub fn with_interp(mut self, interp: Interp) -> Self {
+        self.interp = interp;
+        self
+    }
 }
 
 /// Gradient data
@@ -89,7 +142,7 @@ impl Gradient {
             return left.color;
         }
 
-        let factor = (t - left.position) / range;
+        let factor = left.interp.ease((t - left.position) / range);
         Color32::from_rgba_unmultiplied(
             lerp_u8(left.color.r(), right.color.r(), factor),
             lerp_u8(left.color.g(), right.color.g(), factor),
@@ -117,6 +170,12 @@ impl Gradient {
         }
     }
 
+    pub fn set_stop_interp(&mut self, index: usize, interp: Interp) {
+        if let Some(stop) = self.stops.get_mut(index) {
+            stop.interp = interp;
+        }
+    }
+
     pub fn move_stop(&mut self, index: usize, new_position: f32) {
         if let Some(stop) = self.stops.get_mut(index) {
             stop.position = new_position.clamp(0.0, 1.0);
@@ -126,6 +185,274 @@ impl Gradient {
     }
 }
 
+// ============================================================
+// CSS / JSON interchange
+// ============================================================
+
+/// Error parsing a CSS gradient
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseGradientError {
+    /// What was wrong with the input
+    pub reason: String,
+}
+
+impl ParseGradientError {
+    fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ParseGradientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid CSS gradient: {}", self.reason)
+    }
+}
+
+impl std::error::Error for ParseGradientError {}
+
+impl Gradient {
+    /// Parse a CSS `linear-gradient(...)`
+    ///
+    /// The angle (or `to right` style direction) may be omitted and is
+    /// ignored. Colors can be hex (`#f00`, `#ff0000`, `#ff000080`),
+    /// `rgb()`/`rgba()` or basic named colors. Stops without a percentage
+    /// are spread evenly between their neighbours, as in CSS.
+    ///
+    /// ```ignore
+    /// let g = Gradient::from_css("linear-gradient(90deg, #f00 0%, #0f0 50%, #00f 100%)")?;
+    /// ```
+    pub fn from_css(css: &str) -> Result<Self, ParseGradientError> {
+        let inner = css
+            .trim()
+            .trim_end_matches(';')
+            .strip_prefix("linear-gradient(")
+            .and_then(|s| s.strip_suffix(')'))
+            .ok_or_else(|| ParseGradientError::new("expected linear-gradient(...)"))?;
+
+        let mut args = split_top_level(inner);
+        if args.first().is_some_and(|first| is_direction(first)) {
+            args.remove(0);
+        }
+        if args.len() < 2 {
+            return Err(ParseGradientError::new("need at least two color stops"));
+        }
+
+        let mut colors = Vec::with_capacity(args.len());
+        let mut positions: Vec<Option<f32>> = Vec::with_capacity(args.len());
+        for arg in &args {
+            let (color, position) = match arg.rsplit_once(char::is_whitespace) {
+                Some((color, pos)) if pos.ends_with('%') => {
+                    let pos: f32 = pos
+                        .trim_end_matches('%')
+                        .parse()
+                        .map_err(|_| ParseGradientError::new(format!("bad position in `{arg}`")))?;
+                    (color.trim(), Some(pos / 100.0))
+                }
+                _ => (arg.as_str(), None),
+            };
+            colors.push(
+                parse_css_color(color)
+                    .ok_or_else(|| ParseGradientError::new(format!("bad color `{color}`")))?,
+            );
+            positions.push(position);
+        }
+
+        let positions = distribute_positions(&positions);
+        Ok(Self::from_stops(
+            colors
+                .into_iter()
+                .zip(positions)
+                .map(|(color, position)| GradientStop::new(position, color))
+                .collect(),
+        ))
+    }
+
+    /// Format as a CSS `linear-gradient(90deg, ...)`
+    ///
+    /// CSS only blends linearly: `Step` segments become hard stops and
+    /// `Smooth` segments are written as linear.
+    pub fn to_css(&self) -> String {
+        let mut parts = vec!["90deg".to_string()];
+        for (i, stop) in self.stops.iter().enumerate() {
+            parts.push(format!(
+                "{} {}%",
+                color_to_hex(stop.color),
+                percent(stop.position)
+            ));
+            if stop.interp == Interp::Step {
+                if let Some(next) = self.stops.get(i + 1) {
+                    parts.push(format!(
+                        "{} {}%",
+                        color_to_hex(stop.color),
+                        percent(next.position)
+                    ));
+                }
+            }
+        }
+        format!("linear-gradient({})", parts.join(", "))
+    }
+
+    /// Format as JSON, keeping each stop's interpolation
+    ///
+    /// ```json
+    /// {"stops":[{"position":0,"color":"#000000","interp":"linear"},{"position":1,"color":"#FFFFFF","interp":"linear"}]}
+    /// ```
+    pub fn to_json(&self) -> String {
+        let stops: Vec<String> = self
+            .stops
+            .iter()
+            .map(|stop| {
+                format!(
+                    r#"{{"position":{},"color":"{}","interp":"{}"}}"#,
+                    stop.position,
+                    color_to_hex(stop.color),
+                    stop.interp.name().to_lowercase()
+                )
+            })
+            .collect();
+        format!(r#"{{"stops":[{}]}}"#, stops.join(","))
+    }
+}
+
+/// Split on commas that are not inside parentheses
+fn split_top_level(s: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut current = String::new();
+    for c in s.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    parts.push(current.trim().to_string());
+    parts
+}
+
+/// Whether a gradient argument is an angle or `to <side>` direction
+fn is_direction(arg: &str) -> bool {
+    arg.starts_with("to ")
+        || ["deg", "rad", "grad", "turn"].iter().any(|unit| {
+            arg.strip_suffix(unit)
+                .is_some_and(|n| n.parse::<f32>().is_ok())
+        })
+}
+
+/// Fill missing stop positions like CSS does
+///
+/// The first and last stops default to 0 and 1, and runs of missing
+/// positions are spread evenly between the known positions around them.
+fn distribute_positions(positions: &[Option<f32>]) -> Vec<f32> {
+    let mut out: Vec<Option<f32>> = positions.to_vec();
+    let last = out.len() - 1;
+    out[0] = out[0].or(Some(0.0));
+    out[last] = out[last].or(Some(1.0));
+
+    let mut start = 0;
+    for i in 1..out.len() {
+        if let Some(end_pos) = out[i] {
+            let start_pos = out[start].unwrap_or(0.0);
+            let gap = i - start;
+            for (k, slot) in out[start + 1..i].iter_mut().enumerate() {
+                *slot = Some(start_pos + (end_pos - start_pos) * (k + 1) as f32 / gap as f32);
+            }
+            start = i;
+        }
+    }
+    out.into_iter().map(|p| p.unwrap_or(0.0)).collect()
+}
+
+/// Parse a CSS color: hex, `rgb()`/`rgba()` or a basic named color
+fn parse_css_color(s: &str) -> Option<Color32> {
+    let s = s.trim();
+    if let Some(hex) = s.strip_prefix('#') {
+        let digit = |i: usize| u8::from_str_radix(hex.get(i..i + 1)?, 16).ok();
+        let byte = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+        return match hex.len() {
+            3 => Some(Color32::from_rgb(
+                digit(0)? * 17,
+                digit(1)? * 17,
+                digit(2)? * 17,
+            )),
+            4 => Some(Color32::from_rgba_unmultiplied(
+                digit(0)? * 17,
+                digit(1)? * 17,
+                digit(2)? * 17,
+                digit(3)? * 17,
+            )),
+            6 => Some(Color32::from_rgb(byte(0)?, byte(2)?, byte(4)?)),
+            8 => Some(Color32::from_rgba_unmultiplied(
+                byte(0)?,
+                byte(2)?,
+                byte(4)?,
+                byte(6)?,
+            )),
+            _ => None,
+        };
+    }
+
+    if let Some(inner) = s
+        .strip_prefix("rgba(")
+        .or_else(|| s.strip_prefix("rgb("))
+        .and_then(|s| s.strip_suffix(')'))
+    {
+        let parts: Vec<&str> = inner.split(',').map(str::trim).collect();
+        let channel = |i: usize| parts.get(i)?.parse::<u8>().ok();
+        let alpha = match parts.get(3) {
+            None => 255,
+            Some(a) => (a.parse::<f32>().ok()?.clamp(0.0, 1.0) * 255.0).round() as u8,
+        };
+        if parts.len() > 4 {
+            return None;
+        }
+        return Some(Color32::from_rgba_unmultiplied(
+            channel(0)?,
+            channel(1)?,
+            channel(2)?,
+            alpha,
+        ));
+    }
+
+    Some(match s.to_ascii_lowercase().as_str() {
+        "black" => Color32::BLACK,
+        "white" => Color32::WHITE,
+        "red" => Color32::from_rgb(255, 0, 0),
+        "lime" => Color32::from_rgb(0, 255, 0),
+        "green" => Color32::from_rgb(0, 128, 0),
+        "blue" => Color32::from_rgb(0, 0, 255),
+        "yellow" => Color32::from_rgb(255, 255, 0),
+        "cyan" | "aqua" => Color32::from_rgb(0, 255, 255),
+        "magenta" | "fuchsia" => Color32::from_rgb(255, 0, 255),
+        "gray" | "grey" => Color32::from_rgb(128, 128, 128),
+        "transparent" => Color32::TRANSPARENT,
+        _ => return None,
+    })
+}
+
+/// `#RRGGBB`, or `#RRGGBBAA` when not opaque
+fn color_to_hex(color: Color32) -> String {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    if a == 255 {
+        format!("#{:02X}{:02X}{:02X}", r, g, b)
+    } else {
+        format!("#{:02X}{:02X}{:02X}{:02X}", r, g, b, a)
+    }
+}
+
+/// Position as a percentage with at most two decimals
+fn percent(position: f32) -> f32 {
+    (position * 10000.0).round() / 100.0
+}
+
 impl Default for Gradient {
     fn default() -> Self {
         Self::new()
@@ -140,10 +467,18 @@ fn lerp_u8(a: u8, b: u8, t: f32) -> u8 {
 #[derive(Debug, Clone)]
 pub enum GradientEvent {
     AddStop(f32),
-    MoveStop { index: usize, position: f32 },
+    MoveStop {
+        index: usize,
+        position: f32,
+    },
     RemoveStop(usize),
-    SetStopColor { index: usize, color: Color32 },
+    SetStopColor {
+        index: usize,
+        color: Color32,
+    },
     SelectStop(Option<usize>),
+    /// Interpolation after stop `i` changed (from its right-click menu)
+    StopInterpChange(usize, Interp),
 }
 
 /// Gradient direction
@@ -266,7 +601,7 @@ impl<'a> GradientEditor<'a> {
             dragged: bool,
             drag_pos: Option<Pos2>,
             clicked: bool,
-            secondary_clicked: bool,
+            response: Option<egui::Response>,
         }
 
         let stop_y = bar_rect.max.y + theme.spacing_xs;
@@ -291,7 +626,7 @@ impl<'a> GradientEditor<'a> {
                     dragged: resp.dragged(),
                     drag_pos: resp.interact_pointer_pos(),
                     clicked: resp.clicked(),
-                    secondary_clicked: resp.secondary_clicked(),
+                    response: Some(resp),
                 });
             } else {
                 stop_infos.push(StopInfo {
@@ -301,7 +636,7 @@ impl<'a> GradientEditor<'a> {
                     dragged: false,
                     drag_pos: None,
                     clicked: false,
-                    secondary_clicked: false,
+                    response: None,
                 });
             }
         }
@@ -432,7 +767,10 @@ impl<'a> GradientEditor<'a> {
 
             // Position value on hover
             if self.show_stop_values && is_hovered {
-                let value_text = format!("{:.0}%", stop.position * 100.0);
+                let value_text = match stop.interp {
+                    Interp::Linear => format!("{:.0}%", stop.position * 100.0),
+                    interp => format!("{:.0}% {}", stop.position * 100.0, interp.name()),
+                };
                 painter.text(
                     Pos2::new(info.stop_x, swatch_rect.max.y + theme.spacing_xs),
                     egui::Align2::CENTER_TOP,
@@ -490,11 +828,116 @@ impl<'a> GradientEditor<'a> {
                         position: new_pos.clamp(0.0, 1.0),
                     });
                 }
-            } else if info.secondary_clicked && self.gradient.stops.len() > 2 {
-                event = Some(GradientEvent::RemoveStop(info.idx));
             }
         }
 
+        // Right-click menu: interpolation and removal
+        let can_remove = self.gradient.stops.len() > 2;
+        for (info, stop) in stop_infos.iter().zip(self.gradient.stops.iter()) {
+            let Some(response) = &info.response else {
+                continue;
+            };
+            response.context_menu(|ui| {
+                for &interp in Interp::all() {
+                    if ui.radio(stop.interp == interp, interp.name()).clicked() {
+                        event = Some(GradientEvent::StopInterpChange(info.idx, interp));
+                        ui.close();
+                    }
+                }
+                if can_remove {
+                    ui.separator();
+                    let remove = egui::RichText::new("Remove").color(theme.state_danger);
+                    if ui.button(remove).clicked() {
+                        event = Some(GradientEvent::RemoveStop(info.idx));
+                        ui.close();
+                    }
+                }
+            });
+        }
+
         event
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_holds_lower_stop_color() {
+        let gradient = Gradient::from_stops(vec![
+            GradientStop::new(0.0, Color32::RED).with_interp(Interp::Step),
+            GradientStop::new(1.0, Color32::BLUE),
+        ]);
+        assert_eq!(gradient.sample(0.5), Color32::RED);
+        assert_eq!(gradient.sample(0.99), Color32::RED);
+        assert_eq!(gradient.sample(1.0), Color32::BLUE);
+    }
+
+    #[test]
+    fn test_css_round_trip() {
+        let gradient = Gradient::from_stops(vec![
+            GradientStop::new(0.0, Color32::from_rgb(255, 0, 0)),
+            GradientStop::new(0.5, Color32::from_rgb(0, 255, 0)),
+            GradientStop::new(1.0, Color32::from_rgb(0, 0, 255)),
+        ]);
+        let css = gradient.to_css();
+        assert_eq!(
+            css,
+            "linear-gradient(90deg, #FF0000 0%, #00FF00 50%, #0000FF 100%)"
+        );
+        assert_eq!(Gradient::from_css(&css), Ok(gradient));
+
+        let short = Gradient::from_css("linear-gradient(90deg, #f00 0%, #0f0 50%, #00f 100%)");
+        assert_eq!(short, Gradient::from_css(&css));
+    }
+
+    #[test]
+    fn test_css_distributes_missing_positions() {
+        let gradient = Gradient::from_css("linear-gradient(red, rgb(0, 255, 0), #00f)").unwrap();
+        let positions: Vec<f32> = gradient.stops.iter().map(|s| s.position).collect();
+        assert_eq!(positions, vec![0.0, 0.5, 1.0]);
+        assert_eq!(gradient.stops[1].color, Color32::from_rgb(0, 255, 0));
+
+        let gradient =
+            Gradient::from_css("linear-gradient(to right, black 20%, gray, white 80%, black)")
+                .unwrap();
+        let positions: Vec<f32> = gradient.stops.iter().map(|s| s.position).collect();
+        assert_eq!(positions, vec![0.2, 0.5, 0.8, 1.0]);
+
+        assert!(Gradient::from_css("radial-gradient(red, blue)").is_err());
+        assert!(Gradient::from_css("linear-gradient(red)").is_err());
+        assert!(Gradient::from_css("linear-gradient(red, nope)").is_err());
+    }
+
+    #[test]
+    fn test_to_json() {
+        let gradient = Gradient::from_stops(vec![
+            GradientStop::new(0.0, Color32::BLACK).with_interp(Interp::Step),
+            GradientStop::new(1.0, Color32::WHITE),
+        ]);
+        assert_eq!(
+            gradient.to_json(),
+            r##"{"stops":[{"position":0,"color":"#000000","interp":"step"},{"position":1,"color":"#FFFFFF","interp":"linear"}]}"##
+        );
+    }
+
+    #[test]
+    fn test_interp_per_segment() {
+        let mut gradient = Gradient::from_stops(vec![
+            GradientStop::new(0.0, Color32::BLACK),
+            GradientStop::new(0.5, Color32::WHITE),
+            GradientStop::new(1.0, Color32::BLACK),
+        ]);
+        let linear = gradient.sample(0.125);
+        assert_eq!(linear, Color32::from_gray(63));
+
+        // Smooth eases out of the stop, so a quarter of the way is darker
+        gradient.set_stop_interp(0, Interp::Smooth);
+        assert!(gradient.sample(0.125).r() < linear.r());
+        assert_eq!(gradient.sample(0.25), Color32::from_gray(127));
+
+        // The second segment is unaffected
+        assert_eq!(gradient.sample(0.75), Color32::from_gray(127));
+    }
+}
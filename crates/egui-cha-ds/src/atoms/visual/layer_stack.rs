@@ -9,6 +9,8 @@
 //! - Blend mode selection
 //! - Drag-to-reorder layers
 //! - Solo/Lock functionality
+//! - Collapsible layer groups (folders)
+//! - Composite preview swatch
 //! - Theme-aware styling
 //!
 //! # Example
@@ -23,20 +25,27 @@
 //!         LayerEvent::SetBlendMode(idx, mode) => Msg::SetBlendMode(idx, mode),
 //!     });
 //! ```
+//!
+//! # Groups
+//! Layers are a flat list, top layer first. A group is a folder row
+//! followed by its children, which sit one level deeper:
+//!
+//! ```ignore
+//! let mut layers = vec![Layer::new("Title")];
+//! layers.extend(Layer::group("FX", [Layer::new("Blur"), Layer::new("Glow")]));
+//! layers.push(Layer::new("Background"));
+//! ```
+//!
+//! A hidden group hides its children and a group's opacity scales theirs
+//! (see [`effective_visible`] / [`effective_opacity`]). Apply
+//! `LayerEvent::Reorder` with [`reorder_layers`] so a group moves together
+//! with its children.
 
 use crate::Theme;
 use egui::{Color32, Id, Pos2, Rect, Sense, Stroke, Ui, Vec2};
+use egui_cha::drag_drop::ReorderDrag;
 use egui_cha::ViewCtx;
 
-/// Drag state for reordering layers
-#[derive(Clone, Debug, Default)]
-struct LayerDragState {
-    /// Index of layer being dragged
-    dragging: Option<usize>,
-    /// Current drop target index (where to insert)
-    drop_target: Option<usize>,
-}
-
 /// Blend modes for layer compositing
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum BlendMode {
@@ -68,6 +77,41 @@ impl BlendMode {
         }
     }
 
+    /// Blend one channel of `source` onto `backdrop` (both in `0.0..=1.0`)
+    pub fn blend_channel(&self, backdrop: f32, source: f32) -> f32 {
+        let (b, s) = (backdrop, source);
+        let result = match self {
+            BlendMode::Normal => s,
+            BlendMode::Add => b + s,
+            BlendMode::Multiply => b * s,
+            BlendMode::Screen => b + s - b * s,
+            BlendMode::Overlay => {
+                if b <= 0.5 {
+                    2.0 * b * s
+                } else {
+                    1.0 - 2.0 * (1.0 - b) * (1.0 - s)
+                }
+            }
+            BlendMode::Difference => (b - s).abs(),
+            BlendMode::Exclusion => b + s - 2.0 * b * s,
+            BlendMode::ColorDodge => {
+                if s >= 1.0 {
+                    1.0
+                } else {
+                    b / (1.0 - s)
+                }
+            }
+            BlendMode::ColorBurn => {
+                if s <= 0.0 {
+                    0.0
+                } else {
+                    1.0 - (1.0 - b) / s
+                }
+            }
+        };
+        result.clamp(0.0, 1.0)
+    }
+
     /// Get all blend modes
     pub fn all() -> &'static [BlendMode] {
         &[
@@ -95,6 +139,12 @@ pub struct Layer {
     pub blend_mode: BlendMode,
     pub color: Option<Color32>,
     pub thumbnail: Option<egui::TextureId>,
+    /// Nesting level (0 = top level, children of a group are one deeper)
+    pub depth: usize,
+    /// Whether this layer is a group folder
+    pub is_group: bool,
+    /// Whether a group's children are hidden in the list
+    pub collapsed: bool,
 }
 
 impl Layer {
@@ -108,9 +158,33 @@ impl Layer {
             blend_mode: BlendMode::Normal,
             color: None,
             thumbnail: None,
+            depth: 0,
+            is_group: false,
+            collapsed: false,
         }
     }
 
+    /// Create a group folder followed by its children
+    ///
+    /// Returns the flattened rows: the folder, then each child one level
+    /// deeper. Nested groups work by passing another group's rows as children.
+    pub fn group(name: impl Into<String>, children: impl IntoIterator<Item = Layer>) -> Vec<Layer> {
+        let mut folder = Layer::new(name);
+        folder.is_group = true;
+        let mut rows = vec![folder];
+        rows.extend(children.into_iter().map(|mut child| {
+            child.depth += 1;
+            child
+        }));
+        rows
+    }
+
+    /// Start a group collapsed
+    pub fn with_collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
     pub fn with_opacity(mut self, opacity: f32) -> Self {
         self.opacity = opacity.clamp(0.0, 1.0);
         self
@@ -132,6 +206,101 @@ impl Layer {
     }
 }
 
+/// Indices of the groups containing layer `idx`, innermost first
+fn ancestors(layers: &[Layer], idx: usize) -> impl Iterator<Item = usize> + '_ {
+    let mut depth = layers.get(idx).map_or(0, |l| l.depth);
+    (0..idx).rev().filter(move |&i| {
+        if layers[i].depth < depth {
+            depth = layers[i].depth;
+            true
+        } else {
+            false
+        }
+    })
+}
+
+/// Index just past the last child of layer `idx` (`idx + 1` for a plain layer)
+fn block_end(layers: &[Layer], idx: usize) -> usize {
+    let depth = layers[idx].depth;
+    layers[idx + 1..]
+        .iter()
+        .position(|l| l.depth <= depth)
+        .map_or(layers.len(), |offset| idx + 1 + offset)
+}
+
+/// Whether layer `idx` and every group containing it are visible
+pub fn effective_visible(layers: &[Layer], idx: usize) -> bool {
+    layers.get(idx).is_some_and(|l| l.visible) && ancestors(layers, idx).all(|i| layers[i].visible)
+}
+
+/// Layer `idx`'s opacity scaled by the opacity of every group containing it
+pub fn effective_opacity(layers: &[Layer], idx: usize) -> f32 {
+    layers.get(idx).map_or(0.0, |l| l.opacity)
+        * ancestors(layers, idx)
+            .map(|i| layers[i].opacity)
+            .product::<f32>()
+}
+
+/// Apply a `LayerEvent::Reorder`, moving a group together with its children
+///
+/// `to` is the insertion index in the original order. The moved rows take
+/// the nesting level of the row they land above, so dropping onto a
+/// group's first child moves the layer into that group.
+pub fn reorder_layers(layers: &mut Vec<Layer>, from: usize, to: usize) {
+    if from >= layers.len() || to > layers.len() {
+        return;
+    }
+    let end = block_end(layers, from);
+    if (from..=end).contains(&to) {
+        return;
+    }
+
+    let target_depth = layers.get(to).map_or(0, |l| l.depth);
+    let mut block: Vec<Layer> = layers.drain(from..end).collect();
+    let root_depth = block[0].depth;
+    for layer in &mut block {
+        layer.depth = layer.depth - root_depth + target_depth;
+    }
+
+    let insert_at = if to > from { to - block.len() } else { to };
+    layers.splice(insert_at..insert_at, block);
+}
+
+/// Composite the visible layers over `background`
+///
+/// Layers are listed top first, so they are applied from the last one up.
+/// Each layer blends its color with its blend mode, then mixes the result
+/// in by its opacity (and the color's alpha). Layers without a color and
+/// group folders are skipped; groups pass their visibility and opacity on
+/// to their children. If any layer is soloed, only soloed layers take part.
+pub fn composite_layers(layers: &[Layer], background: Color32) -> Color32 {
+    let any_solo = layers.iter().any(|l| l.solo);
+    let to_unit = |c: u8| c as f32 / 255.0;
+    let mut out = [
+        to_unit(background.r()),
+        to_unit(background.g()),
+        to_unit(background.b()),
+    ];
+
+    for (idx, layer) in layers.iter().enumerate().rev() {
+        if layer.is_group || !effective_visible(layers, idx) || (any_solo && !layer.solo) {
+            continue;
+        }
+        let Some(color) = layer.color else {
+            continue;
+        };
+        let [r, g, b, a] = color.to_srgba_unmultiplied();
+        let amount = effective_opacity(layers, idx) * to_unit(a);
+        for (channel, source) in out.iter_mut().zip([r, g, b]) {
+            let blended = layer.blend_mode.blend_channel(*channel, to_unit(source));
+            *channel += (blended - *channel) * amount;
+        }
+    }
+
+    let to_u8 = |c: f32| (c * 255.0).round() as u8;
+    Color32::from_rgb(to_u8(out[0]), to_u8(out[1]), to_u8(out[2]))
+}
+
 /// Events emitted by LayerStack
 #[derive(Debug, Clone)]
 pub enum LayerEvent {
@@ -148,7 +317,12 @@ pub enum LayerEvent {
     /// Layer blend mode changed
     SetBlendMode(usize, BlendMode),
     /// Layers reordered (from, to)
+    ///
+    /// `to` is the insertion index in the original order; apply it with
+    /// [`reorder_layers`] to keep groups together.
     Reorder { from: usize, to: usize },
+    /// Group folder expanded/collapsed
+    ToggleGroup(usize),
     /// Add new layer requested
     AddLayer,
     /// Delete layer requested
@@ -166,6 +340,7 @@ pub struct LayerStack<'a> {
     show_blend_modes: bool,
     show_controls: bool,
     compact: bool,
+    composite_preview: bool,
 }
 
 impl<'a> LayerStack<'a> {
@@ -179,6 +354,7 @@ impl<'a> LayerStack<'a> {
             show_blend_modes: true,
             show_controls: true,
             compact: false,
+            composite_preview: false,
         }
     }
 
@@ -212,6 +388,15 @@ impl<'a> LayerStack<'a> {
         self
     }
 
+    /// Show a swatch of the composited layers above the list
+    ///
+    /// The swatch composites each visible layer's color over black
+    /// (see [`composite_layers`]).
+    pub fn with_composite_preview(mut self, show: bool) -> Self {
+        self.composite_preview = show;
+        self
+    }
+
     /// Use compact mode (smaller UI)
     pub fn compact(mut self, compact: bool) -> Self {
         self.compact = compact;
@@ -257,11 +442,8 @@ impl<'a> LayerStack<'a> {
         let opacity_width = if self.compact { 40.0 } else { 60.0 };
         let blend_width = if self.show_blend_modes { 45.0 } else { 0.0 };
 
-        // Drag state management
-        let drag_id = Id::new("layer_stack_drag");
-        let mut drag_state: LayerDragState = ui
-            .ctx()
-            .data_mut(|d| d.get_temp(drag_id).unwrap_or_default());
+        // Drag items are the shown rows, in `layer_infos` order
+        let mut drag = ReorderDrag::new(ui, Id::new("layer_stack_drag"));
 
         // Header with controls
         if self.show_controls {
@@ -282,14 +464,28 @@ impl<'a> LayerStack<'a> {
             ui.add_space(theme.spacing_xs);
         }
 
+        // Composite preview swatch
+        if self.composite_preview {
+            let (rect, _) = ui
+                .allocate_exact_size(Vec2::new(available_width, row_height * 0.6), Sense::hover());
+            let composite = composite_layers(self.layers, Color32::BLACK);
+            let painter = ui.painter();
+            painter.rect_filled(rect, theme.radius_sm, composite);
+            painter.rect_stroke(
+                rect,
+                theme.radius_sm,
+                Stroke::new(theme.border_width, theme.border),
+                egui::StrokeKind::Inside,
+            );
+            ui.add_space(theme.spacing_xs);
+        }
+
         // Collect layer info in first pass
         struct LayerInfo {
             idx: usize,
             rect: Rect,
             row_hovered: bool,
             row_clicked: bool,
-            row_drag_started: bool,
-            row_dragged: bool,
             vis_rect: Rect,
             vis_hovered: bool,
             vis_clicked: bool,
@@ -303,12 +499,21 @@ impl<'a> LayerStack<'a> {
             opacity_hovered: bool,
             opacity_dragged: bool,
             opacity_drag_pos: Option<Pos2>,
+            indent: f32,
+            toggle_rect: Option<Rect>,
+            toggle_clicked: bool,
         }
 
         let mut layer_infos: Vec<LayerInfo> = Vec::with_capacity(self.layers.len());
 
         // First pass: allocate and collect interactions
-        for (idx, _layer) in self.layers.iter().enumerate() {
+        let indent_width = theme.spacing_md;
+        for (idx, layer) in self.layers.iter().enumerate() {
+            // Rows inside a collapsed group are not shown
+            if ancestors(self.layers, idx).any(|i| self.layers[i].collapsed) {
+                continue;
+            }
+
             let (rect, response) = ui.allocate_exact_size(
                 Vec2::new(available_width, row_height),
                 Sense::click_and_drag(),
@@ -334,7 +539,17 @@ impl<'a> LayerStack<'a> {
                 Vec2::new(lock_width, row_height),
             );
             let lock_response = ui.allocate_rect(lock_rect, Sense::click());
-            // x_offset no longer needed after this point
+            x_offset += lock_width;
+
+            // Group expand/collapse toggle
+            let indent = layer.depth as f32 * indent_width;
+            let toggle_rect = layer.is_group.then(|| {
+                Rect::from_min_size(
+                    Pos2::new(x_offset + indent, rect.min.y),
+                    Vec2::new(indent_width, row_height),
+                )
+            });
+            let toggle_response = toggle_rect.map(|r| ui.allocate_rect(r, Sense::click()));
 
             // Blend mode rect
             let blend_rect = if self.show_blend_modes {
@@ -360,13 +575,12 @@ impl<'a> LayerStack<'a> {
             );
             let opacity_response = ui.allocate_rect(opacity_rect, Sense::click_and_drag());
 
+            drag.item(rect, response.drag_started() && !layer.locked);
             layer_infos.push(LayerInfo {
                 idx,
                 rect,
                 row_hovered: response.hovered(),
                 row_clicked: response.clicked(),
-                row_drag_started: response.drag_started(),
-                row_dragged: response.dragged(),
                 vis_rect,
                 vis_hovered: vis_response.hovered(),
                 vis_clicked: vis_response.clicked(),
@@ -380,51 +594,38 @@ impl<'a> LayerStack<'a> {
                 opacity_hovered: opacity_response.hovered(),
                 opacity_dragged: opacity_response.dragged(),
                 opacity_drag_pos: opacity_response.interact_pointer_pos(),
+                indent,
+                toggle_rect,
+                toggle_clicked: toggle_response.as_ref().is_some_and(|r| r.clicked()),
             });
         }
 
-        // Handle drag-to-reorder
-        let pointer_pos = ui.input(|i| i.pointer.hover_pos());
-        for info in &layer_infos {
-            // Start drag
-            if info.row_drag_started && drag_state.dragging.is_none() {
-                let layer = &self.layers[info.idx];
-                if !layer.locked {
-                    drag_state.dragging = Some(info.idx);
-                }
-            }
-
-            // Update drop target during drag
-            if drag_state.dragging.is_some() && info.row_hovered {
-                if let Some(pos) = pointer_pos {
-                    // Determine if we're in the top or bottom half
-                    let mid_y = info.rect.center().y;
-                    if pos.y < mid_y {
-                        drag_state.drop_target = Some(info.idx);
-                    } else {
-                        drag_state.drop_target = Some(info.idx + 1);
-                    }
-                }
-            }
-        }
-
-        // Complete drag on release
-        if !ui.input(|i| i.pointer.any_down()) {
-            if let (Some(from), Some(to)) = (drag_state.dragging, drag_state.drop_target) {
-                // Only emit if actually moved
-                if from != to && from + 1 != to {
-                    event = Some(LayerEvent::Reorder { from, to });
-                }
+        // Map the row move back to a layer insertion index
+        if let Some(reorder) = drag.update(ui) {
+            let from = layer_infos[reorder.from].idx;
+            let below = if reorder.to > reorder.from {
+                reorder.to + 1
+            } else {
+                reorder.to
+            };
+            let to = match layer_infos.get(below) {
+                Some(info) => info.idx,
+                // After the last row, past any hidden children
+                None => block_end(self.layers, layer_infos[layer_infos.len() - 1].idx),
+            };
+            // Dropping a group among its own children is not a move
+            if !(from..=block_end(self.layers, from)).contains(&to) {
+                event = Some(LayerEvent::Reorder { from, to });
             }
-            drag_state.dragging = None;
-            drag_state.drop_target = None;
         }
 
         // Second pass: draw everything
         let painter = ui.painter();
 
-        for (info, layer) in layer_infos.iter().zip(self.layers.iter()) {
+        for (row, info) in layer_infos.iter().enumerate() {
+            let layer = &self.layers[info.idx];
             let is_selected = self.selected == Some(info.idx);
+            let shown = effective_visible(self.layers, info.idx);
 
             // Background
             let bg_color = if is_selected {
@@ -480,11 +681,24 @@ impl<'a> LayerStack<'a> {
                 lock_color,
             );
 
+            // Group toggle
+            let mut content_x = info.lock_rect.max.x + info.indent;
+            if let Some(toggle_rect) = info.toggle_rect {
+                painter.text(
+                    toggle_rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    if layer.collapsed { "▶" } else { "▼" },
+                    egui::FontId::proportional(theme.font_size_xs),
+                    theme.text_secondary,
+                );
+                content_x = toggle_rect.max.x;
+            }
+
             // Thumbnail
-            if self.show_thumbnails {
+            if self.show_thumbnails && !layer.is_group {
                 let thumb_rect = Rect::from_min_size(
                     Pos2::new(
-                        info.lock_rect.max.x + theme.spacing_xs,
+                        content_x + theme.spacing_xs,
                         info.rect.min.y + theme.spacing_xs,
                     ),
                     Vec2::splat(row_height - theme.spacing_sm),
@@ -500,13 +714,13 @@ impl<'a> LayerStack<'a> {
             }
 
             // Layer name
-            let name_x = if self.show_thumbnails {
-                info.lock_rect.max.x + row_height + theme.spacing_sm
+            let name_x = if self.show_thumbnails && !layer.is_group {
+                content_x + row_height + theme.spacing_sm
             } else {
-                info.lock_rect.max.x + theme.spacing_sm
+                content_x + theme.spacing_sm
             };
 
-            let name_color = if layer.visible {
+            let name_color = if shown {
                 if is_selected {
                     theme.text_primary
                 } else {
@@ -574,7 +788,7 @@ impl<'a> LayerStack<'a> {
             );
 
             // Separator line
-            if info.idx < self.layers.len() - 1 {
+            if info.idx + 1 < self.layers.len() {
                 painter.line_segment(
                     [
                         Pos2::new(info.rect.min.x + theme.spacing_sm, info.rect.max.y),
@@ -585,7 +799,7 @@ impl<'a> LayerStack<'a> {
             }
 
             // Drag overlay for dragged layer
-            if drag_state.dragging == Some(info.idx) {
+            if drag.dragging() == Some(row) {
                 painter.rect_filled(
                     info.rect,
                     theme.radius_sm,
@@ -604,32 +818,11 @@ impl<'a> LayerStack<'a> {
                 );
             }
 
-            // Drop indicator line
-            if let Some(drop_idx) = drag_state.drop_target {
-                if drop_idx == info.idx {
-                    // Draw line above this row
-                    painter.line_segment(
-                        [
-                            Pos2::new(info.rect.min.x, info.rect.min.y),
-                            Pos2::new(info.rect.max.x, info.rect.min.y),
-                        ],
-                        Stroke::new(3.0, theme.primary),
-                    );
-                } else if drop_idx == info.idx + 1 && info.idx == self.layers.len() - 1 {
-                    // Draw line below last row
-                    painter.line_segment(
-                        [
-                            Pos2::new(info.rect.min.x, info.rect.max.y),
-                            Pos2::new(info.rect.max.x, info.rect.max.y),
-                        ],
-                        Stroke::new(3.0, theme.primary),
-                    );
-                }
-            }
-
             // Handle events (only if not dragging)
-            if event.is_none() && drag_state.dragging.is_none() {
-                if info.row_clicked {
+            if event.is_none() && drag.dragging().is_none() {
+                if info.toggle_clicked {
+                    event = Some(LayerEvent::ToggleGroup(info.idx));
+                } else if info.row_clicked {
                     event = Some(LayerEvent::Select(info.idx));
                 } else if info.vis_clicked {
                     event = Some(LayerEvent::ToggleVisible(info.idx));
@@ -654,9 +847,179 @@ impl<'a> LayerStack<'a> {
             }
         }
 
-        // Save drag state
-        ui.ctx().data_mut(|d| d.insert_temp(drag_id, drag_state));
+        drag.paint_drop_marker(painter, Stroke::new(3.0, theme.primary));
 
         event
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{drag, painted_text_rect, painted_texts, run_ui, test_context};
+
+    #[test]
+    fn test_half_red_over_white_is_pink() {
+        let layers = [Layer::new("Red").with_color(Color32::RED).with_opacity(0.5)];
+        assert_eq!(
+            composite_layers(&layers, Color32::WHITE),
+            Color32::from_rgb(255, 128, 128)
+        );
+    }
+
+    #[test]
+    fn test_composite_applies_bottom_up_with_blend_modes() {
+        let gray = Color32::from_gray(128);
+        let layers = [
+            // Top: multiply by gray darkens the red below
+            Layer::new("Shade")
+                .with_color(gray)
+                .with_blend_mode(BlendMode::Multiply),
+            Layer::new("Red").with_color(Color32::RED),
+            Layer::new("Hidden")
+                .with_color(Color32::BLUE)
+                .with_visible(false),
+        ];
+        assert_eq!(
+            composite_layers(&layers, Color32::BLACK),
+            Color32::from_rgb(128, 0, 0)
+        );
+
+        let add = [
+            Layer::new("Green")
+                .with_color(Color32::GREEN)
+                .with_blend_mode(BlendMode::Add),
+            Layer::new("Red").with_color(Color32::RED),
+        ];
+        assert_eq!(
+            composite_layers(&add, Color32::BLACK),
+            Color32::from_rgb(255, 255, 0)
+        );
+
+        let screen = BlendMode::Screen.blend_channel(0.5, 0.5);
+        assert!((screen - 0.75).abs() < 1e-6);
+    }
+
+    /// Top, FX group (Blur, Glow), Background
+    fn grouped_layers() -> Vec<Layer> {
+        let mut layers = vec![Layer::new("Top").with_color(Color32::RED)];
+        layers.extend(Layer::group(
+            "FX",
+            [
+                Layer::new("Blur").with_color(Color32::GREEN),
+                Layer::new("Glow").with_color(Color32::BLUE),
+            ],
+        ));
+        layers.push(Layer::new("Background"));
+        layers
+    }
+
+    #[test]
+    fn test_group_visibility_and_opacity_cascade() {
+        let mut layers = grouped_layers();
+        assert!(effective_visible(&layers, 2));
+        assert!(effective_visible(&layers, 3));
+
+        layers[1].visible = false;
+        assert!(!effective_visible(&layers, 2));
+        assert!(!effective_visible(&layers, 3));
+        assert!(effective_visible(&layers, 0));
+        assert!(effective_visible(&layers, 4));
+
+        layers[1].visible = true;
+        layers[1].opacity = 0.5;
+        layers[2].opacity = 0.5;
+        assert_eq!(effective_opacity(&layers, 2), 0.25);
+        assert_eq!(effective_opacity(&layers, 3), 0.5);
+        assert_eq!(effective_opacity(&layers, 4), 1.0);
+    }
+
+    #[test]
+    fn test_collapsed_group_hides_child_rows() {
+        let ctx = test_context();
+        let mut layers = grouped_layers();
+
+        let output = run_ui(&ctx, |ui| {
+            LayerStack::new(&layers).show(ui);
+        });
+        let texts = painted_texts(&output);
+        assert!(texts.iter().any(|t| t == "Blur"));
+        assert!(texts.iter().any(|t| t == "Glow"));
+
+        layers[1].collapsed = true;
+        let output = run_ui(&ctx, |ui| {
+            LayerStack::new(&layers).show(ui);
+        });
+        let texts = painted_texts(&output);
+        assert!(texts.iter().any(|t| t == "FX"));
+        assert!(texts.iter().any(|t| t == "Background"));
+        assert!(!texts.iter().any(|t| t == "Blur" || t == "Glow"));
+    }
+
+    #[test]
+    fn test_drag_below_collapsed_group_skips_its_children() {
+        let ctx = test_context();
+        let mut layers = grouped_layers();
+        layers[1].collapsed = true;
+
+        let output = run_ui(&ctx, |ui| {
+            LayerStack::new(&layers).show(ui);
+        });
+        let top = painted_text_rect(&output, "Top").unwrap().center();
+        let fx = painted_text_rect(&output, "FX").unwrap().center();
+
+        let mut events = Vec::new();
+        drag(&ctx, top, fx + Vec2::new(0.0, 4.0), |ui| {
+            events.extend(LayerStack::new(&layers).show(ui));
+        });
+
+        let reorders: Vec<_> = events
+            .iter()
+            .filter_map(|e| match *e {
+                LayerEvent::Reorder { from, to } => Some((from, to)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(reorders, vec![(0, 4)]);
+    }
+
+    #[test]
+    fn test_reorder_moves_group_with_children() {
+        let names = |layers: &[Layer]| -> Vec<(String, usize)> {
+            layers.iter().map(|l| (l.name.clone(), l.depth)).collect()
+        };
+
+        // Move the FX group below Background
+        let mut layers = grouped_layers();
+        reorder_layers(&mut layers, 1, 5);
+        assert_eq!(
+            names(&layers),
+            vec![
+                ("Top".into(), 0),
+                ("Background".into(), 0),
+                ("FX".into(), 0),
+                ("Blur".into(), 1),
+                ("Glow".into(), 1),
+            ]
+        );
+
+        // Dropping a group inside itself is ignored
+        let mut layers = grouped_layers();
+        reorder_layers(&mut layers, 1, 3);
+        assert_eq!(names(&layers), names(&grouped_layers()));
+
+        // Dropping above a child moves the layer into the group
+        let mut layers = grouped_layers();
+        reorder_layers(&mut layers, 4, 2);
+        assert_eq!(layers[2].name, "Background");
+        assert_eq!(layers[2].depth, 1);
+    }
+
+    #[test]
+    fn test_solo_limits_composite() {
+        let mut red = Layer::new("Red").with_color(Color32::RED);
+        red.solo = true;
+        let layers = [Layer::new("Blue").with_color(Color32::BLUE), red];
+        assert_eq!(composite_layers(&layers, Color32::BLACK), Color32::RED);
+    }
+}
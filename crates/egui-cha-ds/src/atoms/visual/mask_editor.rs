@@ -6,9 +6,10 @@
 //! # Features
 //! - Multiple mask types (rectangle, ellipse, polygon, freehand)
 //! - Point editing (add, move, delete)
-//! - Feather/blur control
+//! - Feathered (soft-edged) masks
+//! - Multiple shapes combined with add/subtract/intersect
 //! - Invert mask option
-//! - Visual preview
+//! - Visual preview of the combined coverage
 //! - Theme-aware styling
 //!
 //! # Example
@@ -21,6 +22,18 @@
 //!         MaskEvent::SetFeather(val) => Msg::SetFeather(val),
 //!         MaskEvent::ToggleInvert => Msg::ToggleInvert,
 //!     });
+//!
+//! // Several shapes, editing the second one
+//! MaskEditor::multi(&masks)
+//!     .active(1)
+//!     .show_with(ctx, |event| match event {
+//!         MaskEvent::SelectShape(idx) => Msg::SelectMask(idx),
+//!         MaskEvent::SetCombine(mode) => Msg::SetCombine(mode),
+//!         _ => Msg::Noop,
+//!     });
+//!
+//! // Query coverage programmatically
+//! let alpha = combined_coverage(&masks, MaskPoint::new(0.5, 0.5));
 //! ```
 
 use crate::Theme;
@@ -61,6 +74,45 @@ impl MaskShape {
     }
 }
 
+/// How a shape combines with the shapes before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CombineMode {
+    /// Union: coverage is added
+    #[default]
+    Add,
+    /// Cut this shape out of the shapes before it
+    Subtract,
+    /// Keep only the overlap with the shapes before it
+    Intersect,
+}
+
+impl CombineMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CombineMode::Add => "Add",
+            CombineMode::Subtract => "Sub",
+            CombineMode::Intersect => "Int",
+        }
+    }
+
+    pub fn all() -> &'static [CombineMode] {
+        &[
+            CombineMode::Add,
+            CombineMode::Subtract,
+            CombineMode::Intersect,
+        ]
+    }
+
+    /// Combine accumulated coverage `base` with a shape's coverage `shape`
+    pub fn apply(&self, base: f32, shape: f32) -> f32 {
+        match self {
+            CombineMode::Add => base + shape * (1.0 - base),
+            CombineMode::Subtract => base * (1.0 - shape),
+            CombineMode::Intersect => base * shape,
+        }
+    }
+}
+
 /// A point in the mask (normalized 0.0-1.0)
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct MaskPoint {
@@ -108,7 +160,7 @@ pub struct Mask {
     pub shape: MaskShape,
     /// Points defining the mask
     pub points: Vec<MaskPoint>,
-    /// Feather/blur amount (0.0-1.0)
+    /// Width of the soft edge band, in normalized canvas units (0.0-1.0)
     pub feather: f32,
     /// Invert mask
     pub inverted: bool,
@@ -116,6 +168,8 @@ pub struct Mask {
     pub opacity: f32,
     /// Mask color (for preview)
     pub color: Color32,
+    /// How this shape combines with the shapes before it
+    pub combine: CombineMode,
 }
 
 impl Mask {
@@ -128,6 +182,7 @@ impl Mask {
             inverted: false,
             opacity: 1.0,
             color: Color32::WHITE,
+            combine: CombineMode::Add,
         }
     }
 
@@ -143,6 +198,7 @@ impl Mask {
             inverted: false,
             opacity: 1.0,
             color: Color32::WHITE,
+            combine: CombineMode::Add,
         }
     }
 
@@ -155,6 +211,7 @@ impl Mask {
             inverted: false,
             opacity: 1.0,
             color: Color32::WHITE,
+            combine: CombineMode::Add,
         }
     }
 
@@ -186,6 +243,135 @@ impl Mask {
         self.color = color;
         self
     }
+
+    pub fn with_combine(mut self, combine: CombineMode) -> Self {
+        self.combine = combine;
+        self
+    }
+
+    /// Coverage of this mask at `point`, from 0.0 (outside) to 1.0 (inside)
+    ///
+    /// With feather, coverage ramps linearly across a band of width
+    /// `feather` centered on the edge, so it is 0.5 exactly on the edge.
+    /// Inversion is applied; opacity is not.
+    pub fn contains(&self, point: MaskPoint) -> f32 {
+        let Some(distance) = self.signed_distance(Vec2::new(point.x, point.y)) else {
+            return if self.inverted { 1.0 } else { 0.0 };
+        };
+        let coverage = if self.feather > 0.0 {
+            (0.5 - distance / self.feather).clamp(0.0, 1.0)
+        } else if distance <= 0.0 {
+            1.0
+        } else {
+            0.0
+        };
+        if self.inverted {
+            1.0 - coverage
+        } else {
+            coverage
+        }
+    }
+
+    /// Signed distance to the shape's edge (negative inside)
+    ///
+    /// `None` when there are too few points to form a shape.
+    fn signed_distance(&self, p: Vec2) -> Option<f32> {
+        let pts: Vec<Vec2> = self.points.iter().map(|pt| Vec2::new(pt.x, pt.y)).collect();
+        match self.shape {
+            MaskShape::Rectangle => {
+                let (a, b) = (*pts.first()?, *pts.get(1)?);
+                let center = (a + b) * 0.5;
+                let half = (b - a).abs() * 0.5;
+                let q = (p - center).abs() - half;
+                let outside = q.max(Vec2::ZERO).length();
+                let inside = q.x.max(q.y).min(0.0);
+                Some(outside + inside)
+            }
+            MaskShape::Ellipse => {
+                let (center, radius) = (*pts.first()?, *pts.get(1)?);
+                if radius.x <= 0.0 || radius.y <= 0.0 {
+                    return None;
+                }
+                // Scaled-circle approximation of the ellipse distance
+                let k = ((p - center) / radius).length();
+                Some((k - 1.0) * radius.x.min(radius.y))
+            }
+            MaskShape::Polygon | MaskShape::Freehand => {
+                if pts.len() < 3 {
+                    return None;
+                }
+                let mut distance = f32::INFINITY;
+                let mut inside = false;
+                for (i, &a) in pts.iter().enumerate() {
+                    let b = pts[(i + 1) % pts.len()];
+                    let ab = b - a;
+                    let t = ((p - a).dot(ab) / ab.length_sq().max(f32::EPSILON)).clamp(0.0, 1.0);
+                    distance = distance.min((p - (a + ab * t)).length());
+                    if (a.y > p.y) != (b.y > p.y) && p.x < a.x + (p.y - a.y) / (b.y - a.y) * ab.x {
+                        inside = !inside;
+                    }
+                }
+                Some(if inside { -distance } else { distance })
+            }
+        }
+    }
+
+    /// Outline in screen space, for drawing
+    fn outline(&self, rect: Rect) -> Vec<Pos2> {
+        match self.shape {
+            MaskShape::Rectangle => {
+                if self.points.len() < 2 {
+                    return Vec::new();
+                }
+                let r =
+                    Rect::from_two_pos(self.points[0].to_pos(rect), self.points[1].to_pos(rect));
+                vec![
+                    r.left_top(),
+                    r.right_top(),
+                    r.right_bottom(),
+                    r.left_bottom(),
+                ]
+            }
+            MaskShape::Ellipse => {
+                if self.points.len() < 2 {
+                    return Vec::new();
+                }
+                let center = self.points[0].to_pos(rect);
+                let radius = Vec2::new(
+                    self.points[1].x * rect.width(),
+                    self.points[1].y * rect.height(),
+                );
+                let segments = 32;
+                (0..segments)
+                    .map(|i| {
+                        let angle = (i as f32 / segments as f32) * std::f32::consts::TAU;
+                        Pos2::new(
+                            center.x + angle.cos() * radius.x,
+                            center.y + angle.sin() * radius.y,
+                        )
+                    })
+                    .collect()
+            }
+            MaskShape::Polygon | MaskShape::Freehand => {
+                self.points.iter().map(|p| p.to_pos(rect)).collect()
+            }
+        }
+    }
+}
+
+/// Coverage of several masks combined in order
+///
+/// Each mask's [`CombineMode`] says how it merges with the result of the
+/// masks before it; the first mask's mode is ignored.
+pub fn combined_coverage(masks: &[Mask], point: MaskPoint) -> f32 {
+    masks.iter().enumerate().fold(0.0, |acc, (i, mask)| {
+        let coverage = mask.contains(point);
+        if i == 0 {
+            coverage
+        } else {
+            mask.combine.apply(acc, coverage)
+        }
+    })
 }
 
 impl Default for Mask {
@@ -213,13 +399,18 @@ pub enum MaskEvent {
     ToggleInvert,
     /// Shape type changed
     SetShape(MaskShape),
+    /// Another shape was chosen for editing (multi-shape editors)
+    SelectShape(usize),
+    /// Combine mode of the active shape changed
+    SetCombine(CombineMode),
     /// Reset to default
     Reset,
 }
 
 /// Mask editor widget
 pub struct MaskEditor<'a> {
-    mask: &'a Mask,
+    masks: &'a [Mask],
+    active: usize,
     size: Vec2,
     selected_point: Option<usize>,
     show_controls: bool,
@@ -229,8 +420,17 @@ pub struct MaskEditor<'a> {
 
 impl<'a> MaskEditor<'a> {
     pub fn new(mask: &'a Mask) -> Self {
+        Self::multi(std::slice::from_ref(mask))
+    }
+
+    /// Edit several shapes that combine into one mask
+    ///
+    /// Only the [`active`](Self::active) shape shows handles; the others
+    /// are outlined and contribute to the preview.
+    pub fn multi(masks: &'a [Mask]) -> Self {
         Self {
-            mask,
+            masks,
+            active: 0,
             size: Vec2::new(300.0, 200.0),
             selected_point: None,
             show_controls: true,
@@ -239,6 +439,12 @@ impl<'a> MaskEditor<'a> {
         }
     }
 
+    /// Index of the shape being edited
+    pub fn active(mut self, index: usize) -> Self {
+        self.active = index;
+        self
+    }
+
     pub fn size(mut self, width: f32, height: f32) -> Self {
         self.size = Vec2::new(width, height);
         self
@@ -278,11 +484,14 @@ impl<'a> MaskEditor<'a> {
     fn show_internal(self, ui: &mut Ui) -> Option<MaskEvent> {
         let theme = Theme::current(ui.ctx());
         let mut event: Option<MaskEvent> = None;
-
-        let controls_height = if self.show_controls {
-            theme.spacing_xl + theme.spacing_sm
-        } else {
-            0.0
+        let mask = self.masks.get(self.active)?;
+        let multi = self.masks.len() > 1;
+
+        let row_height = theme.spacing_lg + theme.spacing_xs;
+        let controls_height = match (self.show_controls, multi) {
+            (false, _) => 0.0,
+            (true, false) => theme.spacing_xl + theme.spacing_sm,
+            (true, true) => theme.spacing_xl + theme.spacing_sm + row_height,
         };
         let total_height = self.size.y + controls_height;
 
@@ -316,7 +525,7 @@ impl<'a> MaskEditor<'a> {
         let mut point_infos: Vec<PointInfo> = Vec::new();
 
         if self.editable {
-            for (idx, point) in self.mask.points.iter().enumerate() {
+            for (idx, point) in mask.points.iter().enumerate() {
                 let screen_pos = point.to_pos(canvas_rect);
                 let handle_rect =
                     Rect::from_center_size(screen_pos, Vec2::splat(handle_size * 2.0));
@@ -338,6 +547,8 @@ impl<'a> MaskEditor<'a> {
         let mut shape_clicked: Option<MaskShape> = None;
         let mut invert_clicked = false;
         let mut feather_drag: Option<f32> = None;
+        let mut shape_tab_clicked: Option<usize> = None;
+        let mut combine_clicked: Option<CombineMode> = None;
 
         if self.show_controls {
             let controls_y = canvas_rect.max.y + theme.spacing_xs;
@@ -384,6 +595,21 @@ impl<'a> MaskEditor<'a> {
                         Some(((pos.x - bar_rect.min.x) / bar_rect.width()).clamp(0.0, 1.0));
                 }
             }
+
+            if multi {
+                let (tabs, combines) =
+                    multi_row_rects(rect, controls_y + row_height, self.masks.len(), &theme);
+                for (idx, tab_rect) in tabs.into_iter().enumerate() {
+                    if ui.allocate_rect(tab_rect, Sense::click()).clicked() {
+                        shape_tab_clicked = Some(idx);
+                    }
+                }
+                for (mode, btn_rect) in CombineMode::all().iter().zip(combines) {
+                    if ui.allocate_rect(btn_rect, Sense::click()).clicked() {
+                        combine_clicked = Some(*mode);
+                    }
+                }
+            }
         }
 
         // Second pass: draw everything
@@ -437,76 +663,35 @@ impl<'a> MaskEditor<'a> {
             );
         }
 
-        // Draw mask shape preview
-        let mask_color = if self.mask.inverted {
+        // Combined coverage preview, sampled on a grid so feathering and
+        // combine modes show up as they would when compositing
+        let mask_color = if mask.inverted {
             Color32::from_rgba_unmultiplied(255, 100, 100, 100)
         } else {
             Color32::from_rgba_unmultiplied(100, 200, 255, 100)
         };
-        let mask_stroke = Stroke::new(2.0, theme.primary);
+        painter.add(coverage_mesh(self.masks, canvas_rect, mask_color));
 
-        match self.mask.shape {
-            MaskShape::Rectangle => {
-                if self.mask.points.len() >= 2 {
-                    let p1 = self.mask.points[0].to_pos(canvas_rect);
-                    let p2 = self.mask.points[1].to_pos(canvas_rect);
-                    let shape_rect = Rect::from_two_pos(p1, p2);
-                    painter.rect_filled(shape_rect, 0.0, mask_color);
-                    painter.rect_stroke(shape_rect, 0.0, mask_stroke, egui::StrokeKind::Outside);
-                }
-            }
-            MaskShape::Ellipse => {
-                if self.mask.points.len() >= 2 {
-                    let center = self.mask.points[0].to_pos(canvas_rect);
-                    let radius_pt = self.mask.points[1];
-                    let radius = Vec2::new(
-                        radius_pt.x * canvas_rect.width(),
-                        radius_pt.y * canvas_rect.height(),
-                    );
-
-                    // Draw ellipse using segments
-                    let segments = 32;
-                    let mut points = Vec::with_capacity(segments);
-                    for i in 0..segments {
-                        let angle = (i as f32 / segments as f32) * std::f32::consts::TAU;
-                        points.push(Pos2::new(
-                            center.x + angle.cos() * radius.x,
-                            center.y + angle.sin() * radius.y,
-                        ));
-                    }
-                    painter.add(egui::Shape::convex_polygon(
-                        points.clone(),
-                        mask_color,
-                        Stroke::NONE,
-                    ));
-                    painter.add(egui::Shape::closed_line(points, mask_stroke));
-                }
-            }
-            MaskShape::Polygon | MaskShape::Freehand => {
-                if self.mask.points.len() >= 3 {
-                    let points: Vec<Pos2> = self
-                        .mask
-                        .points
-                        .iter()
-                        .map(|p| p.to_pos(canvas_rect))
-                        .collect();
-                    painter.add(egui::Shape::convex_polygon(
-                        points.clone(),
-                        mask_color,
-                        Stroke::NONE,
-                    ));
-                    painter.add(egui::Shape::closed_line(points, mask_stroke));
-                } else if self.mask.points.len() == 2 {
-                    let p1 = self.mask.points[0].to_pos(canvas_rect);
-                    let p2 = self.mask.points[1].to_pos(canvas_rect);
-                    painter.line_segment([p1, p2], mask_stroke);
-                }
+        // Shape outlines, active shape on top
+        for (idx, other) in self.masks.iter().enumerate() {
+            if idx == self.active {
+                continue;
             }
+            draw_outline(
+                painter,
+                other.outline(canvas_rect),
+                Stroke::new(1.0, theme.text_muted),
+            );
         }
+        draw_outline(
+            painter,
+            mask.outline(canvas_rect),
+            Stroke::new(2.0, theme.primary),
+        );
 
         // Draw feather indicator
-        if self.mask.feather > 0.01 {
-            let feather_text = format!("Feather: {:.0}%", self.mask.feather * 100.0);
+        if mask.feather > 0.01 {
+            let feather_text = format!("Feather: {:.0}%", mask.feather * 100.0);
             painter.text(
                 Pos2::new(
                     canvas_rect.min.x + theme.spacing_xs,
@@ -568,7 +753,7 @@ impl<'a> MaskEditor<'a> {
                     Pos2::new(x, controls_y),
                     Vec2::new(button_width, theme.spacing_lg),
                 );
-                let is_active = self.mask.shape == *shape;
+                let is_active = mask.shape == *shape;
                 let bg = if is_active {
                     theme.primary
                 } else {
@@ -596,7 +781,7 @@ impl<'a> MaskEditor<'a> {
                 Pos2::new(x + theme.spacing_sm, controls_y),
                 Vec2::new(40.0, theme.spacing_lg),
             );
-            let invert_bg = if self.mask.inverted {
+            let invert_bg = if mask.inverted {
                 theme.state_warning
             } else {
                 theme.bg_tertiary
@@ -607,7 +792,7 @@ impl<'a> MaskEditor<'a> {
                 egui::Align2::CENTER_CENTER,
                 "INV",
                 egui::FontId::proportional(theme.font_size_xs),
-                if self.mask.inverted {
+                if mask.inverted {
                     theme.primary_text
                 } else {
                     theme.text_secondary
@@ -637,16 +822,42 @@ impl<'a> MaskEditor<'a> {
             painter.rect_filled(bar_rect, 3.0, theme.bg_tertiary);
             let fill_rect = Rect::from_min_size(
                 bar_rect.min,
-                Vec2::new(bar_rect.width() * self.mask.feather, bar_rect.height()),
+                Vec2::new(bar_rect.width() * mask.feather, bar_rect.height()),
             );
             painter.rect_filled(fill_rect, 3.0, theme.primary);
 
-            let handle_x = bar_rect.min.x + self.mask.feather * bar_rect.width();
+            let handle_x = bar_rect.min.x + mask.feather * bar_rect.width();
             painter.circle_filled(
                 Pos2::new(handle_x, bar_rect.center().y),
                 5.0,
                 Color32::WHITE,
             );
+
+            if multi {
+                let (tabs, combines) =
+                    multi_row_rects(rect, controls_y + row_height, self.masks.len(), &theme);
+                let labels = (0..tabs.len()).map(|i| ((i + 1).to_string(), i == self.active));
+                let modes = CombineMode::all()
+                    .iter()
+                    .map(|m| (m.label().to_string(), *m == mask.combine));
+                for (btn_rect, (label, is_active)) in
+                    tabs.into_iter().chain(combines).zip(labels.chain(modes))
+                {
+                    let (bg, text_color) = if is_active {
+                        (theme.primary, theme.primary_text)
+                    } else {
+                        (theme.bg_tertiary, theme.text_secondary)
+                    };
+                    painter.rect_filled(btn_rect, theme.radius_sm, bg);
+                    painter.text(
+                        btn_rect.center(),
+                        egui::Align2::CENTER_CENTER,
+                        label,
+                        egui::FontId::proportional(theme.font_size_xs),
+                        text_color,
+                    );
+                }
+            }
         }
 
         // Process events
@@ -667,7 +878,7 @@ impl<'a> MaskEditor<'a> {
                         break;
                     }
                 }
-                if info.secondary_clicked && self.mask.points.len() > 3 {
+                if info.secondary_clicked && mask.points.len() > 3 {
                     event = Some(MaskEvent::DeletePoint(info.index));
                     break;
                 }
@@ -690,9 +901,179 @@ impl<'a> MaskEditor<'a> {
                 event = Some(MaskEvent::ToggleInvert);
             } else if let Some(feather) = feather_drag {
                 event = Some(MaskEvent::SetFeather(feather));
+            } else if let Some(idx) = shape_tab_clicked {
+                event = Some(MaskEvent::SelectShape(idx));
+            } else if let Some(mode) = combine_clicked {
+                event = Some(MaskEvent::SetCombine(mode));
             }
         }
 
         event
     }
 }
+
+/// Layout of the multi-shape row: shape tabs on the left, combine modes on the right
+fn multi_row_rects(
+    rect: Rect,
+    y: f32,
+    shape_count: usize,
+    theme: &Theme,
+) -> (Vec<Rect>, Vec<Rect>) {
+    let tab_width = theme.spacing_lg;
+    let tabs = (0..shape_count)
+        .map(|i| {
+            Rect::from_min_size(
+                Pos2::new(rect.min.x + i as f32 * (tab_width + theme.spacing_xs), y),
+                Vec2::new(tab_width, theme.spacing_lg),
+            )
+        })
+        .collect();
+
+    let combine_width = 36.0;
+    let modes = CombineMode::all();
+    let combines = (0..modes.len())
+        .map(|i| {
+            let from_right = (modes.len() - i) as f32 * (combine_width + theme.spacing_xs);
+            Rect::from_min_size(
+                Pos2::new(rect.max.x - from_right, y),
+                Vec2::new(combine_width, theme.spacing_lg),
+            )
+        })
+        .collect();
+
+    (tabs, combines)
+}
+
+/// Mesh shading `rect` by the combined coverage of `masks`
+fn coverage_mesh(masks: &[Mask], rect: Rect, color: Color32) -> egui::Mesh {
+    const CELL: f32 = 6.0;
+    let cols = ((rect.width() / CELL).ceil() as u32).clamp(1, 96);
+    let rows = ((rect.height() / CELL).ceil() as u32).clamp(1, 96);
+
+    let mut mesh = egui::Mesh::default();
+    for row in 0..=rows {
+        for col in 0..=cols {
+            let t = Vec2::new(col as f32 / cols as f32, row as f32 / rows as f32);
+            let coverage = combined_coverage(masks, MaskPoint::new(t.x, t.y));
+            mesh.colored_vertex(rect.min + t * rect.size(), color.gamma_multiply(coverage));
+        }
+    }
+    let stride = cols + 1;
+    for row in 0..rows {
+        for col in 0..cols {
+            let i = row * stride + col;
+            mesh.add_triangle(i, i + 1, i + stride);
+            mesh.add_triangle(i + 1, i + stride + 1, i + stride);
+        }
+    }
+    mesh
+}
+
+fn draw_outline(painter: &egui::Painter, points: Vec<Pos2>, stroke: Stroke) {
+    match points.len() {
+        0 | 1 => {}
+        2 => {
+            painter.line_segment([points[0], points[1]], stroke);
+        }
+        _ => {
+            painter.add(egui::Shape::closed_line(points, stroke));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, run_ui, test_context};
+
+    #[test]
+    fn test_feathered_coverage() {
+        let mask = Mask::rectangle().with_feather(0.2);
+
+        assert_eq!(mask.contains(MaskPoint::new(0.5, 0.5)), 1.0);
+        assert_eq!(mask.contains(MaskPoint::new(0.0, 0.5)), 0.0);
+
+        let edge = mask.contains(MaskPoint::new(0.8, 0.5));
+        assert!((edge - 0.5).abs() < 1e-4);
+        let near_edge = mask.contains(MaskPoint::new(0.85, 0.5));
+        assert!(near_edge > 0.0 && near_edge < 0.5, "{near_edge}");
+
+        let hard = Mask::rectangle();
+        assert_eq!(hard.contains(MaskPoint::new(0.79, 0.5)), 1.0);
+        assert_eq!(hard.contains(MaskPoint::new(0.81, 0.5)), 0.0);
+        assert_eq!(
+            hard.with_inverted(true).contains(MaskPoint::new(0.5, 0.5)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_shape_coverage() {
+        let ellipse = Mask::ellipse();
+        assert_eq!(ellipse.contains(MaskPoint::new(0.5, 0.5)), 1.0);
+        assert_eq!(ellipse.contains(MaskPoint::new(0.95, 0.5)), 0.0);
+
+        let triangle = Mask::triangle();
+        assert_eq!(triangle.contains(MaskPoint::new(0.5, 0.6)), 1.0);
+        assert_eq!(triangle.contains(MaskPoint::new(0.25, 0.3)), 0.0);
+    }
+
+    #[test]
+    fn test_combine_modes() {
+        let left = Mask::polygon(vec![
+            MaskPoint::new(0.0, 0.0),
+            MaskPoint::new(0.6, 0.0),
+            MaskPoint::new(0.6, 1.0),
+            MaskPoint::new(0.0, 1.0),
+        ]);
+        let right = Mask::polygon(vec![
+            MaskPoint::new(0.4, 0.0),
+            MaskPoint::new(1.0, 0.0),
+            MaskPoint::new(1.0, 1.0),
+            MaskPoint::new(0.4, 1.0),
+        ]);
+        let coverage_at = |mode: CombineMode, x: f32| {
+            let masks = [left.clone(), right.clone().with_combine(mode)];
+            combined_coverage(&masks, MaskPoint::new(x, 0.5))
+        };
+
+        assert_eq!(coverage_at(CombineMode::Add, 0.2), 1.0);
+        assert_eq!(coverage_at(CombineMode::Add, 0.8), 1.0);
+        assert_eq!(coverage_at(CombineMode::Subtract, 0.2), 1.0);
+        assert_eq!(coverage_at(CombineMode::Subtract, 0.5), 0.0);
+        assert_eq!(coverage_at(CombineMode::Intersect, 0.2), 0.0);
+        assert_eq!(coverage_at(CombineMode::Intersect, 0.5), 1.0);
+    }
+
+    #[test]
+    fn test_combine_button_emits_event() {
+        let ctx = test_context();
+        let masks = [Mask::rectangle(), Mask::ellipse()];
+        let mut origin = Pos2::ZERO;
+        let mut event = None;
+        let theme = Theme::default();
+
+        run_ui(&ctx, |ui| origin = ui.next_widget_position());
+        let rect = Rect::from_min_size(origin, Vec2::new(300.0, 200.0));
+        let row_y = rect.max.y + theme.spacing_xs + theme.spacing_lg + theme.spacing_xs;
+        let (tabs, combines) = multi_row_rects(rect, row_y, masks.len(), &theme);
+
+        click_at(&ctx, combines[1].center(), |ui| {
+            if let Some(e) = MaskEditor::multi(&masks).active(1).show(ui) {
+                event = Some(e);
+            }
+        });
+        assert!(matches!(
+            event,
+            Some(MaskEvent::SetCombine(CombineMode::Subtract))
+        ));
+
+        event = None;
+        click_at(&ctx, tabs[0].center(), |ui| {
+            if let Some(e) = MaskEditor::multi(&masks).active(1).show(ui) {
+                event = Some(e);
+            }
+        });
+        assert!(matches!(event, Some(MaskEvent::SelectShape(0))));
+    }
+}
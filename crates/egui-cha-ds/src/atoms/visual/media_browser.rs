@@ -2,7 +2,30 @@
 //!
 //! A component for browsing and selecting media files with thumbnail previews.
 //! Supports images, videos, and other media types with filtering and search.
-
+//! Items can be nested in folders, navigated with a breadcrumb bar.
+//!
+//! # Example
+//! ```ignore
+//! let items = vec![
+//!     MediaItem::folder("Loops", vec![
+//!         MediaItem::new("kick", "kick_loop.wav").with_type(MediaType::Audio),
+//!     ]),
+//!     MediaItem::new("bg", "background.jpg"),
+//! ];
+//!
+//! MediaBrowser::new(&items)
+//!     .path(&model.media_path)
+//!     .search(&model.media_search)
+//!     .show_with(ctx, |e| match e {
+//!         MediaBrowserEvent::EnterFolder(id) => Msg::EnterFolder(id),
+//!         MediaBrowserEvent::NavigateUp => Msg::NavigateUp,
+//!         MediaBrowserEvent::SearchChange(query) => Msg::SetMediaSearch(query),
+//!         _ => Msg::Noop,
+//!     });
+//! ```
+
+use super::preview::{fit_rect, texture_size};
+use crate::molecules::SearchBar;
 use crate::Theme;
 use egui::{Color32, Pos2, Rect, Sense, Stroke, TextureId, Ui, Vec2};
 use egui_cha::ViewCtx;
@@ -16,6 +39,7 @@ pub enum MediaType {
     Audio,
     Text,
     Other,
+    Folder,
 }
 
 impl MediaType {
@@ -26,6 +50,7 @@ impl MediaType {
             MediaType::Audio => "🎵",
             MediaType::Text => "📄",
             MediaType::Other => "📁",
+            MediaType::Folder => "📂",
         }
     }
 
@@ -36,6 +61,7 @@ impl MediaType {
             MediaType::Audio => "Audio",
             MediaType::Text => "Text",
             MediaType::Other => "Other",
+            MediaType::Folder => "Folder",
         }
     }
 }
@@ -49,6 +75,8 @@ pub struct MediaItem {
     pub thumbnail: Option<TextureId>,
     pub duration: Option<f32>,
     pub tags: Vec<String>,
+    /// Contents, for folders
+    pub children: Vec<MediaItem>,
 }
 
 impl MediaItem {
@@ -60,9 +88,23 @@ impl MediaItem {
             thumbnail: None,
             duration: None,
             tags: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Create a folder; its name doubles as its id
+    pub fn folder(name: impl Into<String>, children: Vec<MediaItem>) -> Self {
+        let name = name.into();
+        Self {
+            children,
+            ..Self::new(name.clone(), name).with_type(MediaType::Folder)
         }
     }
 
+    pub fn is_folder(&self) -> bool {
+        self.media_type == MediaType::Folder
+    }
+
     pub fn with_type(mut self, media_type: MediaType) -> Self {
         self.media_type = media_type;
         self
@@ -102,11 +144,18 @@ pub enum MediaBrowserEvent {
     FilterChange(Option<MediaType>),
     SearchChange(String),
     ViewModeChange(BrowserViewMode),
+    /// A folder in the current view was opened (double-click)
+    EnterFolder(String),
+    /// The up button was clicked
+    NavigateUp,
+    /// A breadcrumb was clicked; keep this many path segments (0 = root)
+    NavigateTo(usize),
 }
 
 /// Media browser widget
 pub struct MediaBrowser<'a> {
     items: &'a [MediaItem],
+    path: &'a [String],
     selected: Option<&'a str>,
     filter: Option<MediaType>,
     search: &'a str,
@@ -122,6 +171,7 @@ impl<'a> MediaBrowser<'a> {
     pub fn new(items: &'a [MediaItem]) -> Self {
         Self {
             items,
+            path: &[],
             selected: None,
             filter: None,
             search: "",
@@ -134,6 +184,12 @@ impl<'a> MediaBrowser<'a> {
         }
     }
 
+    /// Folder ids from the root down to the folder being shown
+    pub fn path(mut self, path: &'a [String]) -> Self {
+        self.path = path;
+        self
+    }
+
     pub fn selected(mut self, id: Option<&'a str>) -> Self {
         self.selected = id;
         self
@@ -193,15 +249,73 @@ impl<'a> MediaBrowser<'a> {
         self.show_internal(ui)
     }
 
+    /// Folders along `path` that exist, starting below the root
+    fn resolved_path(&self) -> Vec<&'a MediaItem> {
+        let mut folders = Vec::new();
+        let mut items = self.items;
+        for id in self.path {
+            match items.iter().find(|item| item.is_folder() && &item.id == id) {
+                Some(folder) => {
+                    folders.push(folder);
+                    items = &folder.children;
+                }
+                None => break,
+            }
+        }
+        folders
+    }
+
+    /// Items to show: the current folder, or its whole subtree while searching
+    fn visible_items(&self) -> Vec<&'a MediaItem> {
+        let current = match self.resolved_path().last() {
+            Some(folder) => &folder.children,
+            None => self.items,
+        };
+
+        let search_lower = self.search.to_lowercase();
+        let matches = |item: &MediaItem| {
+            if let Some(filter) = self.filter {
+                if item.media_type != filter {
+                    return false;
+                }
+            }
+            item.name.to_lowercase().contains(&search_lower)
+                || item
+                    .tags
+                    .iter()
+                    .any(|t| t.to_lowercase().contains(&search_lower))
+        };
+
+        if self.search.is_empty() {
+            // Folders stay visible under a type filter so they can be entered
+            return current
+                .iter()
+                .filter(|item| item.is_folder() || matches(item))
+                .collect();
+        }
+
+        let mut found = Vec::new();
+        let mut stack: Vec<&MediaItem> = current.iter().rev().collect();
+        while let Some(item) = stack.pop() {
+            if matches(item) {
+                found.push(item);
+            }
+            stack.extend(item.children.iter().rev());
+        }
+        found
+    }
+
     fn show_internal(self, ui: &mut Ui) -> Option<MediaBrowserEvent> {
         let theme = Theme::current(ui.ctx());
         let mut event: Option<MediaBrowserEvent> = None;
 
-        let toolbar_height = if self.show_toolbar {
+        let bar_height = if self.show_toolbar {
             theme.spacing_xl
         } else {
             0.0
         };
+        // Filter/view toolbar plus breadcrumb/search bar
+        let toolbar_height = bar_height * 2.0;
         let total_height = self.size.y + toolbar_height;
 
         let (rect, _response) =
@@ -211,38 +325,36 @@ impl<'a> MediaBrowser<'a> {
             return None;
         }
 
-        // Filter items
-        let filtered_items: Vec<&MediaItem> = self
-            .items
-            .iter()
-            .filter(|item| {
-                if let Some(filter) = self.filter {
-                    if item.media_type != filter {
-                        return false;
-                    }
-                }
-                if !self.search.is_empty() {
-                    let search_lower = self.search.to_lowercase();
-                    if !item.name.to_lowercase().contains(&search_lower)
-                        && !item
-                            .tags
-                            .iter()
-                            .any(|t| t.to_lowercase().contains(&search_lower))
-                    {
-                        return false;
-                    }
-                }
-                true
-            })
-            .collect();
+        let filtered_items = self.visible_items();
+        let folders = self.resolved_path();
 
         // Toolbar interactions
         let mut toolbar_filter_clicked: Option<Option<MediaType>> = None;
         let mut view_mode_clicked: Option<BrowserViewMode> = None;
+        let mut up_clicked = false;
+        let mut crumb_clicked: Option<usize> = None;
+        let mut search_changed: Option<String> = None;
+
+        // Breadcrumb segments: root plus each resolved folder
+        let crumb_font = egui::FontId::proportional(theme.font_size_sm);
+        let crumb_labels: Vec<&str> = std::iter::once("Root")
+            .chain(folders.iter().map(|f| f.name.as_str()))
+            .collect();
+        let mut crumb_rects: Vec<Rect> = Vec::new();
+        let nav_rect = Rect::from_min_size(
+            Pos2::new(rect.min.x, rect.min.y + bar_height),
+            Vec2::new(self.size.x, bar_height),
+        );
+        let up_rect = Rect::from_min_size(
+            Pos2::new(
+                nav_rect.min.x + theme.spacing_xs,
+                nav_rect.min.y + theme.spacing_xs,
+            ),
+            Vec2::new(30.0, bar_height - theme.spacing_sm),
+        );
 
         if self.show_toolbar {
-            let toolbar_rect =
-                Rect::from_min_size(rect.min, Vec2::new(self.size.x, toolbar_height));
+            let toolbar_rect = Rect::from_min_size(rect.min, Vec2::new(self.size.x, bar_height));
 
             // Filter buttons
             let filter_types = [
@@ -288,6 +400,49 @@ impl<'a> MediaBrowser<'a> {
                     view_mode_clicked = Some(*mode);
                 }
             }
+
+            // Up button and breadcrumbs
+            if !folders.is_empty() && ui.allocate_rect(up_rect, Sense::click()).clicked() {
+                up_clicked = true;
+            }
+            let mut x = up_rect.max.x + theme.spacing_sm;
+            for (depth, label) in crumb_labels.iter().enumerate() {
+                let width = ui
+                    .painter()
+                    .layout_no_wrap(label.to_string(), crumb_font.clone(), theme.text_primary)
+                    .size()
+                    .x;
+                let crumb_rect = Rect::from_min_size(
+                    Pos2::new(x, up_rect.min.y),
+                    Vec2::new(width + theme.spacing_xs * 2.0, up_rect.height()),
+                );
+                if depth < folders.len() && ui.allocate_rect(crumb_rect, Sense::click()).clicked() {
+                    crumb_clicked = Some(depth);
+                }
+                crumb_rects.push(crumb_rect);
+                x = crumb_rect.max.x + theme.spacing_md;
+            }
+
+            // Search field
+            let search_width = (self.size.x * 0.5).min(270.0);
+            let search_rect = Rect::from_min_max(
+                Pos2::new(nav_rect.max.x - search_width, nav_rect.min.y),
+                nav_rect.max,
+            );
+            let mut query = self.search.to_string();
+            ui.scope_builder(
+                egui::UiBuilder::new()
+                    .max_rect(search_rect)
+                    .layout(egui::Layout::left_to_right(egui::Align::Center)),
+                |ui| {
+                    SearchBar::new()
+                        .placeholder("Search media...")
+                        .show(ui, &mut query);
+                },
+            );
+            if query != self.search {
+                search_changed = Some(query);
+            }
         }
 
         // Content area
@@ -380,8 +535,7 @@ impl<'a> MediaBrowser<'a> {
 
         // Toolbar
         if self.show_toolbar {
-            let toolbar_rect =
-                Rect::from_min_size(rect.min, Vec2::new(self.size.x, toolbar_height));
+            let toolbar_rect = Rect::from_min_size(rect.min, Vec2::new(self.size.x, bar_height));
             painter.rect_filled(toolbar_rect, 0.0, theme.bg_tertiary);
 
             let filter_types: [(Option<MediaType>, &str); 4] = [
@@ -469,6 +623,47 @@ impl<'a> MediaBrowser<'a> {
                 egui::FontId::proportional(theme.font_size_xs),
                 theme.text_muted,
             );
+
+            // Up button and breadcrumbs
+            let up_color = if folders.is_empty() {
+                theme.text_muted
+            } else {
+                theme.text_secondary
+            };
+            painter.rect_filled(up_rect, theme.radius_sm, theme.bg_tertiary);
+            painter.text(
+                up_rect.center(),
+                egui::Align2::CENTER_CENTER,
+                "⬆",
+                egui::FontId::proportional(theme.font_size_sm),
+                up_color,
+            );
+            for (depth, (label, crumb_rect)) in crumb_labels.iter().zip(&crumb_rects).enumerate() {
+                let is_current = depth == folders.len();
+                if depth > 0 {
+                    painter.text(
+                        Pos2::new(
+                            crumb_rect.min.x - theme.spacing_md / 2.0,
+                            crumb_rect.center().y,
+                        ),
+                        egui::Align2::CENTER_CENTER,
+                        "›",
+                        crumb_font.clone(),
+                        theme.text_muted,
+                    );
+                }
+                painter.text(
+                    crumb_rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    *label,
+                    crumb_font.clone(),
+                    if is_current {
+                        theme.text_primary
+                    } else {
+                        theme.primary
+                    },
+                );
+            }
         }
 
         // Content background
@@ -494,11 +689,12 @@ impl<'a> MediaBrowser<'a> {
                     let thumb_rect =
                         Rect::from_min_size(info.rect.min, Vec2::splat(self.thumbnail_size));
 
-                    // Thumbnail or placeholder
+                    // Thumbnail (letterboxed to its aspect ratio) or placeholder
                     if let Some(tex) = item.thumbnail {
+                        painter.rect_filled(thumb_rect, theme.radius_sm, Color32::BLACK);
                         painter.image(
                             tex,
-                            thumb_rect,
+                            thumbnail_rect(ui.ctx(), tex, thumb_rect),
                             Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0)),
                             Color32::WHITE,
                         );
@@ -621,9 +817,13 @@ impl<'a> MediaBrowser<'a> {
         );
 
         // Process events
-        for info in item_infos.iter() {
+        for (info, item) in item_infos.iter().zip(filtered_items.iter()) {
             if info.double_clicked {
-                event = Some(MediaBrowserEvent::DoubleClick(info.id.clone()));
+                event = Some(if item.is_folder() {
+                    MediaBrowserEvent::EnterFolder(info.id.clone())
+                } else {
+                    MediaBrowserEvent::DoubleClick(info.id.clone())
+                });
                 break;
             }
             if info.clicked {
@@ -637,7 +837,13 @@ impl<'a> MediaBrowser<'a> {
         }
 
         if event.is_none() {
-            if let Some(filter) = toolbar_filter_clicked {
+            if up_clicked {
+                event = Some(MediaBrowserEvent::NavigateUp);
+            } else if let Some(depth) = crumb_clicked {
+                event = Some(MediaBrowserEvent::NavigateTo(depth));
+            } else if let Some(query) = search_changed {
+                event = Some(MediaBrowserEvent::SearchChange(query));
+            } else if let Some(filter) = toolbar_filter_clicked {
                 event = Some(MediaBrowserEvent::FilterChange(filter));
             } else if let Some(mode) = view_mode_clicked {
                 event = Some(MediaBrowserEvent::ViewModeChange(mode));
@@ -648,6 +854,13 @@ impl<'a> MediaBrowser<'a> {
     }
 }
 
+/// Where a thumbnail is painted inside its cell
+fn thumbnail_rect(ctx: &egui::Context, texture: TextureId, cell: Rect) -> Rect {
+    texture_size(ctx, texture)
+        .map(|size| fit_rect(size, cell))
+        .unwrap_or(cell)
+}
+
 fn format_duration(seconds: f32) -> String {
     let mins = (seconds / 60.0) as u32;
     let secs = (seconds % 60.0) as u32;
@@ -661,3 +874,117 @@ fn truncate_text(text: &str, max_chars: usize) -> String {
         format!("{}…", text.chars().take(max_chars - 1).collect::<String>())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{painted_texts, run_ui, test_context};
+
+    fn library() -> Vec<MediaItem> {
+        vec![
+            MediaItem::folder(
+                "Clips",
+                vec![
+                    MediaItem::new("intro", "Intro.mov").with_type(MediaType::Video),
+                    MediaItem::folder(
+                        "Loops",
+                        vec![MediaItem::new("kick", "KICK_loop.wav").with_type(MediaType::Audio)],
+                    ),
+                ],
+            ),
+            MediaItem::new("bg", "background.jpg"),
+            MediaItem::new("logo", "Logo.png"),
+        ]
+    }
+
+    fn ids(items: Vec<&MediaItem>) -> Vec<&str> {
+        items.into_iter().map(|i| i.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_entering_folder_shows_children() {
+        let items = library();
+        assert_eq!(
+            ids(MediaBrowser::new(&items).visible_items()),
+            vec!["Clips", "bg", "logo"]
+        );
+
+        let path = vec!["Clips".to_string()];
+        let browser = MediaBrowser::new(&items).path(&path);
+        assert_eq!(ids(browser.visible_items()), vec!["intro", "Loops"]);
+
+        let ctx = test_context();
+        let output = run_ui(&ctx, |ui| {
+            MediaBrowser::new(&items).path(&path).show(ui);
+        });
+        let texts = painted_texts(&output);
+        assert!(texts.iter().any(|t| t == "Intro.mov"));
+        assert!(!texts.iter().any(|t| t == "background.jpg"));
+        assert!(texts.iter().any(|t| t == "Root"));
+
+        // Unknown segments stop the walk at the deepest known folder
+        let path = vec!["Clips".to_string(), "Missing".to_string()];
+        let browser = MediaBrowser::new(&items).path(&path);
+        assert_eq!(ids(browser.visible_items()), vec!["intro", "Loops"]);
+    }
+
+    #[test]
+    fn test_search_filters_case_insensitively() {
+        let items = library();
+        let browser = MediaBrowser::new(&items).search("LOGO");
+        assert_eq!(ids(browser.visible_items()), vec!["logo"]);
+
+        // Searching reaches into subfolders of the current folder
+        let browser = MediaBrowser::new(&items).search("kick");
+        assert_eq!(ids(browser.visible_items()), vec!["kick"]);
+
+        let path = vec!["Clips".to_string()];
+        let browser = MediaBrowser::new(&items).path(&path).search("o");
+        assert_eq!(ids(browser.visible_items()), vec!["intro", "Loops", "kick"]);
+    }
+
+    #[test]
+    fn test_thumbnail_keeps_aspect_ratio() {
+        let ctx = test_context();
+        let texture = ctx.load_texture(
+            "thumb",
+            egui::ColorImage::filled([200, 100], Color32::RED),
+            Default::default(),
+        );
+        let items = vec![MediaItem::new("wide", "wide.png").with_thumbnail(texture.id())];
+
+        let output = run_ui(&ctx, |ui| {
+            MediaBrowser::new(&items).thumbnail_size(80.0).show(ui);
+        });
+        let image_rect = output
+            .shapes
+            .iter()
+            .find_map(|clipped| match &clipped.shape {
+                egui::Shape::Mesh(mesh) if mesh.texture_id == texture.id() => {
+                    Some(mesh.calc_bounds())
+                }
+                _ => None,
+            })
+            .expect("thumbnail painted");
+
+        assert!((image_rect.width() - 80.0).abs() < 0.01);
+        assert!((image_rect.height() - 40.0).abs() < 0.01);
+
+        let cell = thumbnail_rect(
+            &ctx,
+            texture.id(),
+            Rect::from_min_size(Pos2::ZERO, Vec2::splat(80.0)),
+        );
+        assert_eq!(
+            cell,
+            Rect::from_min_max(Pos2::new(0.0, 20.0), Pos2::new(80.0, 60.0))
+        );
+    }
+
+    #[test]
+    fn test_type_filter_keeps_folders() {
+        let items = library();
+        let browser = MediaBrowser::new(&items).filter(Some(MediaType::Video));
+        assert_eq!(ids(browser.visible_items()), vec!["Clips"]);
+    }
+}
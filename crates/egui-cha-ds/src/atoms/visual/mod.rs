@@ -14,13 +14,19 @@ mod preview;
 mod timeline;
 mod transform_gizmo;
 
-pub use clip_grid::{ClipCell, ClipGrid, ClipState};
+pub use clip_grid::{ClipCell, ClipGrid, ClipGridEvent, ClipState};
 pub use color_wheel::{ColorWheel, Hsva, WheelStyle};
 pub use gradient_editor::{
-    Gradient, GradientDirection, GradientEditor, GradientEvent, GradientStop,
+    Gradient, GradientDirection, GradientEditor, GradientEvent, GradientStop, Interp,
+    ParseGradientError,
+};
+pub use layer_stack::{
+    composite_layers, effective_opacity, effective_visible, reorder_layers, BlendMode, Layer,
+    LayerEvent, LayerStack,
+};
+pub use mask_editor::{
+    combined_coverage, CombineMode, Mask, MaskEditor, MaskEvent, MaskPoint, MaskShape,
 };
-pub use layer_stack::{BlendMode, Layer, LayerEvent, LayerStack};
-pub use mask_editor::{Mask, MaskEditor, MaskEvent, MaskPoint, MaskShape};
 pub use media_browser::{BrowserViewMode, MediaBrowser, MediaBrowserEvent, MediaItem, MediaType};
 pub use output_router::{
     OutputRouter, OutputType, RouteConnection, RouteOutput, RouteSource, RouterEvent, SourceType,
@@ -2,6 +2,9 @@
 //!
 //! A component for visualizing and controlling routing between sources and outputs.
 //! Supports multiple displays, NDI outputs, recording, and streaming destinations.
+//!
+//! Connections can be toggled by clicking matrix intersections, or by dragging
+//! from a source to an output (or back) with a live cable preview.
 
 use crate::Theme;
 use egui::{Color32, Pos2, Rect, Sense, Stroke, Ui, Vec2};
@@ -268,6 +271,8 @@ impl<'a> OutputRouter<'a> {
             node_pos: Pos2,
             clicked: bool,
             hovered: bool,
+            dragged: bool,
+            drag_stopped: bool,
         }
 
         struct OutputInfo {
@@ -277,6 +282,8 @@ impl<'a> OutputRouter<'a> {
             clicked: bool,
             hovered: bool,
             toggle_clicked: bool,
+            dragged: bool,
+            drag_stopped: bool,
         }
 
         struct MatrixNodeInfo {
@@ -307,13 +314,15 @@ impl<'a> OutputRouter<'a> {
             );
             let node_pos = Pos2::new(matrix_rect.min.x, y);
 
-            let resp = ui.allocate_rect(source_rect, Sense::click());
+            let resp = ui.allocate_rect(source_rect, Sense::click_and_drag());
             source_infos.push(SourceInfo {
                 id: source.id.clone(),
                 rect: source_rect,
                 node_pos,
                 clicked: resp.clicked(),
                 hovered: resp.hovered(),
+                dragged: resp.dragged(),
+                drag_stopped: resp.drag_stopped(),
             });
         }
 
@@ -332,7 +341,7 @@ impl<'a> OutputRouter<'a> {
             );
             let node_pos = Pos2::new(x, matrix_rect.max.y);
 
-            let resp = ui.allocate_rect(output_rect, Sense::click());
+            let resp = ui.allocate_rect(output_rect, Sense::click_and_drag());
 
             // Toggle button area
             let toggle_rect = Rect::from_center_size(
@@ -348,6 +357,8 @@ impl<'a> OutputRouter<'a> {
                 clicked: resp.clicked() && !toggle_resp.hovered(),
                 hovered: resp.hovered(),
                 toggle_clicked: toggle_resp.clicked(),
+                dragged: resp.dragged(),
+                drag_stopped: resp.drag_stopped(),
             });
         }
 
@@ -377,6 +388,15 @@ impl<'a> OutputRouter<'a> {
             }
         }
 
+        // Cable drag: from a source to an output, or from an output to a source
+        let pointer = ui.input(|i| i.pointer.latest_pos());
+        let drag_source = source_infos.iter().find(|s| s.dragged || s.drag_stopped);
+        let drag_output = output_infos.iter().find(|o| o.dragged || o.drag_stopped);
+        let drop_output = drag_source
+            .and_then(|_| pointer.and_then(|p| output_infos.iter().find(|o| o.rect.contains(p))));
+        let drop_source = drag_output
+            .and_then(|_| pointer.and_then(|p| source_infos.iter().find(|s| s.rect.contains(p))));
+
         // Drawing
         let painter = ui.painter();
 
@@ -575,6 +595,33 @@ impl<'a> OutputRouter<'a> {
             }
         }
 
+        // Live cable preview
+        if let Some(pointer) = pointer {
+            let cable = match (drag_source, drag_output) {
+                (Some(src), _) if src.dragged => {
+                    Some((src.node_pos, drop_output.map_or(pointer, |o| o.node_pos)))
+                }
+                (_, Some(out)) if out.dragged => {
+                    Some((drop_source.map_or(pointer, |s| s.node_pos), out.node_pos))
+                }
+                _ => None,
+            };
+            if let Some((from, to)) = cable {
+                let target = drop_output
+                    .map(|o| o.rect)
+                    .or_else(|| drop_source.map(|s| s.rect));
+                if let Some(target) = target {
+                    painter.rect_stroke(
+                        target,
+                        theme.radius_sm,
+                        Stroke::new(1.5, theme.primary),
+                        egui::StrokeKind::Inside,
+                    );
+                }
+                painter.add(cable_shape(from, to, Stroke::new(2.0, theme.primary)));
+            }
+        }
+
         // Border
         painter.rect_stroke(
             rect,
@@ -584,7 +631,31 @@ impl<'a> OutputRouter<'a> {
         );
 
         // Process events
-        for info in matrix_infos.iter() {
+        let dropped = match (drag_source, drop_output, drag_output, drop_source) {
+            (Some(src), Some(out), _, _) if src.drag_stopped => Some((&src.id, &out.id)),
+            (_, _, Some(out), Some(src)) if out.drag_stopped => Some((&src.id, &out.id)),
+            _ => None,
+        };
+        if let Some((source_id, output_id)) = dropped {
+            let connected = self
+                .connections
+                .iter()
+                .any(|c| &c.source_id == source_id && &c.output_id == output_id);
+            let (source_id, output_id) = (source_id.clone(), output_id.clone());
+            event = Some(if connected {
+                RouterEvent::Disconnect {
+                    source_id,
+                    output_id,
+                }
+            } else {
+                RouterEvent::Connect {
+                    source_id,
+                    output_id,
+                }
+            });
+        }
+
+        for info in matrix_infos.iter().filter(|_| event.is_none()) {
             if info.clicked {
                 if info.connected {
                     event = Some(RouterEvent::Disconnect {
@@ -626,3 +697,89 @@ impl<'a> OutputRouter<'a> {
         event
     }
 }
+
+/// Cable from a source node (leaving rightwards) to an output node (entering from above)
+fn cable_shape(from: Pos2, to: Pos2, stroke: Stroke) -> egui::Shape {
+    let reach = (from.distance(to) * 0.4).max(30.0);
+    egui::epaint::CubicBezierShape::from_points_stroke(
+        [
+            from,
+            from + Vec2::new(reach, 0.0),
+            to - Vec2::new(0.0, reach),
+            to,
+        ],
+        false,
+        Color32::TRANSPARENT,
+        stroke,
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{drag, run_ui, test_context};
+
+    fn routing() -> (Vec<RouteSource>, Vec<RouteOutput>) {
+        (
+            vec![
+                RouteSource::new("main", "Main"),
+                RouteSource::new("aux", "Aux").with_type(SourceType::Aux),
+            ],
+            vec![
+                RouteOutput::new("proj", "Projector"),
+                RouteOutput::new("rec", "Recorder").with_type(OutputType::Record),
+            ],
+        )
+    }
+
+    /// Center of the painted label `text`
+    fn label_center(output: &egui::FullOutput, text: &str) -> Pos2 {
+        output
+            .shapes
+            .iter()
+            .find_map(|clipped| match &clipped.shape {
+                egui::Shape::Text(t) if t.galley.text() == text => {
+                    Some(t.visual_bounding_rect().center())
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("label {text} not painted"))
+    }
+
+    fn drag_between(connections: &[RouteConnection], from: &str, to: &str) -> Vec<RouterEvent> {
+        let (sources, outputs) = routing();
+        let ctx = test_context();
+        let output = run_ui(&ctx, |ui| {
+            OutputRouter::new(&sources, &outputs, connections).show(ui);
+        });
+        let (from, to) = (label_center(&output, from), label_center(&output, to));
+
+        let mut events = Vec::new();
+        drag(&ctx, from, to, |ui| {
+            events.extend(OutputRouter::new(&sources, &outputs, connections).show(ui));
+        });
+        events
+    }
+
+    #[test]
+    fn test_drag_source_to_output_connects() {
+        let events = drag_between(&[], "Main", "Projector");
+        assert!(matches!(
+            events.as_slice(),
+            [RouterEvent::Connect { source_id, output_id }]
+                if source_id == "main" && output_id == "proj"
+        ));
+    }
+
+    #[test]
+    fn test_drag_onto_existing_connection_disconnects() {
+        let connections = [RouteConnection::new("aux", "rec")];
+        let events = drag_between(&connections, "Recorder", "Aux");
+        assert!(matches!(
+            events.as_slice(),
+            [RouterEvent::Disconnect { source_id, output_id }]
+                if source_id == "aux" && output_id == "rec"
+        ));
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! A preview component for displaying video frames, images, or thumbnails
 //! with optional overlays for timecode, playback state, and labels.
+//! Textures are fitted to the frame with letterboxing, keeping their aspect
+//! ratio.
 //!
 //! # Example
 //! ```ignore
@@ -130,6 +132,12 @@ impl<'a> Preview<'a> {
         }
     }
 
+    /// Set the texture to display (replaces the placeholder)
+    pub fn texture(mut self, texture: TextureId) -> Self {
+        self.texture = Some(texture);
+        self
+    }
+
     /// Set size
     pub fn size(mut self, width: f32, height: f32) -> Self {
         self.size = Vec2::new(width, height);
@@ -250,9 +258,15 @@ impl<'a> Preview<'a> {
 
         // Draw texture if available
         if let Some(texture) = self.texture {
+            let image_rect = texture_size(ui.ctx(), texture)
+                .map(|size| fit_rect(size, rect))
+                .unwrap_or(rect);
+            if image_rect != rect {
+                painter.rect_filled(rect, theme.radius_sm, Color32::BLACK);
+            }
             painter.image(
                 texture,
-                rect,
+                image_rect,
                 Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
                 Color32::WHITE,
             );
@@ -468,3 +482,40 @@ impl<'a> Preview<'a> {
         event
     }
 }
+
+/// Largest rect with the aspect ratio of `content` centered in `bounds`
+pub(crate) fn fit_rect(content: Vec2, bounds: Rect) -> Rect {
+    if content.x <= 0.0 || content.y <= 0.0 {
+        return bounds;
+    }
+    let scale = (bounds.width() / content.x).min(bounds.height() / content.y);
+    Rect::from_center_size(bounds.center(), content * scale)
+}
+
+/// Pixel size of a texture egui manages; `None` for user textures
+pub(crate) fn texture_size(ctx: &egui::Context, texture: TextureId) -> Option<Vec2> {
+    let meta = ctx.tex_manager().read().meta(texture).cloned()?;
+    Some(Vec2::new(meta.size[0] as f32, meta.size[1] as f32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::Pos2;
+
+    #[test]
+    fn test_fit_rect_letterboxes() {
+        let bounds = Rect::from_min_size(Pos2::ZERO, Vec2::new(160.0, 90.0));
+
+        // Square content: pillarboxed
+        let fitted = fit_rect(Vec2::new(100.0, 100.0), bounds);
+        assert_eq!(fitted.size(), Vec2::new(90.0, 90.0));
+        assert_eq!(fitted.center(), bounds.center());
+
+        // Wider content: letterboxed
+        let fitted = fit_rect(Vec2::new(320.0, 90.0), bounds);
+        assert_eq!(fitted.size(), Vec2::new(160.0, 45.0));
+
+        assert_eq!(fit_rect(Vec2::ZERO, bounds), bounds);
+    }
+}
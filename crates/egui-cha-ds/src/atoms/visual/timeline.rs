@@ -7,11 +7,17 @@
 //! Timeline::new(model.duration)
 //!     .position(model.position)
 //!     .markers(&model.markers)
+//!     .format(TimeFormat::Smpte { fps: 30 })
 //!     .show_with(ctx, |event| match event {
 //!         TimelineEvent::Seek(pos) => Msg::Seek(pos),
 //!         TimelineEvent::MarkerClick(idx) => Msg::JumpToMarker(idx),
 //!     });
 //! ```
+//!
+//! # Editing regions
+//! With [`Timeline::editable_regions`], dragging on the empty track creates a
+//! region, dragging a region's edge resizes it and dragging its body moves it.
+//! Scrubbing then happens by dragging the playhead; clicks still seek.
 
 use crate::Theme;
 use egui::{Color32, Rect, Sense, Stroke, Ui, Vec2};
@@ -28,8 +34,30 @@ pub enum TimelineEvent {
     MarkerClick(usize),
     /// Region selected (start, end in normalized 0.0-1.0)
     RegionSelect(f64, f64),
+    /// A region was drawn on the empty track (emitted on release)
+    RegionCreated { start: f64, end: f64 },
+    /// A region edge is being dragged
+    RegionResized { index: usize, start: f64, end: f64 },
+    /// A region is being dragged as a whole
+    RegionMoved { index: usize, start: f64, end: f64 },
+}
+
+/// What an in-progress drag on the track is doing
+#[derive(Clone, Copy, Debug)]
+enum RegionDrag {
+    /// Scrubbing the playhead
+    Scrub,
+    /// Drawing a new region from `anchor`
+    Create { anchor: f64 },
+    /// Dragging one edge of a region
+    Resize { index: usize, start_edge: bool },
+    /// Dragging a region, grabbed `grab` after its start
+    Move { index: usize, grab: f64 },
 }
 
+/// Pixel distance within which a press grabs a region edge or the playhead
+const GRAB_DISTANCE: f32 = 5.0;
+
 /// A marker on the timeline
 #[derive(Debug, Clone)]
 pub struct TimelineMarker {
@@ -85,18 +113,76 @@ impl TimelineRegion {
     }
 }
 
-/// Time display format
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Time display format, used for the readout and tick labels
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum TimeFormat {
     /// Seconds only (e.g., "42.5")
     Seconds,
-    /// Minutes:Seconds (e.g., "1:30")
+    /// Minutes:Seconds (e.g., "1:30.00")
     #[default]
-    MinutesSeconds,
-    /// Hours:Minutes:Seconds (e.g., "1:30:00")
+    MinSec,
+    /// Hours:Minutes:Seconds (e.g., "1:30:00.00")
     HoursMinutesSeconds,
-    /// Bars:Beats (for music, requires BPM)
-    BarsBeat,
+    /// SMPTE timecode `HH:MM:SS:FF` (non-drop-frame)
+    Smpte { fps: u32 },
+    /// Bars:Beats (e.g., "5:1"), 1-based
+    ///
+    /// `bpm` counts beats of the signature's note value; `sig` is
+    /// (beats per bar, note value), e.g. `(4, 4)` or `(6, 8)`.
+    BarsBeats { bpm: f32, sig: (u32, u32) },
+}
+
+impl TimeFormat {
+    /// Format a time in seconds
+    pub fn format(&self, seconds: f64) -> String {
+        let seconds = seconds.max(0.0);
+        match *self {
+            TimeFormat::Seconds => format!("{:.1}", seconds),
+            TimeFormat::MinSec => {
+                let mins = (seconds / 60.0).floor() as u32;
+                let secs = seconds % 60.0;
+                format!("{}:{:05.2}", mins, secs)
+            }
+            TimeFormat::HoursMinutesSeconds => {
+                let hours = (seconds / 3600.0).floor() as u32;
+                let mins = ((seconds % 3600.0) / 60.0).floor() as u32;
+                let secs = seconds % 60.0;
+                format!("{}:{:02}:{:05.2}", hours, mins, secs)
+            }
+            TimeFormat::Smpte { fps } => {
+                let fps = fps.max(1) as u64;
+                // Count whole frames first so rounding never shows frame == fps
+                let frames = (seconds * fps as f64 + 1e-6).floor() as u64;
+                let total_secs = frames / fps;
+                format!(
+                    "{:02}:{:02}:{:02}:{:02}",
+                    total_secs / 3600,
+                    (total_secs % 3600) / 60,
+                    total_secs % 60,
+                    frames % fps
+                )
+            }
+            TimeFormat::BarsBeats { bpm, sig } => {
+                let beats_per_bar = sig.0.max(1) as u64;
+                let beats = (seconds * bpm.max(f32::EPSILON) as f64 / 60.0 + 1e-9).floor() as u64;
+                format!(
+                    "{}:{}",
+                    beats / beats_per_bar + 1,
+                    beats % beats_per_bar + 1
+                )
+            }
+        }
+    }
+
+    /// Tick spacing and major tick period this format prefers, if any
+    fn tick_grid(&self) -> Option<(f64, usize)> {
+        match *self {
+            TimeFormat::BarsBeats { bpm, sig } if bpm > 0.0 => {
+                Some((60.0 / bpm as f64, sig.0.max(1) as usize))
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Timeline component
@@ -108,10 +194,10 @@ pub struct Timeline<'a> {
     height: f32,
     show_time: bool,
     time_format: TimeFormat,
-    bpm: Option<f32>,
     show_ticks: bool,
     tick_interval: Option<f64>,
     loop_region: Option<(f64, f64)>,
+    editable_regions: bool,
 }
 
 impl<'a> Timeline<'a> {
@@ -125,10 +211,10 @@ impl<'a> Timeline<'a> {
             height: 32.0,
             show_time: true,
             time_format: TimeFormat::default(),
-            bpm: None,
             show_ticks: true,
             tick_interval: None,
             loop_region: None,
+            editable_regions: false,
         }
     }
 
@@ -168,18 +254,12 @@ impl<'a> Timeline<'a> {
         self
     }
 
-    /// Set time format
-    pub fn time_format(mut self, format: TimeFormat) -> Self {
+    /// Set the time format for the readout and tick labels
+    pub fn format(mut self, format: TimeFormat) -> Self {
         self.time_format = format;
         self
     }
 
-    /// Set BPM for musical time display
-    pub fn bpm(mut self, bpm: f32) -> Self {
-        self.bpm = Some(bpm);
-        self
-    }
-
     /// Show/hide tick marks
     pub fn show_ticks(mut self, show: bool) -> Self {
         self.show_ticks = show;
@@ -198,6 +278,73 @@ impl<'a> Timeline<'a> {
         self
     }
 
+    /// Create, resize and move regions by dragging (see module docs)
+    pub fn editable_regions(mut self, editable: bool) -> Self {
+        self.editable_regions = editable;
+        self
+    }
+
+    /// Decide what a drag starting at `x` on the track does
+    fn hit_test(&self, track_rect: Rect, x: f32) -> RegionDrag {
+        let to_x = |pos: f64| track_rect.min.x + pos as f32 * track_rect.width();
+        let pos = ((x - track_rect.min.x) / track_rect.width()).clamp(0.0, 1.0) as f64;
+
+        // Later regions are drawn on top, so they win
+        for (index, region) in self.regions.iter().enumerate().rev() {
+            if (x - to_x(region.start)).abs() <= GRAB_DISTANCE {
+                return RegionDrag::Resize {
+                    index,
+                    start_edge: true,
+                };
+            }
+            if (x - to_x(region.end)).abs() <= GRAB_DISTANCE {
+                return RegionDrag::Resize {
+                    index,
+                    start_edge: false,
+                };
+            }
+        }
+        if (x - to_x(self.position)).abs() <= GRAB_DISTANCE {
+            return RegionDrag::Scrub;
+        }
+        for (index, region) in self.regions.iter().enumerate().rev() {
+            if pos > region.start && pos < region.end {
+                return RegionDrag::Move {
+                    index,
+                    grab: pos - region.start,
+                };
+            }
+        }
+        RegionDrag::Create { anchor: pos }
+    }
+
+    /// Event for a region drag with the pointer at normalized `pos`
+    fn region_drag_event(&self, drag: RegionDrag, pos: f64) -> Option<TimelineEvent> {
+        match drag {
+            RegionDrag::Scrub => Some(TimelineEvent::Seek(pos)),
+            RegionDrag::Create { .. } => None,
+            RegionDrag::Resize { index, start_edge } => {
+                let region = self.regions.get(index)?;
+                let (start, end) = if start_edge {
+                    (pos.min(region.end), region.end)
+                } else {
+                    (region.start, pos.max(region.start))
+                };
+                Some(TimelineEvent::RegionResized { index, start, end })
+            }
+            RegionDrag::Move { index, grab } => {
+                let region = self.regions.get(index)?;
+                let length = region.end - region.start;
+                let start = (pos - grab).clamp(0.0, (1.0 - length).max(0.0));
+                Some(TimelineEvent::RegionMoved {
+                    index,
+                    start,
+                    end: start + length,
+                })
+            }
+        }
+    }
+
     /// TEA-style: Show timeline and emit events
     pub fn show_with<Msg>(
         self,
@@ -218,8 +365,13 @@ impl<'a> Timeline<'a> {
         let theme = Theme::current(ui.ctx());
         let mut event = None;
 
-        // Calculate dimensions
-        let time_width = if self.show_time { 60.0 } else { 0.0 };
+        // Calculate dimensions; wide enough for the longest readout
+        let time_width = if self.show_time {
+            let chars = self.format_time(self.duration).chars().count() as f32;
+            (chars * theme.font_size_sm * 0.62 + theme.spacing_sm).max(60.0)
+        } else {
+            0.0
+        };
         let available_width = ui.available_width();
         let track_width = available_width - time_width - theme.spacing_sm;
 
@@ -237,8 +389,55 @@ impl<'a> Timeline<'a> {
             Vec2::new(track_width, self.height),
         );
 
+        let to_norm = |x: f32| ((x - track_rect.min.x) / track_rect.width()).clamp(0.0, 1.0) as f64;
+        let drag_id = response.id.with("region_drag");
+        let mut creating: Option<(f64, f64)> = None;
+
+        // Region editing
+        if self.editable_regions {
+            let pointer = ui.input(|i| i.pointer.latest_pos());
+            if response.drag_started() {
+                let origin = ui.input(|i| i.pointer.press_origin()).or(pointer);
+                let drag = origin
+                    .filter(|p| track_rect.contains(*p))
+                    .map(|p| self.hit_test(track_rect, p.x));
+                ui.ctx().data_mut(|d| d.insert_temp(drag_id, drag));
+            }
+
+            let drag: Option<RegionDrag> = ui.ctx().data(|d| d.get_temp(drag_id)).flatten();
+            if let (Some(drag), Some(pointer)) = (drag, pointer) {
+                let pos = to_norm(pointer.x);
+                if response.dragged() {
+                    event = self.region_drag_event(drag, pos);
+                    if let RegionDrag::Create { anchor } = drag {
+                        creating = Some((anchor.min(pos), anchor.max(pos)));
+                    }
+                } else if response.drag_stopped() {
+                    if let RegionDrag::Create { anchor } = drag {
+                        let (start, end) = (anchor.min(pos), anchor.max(pos));
+                        if end > start {
+                            event = Some(TimelineEvent::RegionCreated { start, end });
+                        }
+                    }
+                    ui.ctx()
+                        .data_mut(|d| d.remove::<Option<RegionDrag>>(drag_id));
+                }
+            }
+
+            // Resize cursor over region edges
+            if let Some(hover) = response.hover_pos() {
+                if matches!(
+                    self.hit_test(track_rect, hover.x),
+                    RegionDrag::Resize { .. }
+                ) {
+                    ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeHorizontal);
+                }
+            }
+        }
+
         // Handle seek interaction
-        if response.clicked() || response.dragged() {
+        let scrubbing = response.dragged() && !self.editable_regions;
+        if response.clicked() || scrubbing {
             if let Some(pos) = response.interact_pointer_pos() {
                 if track_rect.contains(pos) {
                     let normalized = ((pos.x - track_rect.min.x) / track_rect.width()) as f64;
@@ -296,6 +495,31 @@ impl<'a> Timeline<'a> {
             painter.rect_filled(region_rect, theme.radius_sm * 0.5, region.color);
         }
 
+        // Draw region being created
+        if let Some((start, end)) = creating {
+            let preview_rect = Rect::from_min_max(
+                egui::pos2(
+                    track_rect.min.x + start as f32 * track_rect.width(),
+                    track_rect.min.y,
+                ),
+                egui::pos2(
+                    track_rect.min.x + end as f32 * track_rect.width(),
+                    track_rect.max.y,
+                ),
+            );
+            painter.rect_filled(
+                preview_rect,
+                theme.radius_sm * 0.5,
+                theme.primary.gamma_multiply(0.25),
+            );
+            painter.rect_stroke(
+                preview_rect,
+                theme.radius_sm * 0.5,
+                Stroke::new(1.0, theme.primary),
+                egui::StrokeKind::Inside,
+            );
+        }
+
         // Draw loop region
         if let Some((start, end)) = self.loop_region {
             let start_x = track_rect.min.x + (start as f32) * track_rect.width();
@@ -331,10 +555,10 @@ impl<'a> Timeline<'a> {
 
         // Draw tick marks
         if self.show_ticks {
-            let interval = self
-                .tick_interval
-                .unwrap_or_else(|| self.auto_tick_interval());
+            let (interval, major_every) = self.tick_layout(track_rect.width());
             let num_ticks = (self.duration / interval).ceil() as usize;
+            let label_font = egui::FontId::proportional(theme.font_size_xs * 0.8);
+            let show_labels = self.height >= 24.0;
 
             for i in 0..=num_ticks {
                 let time = i as f64 * interval;
@@ -342,7 +566,7 @@ impl<'a> Timeline<'a> {
                     break;
                 }
                 let x = track_rect.min.x + (time / self.duration) as f32 * track_rect.width();
-                let is_major = i % 4 == 0;
+                let is_major = i % major_every == 0;
                 let tick_height = if is_major { 8.0 } else { 4.0 };
                 let tick_color = if is_major {
                     theme.text_muted
@@ -362,6 +586,16 @@ impl<'a> Timeline<'a> {
                     ],
                     Stroke::new(1.0, tick_color),
                 );
+
+                if is_major && show_labels && i > 0 {
+                    painter.text(
+                        egui::pos2(x + 2.0, track_rect.max.y - 1.0),
+                        egui::Align2::LEFT_BOTTOM,
+                        self.format_time(time),
+                        label_font.clone(),
+                        theme.text_muted,
+                    );
+                }
             }
         }
 
@@ -430,30 +664,25 @@ impl<'a> Timeline<'a> {
     }
 
     fn format_time(&self, seconds: f64) -> String {
-        match self.time_format {
-            TimeFormat::Seconds => format!("{:.1}", seconds),
-            TimeFormat::MinutesSeconds => {
-                let mins = (seconds / 60.0).floor() as u32;
-                let secs = seconds % 60.0;
-                format!("{}:{:05.2}", mins, secs)
-            }
-            TimeFormat::HoursMinutesSeconds => {
-                let hours = (seconds / 3600.0).floor() as u32;
-                let mins = ((seconds % 3600.0) / 60.0).floor() as u32;
-                let secs = seconds % 60.0;
-                format!("{}:{:02}:{:05.2}", hours, mins, secs)
-            }
-            TimeFormat::BarsBeat => {
-                if let Some(bpm) = self.bpm {
-                    let beats_per_second = bpm as f64 / 60.0;
-                    let total_beats = seconds * beats_per_second;
-                    let bars = (total_beats / 4.0).floor() as u32 + 1;
-                    let beat = (total_beats % 4.0).floor() as u32 + 1;
-                    format!("{}:{}", bars, beat)
+        self.time_format.format(seconds)
+    }
+
+    /// Tick interval in seconds and how many ticks make a major (labelled) one
+    fn tick_layout(&self, track_width: f32) -> (f64, usize) {
+        if let Some(interval) = self.tick_interval {
+            return (interval, 4);
+        }
+        match self.time_format.tick_grid() {
+            // Beat ticks with bar majors, thinned to bars when beats get too dense
+            Some((beat, beats_per_bar)) => {
+                let beats = self.duration / beat;
+                if beats > (track_width / 6.0) as f64 {
+                    (beat * beats_per_bar as f64, 4)
                 } else {
-                    format!("{:.1}", seconds)
+                    (beat, beats_per_bar)
                 }
             }
+            None => (self.auto_tick_interval(), 4),
         }
     }
 
@@ -471,3 +700,137 @@ impl<'a> Timeline<'a> {
             .unwrap_or(60.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{drag, painted_texts, run_ui, test_context};
+
+    #[test]
+    fn test_smpte_format() {
+        let format = TimeFormat::Smpte { fps: 30 };
+        assert_eq!(format.format(90.0), "00:01:30:00");
+        assert_eq!(format.format(3725.5), "01:02:05:15");
+        // Just under a second stays on the last frame
+        assert_eq!(format.format(0.999), "00:00:00:29");
+    }
+
+    #[test]
+    fn test_bars_beats_format() {
+        let format = TimeFormat::BarsBeats {
+            bpm: 120.0,
+            sig: (4, 4),
+        };
+        // 2 beats per second: 8s = 16 beats = start of bar 5
+        assert_eq!(format.format(8.0), "5:1");
+        assert_eq!(format.format(9.5), "5:4");
+        assert_eq!(format.format(0.0), "1:1");
+
+        let waltz = TimeFormat::BarsBeats {
+            bpm: 60.0,
+            sig: (3, 4),
+        };
+        assert_eq!(waltz.format(4.0), "2:2");
+    }
+
+    #[test]
+    fn test_other_formats() {
+        assert_eq!(TimeFormat::Seconds.format(42.54), "42.5");
+        assert_eq!(TimeFormat::MinSec.format(90.0), "1:30.00");
+        assert_eq!(TimeFormat::HoursMinutesSeconds.format(5400.0), "1:30:00.00");
+    }
+
+    /// Drag across the track of an editable timeline between normalized positions
+    fn drag_track(
+        regions: &[TimelineRegion],
+        position: f64,
+        from: f64,
+        to: f64,
+    ) -> Vec<TimelineEvent> {
+        let ctx = test_context();
+        let timeline = || {
+            Timeline::new(10.0)
+                .position(position)
+                .regions(regions)
+                .show_time(false)
+                .show_ticks(false)
+                .editable_regions(true)
+        };
+        let mut rect = Rect::NOTHING;
+        run_ui(&ctx, |ui| {
+            rect = ui.scope(|ui| timeline().show(ui)).response.rect;
+        });
+
+        let theme = Theme::current(&ctx);
+        let track_min = rect.min.x + theme.spacing_sm;
+        let track_width = rect.width() - theme.spacing_sm;
+        let at = |t: f64| egui::pos2(track_min + t as f32 * track_width, rect.center().y);
+
+        let mut events = Vec::new();
+        drag(&ctx, at(from), at(to), |ui| {
+            events.extend(timeline().show(ui));
+        });
+        events
+    }
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn test_drag_on_empty_track_creates_region() {
+        let events = drag_track(&[], 0.0, 0.2, 0.4);
+        assert!(matches!(
+            events.last(),
+            Some(TimelineEvent::RegionCreated { start, end })
+                if approx(*start, 0.2) && approx(*end, 0.4)
+        ));
+        assert_eq!(
+            events
+                .iter()
+                .filter(|e| matches!(e, TimelineEvent::RegionCreated { .. }))
+                .count(),
+            1
+        );
+
+        // Dragging right-to-left normalizes the range
+        let events = drag_track(&[], 0.0, 0.6, 0.5);
+        assert!(matches!(
+            events.last(),
+            Some(TimelineEvent::RegionCreated { start, end })
+                if approx(*start, 0.5) && approx(*end, 0.6)
+        ));
+    }
+
+    #[test]
+    fn test_drag_region_edge_and_body() {
+        let regions = [TimelineRegion::new(0.3, 0.5, Color32::RED)];
+
+        let events = drag_track(&regions, 0.0, 0.5, 0.7);
+        assert!(matches!(
+            events.last(),
+            Some(TimelineEvent::RegionResized { index: 0, start, end })
+                if approx(*start, 0.3) && approx(*end, 0.7)
+        ));
+
+        // Moving is clamped so the region stays on the track
+        let events = drag_track(&regions, 0.0, 0.4, 0.95);
+        assert!(matches!(
+            events.last(),
+            Some(TimelineEvent::RegionMoved { index: 0, start, end })
+                if approx(*start, 0.8) && approx(*end, 1.0)
+        ));
+    }
+
+    #[test]
+    fn test_readout_uses_format() {
+        let ctx = test_context();
+        let output = run_ui(&ctx, |ui| {
+            Timeline::new(120.0)
+                .position_seconds(90.0)
+                .format(TimeFormat::Smpte { fps: 30 })
+                .show(ui);
+        });
+        assert!(painted_texts(&output).iter().any(|t| t == "00:01:30:00"));
+    }
+}
@@ -9,15 +9,21 @@
 //! - Scale handles (corners and edges)
 //! - Uniform/non-uniform scale modes
 //! - Pivot point display
+//! - Arrow-key nudging (Shift for larger steps) once the gizmo is focused
+//! - Optional editable numeric readout
+//! - Rotation snapping
 //! - Theme-aware styling
 //!
 //! # Example
 //! ```ignore
 //! TransformGizmo::new()
+//!     .show_readout(true)
+//!     .snap_rotation(15.0)
 //!     .show_with(ctx, &transform, |event| match event {
 //!         TransformEvent::Translate(delta) => Msg::Move(delta),
 //!         TransformEvent::Rotate(angle) => Msg::Rotate(angle),
 //!         TransformEvent::Scale(scale) => Msg::Scale(scale),
+//!         _ => Msg::Noop,
 //!     });
 //! ```
 
@@ -90,8 +96,11 @@ pub enum GizmoHandle {
 /// Transform events emitted by the gizmo
 #[derive(Debug, Clone, Copy)]
 pub enum TransformEvent {
+    /// Move by a delta (from dragging, arrow keys or the readout)
     Translate(Vec2),
+    /// Rotate by a delta in radians
     Rotate(f32),
+    /// Set the scale
     Scale(Vec2),
     DragStart(GizmoHandle),
     DragEnd,
@@ -116,6 +125,10 @@ pub struct TransformGizmo {
     uniform_scale: bool,
     mode: TransformMode,
     show_pivot: bool,
+    show_readout: bool,
+    nudge_step: f32,
+    nudge_step_large: f32,
+    snap_rotation: Option<f32>,
 }
 
 impl TransformGizmo {
@@ -128,6 +141,10 @@ impl TransformGizmo {
             uniform_scale: false,
             mode: TransformMode::All,
             show_pivot: true,
+            show_readout: false,
+            nudge_step: 1.0,
+            nudge_step_large: 10.0,
+            snap_rotation: None,
         }
     }
 
@@ -166,6 +183,34 @@ impl TransformGizmo {
         self
     }
 
+    /// Replace the info line with editable x/y/rotation/scale fields
+    pub fn show_readout(mut self, show: bool) -> Self {
+        self.show_readout = show;
+        self
+    }
+
+    /// Arrow-key nudge distance, and the distance with Shift held
+    pub fn nudge_step(mut self, step: f32, large_step: f32) -> Self {
+        self.nudge_step = step;
+        self.nudge_step_large = large_step;
+        self
+    }
+
+    /// Snap rotation to multiples of `degrees`
+    pub fn snap_rotation(mut self, degrees: f32) -> Self {
+        self.snap_rotation = (degrees > 0.0).then(|| degrees.to_radians());
+        self
+    }
+
+    /// Rotation delta that takes `current` to `target`, snapped if enabled
+    fn rotation_delta(&self, current: f32, target: f32) -> f32 {
+        let target = match self.snap_rotation {
+            Some(step) => snap_angle(target, step),
+            None => target,
+        };
+        target - current
+    }
+
     pub fn show_with<Msg>(
         self,
         ctx: &mut ViewCtx<'_, Msg>,
@@ -188,18 +233,22 @@ impl TransformGizmo {
 
         let scaled_size = self.size * transform.scale;
 
-        let (rect, _response) = ui.allocate_exact_size(
+        let (rect, response) = ui.allocate_exact_size(
             Vec2::new(
                 self.size.x + self.handle_size * 4.0,
                 self.size.y + self.handle_size * 4.0 + 30.0,
             ),
-            Sense::hover(),
+            Sense::click(),
         );
 
         if !ui.is_rect_visible(rect) {
             return events;
         }
 
+        if response.clicked() {
+            response.request_focus();
+        }
+
         let center = rect.center() + transform.position;
         let half_w = scaled_size.x / 2.0;
         let half_h = scaled_size.y / 2.0;
@@ -234,6 +283,39 @@ impl TransformGizmo {
             self.show_scale && matches!(self.mode, TransformMode::All | TransformMode::ScaleOnly);
         let can_translate = matches!(self.mode, TransformMode::All | TransformMode::TranslateOnly);
 
+        // Arrow-key nudging while focused
+        let mut nudge = Vec2::ZERO;
+        if response.has_focus() && can_translate {
+            // Keep arrows from moving focus to the next widget
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    response.id,
+                    egui::EventFilter {
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        ..Default::default()
+                    },
+                )
+            });
+            ui.input_mut(|i| {
+                for (key, dir) in [
+                    (egui::Key::ArrowLeft, Vec2::new(-1.0, 0.0)),
+                    (egui::Key::ArrowRight, Vec2::new(1.0, 0.0)),
+                    (egui::Key::ArrowUp, Vec2::new(0.0, -1.0)),
+                    (egui::Key::ArrowDown, Vec2::new(0.0, 1.0)),
+                ] {
+                    let step = if i.consume_key(egui::Modifiers::SHIFT, key) {
+                        self.nudge_step_large
+                    } else if i.consume_key(egui::Modifiers::NONE, key) {
+                        self.nudge_step
+                    } else {
+                        continue;
+                    };
+                    nudge += dir * step;
+                }
+            });
+        }
+
         // First pass: collect interactions
         struct HandleInfo {
             handle: GizmoHandle,
@@ -501,22 +583,41 @@ impl TransformGizmo {
             );
         }
 
+        if response.has_focus() {
+            painter.rect_stroke(
+                rect,
+                theme.radius_sm,
+                Stroke::new(theme.stroke_width, primary_color.gamma_multiply(0.5)),
+                egui::StrokeKind::Inside,
+            );
+        }
+
         // Info display
-        let info_text = format!(
-            "P({:.0},{:.0}) R:{:.1}° S({:.2},{:.2})",
-            transform.position.x,
-            transform.position.y,
-            transform.rotation.to_degrees(),
-            transform.scale.x,
-            transform.scale.y,
-        );
-        painter.text(
-            Pos2::new(rect.center().x, rect.max.y - theme.font_size_xs),
-            egui::Align2::CENTER_BOTTOM,
-            &info_text,
-            egui::FontId::proportional(theme.font_size_xs),
-            theme.text_muted,
-        );
+        if self.show_readout {
+            let readout_rect =
+                Rect::from_min_max(Pos2::new(rect.min.x, rect.max.y - 30.0), rect.max);
+            self.readout(ui, readout_rect, transform, &mut events);
+        } else {
+            let info_text = format!(
+                "P({:.0},{:.0}) R:{:.1}° S({:.2},{:.2})",
+                transform.position.x,
+                transform.position.y,
+                transform.rotation.to_degrees(),
+                transform.scale.x,
+                transform.scale.y,
+            );
+            ui.painter().text(
+                Pos2::new(rect.center().x, rect.max.y - theme.font_size_xs),
+                egui::Align2::CENTER_BOTTOM,
+                &info_text,
+                egui::FontId::proportional(theme.font_size_xs),
+                theme.text_muted,
+            );
+        }
+
+        if nudge != Vec2::ZERO {
+            events.push(TransformEvent::Translate(nudge));
+        }
 
         // Process handle events
         for info in handles.iter() {
@@ -532,7 +633,7 @@ impl TransformGizmo {
                         if let Some(pos) = info.interact_pos {
                             let to_cursor = pos - center;
                             let angle = to_cursor.y.atan2(to_cursor.x) + PI / 2.0;
-                            let delta = angle - transform.rotation;
+                            let delta = self.rotation_delta(transform.rotation, angle);
                             events.push(TransformEvent::Rotate(delta));
                         }
                     }
@@ -608,6 +709,86 @@ impl TransformGizmo {
 
         events
     }
+
+    /// Editable x/y/rotation/scale fields laid out in `rect`
+    fn readout(
+        &self,
+        ui: &mut Ui,
+        rect: Rect,
+        transform: &Transform2D,
+        events: &mut Vec<TransformEvent>,
+    ) {
+        let theme = Theme::current(ui.ctx());
+        let can_translate = matches!(self.mode, TransformMode::All | TransformMode::TranslateOnly);
+        let can_rotate = matches!(self.mode, TransformMode::All | TransformMode::RotateOnly);
+        let can_scale = matches!(self.mode, TransformMode::All | TransformMode::ScaleOnly);
+
+        let builder = egui::UiBuilder::new()
+            .max_rect(rect)
+            .layout(egui::Layout::left_to_right(egui::Align::Center));
+        ui.scope_builder(builder, |ui| {
+            ui.spacing_mut().item_spacing.x = theme.spacing_xs;
+            let label = |ui: &mut Ui, text: &str| {
+                ui.label(
+                    egui::RichText::new(text)
+                        .size(theme.font_size_xs)
+                        .color(theme.text_muted),
+                );
+            };
+
+            let mut x = transform.position.x;
+            let mut y = transform.position.y;
+            label(ui, "X");
+            let x_changed = ui
+                .add_enabled(can_translate, egui::DragValue::new(&mut x).speed(1.0))
+                .changed();
+            label(ui, "Y");
+            let y_changed = ui
+                .add_enabled(can_translate, egui::DragValue::new(&mut y).speed(1.0))
+                .changed();
+            if x_changed || y_changed {
+                events.push(TransformEvent::Translate(Vec2::new(
+                    x - transform.position.x,
+                    y - transform.position.y,
+                )));
+            }
+
+            let mut degrees = transform.rotation.to_degrees();
+            label(ui, "R");
+            let rotated = ui
+                .add_enabled(
+                    can_rotate,
+                    egui::DragValue::new(&mut degrees).speed(1.0).suffix("°"),
+                )
+                .changed();
+            if rotated {
+                let delta = self.rotation_delta(transform.rotation, degrees.to_radians());
+                events.push(TransformEvent::Rotate(delta));
+            }
+
+            let mut scale = transform.scale.x;
+            label(ui, "S");
+            let scaled = ui
+                .add_enabled(
+                    can_scale,
+                    egui::DragValue::new(&mut scale)
+                        .speed(0.01)
+                        .range(0.1..=f32::MAX)
+                        .fixed_decimals(2),
+                )
+                .changed();
+            if scaled {
+                // Keep the aspect ratio of non-uniform scales
+                let ratio = scale / transform.scale.x.max(f32::EPSILON);
+                events.push(TransformEvent::Scale(transform.scale * ratio));
+            }
+        });
+    }
+}
+
+/// Round `angle` to the nearest multiple of `step` (both in radians)
+fn snap_angle(angle: f32, step: f32) -> f32 {
+    (angle / step).round() * step
 }
 
 impl Default for TransformGizmo {
@@ -615,3 +796,79 @@ impl Default for TransformGizmo {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, run_ui, run_ui_with_events, test_context};
+
+    fn key(key: egui::Key, modifiers: egui::Modifiers) -> egui::Event {
+        egui::Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers,
+        }
+    }
+
+    #[test]
+    fn test_arrow_keys_nudge_when_focused() {
+        let ctx = test_context();
+        let transform = Transform2D::new();
+        let mut origin = Pos2::ZERO;
+        run_ui(&ctx, |ui| origin = ui.next_widget_position());
+
+        // Click empty space inside the gizmo to focus it
+        click_at(&ctx, origin + Vec2::splat(2.0), |ui| {
+            TransformGizmo::new().show(ui, &transform);
+        });
+
+        let mut events = Vec::new();
+        run_ui_with_events(
+            &ctx,
+            vec![key(egui::Key::ArrowRight, egui::Modifiers::NONE)],
+            |ui| events = TransformGizmo::new().show(ui, &transform),
+        );
+        assert!(matches!(
+            events.as_slice(),
+            [TransformEvent::Translate(delta)] if delta.x > 0.0 && delta.y == 0.0
+        ));
+
+        run_ui_with_events(
+            &ctx,
+            vec![key(egui::Key::ArrowUp, egui::Modifiers::SHIFT)],
+            |ui| events = TransformGizmo::new().show(ui, &transform),
+        );
+        assert!(matches!(
+            events.as_slice(),
+            [TransformEvent::Translate(delta)] if *delta == Vec2::new(0.0, -10.0)
+        ));
+    }
+
+    #[test]
+    fn test_arrow_keys_ignored_without_focus() {
+        let ctx = test_context();
+        let transform = Transform2D::new();
+        let mut events = Vec::new();
+        run_ui_with_events(
+            &ctx,
+            vec![key(egui::Key::ArrowRight, egui::Modifiers::NONE)],
+            |ui| events = TransformGizmo::new().show(ui, &transform),
+        );
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_snap_rotation() {
+        let gizmo = TransformGizmo::new().snap_rotation(15.0);
+        let delta = gizmo.rotation_delta(0.0, 20f32.to_radians());
+        assert!((delta.to_degrees() - 15.0).abs() < 1e-4);
+
+        let delta = gizmo.rotation_delta(15f32.to_radians(), 24f32.to_radians());
+        assert!((delta.to_degrees() - 15.0).abs() < 1e-4);
+
+        let free = TransformGizmo::new();
+        assert!((free.rotation_delta(0.0, 0.3) - 0.3).abs() < 1e-6);
+    }
+}
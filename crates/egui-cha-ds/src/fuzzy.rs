@@ -0,0 +1,174 @@
+//! Fuzzy matching for filtering lists by a typed query
+//!
+//! Used by [`CommandPalette`](crate::CommandPalette) and
+//! [`SearchBar`](crate::SearchBar) suggestions; components can use the
+//! returned indices to highlight matched characters.
+//!
+//! ```ignore
+//! use egui_cha_ds::fuzzy::fuzzy_match;
+//!
+//! let found = fuzzy_match("set", "Open Settings").unwrap();
+//! assert_eq!(found.match_indices, vec![5, 6, 7]);
+//! ```
+
+/// Base score of a contiguous match
+const CONTIGUOUS: i32 = 1000;
+/// Extra score when a match starts the haystack
+const PREFIX_BONUS: i32 = 200;
+/// Extra score when a match starts a word
+const BOUNDARY_BONUS: i32 = 100;
+/// Per-char score of a scattered match landing on a word start
+const SCATTERED_BOUNDARY: i32 = 10;
+/// Per-char score of a scattered match following the previous one
+const SCATTERED_ADJACENT: i32 = 5;
+
+/// A successful fuzzy match
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FuzzyScore {
+    /// Higher is better
+    pub score: i32,
+    /// Char (not byte) indices into the haystack of the matched characters
+    pub match_indices: Vec<usize>,
+}
+
+/// Match `needle` against `haystack`, ignoring case
+///
+/// Every needle char must appear in the haystack in order. A contiguous match
+/// scores above a scattered one; among contiguous matches a prefix wins over
+/// a word start, which wins over a match inside a word. An empty needle
+/// matches everything with score 0.
+pub fn fuzzy_match(needle: &str, haystack: &str) -> Option<FuzzyScore> {
+    let needle: Vec<char> = needle.chars().collect();
+    let hay: Vec<char> = haystack.chars().collect();
+    if needle.is_empty() {
+        return Some(FuzzyScore {
+            score: 0,
+            match_indices: Vec::new(),
+        });
+    }
+    if needle.len() > hay.len() {
+        return None;
+    }
+
+    // Best contiguous match: prefix, then word start, then earliest
+    let contiguous = (0..=hay.len() - needle.len())
+        .filter(|start| {
+            needle
+                .iter()
+                .zip(&hay[*start..])
+                .all(|(n, h)| eq_ignore_case(*n, *h))
+        })
+        .map(|start| {
+            let bonus = if start == 0 {
+                PREFIX_BONUS
+            } else if is_boundary(&hay, start) {
+                BOUNDARY_BONUS
+            } else {
+                0
+            };
+            (CONTIGUOUS + bonus - start as i32, start)
+        })
+        .max_by_key(|(score, start)| (*score, std::cmp::Reverse(*start)));
+    if let Some((score, start)) = contiguous {
+        return Some(FuzzyScore {
+            score,
+            match_indices: (start..start + needle.len()).collect(),
+        });
+    }
+
+    // Scattered subsequence, taking the earliest occurrence of each char
+    let mut match_indices = Vec::with_capacity(needle.len());
+    let mut score = 0;
+    let mut from = 0;
+    for n in &needle {
+        let skipped = hay[from..].iter().position(|h| eq_ignore_case(*n, *h))?;
+        let index = from + skipped;
+        if is_boundary(&hay, index) {
+            score += SCATTERED_BOUNDARY;
+        }
+        if match_indices.last().is_some_and(|last| last + 1 == index) {
+            score += SCATTERED_ADJACENT;
+        }
+        score -= skipped as i32;
+        match_indices.push(index);
+        from = index + 1;
+    }
+    Some(FuzzyScore {
+        score,
+        match_indices,
+    })
+}
+
+/// Indices of `items` matching `needle`, best match first
+///
+/// Items with equal scores keep their original order.
+pub fn fuzzy_filter<S: AsRef<str>>(needle: &str, items: &[S]) -> Vec<(usize, FuzzyScore)> {
+    let mut found: Vec<(usize, FuzzyScore)> = items
+        .iter()
+        .enumerate()
+        .filter_map(|(i, item)| Some((i, fuzzy_match(needle, item.as_ref())?)))
+        .collect();
+    found.sort_by_key(|(_, found)| std::cmp::Reverse(found.score));
+    found
+}
+
+fn eq_ignore_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// Whether `hay[index]` starts a word (after a separator or a camelCase hump)
+fn is_boundary(hay: &[char], index: usize) -> bool {
+    match index.checked_sub(1).map(|prev| hay[prev]) {
+        None => true,
+        Some(prev) => !prev.is_alphanumeric() || (prev.is_lowercase() && hay[index].is_uppercase()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(needle: &str, haystack: &str) -> i32 {
+        fuzzy_match(needle, haystack).unwrap().score
+    }
+
+    #[test]
+    fn prefix_beats_boundary_beats_inner_beats_scattered() {
+        let prefix = score("set", "Settings");
+        let boundary = score("set", "Open Settings");
+        let inner = score("set", "Reset");
+        let scattered = score("set", "Select Text");
+        assert!(prefix > boundary, "{prefix} > {boundary}");
+        assert!(boundary > inner, "{boundary} > {inner}");
+        assert!(inner > scattered, "{inner} > {scattered}");
+    }
+
+    #[test]
+    fn match_indices_point_at_matched_chars() {
+        let found = fuzzy_match("ot", "Open Tab").unwrap();
+        assert_eq!(found.match_indices, vec![0, 5]);
+
+        let found = fuzzy_match("SET", "Open Settings").unwrap();
+        assert_eq!(found.match_indices, vec![5, 6, 7]);
+
+        // Char indices, not byte indices
+        let found = fuzzy_match("ab", "ääab").unwrap();
+        assert_eq!(found.match_indices, vec![2, 3]);
+    }
+
+    #[test]
+    fn missing_chars_do_not_match() {
+        assert_eq!(fuzzy_match("xyz", "Settings"), None);
+        assert_eq!(fuzzy_match("ts", "st"), None);
+    }
+
+    #[test]
+    fn filter_ranks_best_first() {
+        let items = ["Select Text", "Reset", "Settings", "Open Settings", "Help"];
+        let ranked: Vec<usize> = fuzzy_filter("set", &items)
+            .into_iter()
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(ranked, vec![2, 3, 1, 0]);
+    }
+}
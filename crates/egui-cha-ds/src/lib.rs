@@ -35,6 +35,7 @@
 //! ```
 
 mod atoms;
+pub mod fuzzy;
 mod molecules;
 pub mod semantics;
 mod theme;
@@ -42,12 +43,15 @@ mod theme;
 #[cfg(feature = "vibrancy")]
 pub mod vibrancy;
 
+#[cfg(test)]
+mod test_utils;
+
 #[cfg(feature = "vibrancy")]
 pub mod titlebar;
 
 pub use atoms::*;
 pub use molecules::*;
-pub use theme::{LightweightTheme, Theme, ThemeProvider, ThemeVariant};
+pub use theme::{LightweightTheme, Theme, ThemeOverrideExt, ThemeProvider, ThemeVariant};
 
 #[cfg(feature = "serde")]
 pub use theme::{ThemeConfig, ThemeLoadError, ThemeSaveError};
@@ -98,8 +102,10 @@ pub use egui_cha_macros::cha;
 pub mod prelude {
     pub use crate::atoms::*;
     pub use crate::molecules::*;
-    pub use crate::semantics::{self, ButtonStyle, LogSeverity, SeverityLog};
-    pub use crate::theme::{LightweightTheme, Theme, ThemeProvider, ThemeVariant};
+    pub use crate::semantics::{self, ButtonStyle, LogSeverity, SemanticLabels, SeverityLog};
+    pub use crate::theme::{
+        LightweightTheme, Theme, ThemeOverrideExt, ThemeProvider, ThemeVariant,
+    };
 
     #[cfg(feature = "serde")]
     pub use crate::theme::{ThemeConfig, ThemeLoadError, ThemeSaveError};
@@ -1,14 +1,44 @@
 //! Card molecule
+//!
+//! # Example
+//!
+//! ```ignore
+//! Card::titled("Advanced")
+//!     .collapsible(true)
+//!     .default_open(false)
+//!     .show_ctx(ctx, |ctx| {
+//!         ctx.ui.label("Only rendered while open");
+//!     });
+//!
+//! // Actions pinned below the body
+//! Card::titled("Edit")
+//!     .with_footer(|ctx| {
+//!         Button::primary("Save").on_click(ctx, Msg::Save);
+//!     })
+//!     .show_ctx(ctx, |ctx| {
+//!         ctx.ui.label("Body");
+//!     });
+//! ```
+//!
+//! # Return value
+//!
+//! `show` and `show_ctx` return `Option<R>` (they used to return `R`), since
+//! a collapsed card never runs its content closure. Cards that are not
+//! collapsible always return `Some`; callers that used the result need
+//! `if let Some(..)` or `.unwrap()` when they upgrade.
 
-use egui::Ui;
+use egui::{Id, RichText, Sense, Ui};
 use egui_cha::ViewCtx;
 
-use crate::Theme;
+use crate::{icons, Theme};
 
 /// A card container with optional header
 pub struct Card<'a> {
     title: Option<&'a str>,
     padding: Option<f32>,
+    collapsible: bool,
+    default_open: bool,
+    id_salt: Option<Id>,
 }
 
 impl<'a> Card<'a> {
@@ -16,13 +46,16 @@ impl<'a> Card<'a> {
         Self {
             title: None,
             padding: None,
+            collapsible: false,
+            default_open: true,
+            id_salt: None,
         }
     }
 
     pub fn titled(title: &'a str) -> Self {
         Self {
             title: Some(title),
-            padding: None,
+            ..Self::new()
         }
     }
 
@@ -31,39 +64,127 @@ impl<'a> Card<'a> {
         self
     }
 
+    /// Allow collapsing the body by clicking the header
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    /// Initial open state for collapsible cards (default: true)
+    pub fn default_open(mut self, open: bool) -> Self {
+        self.default_open = open;
+        self
+    }
+
+    /// Set the id used to store the open state
+    ///
+    /// Defaults to the title; untitled cards fall back to their position in
+    /// the layout, so set a salt if an untitled card moves around.
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// Add a footer rendered below the body, separated and themed with `bg_secondary`
+    ///
+    /// The footer closure takes the same argument as the body closure
+    /// (`&mut ViewCtx` for `show_ctx`, `&mut Ui` for `show`).
+    pub fn with_footer<F>(self, footer: F) -> CardWithFooter<'a, F> {
+        CardWithFooter { card: self, footer }
+    }
+
     /// Show card with content (ViewCtx version)
+    ///
+    /// Returns `None` if the card is collapsed (content is not rendered).
+    /// Breaking change: this used to return `R`, see the [module docs](self).
     pub fn show_ctx<Msg, R>(
         self,
         ctx: &mut ViewCtx<'_, Msg>,
         content: impl FnOnce(&mut ViewCtx<'_, Msg>) -> R,
-    ) -> R {
+    ) -> Option<R> {
         let theme = Theme::current(ctx.ui.ctx());
         ctx.group(|ctx| {
-            if let Some(title) = self.title {
-                ctx.ui.heading(title);
-                ctx.ui.separator();
-                ctx.ui.add_space(theme.spacing_sm);
+            if !self.header(ctx.ui, &theme) {
+                return None;
             }
-            content(ctx)
+            Some(content(ctx))
         })
     }
 
     /// Show card with content (Ui version)
-    pub fn show<R>(self, ui: &mut Ui, content: impl FnOnce(&mut Ui) -> R) -> R {
+    ///
+    /// Returns `None` if the card is collapsed (content is not rendered).
+    /// Breaking change: this used to return `R`, see the [module docs](self).
+    pub fn show<R>(self, ui: &mut Ui, content: impl FnOnce(&mut Ui) -> R) -> Option<R> {
         let theme = Theme::current(ui.ctx());
         let padding = self.padding.unwrap_or(theme.spacing_md);
         egui::Frame::group(ui.style())
             .inner_margin(padding)
             .show(ui, |ui| {
-                if let Some(title) = self.title {
-                    ui.heading(title);
-                    ui.separator();
-                    ui.add_space(theme.spacing_sm);
+                if !self.header(ui, &theme) {
+                    return None;
                 }
-                content(ui)
+                Some(content(ui))
             })
             .inner
     }
+
+    /// Render the header, returning whether the body should be shown
+    fn header(&self, ui: &mut Ui, theme: &Theme) -> bool {
+        if !self.collapsible {
+            if let Some(title) = self.title {
+                ui.heading(title);
+                ui.separator();
+                ui.add_space(theme.spacing_sm);
+            }
+            return true;
+        }
+
+        let salt = match (self.id_salt, self.title) {
+            (Some(salt), _) => salt,
+            (None, Some(title)) => Id::new(title),
+            (None, None) => ui.next_auto_id(),
+        };
+        let id = ui.make_persistent_id(salt);
+        let mut open = ui
+            .ctx()
+            .data_mut(|d| *d.get_persisted_mut_or(id, self.default_open));
+
+        let response = ui
+            .horizontal(|ui| {
+                let chevron = if open {
+                    icons::CARET_DOWN
+                } else {
+                    icons::CARET_RIGHT
+                };
+                // Non-selectable, so clicks reach the header instead of the text
+                ui.add(
+                    egui::Label::new(
+                        RichText::new(chevron)
+                            .family(egui::FontFamily::Name("icons".into()))
+                            .color(theme.text_secondary),
+                    )
+                    .selectable(false),
+                );
+                if let Some(title) = self.title {
+                    ui.add(egui::Label::new(RichText::new(title).heading()).selectable(false));
+                }
+            })
+            .response
+            .interact(Sense::click())
+            .on_hover_cursor(egui::CursorIcon::PointingHand);
+
+        if response.clicked() {
+            open = !open;
+            ui.ctx().data_mut(|d| d.insert_persisted(id, open));
+        }
+
+        if open {
+            ui.separator();
+            ui.add_space(theme.spacing_sm);
+        }
+        open
+    }
 }
 
 impl<'a> Default for Card<'a> {
@@ -71,3 +192,163 @@ impl<'a> Default for Card<'a> {
         Self::new()
     }
 }
+
+/// A card with a footer slot (see [`Card::with_footer`])
+pub struct CardWithFooter<'a, F> {
+    card: Card<'a>,
+    footer: F,
+}
+
+impl<'a, F> CardWithFooter<'a, F> {
+    /// Show card with body and footer (ViewCtx version)
+    ///
+    /// Returns `None` if the card is collapsed (body and footer are not rendered).
+    pub fn show_ctx<Msg, R>(
+        self,
+        ctx: &mut ViewCtx<'_, Msg>,
+        content: impl FnOnce(&mut ViewCtx<'_, Msg>) -> R,
+    ) -> Option<R>
+    where
+        F: FnOnce(&mut ViewCtx<'_, Msg>),
+    {
+        let theme = Theme::current(ctx.ui.ctx());
+        let footer = self.footer;
+        ctx.group(|ctx| {
+            if !self.card.header(ctx.ui, &theme) {
+                return None;
+            }
+            let result = content(ctx);
+            ctx.ui.separator();
+            let mut prepared = footer_frame(&theme).begin(ctx.ui);
+            footer(&mut ctx.with_ui(&mut prepared.content_ui));
+            prepared.end(ctx.ui);
+            Some(result)
+        })
+    }
+
+    /// Show card with body and footer (Ui version)
+    ///
+    /// Returns `None` if the card is collapsed (body and footer are not rendered).
+    pub fn show<R>(self, ui: &mut Ui, content: impl FnOnce(&mut Ui) -> R) -> Option<R>
+    where
+        F: FnOnce(&mut Ui),
+    {
+        let theme = Theme::current(ui.ctx());
+        let padding = self.card.padding.unwrap_or(theme.spacing_md);
+        let footer = self.footer;
+        let card = self.card;
+        egui::Frame::group(ui.style())
+            .inner_margin(padding)
+            .show(ui, |ui| {
+                if !card.header(ui, &theme) {
+                    return None;
+                }
+                let result = content(ui);
+                ui.separator();
+                footer_frame(&theme).show(ui, footer);
+                Some(result)
+            })
+            .inner
+    }
+}
+
+/// Frame for the footer area
+fn footer_frame(theme: &Theme) -> egui::Frame {
+    egui::Frame::new()
+        .fill(theme.bg_secondary)
+        .corner_radius(theme.radius_sm)
+        .inner_margin(theme.spacing_sm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, painted_text_rect, run_ui, test_context};
+
+    #[test]
+    fn test_collapsed_card_skips_content() {
+        let ctx = test_context();
+        let mut called = false;
+
+        run_ui(&ctx, |ui| {
+            let result = Card::titled("Advanced")
+                .collapsible(true)
+                .default_open(false)
+                .show(ui, |_| called = true);
+            assert!(result.is_none());
+        });
+
+        assert!(!called);
+    }
+
+    #[test]
+    fn test_footer_runs_after_body_with_separator() {
+        let ctx = test_context();
+        let order = std::cell::RefCell::new(Vec::new());
+        let mut body_bottom = 0.0;
+        let mut footer_top = 0.0;
+
+        let output = run_ui(&ctx, |ui| {
+            Card::titled("Edit")
+                .with_footer(|ui: &mut Ui| {
+                    order.borrow_mut().push("footer");
+                    footer_top = ui.min_rect().top();
+                })
+                .show(ui, |ui| {
+                    order.borrow_mut().push("body");
+                    ui.label("Body");
+                    body_bottom = ui.min_rect().bottom();
+                });
+        });
+
+        assert_eq!(order.into_inner(), vec!["body", "footer"]);
+
+        // Separator line between body and footer
+        let has_separator = output.shapes.iter().any(|clipped| match &clipped.shape {
+            egui::Shape::LineSegment { points, .. } => {
+                points[0].y == points[1].y && points[0].y > body_bottom && points[0].y < footer_top
+            }
+            _ => false,
+        });
+        assert!(has_separator);
+    }
+
+    #[test]
+    fn test_open_card_runs_content() {
+        let ctx = test_context();
+        let mut called = false;
+
+        run_ui(&ctx, |ui| {
+            Card::titled("Basics")
+                .collapsible(true)
+                .show(ui, |_| called = true);
+        });
+
+        assert!(called);
+    }
+
+    #[test]
+    fn test_untitled_cards_keep_separate_open_state() {
+        let ctx = test_context();
+        let mut shown = [false; 2];
+        let cards = |ui: &mut Ui, shown: &mut [bool; 2]| {
+            for flag in shown.iter_mut() {
+                *flag = Card::new()
+                    .collapsible(true)
+                    .show(ui, |ui| {
+                        ui.label("Body");
+                    })
+                    .is_some();
+            }
+        };
+
+        let output = run_ui(&ctx, |ui| cards(ui, &mut shown));
+        assert_eq!(shown, [true, true]);
+
+        // Collapse the first card only
+        let chevron = painted_text_rect(&output, icons::CARET_DOWN).unwrap();
+        click_at(&ctx, chevron.center(), |ui| cards(ui, &mut shown));
+        run_ui(&ctx, |ui| cards(ui, &mut shown));
+        assert_eq!(shown, [false, true]);
+    }
+}
@@ -6,7 +6,7 @@
 //! # Features
 //!
 //! - **Keyboard Navigation**: Arrow keys, Enter to execute, Escape to close
-//! - **Search**: Filter commands by typing (fuzzy matching optional via feature)
+//! - **Search**: Fuzzy filtering as you type, best matches first with matched characters highlighted
 //! - **Grouping**: Organize commands with groups and separators
 //! - **Shortcuts**: Display keyboard shortcuts for each command
 //! - **Icons**: Phosphor icons support
@@ -37,6 +37,7 @@
 use egui::{Key, Ui};
 use egui_cha::ViewCtx;
 
+use crate::fuzzy::fuzzy_match;
 use crate::Theme;
 
 /// State for CommandPalette (store in your Model)
@@ -247,155 +248,54 @@ impl<Msg: Clone> CommandPalette<Msg> {
         self
     }
 
+    /// Add several command items
+    pub fn commands(mut self, items: impl IntoIterator<Item = CommandItem<Msg>>) -> Self {
+        self.entries
+            .extend(items.into_iter().map(CommandEntry::Item));
+        self
+    }
+
     /// Show the command palette
     ///
-    /// Returns the message to emit when a command is selected
+    /// Emits `on_close` and then the selected command's message when a command
+    /// is picked with Enter or a click.
     pub fn show(self, ctx: &mut ViewCtx<'_, Msg>, state: &mut CommandPaletteState, on_close: Msg) {
-        if !state.is_open {
-            return;
-        }
-
-        let theme = Theme::current(ctx.ui.ctx());
-
-        // Handle keyboard navigation (before rendering, using previous frame's state)
-        let mut should_close = false;
-        let mut selected_msg: Option<Msg> = None;
-
-        ctx.ui.input(|input| {
-            if input.key_pressed(Key::Escape) {
-                should_close = true;
-            }
-        });
-
-        // Store query for later keyboard handling
-        let query_before = state.query.clone();
-
-        // Pre-calculate items to determine height
-        let row_height = theme.spacing_lg + theme.spacing_sm;
-        let header_height = 60.0; // input + separator area
-        let flat_items_for_height = self.flatten_items(&query_before);
-        let content_height = flat_items_for_height.len() as f32 * row_height + header_height;
-        let actual_height = content_height.min(self.max_height);
-
-        // Render overlay
-        egui::Area::new(egui::Id::new("command_palette_area"))
-            .anchor(egui::Align2::CENTER_TOP, [0.0, 100.0])
-            .order(egui::Order::Foreground)
-            .show(ctx.ui.ctx(), |ui| {
-                egui::Frame::popup(ui.style())
-                    .fill(theme.bg_primary)
-                    .stroke(egui::Stroke::new(theme.border_width, theme.border))
-                    .rounding(theme.radius_md)
-                    .shadow(egui::Shadow {
-                        spread: 8,
-                        blur: 16,
-                        color: egui::Color32::from_black_alpha(60),
-                        offset: [0, 4],
-                    })
-                    .show(ui, |ui| {
-                        ui.set_width(self.width);
-                        ui.set_min_height(actual_height);
-
-                        // Search input
-                        ui.add_space(theme.spacing_sm);
-                        let response = ui.add(
-                            egui::TextEdit::singleline(&mut state.query)
-                                .hint_text(&self.placeholder)
-                                .desired_width(f32::INFINITY)
-                                .frame(false)
-                                .font(egui::TextStyle::Body)
-                                .margin(egui::vec2(theme.spacing_sm, 0.0)),
-                        );
-                        // Auto-focus
-                        response.request_focus();
-                        ui.add_space(theme.spacing_xs);
-
-                        ui.separator();
-
-                        // Flatten entries AFTER TextEdit has updated query
-                        let flat_items = self.flatten_items(&state.query);
-                        let item_count = flat_items.len();
-
-                        // Clamp selected_index to valid range
-                        if item_count > 0 && state.selected_index >= item_count {
-                            state.selected_index = item_count - 1;
-                        }
-
-                        // Reset selected_index when query changes
-                        if state.query != query_before {
-                            state.selected_index = 0;
-                        }
-
-                        // Handle arrow keys and enter
-                        ui.input(|input| {
-                            if input.key_pressed(Key::ArrowDown) && item_count > 0 {
-                                state.selected_index = (state.selected_index + 1) % item_count;
-                            }
-                            if input.key_pressed(Key::ArrowUp) && item_count > 0 {
-                                state.selected_index = state
-                                    .selected_index
-                                    .checked_sub(1)
-                                    .unwrap_or(item_count - 1);
-                            }
-                            if input.key_pressed(Key::Enter) && !flat_items.is_empty() {
-                                if let Some(item) = flat_items.get(state.selected_index) {
-                                    selected_msg = Some(item.msg.clone());
-                                    should_close = true;
-                                }
-                            }
-                        });
-
-                        // Results list - height is min of content and max_height
-                        let row_height = theme.spacing_lg + theme.spacing_sm;
-                        let content_height = flat_items.len() as f32 * row_height;
-                        let scroll_max = content_height.min(self.max_height - 60.0);
-
-                        egui::ScrollArea::vertical()
-                            .id_salt("command_palette_scroll")
-                            .max_height(scroll_max)
-                            .show(ui, |ui| {
-                                self.render_entries(
-                                    ui,
-                                    &self.entries,
-                                    &state.query,
-                                    state.selected_index,
-                                    &mut 0,
-                                    &flat_items,
-                                    &theme,
-                                    &mut selected_msg,
-                                    &mut should_close,
-                                );
-                            });
-                    });
-            });
+        let outcome = self.render(ctx.ui, state);
 
         // Handle close
-        if should_close {
-            state.close();
+        if outcome.close {
             ctx.emit(on_close);
         }
 
         // Emit selected command
-        if let Some(msg) = selected_msg {
+        if let Some((_, msg)) = outcome.selected {
             ctx.emit(msg);
         }
     }
 
     /// Show without ViewCtx (returns selected index or None)
+    ///
+    /// The index refers to the visible results: tree order when the query is
+    /// empty, best match first otherwise.
     pub fn show_raw(self, ui: &mut Ui, state: &mut CommandPaletteState) -> Option<usize> {
+        self.render(ui, state).selected.map(|(index, _)| index)
+    }
+
+    /// Render the overlay and handle keyboard navigation
+    fn render(&self, ui: &mut Ui, state: &mut CommandPaletteState) -> PaletteOutcome<Msg> {
+        let mut outcome = PaletteOutcome {
+            selected: None,
+            close: false,
+        };
         if !state.is_open {
-            return None;
+            return outcome;
         }
 
         let theme = Theme::current(ui.ctx());
 
-        // Handle escape key
-        let mut should_close = false;
-        let mut selected_index: Option<usize> = None;
-
         ui.input(|input| {
             if input.key_pressed(Key::Escape) {
-                should_close = true;
+                outcome.close = true;
             }
         });
 
@@ -409,7 +309,7 @@ impl<Msg: Clone> CommandPalette<Msg> {
         let content_height = flat_items_for_height.len() as f32 * row_height + header_height;
         let actual_height = content_height.min(self.max_height);
 
-        // Render
+        // Render overlay
         egui::Area::new(egui::Id::new("command_palette_area"))
             .anchor(egui::Align2::CENTER_TOP, [0.0, 100.0])
             .order(egui::Order::Foreground)
@@ -418,20 +318,30 @@ impl<Msg: Clone> CommandPalette<Msg> {
                     .fill(theme.bg_primary)
                     .stroke(egui::Stroke::new(theme.border_width, theme.border))
                     .rounding(theme.radius_md)
+                    .shadow(egui::Shadow {
+                        spread: 8,
+                        blur: 16,
+                        color: egui::Color32::from_black_alpha(60),
+                        offset: [0, 4],
+                    })
                     .show(ui, |ui| {
                         ui.set_width(self.width);
                         ui.set_min_height(actual_height);
 
+                        // Search input
                         ui.add_space(theme.spacing_sm);
                         let response = ui.add(
                             egui::TextEdit::singleline(&mut state.query)
                                 .hint_text(&self.placeholder)
                                 .desired_width(f32::INFINITY)
                                 .frame(false)
+                                .font(egui::TextStyle::Body)
                                 .margin(egui::vec2(theme.spacing_sm, 0.0)),
                         );
+                        // Auto-focus
                         response.request_focus();
                         ui.add_space(theme.spacing_xs);
+
                         ui.separator();
 
                         // Flatten entries AFTER TextEdit has updated query
@@ -459,182 +369,175 @@ impl<Msg: Clone> CommandPalette<Msg> {
                                     .checked_sub(1)
                                     .unwrap_or(item_count - 1);
                             }
-                            if input.key_pressed(Key::Enter) && !flat_items.is_empty() {
-                                selected_index = Some(state.selected_index);
-                                should_close = true;
+                            if input.key_pressed(Key::Enter) {
+                                if let Some(found) = flat_items.get(state.selected_index) {
+                                    outcome.selected =
+                                        Some((state.selected_index, found.item.msg.clone()));
+                                    outcome.close = true;
+                                }
                             }
                         });
 
                         // Results list - height is min of content and max_height
-                        let row_height = theme.spacing_lg + theme.spacing_sm;
                         let content_height = flat_items.len() as f32 * row_height;
                         let scroll_max = content_height.min(self.max_height - 60.0);
 
-                        let mut dummy_msg: Option<Msg> = None;
-                        let mut dummy_close = false;
-
-                        egui::ScrollArea::vertical()
-                            .id_salt("command_palette_scroll_raw")
+                        let clicked = egui::ScrollArea::vertical()
+                            .id_salt("command_palette_scroll")
                             .max_height(scroll_max)
                             .show(ui, |ui| {
-                                self.render_entries(
-                                    ui,
-                                    &self.entries,
-                                    &state.query,
-                                    state.selected_index,
-                                    &mut 0,
-                                    &flat_items,
-                                    &theme,
-                                    &mut dummy_msg,
-                                    &mut dummy_close,
-                                );
-                            });
-
-                        if dummy_close {
-                            should_close = true;
+                                if state.query.is_empty() {
+                                    self.render_entries(
+                                        ui,
+                                        &self.entries,
+                                        state.selected_index,
+                                        &mut 0,
+                                        &theme,
+                                    )
+                                } else {
+                                    // Ranked results, best match first
+                                    let mut clicked = None;
+                                    for (index, found) in flat_items.iter().enumerate() {
+                                        let is_selected = index == state.selected_index;
+                                        if self.render_item(
+                                            ui,
+                                            found.item,
+                                            &found.indices,
+                                            is_selected,
+                                            &theme,
+                                        ) {
+                                            clicked = Some(index);
+                                        }
+                                    }
+                                    clicked
+                                }
+                            })
+                            .inner;
+
+                        if let Some(index) = clicked {
+                            outcome.selected = Some((index, flat_items[index].item.msg.clone()));
+                            outcome.close = true;
                         }
                     });
             });
 
-        if should_close {
+        if outcome.close {
             state.close();
         }
 
-        selected_index
+        outcome
     }
 
-    /// Flatten items for indexing (filtered by query)
-    fn flatten_items(&self, query: &str) -> Vec<&CommandItem<Msg>> {
+    /// Visible items: tree order for an empty query, otherwise matches
+    /// ranked best first
+    fn flatten_items(&self, query: &str) -> Vec<PaletteMatch<'_, Msg>> {
         let mut items = Vec::new();
-        self.collect_items(&self.entries, query, &mut items);
+        Self::collect_items(&self.entries, query, &mut items);
+        // Stable sort keeps tree order among equal scores
+        items.sort_by_key(|found| std::cmp::Reverse(found.score));
         items
     }
 
     fn collect_items<'a>(
-        &'a self,
         entries: &'a [CommandEntry<Msg>],
         query: &str,
-        out: &mut Vec<&'a CommandItem<Msg>>,
+        out: &mut Vec<PaletteMatch<'a, Msg>>,
     ) {
-        let query_lower = query.to_lowercase();
-
         for entry in entries {
             match entry {
                 CommandEntry::Item(item) => {
-                    if query.is_empty() || self.matches_query(item, &query_lower) {
-                        out.push(item);
+                    if let Some(found) = Self::match_item(item, query) {
+                        out.push(found);
                     }
                 }
                 CommandEntry::Group { items, .. } => {
-                    self.collect_items(items, query, out);
+                    Self::collect_items(items, query, out);
                 }
                 CommandEntry::Separator => {}
             }
         }
     }
 
-    fn matches_query(&self, item: &CommandItem<Msg>, query: &str) -> bool {
-        let label_lower = item.label.to_lowercase();
-
-        #[cfg(feature = "fuzzy")]
-        {
-            // Fuzzy matching with nucleo or similar
-            fuzzy_match(&label_lower, query)
+    /// Match an item's label fuzzily, falling back to a description substring
+    fn match_item<'a>(item: &'a CommandItem<Msg>, query: &str) -> Option<PaletteMatch<'a, Msg>> {
+        if query.is_empty() {
+            return Some(PaletteMatch {
+                item,
+                score: 0,
+                indices: Vec::new(),
+            });
         }
 
-        #[cfg(not(feature = "fuzzy"))]
-        {
-            // Simple substring match
-            label_lower.contains(query)
-                || item
-                    .description
-                    .as_ref()
-                    .map(|d| d.to_lowercase().contains(query))
-                    .unwrap_or(false)
+        if let Some(found) = fuzzy_match(query, &item.label) {
+            return Some(PaletteMatch {
+                item,
+                score: found.score,
+                indices: found.match_indices,
+            });
         }
+
+        item.description
+            .as_ref()
+            .filter(|d| d.to_lowercase().contains(&query.to_lowercase()))
+            .map(|_| PaletteMatch {
+                item,
+                score: i32::MIN,
+                indices: Vec::new(),
+            })
     }
 
+    /// Render entries in tree order (empty query), returning the clicked
+    /// flat index
     fn render_entries(
         &self,
         ui: &mut Ui,
         entries: &[CommandEntry<Msg>],
-        query: &str,
         selected_index: usize,
         current_index: &mut usize,
-        flat_items: &[&CommandItem<Msg>],
         theme: &Theme,
-        selected_msg: &mut Option<Msg>,
-        should_close: &mut bool,
-    ) {
-        let query_lower = query.to_lowercase();
+    ) -> Option<usize> {
+        let mut clicked = None;
 
         for entry in entries {
             match entry {
                 CommandEntry::Item(item) => {
-                    if !query.is_empty() && !self.matches_query(item, &query_lower) {
-                        continue;
-                    }
-
                     let is_selected = *current_index == selected_index;
-                    let clicked = self.render_item(ui, item, is_selected, theme);
-
-                    if clicked {
-                        *selected_msg = Some(item.msg.clone());
-                        *should_close = true;
+                    if self.render_item(ui, item, &[], is_selected, theme) {
+                        clicked = Some(*current_index);
                     }
 
                     *current_index += 1;
                 }
                 CommandEntry::Separator => {
-                    // Hide separator when searching
-                    if query.is_empty() {
-                        ui.add_space(theme.spacing_xs);
-                        ui.separator();
-                        ui.add_space(theme.spacing_xs);
-                    }
+                    ui.add_space(theme.spacing_xs);
+                    ui.separator();
+                    ui.add_space(theme.spacing_xs);
                 }
                 CommandEntry::Group { label, items } => {
-                    // Check if any items in group match
-                    let has_matches = query.is_empty()
-                        || items.iter().any(|e| {
-                            if let CommandEntry::Item(item) = e {
-                                self.matches_query(item, &query_lower)
-                            } else {
-                                false
-                            }
-                        });
-
-                    if has_matches {
-                        // Group label
-                        ui.add_space(theme.spacing_sm);
-                        ui.label(
-                            egui::RichText::new(label.to_uppercase())
-                                .size(theme.font_size_xs)
-                                .color(theme.text_muted),
-                        );
-                        ui.add_space(theme.spacing_xs);
+                    // Group label
+                    ui.add_space(theme.spacing_sm);
+                    ui.label(
+                        egui::RichText::new(label.to_uppercase())
+                            .size(theme.font_size_xs)
+                            .color(theme.text_muted),
+                    );
+                    ui.add_space(theme.spacing_xs);
 
-                        self.render_entries(
-                            ui,
-                            items,
-                            query,
-                            selected_index,
-                            current_index,
-                            flat_items,
-                            theme,
-                            selected_msg,
-                            should_close,
-                        );
-                    }
+                    let group_clicked =
+                        self.render_entries(ui, items, selected_index, current_index, theme);
+                    clicked = clicked.or(group_clicked);
                 }
             }
         }
+
+        clicked
     }
 
     fn render_item(
         &self,
         ui: &mut Ui,
         item: &CommandItem<Msg>,
+        matched: &[usize],
         is_selected: bool,
         theme: &Theme,
     ) -> bool {
@@ -695,12 +598,14 @@ impl<Msg: Clone> CommandPalette<Msg> {
                 }
             }
 
-            // Label
-            let label_galley = painter.layout_no_wrap(
-                item.label.clone(),
+            // Label, with matched characters highlighted
+            let label_galley = painter.layout_job(highlighted_label(
+                &item.label,
+                matched,
                 egui::FontId::proportional(theme.font_size_sm),
                 text_color,
-            );
+                theme.primary,
+            ));
             let label_pos = egui::pos2(x, center_y - label_galley.size().y / 2.0);
             let label_width = label_galley.size().x;
             painter.galley(label_pos, label_galley, text_color);
@@ -750,14 +655,96 @@ impl<Msg: Clone> Default for CommandPalette<Msg> {
     }
 }
 
-#[cfg(feature = "fuzzy")]
-fn fuzzy_match(text: &str, pattern: &str) -> bool {
-    // Simple fuzzy: all chars in pattern appear in order in text
-    let mut pattern_chars = pattern.chars().peekable();
-    for c in text.chars() {
-        if pattern_chars.peek() == Some(&c) {
-            pattern_chars.next();
-        }
+/// Result of one palette frame
+struct PaletteOutcome<Msg> {
+    /// Picked command: (visible index, message)
+    selected: Option<(usize, Msg)>,
+    close: bool,
+}
+
+/// A command matching the current query
+struct PaletteMatch<'a, Msg> {
+    item: &'a CommandItem<Msg>,
+    score: i32,
+    /// Char indices of the label matched by the query
+    indices: Vec<usize>,
+}
+
+/// Lay out `label` with the chars at `matched` in `highlight`
+fn highlighted_label(
+    label: &str,
+    matched: &[usize],
+    font_id: egui::FontId,
+    color: egui::Color32,
+    highlight: egui::Color32,
+) -> egui::text::LayoutJob {
+    let mut job = egui::text::LayoutJob::default();
+    let mut buf = [0; 4];
+    for (index, c) in label.chars().enumerate() {
+        let color = if matched.contains(&index) {
+            highlight
+        } else {
+            color
+        };
+        job.append(
+            c.encode_utf8(&mut buf),
+            0.0,
+            egui::TextFormat::simple(font_id.clone(), color),
+        );
+    }
+    job
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{run_ui_with_events, test_context};
+    use egui::{Event, Modifiers};
+
+    fn palette() -> CommandPalette<&'static str> {
+        CommandPalette::new().commands([
+            CommandItem::new("Show Advanced View", "advanced"),
+            CommandItem::new("Save File", "save").shortcut("⌘S"),
+            CommandItem::new("Open Settings", "settings"),
+        ])
+    }
+
+    #[test]
+    fn prefix_match_ranks_above_scattered_match() {
+        let palette = palette();
+        let found = palette.flatten_items("sav");
+        let ranked: Vec<&str> = found.iter().map(|m| m.item.label.as_str()).collect();
+        assert_eq!(ranked, vec!["Save File", "Show Advanced View"]);
+
+        // Matched chars are kept for highlighting
+        assert_eq!(found[0].indices, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn enter_picks_top_result() {
+        let ctx = test_context();
+        let mut state = CommandPaletteState::new();
+        state.open();
+        let mut picked = None;
+        let mut frame = |ui: &mut Ui| {
+            if let Some((_, msg)) = palette().render(ui, &mut state).selected {
+                picked = Some(msg);
+            }
+        };
+
+        // First frame focuses the input
+        run_ui_with_events(&ctx, Vec::new(), &mut frame);
+        run_ui_with_events(&ctx, vec![Event::Text("sav".into())], &mut frame);
+        let enter = Event::Key {
+            key: Key::Enter,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        };
+        run_ui_with_events(&ctx, vec![enter], &mut frame);
+
+        assert_eq!(picked, Some("save"));
+        assert!(!state.is_open);
     }
-    pattern_chars.peek().is_none()
 }
@@ -1,6 +1,8 @@
 //! ErrorConsole molecule - Collects and displays errors
 
-use crate::atoms::icons;
+use crate::atoms::{icons, ButtonGroup};
+use crate::molecules::SearchBar;
+use crate::semantics::details_toggle;
 use crate::Theme;
 use egui::{Color32, FontFamily, RichText, Ui};
 use egui_cha::{Severity, ViewCtx};
@@ -13,6 +15,10 @@ pub struct ErrorEntry {
     pub message: String,
     pub timestamp: Instant,
     pub level: ErrorLevel,
+    /// Number of grouped repeats (1 = single occurrence)
+    pub count: u32,
+    /// Detail body (stack trace, context) shown behind a collapsed toggle
+    pub details: Option<String>,
 }
 
 /// Error severity level for display in ErrorConsole
@@ -46,10 +52,30 @@ impl From<Severity> for ErrorLevel {
 }
 
 impl ErrorLevel {
+    /// All levels, from least to most severe
+    pub const ALL: [ErrorLevel; 5] = [
+        ErrorLevel::Debug,
+        ErrorLevel::Info,
+        ErrorLevel::Warning,
+        ErrorLevel::Error,
+        ErrorLevel::Critical,
+    ];
+
     /// Check if this level should be shown in production
     pub fn is_production_visible(self) -> bool {
         self >= ErrorLevel::Info
     }
+
+    /// Short display label
+    pub fn label(self) -> &'static str {
+        match self {
+            ErrorLevel::Debug => "Debug",
+            ErrorLevel::Info => "Info",
+            ErrorLevel::Warning => "Warn",
+            ErrorLevel::Error => "Error",
+            ErrorLevel::Critical => "Critical",
+        }
+    }
 }
 
 /// State for ErrorConsole (owned by parent)
@@ -57,6 +83,14 @@ pub struct ErrorConsoleState {
     errors: VecDeque<ErrorEntry>,
     max_entries: usize,
     auto_dismiss: Option<Duration>,
+    /// Entries below this level are hidden (but kept)
+    min_level: ErrorLevel,
+    /// Show a search bar above the entries
+    searchable: bool,
+    /// Case-insensitive substring filter on messages
+    query: String,
+    /// Collapse identical messages into one entry with a count
+    group_repeats: bool,
 }
 
 impl Default for ErrorConsoleState {
@@ -71,6 +105,10 @@ impl ErrorConsoleState {
             errors: VecDeque::new(),
             max_entries: 10,
             auto_dismiss: Some(Duration::from_secs(10)),
+            min_level: ErrorLevel::Debug,
+            searchable: false,
+            query: String::new(),
+            group_repeats: false,
         }
     }
 
@@ -86,6 +124,57 @@ impl ErrorConsoleState {
         self
     }
 
+    /// Set minimum level to display (entries below are hidden, not removed)
+    pub fn with_min_level(mut self, level: ErrorLevel) -> Self {
+        self.min_level = level;
+        self
+    }
+
+    /// Get the minimum displayed level
+    pub fn min_level(&self) -> ErrorLevel {
+        self.min_level
+    }
+
+    /// Set the minimum displayed level
+    pub fn set_min_level(&mut self, level: ErrorLevel) {
+        self.min_level = level;
+    }
+
+    /// Show a search bar for filtering entries by text
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
+    /// Collapse identical messages (same level and text) into one entry
+    ///
+    /// A repeated push bumps the existing entry's count and timestamp
+    /// instead of appending a new row.
+    pub fn group_repeats(mut self, group: bool) -> Self {
+        self.group_repeats = group;
+        self
+    }
+
+    /// Get the current search query
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Set the search query (case-insensitive substring match)
+    pub fn set_query(&mut self, query: impl Into<String>) {
+        self.query = query.into();
+    }
+
+    /// Apply an ErrorConsoleMsg (call this in update)
+    pub fn handle_msg(&mut self, msg: ErrorConsoleMsg) {
+        match msg {
+            ErrorConsoleMsg::Dismiss(index) => self.dismiss(index),
+            ErrorConsoleMsg::DismissAll => self.clear(),
+            ErrorConsoleMsg::SetMinLevel(level) => self.set_min_level(level),
+            ErrorConsoleMsg::SetQuery(query) => self.set_query(query),
+        }
+    }
+
     /// Push a new error
     pub fn push(&mut self, message: impl Into<String>) {
         self.push_with_level(message, ErrorLevel::Error);
@@ -103,10 +192,43 @@ impl ErrorConsoleState {
 
     /// Push with specific level
     pub fn push_with_level(&mut self, message: impl Into<String>, level: ErrorLevel) {
+        self.push_entry(message.into(), level, None);
+    }
+
+    /// Push with a detail body (e.g. stack trace), collapsed under the message
+    pub fn push_with_details(
+        &mut self,
+        message: impl Into<String>,
+        level: ErrorLevel,
+        details: impl Into<String>,
+    ) {
+        self.push_entry(message.into(), level, Some(details.into()));
+    }
+
+    fn push_entry(&mut self, message: String, level: ErrorLevel, details: Option<String>) {
+        if self.group_repeats {
+            let existing = self
+                .errors
+                .iter()
+                .position(|e| e.level == level && e.message == message);
+            if let Some(mut entry) = existing.and_then(|index| self.errors.remove(index)) {
+                // Move to the end so the list stays in timestamp order
+                entry.count += 1;
+                entry.timestamp = Instant::now();
+                if details.is_some() {
+                    entry.details = details;
+                }
+                self.errors.push_back(entry);
+                return;
+            }
+        }
+
         self.errors.push_back(ErrorEntry {
-            message: message.into(),
+            message,
             timestamp: Instant::now(),
             level,
+            count: 1,
+            details,
         });
 
         // Trim to max entries
@@ -151,6 +273,15 @@ impl ErrorConsoleState {
         self.errors.iter()
     }
 
+    /// Iterate over entries passing the level and text filters, with their indices
+    pub fn visible(&self) -> impl Iterator<Item = (usize, &ErrorEntry)> {
+        let query = self.query.to_lowercase();
+        self.errors.iter().enumerate().filter(move |(_, e)| {
+            e.level >= self.min_level
+                && (query.is_empty() || e.message.to_lowercase().contains(&query))
+        })
+    }
+
     /// Drain all errors (useful for batch processing)
     pub fn drain(&mut self) -> impl Iterator<Item = ErrorEntry> + '_ {
         self.errors.drain(..)
@@ -162,6 +293,8 @@ impl ErrorConsoleState {
 pub enum ErrorConsoleMsg {
     Dismiss(usize),
     DismissAll,
+    SetMinLevel(ErrorLevel),
+    SetQuery(String),
 }
 
 /// ErrorConsole component
@@ -201,34 +334,72 @@ impl ErrorConsole {
         state: &ErrorConsoleState,
         map_msg: impl Fn(ErrorConsoleMsg) -> Msg + Clone,
     ) {
-        if state.is_empty() {
-            return;
+        if let Some(msg) = Self::show_ui(ctx.ui, state) {
+            ctx.emit(map_msg(msg));
         }
+    }
 
-        let theme = Theme::current(ctx.ui.ctx());
+    /// Show without ViewCtx (basic Ui version)
+    pub fn show_ui(ui: &mut Ui, state: &ErrorConsoleState) -> Option<ErrorConsoleMsg> {
+        if state.is_empty() {
+            return None;
+        }
 
-        // Collect dismiss clicks first
+        let theme = Theme::current(ui.ctx());
         let mut dismiss_index: Option<usize> = None;
-        let mut clear_all = false;
+        let mut header_msg: Option<ErrorConsoleMsg> = None;
+        let visible_count = state.visible().count();
 
-        ctx.ui.vertical(|ui| {
-            // Header with clear all button
+        ui.vertical(|ui| {
+            // Header with level filter and clear all button
             ui.horizontal(|ui| {
                 ui.label(
-                    RichText::new(format!("Errors ({})", state.len()))
+                    RichText::new(format!("Errors ({})", visible_count))
                         .strong()
                         .color(Self::header_color(&theme)),
                 );
+                let hidden = state.len() - visible_count;
+                if hidden > 0 {
+                    ui.label(
+                        RichText::new(format!("{} hidden", hidden))
+                            .small()
+                            .color(theme.text_muted),
+                    );
+                }
+                ui.add_space(8.0);
+
+                let labels = ErrorLevel::ALL.map(ErrorLevel::label);
+                let mut level_index = state.min_level as usize;
+                if ButtonGroup::new(&labels)
+                    .compact()
+                    .show_index(ui, &mut level_index)
+                    .changed()
+                {
+                    header_msg = Some(ErrorConsoleMsg::SetMinLevel(ErrorLevel::ALL[level_index]));
+                }
+
                 ui.add_space(8.0);
                 if ui.small_button("Clear All").clicked() {
-                    clear_all = true;
+                    header_msg = Some(ErrorConsoleMsg::DismissAll);
                 }
             });
 
             ui.add_space(4.0);
 
+            // Search bar
+            if state.searchable {
+                let mut query = state.query.clone();
+                SearchBar::new()
+                    .placeholder("Filter messages...")
+                    .show(ui, &mut query);
+                if query != state.query {
+                    header_msg = Some(ErrorConsoleMsg::SetQuery(query));
+                }
+                ui.add_space(4.0);
+            }
+
             // Error list
-            for (index, entry) in state.iter().enumerate() {
+            for (index, entry) in state.visible() {
                 let (bg_color, text_color, icon) = Self::level_colors(entry.level, &theme);
 
                 egui::Frame::new()
@@ -243,6 +414,14 @@ impl ErrorConsole {
                                     .color(text_color),
                             );
                             ui.label(RichText::new(&entry.message).color(text_color));
+                            if entry.count > 1 {
+                                ui.label(
+                                    RichText::new(format!("×{}", entry.count))
+                                        .small()
+                                        .strong()
+                                        .color(text_color),
+                                );
+                            }
                             ui.with_layout(
                                 egui::Layout::right_to_left(egui::Align::Center),
                                 |ui| {
@@ -252,74 +431,132 @@ impl ErrorConsole {
                                 },
                             );
                         });
+                        if let Some(details) = &entry.details {
+                            let id = ui.make_persistent_id((
+                                "error_entry_details",
+                                entry.level,
+                                &entry.message,
+                            ));
+                            details_toggle(ui, id, details);
+                        }
                     });
 
                 ui.add_space(2.0);
             }
         });
 
-        // Emit messages after UI rendering
-        if clear_all {
-            ctx.emit(map_msg(ErrorConsoleMsg::DismissAll));
-        } else if let Some(index) = dismiss_index {
-            ctx.emit(map_msg(ErrorConsoleMsg::Dismiss(index)));
-        }
+        header_msg.or(dismiss_index.map(ErrorConsoleMsg::Dismiss))
     }
+}
 
-    /// Show without ViewCtx (basic Ui version)
-    pub fn show_ui(ui: &mut Ui, state: &ErrorConsoleState) -> Option<ErrorConsoleMsg> {
-        if state.is_empty() {
-            return None;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, painted_text_rect, painted_texts, run_ui, test_context};
+
+    #[test]
+    fn test_min_level_filters_rendering() {
+        let mut state = ErrorConsoleState::new().with_min_level(ErrorLevel::Warning);
+        state.push_with_level("debug", ErrorLevel::Debug);
+        state.push_info("info");
+        state.push("error");
+
+        let visible: Vec<_> = state.visible().collect();
+        assert_eq!(visible.len(), 1);
+        assert_eq!(visible[0].0, 2);
+        assert_eq!(visible[0].1.message, "error");
+
+        // Stored entries stay intact
+        assert_eq!(state.len(), 3);
+    }
+
+    #[test]
+    fn test_handle_set_min_level() {
+        let mut state = ErrorConsoleState::new();
+        state.push_info("info");
+        assert_eq!(state.visible().count(), 1);
+
+        state.handle_msg(ErrorConsoleMsg::SetMinLevel(ErrorLevel::Error));
+        assert_eq!(state.min_level(), ErrorLevel::Error);
+        assert_eq!(state.visible().count(), 0);
+        assert_eq!(state.len(), 1);
+    }
+
+    #[test]
+    fn test_query_filters_case_insensitive() {
+        let mut state = ErrorConsoleState::new().searchable(true);
+        state.push("db failed");
+        state.push_info("cache ok");
+
+        state.handle_msg(ErrorConsoleMsg::SetQuery("db".into()));
+        let visible: Vec<_> = state.visible().map(|(_, e)| e.message.as_str()).collect();
+        assert_eq!(visible, vec!["db failed"]);
+
+        state.set_query("DB");
+        assert_eq!(state.visible().count(), 1);
+
+        state.set_query("");
+        assert_eq!(state.visible().count(), 2);
+    }
+
+    #[test]
+    fn test_group_repeats() {
+        let mut state = ErrorConsoleState::new().group_repeats(true);
+        for _ in 0..14 {
+            state.push("Database connection lost");
         }
 
-        let theme = Theme::current(ui.ctx());
-        let mut result = None;
+        assert_eq!(state.len(), 1);
+        let entry = state.iter().next().unwrap();
+        assert_eq!(entry.count, 14);
+        assert_eq!(entry.level, ErrorLevel::Error);
 
-        ui.vertical(|ui| {
-            ui.horizontal(|ui| {
-                ui.label(
-                    RichText::new(format!("Errors ({})", state.len()))
-                        .strong()
-                        .color(Self::header_color(&theme)),
-                );
-                ui.add_space(8.0);
-                if ui.small_button("Clear All").clicked() {
-                    result = Some(ErrorConsoleMsg::DismissAll);
-                }
-            });
+        // Same text at a different level is a separate entry
+        state.push_warning("Database connection lost");
+        assert_eq!(state.len(), 2);
+    }
 
-            ui.add_space(4.0);
+    #[test]
+    fn test_group_repeats_moves_to_end() {
+        let mut state = ErrorConsoleState::new().group_repeats(true);
+        state.push("a");
+        state.push("b");
+        state.push("a");
 
-            for (index, entry) in state.iter().enumerate() {
-                let (bg_color, text_color, icon) = Self::level_colors(entry.level, &theme);
+        let messages: Vec<_> = state.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages, vec!["b", "a"]);
+    }
 
-                egui::Frame::new()
-                    .fill(bg_color)
-                    .corner_radius(4.0)
-                    .inner_margin(egui::Margin::symmetric(8, 4))
-                    .show(ui, |ui| {
-                        ui.horizontal(|ui| {
-                            ui.label(
-                                RichText::new(icon)
-                                    .family(FontFamily::Name("icons".into()))
-                                    .color(text_color),
-                            );
-                            ui.label(RichText::new(&entry.message).color(text_color));
-                            ui.with_layout(
-                                egui::Layout::right_to_left(egui::Align::Center),
-                                |ui| {
-                                    if ui.small_button("×").clicked() && result.is_none() {
-                                        result = Some(ErrorConsoleMsg::Dismiss(index));
-                                    }
-                                },
-                            );
-                        });
-                    });
+    #[test]
+    fn test_no_grouping_by_default() {
+        let mut state = ErrorConsoleState::new();
+        state.push("same");
+        state.push("same");
+        assert_eq!(state.len(), 2);
+    }
 
-                ui.add_space(2.0);
-            }
-        });
+    #[test]
+    fn test_level_order_matches_all() {
+        for (i, level) in ErrorLevel::ALL.iter().enumerate() {
+            assert_eq!(*level as usize, i);
+        }
+    }
+
+    #[test]
+    fn test_details_expand_on_toggle() {
+        let mut state = ErrorConsoleState::new();
+        state.push_with_details("task panicked", ErrorLevel::Critical, "at worker.rs:7");
+        let ctx = test_context();
+        let frame = |ui: &mut Ui| {
+            ErrorConsole::show_ui(ui, &state);
+        };
+
+        let output = run_ui(&ctx, frame);
+        assert!(!painted_texts(&output).iter().any(|t| t == "at worker.rs:7"));
+        let toggle = painted_text_rect(&output, "▶ details").expect("toggle shown");
 
-        result
+        click_at(&ctx, toggle.center(), frame);
+        let output = run_ui(&ctx, frame);
+        assert!(painted_texts(&output).iter().any(|t| t == "at worker.rs:7"));
     }
 }
@@ -10,9 +10,15 @@
 //!     .submit_if(model.can_submit())
 //!     .on_submit(Msg::Submit)
 //!     .show(ctx);
+//!
+//! // Validation-gated: submit is disabled while any field is blocking,
+//! // and blocking messages are summarized below the button
+//! Form::new()
+//!     .add_field(FormField::new("Email", &model.email, &model.email_state, Msg::EmailChanged))
+//!     .show_with(ctx, Msg::Submit);
 //! ```
 
-use crate::atoms::ValidationState;
+use crate::atoms::{icons, ValidationState};
 use crate::theme::Theme;
 use egui_cha::ViewCtx;
 
@@ -26,6 +32,37 @@ pub struct FormField<'a, Msg> {
     placeholder: &'a str,
 }
 
+impl<'a, Msg> FormField<'a, Msg> {
+    /// Create a text field with validation state and change callback
+    pub fn new(
+        label: &'a str,
+        value: &'a str,
+        state: &'a ValidationState,
+        on_change: impl FnOnce(String) -> Msg + 'a,
+    ) -> Self {
+        Self {
+            label,
+            value,
+            state,
+            on_change: Box::new(on_change),
+            password: false,
+            placeholder: "",
+        }
+    }
+
+    /// Make this a password field
+    pub fn password(mut self) -> Self {
+        self.password = true;
+        self
+    }
+
+    /// Set placeholder text
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+}
+
 /// Form builder for creating validated forms (TEA-style)
 pub struct Form<'a, Msg> {
     fields: Vec<FormField<'a, Msg>>,
@@ -85,6 +122,12 @@ impl<'a, Msg: Clone> Form<'a, Msg> {
         self
     }
 
+    /// Add a prebuilt field
+    pub fn add_field(mut self, field: FormField<'a, Msg>) -> Self {
+        self.fields.push(field);
+        self
+    }
+
     /// Set submit button text
     pub fn submit_button(mut self, text: &'a str) -> Self {
         self.submit_text = text;
@@ -109,9 +152,46 @@ impl<'a, Msg: Clone> Form<'a, Msg> {
         self
     }
 
+    /// Check if submit is allowed (enabled and no field is blocking)
+    pub fn can_submit(&self) -> bool {
+        self.submit_enabled && !self.fields.iter().any(|f| f.state.is_blocking())
+    }
+
+    /// Error messages of invalid fields as `(label, message)` pairs
+    pub fn validation_summary(&self) -> Vec<(&'a str, &'a str)> {
+        self.fields
+            .iter()
+            .filter_map(|f| f.state.error_message().map(|msg| (f.label, msg)))
+            .collect()
+    }
+
     /// Show the form (TEA-style)
     pub fn show(self, ctx: &mut ViewCtx<'_, Msg>) {
+        self.render(ctx, false);
+    }
+
+    /// Show the form with validation gating
+    ///
+    /// Submit is disabled while any field is blocking (see
+    /// [`ValidationState::is_blocking`]), and the blocking messages are
+    /// listed below the submit button.
+    pub fn show_with(mut self, ctx: &mut ViewCtx<'_, Msg>, on_submit: Msg) {
+        self.on_submit = Some(on_submit);
+        self.render(ctx, true);
+    }
+
+    fn render(self, ctx: &mut ViewCtx<'_, Msg>, gated: bool) {
         let theme = Theme::current(ctx.ui.ctx());
+        let submit_enabled = if gated {
+            self.can_submit()
+        } else {
+            self.submit_enabled
+        };
+        let summary = if gated {
+            self.validation_summary()
+        } else {
+            Vec::new()
+        };
 
         // Collect messages to emit after UI rendering
         let mut messages: Vec<Msg> = Vec::new();
@@ -128,11 +208,7 @@ impl<'a, Msg: Clone> Form<'a, Msg> {
                 ui.label(field.label);
 
                 // Border color based on state
-                let border_color = match field.state {
-                    ValidationState::None => theme.border,
-                    ValidationState::Valid => theme.state_success,
-                    ValidationState::Invalid(_) => theme.state_danger,
-                };
+                let border_color = field.state.border_color(&theme);
 
                 let mut edit = egui::TextEdit::singleline(&mut current)
                     .hint_text(field.placeholder)
@@ -159,42 +235,14 @@ impl<'a, Msg: Clone> Form<'a, Msg> {
                 }
 
                 // Validation indicator
-                match field.state {
-                    ValidationState::Valid => {
-                        ui.horizontal(|ui| {
-                            ui.label(
-                                egui::RichText::new(crate::icons::CHECK)
-                                    .family(egui::FontFamily::Name("icons".into()))
-                                    .color(theme.state_success)
-                                    .size(14.0),
-                            );
-                            ui.label(
-                                egui::RichText::new("Valid")
-                                    .color(theme.state_success)
-                                    .small(),
-                            );
-                        });
-                    }
-                    ValidationState::Invalid(msg) => {
-                        ui.horizontal(|ui| {
-                            ui.label(
-                                egui::RichText::new(crate::icons::WARNING)
-                                    .family(egui::FontFamily::Name("icons".into()))
-                                    .color(theme.state_danger)
-                                    .size(14.0),
-                            );
-                            ui.label(egui::RichText::new(msg).color(theme.state_danger).small());
-                        });
-                    }
-                    ValidationState::None => {}
-                }
+                field.state.show_indicator(&theme, ui);
             }
 
             ui.add_space(8.0);
 
             // Submit button
             let button_text = egui::RichText::new(self.submit_text)
-                .color(if self.submit_enabled {
+                .color(if submit_enabled {
                     theme.primary_text
                 } else {
                     theme.text_muted
@@ -202,7 +250,7 @@ impl<'a, Msg: Clone> Form<'a, Msg> {
                 .strong();
 
             let button = egui::Button::new(button_text)
-                .fill(if self.submit_enabled {
+                .fill(if submit_enabled {
                     theme.primary
                 } else {
                     theme.bg_secondary
@@ -210,13 +258,30 @@ impl<'a, Msg: Clone> Form<'a, Msg> {
                 .corner_radius(theme.radius_sm)
                 .min_size(egui::vec2(ui.available_width(), 36.0));
 
-            let response = ui.add_enabled(self.submit_enabled, button);
+            let response = ui.add_enabled(submit_enabled, button);
 
             if response.clicked() {
                 if let Some(msg) = self.on_submit {
                     messages.push(msg);
                 }
             }
+
+            // Validation summary
+            for (label, message) in summary {
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(icons::WARNING)
+                            .family(egui::FontFamily::Name("icons".into()))
+                            .color(theme.state_danger)
+                            .size(14.0),
+                    );
+                    ui.label(
+                        egui::RichText::new(format!("{}: {}", label, message))
+                            .color(theme.state_danger)
+                            .small(),
+                    );
+                });
+            }
         });
 
         // Emit all collected messages
@@ -231,3 +296,30 @@ impl<'a, Msg: Clone> Default for Form<'a, Msg> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_field_disables_submit() {
+        let email_state = ValidationState::invalid("Invalid email");
+        let name_state = ValidationState::valid();
+
+        let form: Form<'_, ()> = Form::new()
+            .add_field(FormField::new("Email", "foo", &email_state, |_| ()))
+            .add_field(FormField::new("Name", "Bob", &name_state, |_| ()));
+
+        assert!(!form.can_submit());
+        assert_eq!(form.validation_summary(), vec![("Email", "Invalid email")]);
+    }
+
+    #[test]
+    fn test_warning_field_allows_submit() {
+        let state = ValidationState::warning("Weak password");
+        let form: Form<'_, ()> = Form::new().password_field("Password", "hunter22", &state, |_| ());
+
+        assert!(form.can_submit());
+        assert!(form.validation_summary().is_empty());
+    }
+}
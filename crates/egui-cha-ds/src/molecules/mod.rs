@@ -82,7 +82,7 @@ mod snarl;
 mod toast;
 mod workspace;
 
-pub use card::Card;
+pub use card::{Card, CardWithFooter};
 pub use chat::{Chat, ChatMessage, ChatRole, ChatState};
 pub use command_palette::{CommandEntry, CommandItem, CommandPalette, CommandPaletteState};
 pub use dashboard_layout::{
@@ -92,20 +92,20 @@ pub use dashboard_layout::{
 #[cfg(feature = "dock")]
 pub use dock::{layout as dock_layout, DockArea, DockEvent, DockStyle, DockTree, TabInfo};
 pub use error_console::{ErrorConsole, ErrorConsoleMsg, ErrorConsoleState, ErrorEntry, ErrorLevel};
-pub use form::Form;
+pub use form::{Form, FormField};
 pub use heatmap_grid::{CellState, HeatmapCell, HeatmapGrid};
 pub use log_stream::{LogEntry, LogFilter, LogStream, LogStreamState, TimestampFormat};
 pub use menu::{IconMenu, Menu};
-pub use modal::{ConfirmDialog, ConfirmResult, Modal};
-pub use navbar::{navbar, sidebar, Navbar};
+pub use modal::{ConfirmDialog, ConfirmResult, Modal, ModalSize, PromptDialog, PromptResult};
+pub use navbar::{navbar, sidebar, NavItem, Navbar};
 pub use quick_action_bar::{
     KeyBind, QuickActionBar, QuickActionColumn, QuickActionSize, QuickActionStyle,
 };
-pub use search_bar::SearchBar;
+pub use search_bar::{SearchBar, SearchBarEvent};
 #[cfg(feature = "extras")]
 pub use table::DataColumnWidth;
 pub use table::{DataTable, Table};
-pub use tabs::{TabPanel, Tabs};
+pub use tabs::{TabEvent, TabPanel, Tabs};
 // #[cfg(feature = "tiles")]
 // pub use tiles::{...};  // TODO: egui 0.31 compat
 pub use node_layout::{
@@ -116,5 +116,7 @@ pub use snarl::{
     presets as node_presets, InPin, InPinId, MenuAction, NodeGraph, NodeGraphArea, NodeGraphEvent,
     NodeGraphStyle, NodeId, OutPin, OutPinId, PinInfo, Snarl, SnarlViewer,
 };
+#[cfg(all(feature = "snarl", feature = "serde"))]
+pub use snarl::{GraphConnection, GraphData, GraphNodeData};
 pub use toast::{ToastContainer, ToastId, ToastPosition, ToastVariant};
 pub use workspace::{Edge, LayoutMode, SnapTarget, WorkspaceCanvas, WorkspaceEvent, WorkspacePane};
@@ -1,35 +1,84 @@
 //! Modal/Dialog molecule
+//!
+//! While open, a modal keeps keyboard focus to itself: it focuses its first
+//! widget on open, Tab/Shift+Tab cycle within it, and Escape requests close.
 
-use egui::{Align2, Area, Color32, Frame, Id, Order, RichText, Ui, Vec2};
+use egui::{
+    Align2, Area, Color32, FocusDirection, Frame, Id, Key, LayerId, Order, RichText, ScrollArea,
+    Sense, Ui, Vec2,
+};
 
 use crate::{icons, Theme};
 
+/// Modal size preset (outer width, including the frame's padding)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ModalSize {
+    /// 320px wide - short confirmations
+    Small,
+    /// 400px wide
+    #[default]
+    Medium,
+    /// 720px wide - forms, previews
+    Large,
+    /// Fills the window, leaving a margin
+    Fullscreen,
+}
+
+impl ModalSize {
+    /// Fixed width of the preset, `None` for `Fullscreen`
+    pub fn width(&self) -> Option<f32> {
+        match self {
+            ModalSize::Small => Some(320.0),
+            ModalSize::Medium => Some(400.0),
+            ModalSize::Large => Some(720.0),
+            ModalSize::Fullscreen => None,
+        }
+    }
+}
+
+/// Content width of a modal without a size preset or explicit width
+const DEFAULT_CONTENT_WIDTH: f32 = 400.0;
+
 /// A modal dialog component
+///
+/// The body scrolls vertically when it is taller than the window.
 pub struct Modal<'a> {
     title: Option<&'a str>,
-    width: f32,
+    size: Option<ModalSize>,
+    width: Option<f32>,
     closable: bool,
+    id_salt: Option<Id>,
 }
 
 impl<'a> Modal<'a> {
     pub fn new() -> Self {
         Self {
             title: None,
-            width: 400.0,
+            size: None,
+            width: None,
             closable: true,
+            id_salt: None,
         }
     }
 
     pub fn titled(title: &'a str) -> Self {
         Self {
             title: Some(title),
-            width: 400.0,
-            closable: true,
+            ..Self::new()
         }
     }
 
+    /// Set a size preset
+    pub fn size(mut self, size: ModalSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Set the content width (overrides the size preset's width)
+    ///
+    /// Defaults to 400px of content when no preset is set either.
     pub fn width(mut self, width: f32) -> Self {
-        self.width = width;
+        self.width = Some(width);
         self
     }
 
@@ -38,7 +87,20 @@ impl<'a> Modal<'a> {
         self
     }
 
-    /// Show modal (returns true if close was requested)
+    /// Set the id the modal's layers are keyed by (defaults to the title)
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// Id of the area holding the modal window
+    fn content_id(&self) -> Id {
+        Id::new("modal")
+            .with(self.id_salt.unwrap_or_else(|| Id::new(self.title)))
+            .with("content")
+    }
+
+    /// Show modal (returns true if close was requested via X, backdrop or Escape)
     pub fn show(self, ui: &mut Ui, open: bool, content: impl FnOnce(&mut Ui)) -> bool {
         if !open {
             return false;
@@ -46,18 +108,54 @@ impl<'a> Modal<'a> {
 
         let theme = Theme::current(ui.ctx());
         let is_dark = ui.ctx().style().visuals.dark_mode;
-        let mut close_requested = false;
+        let mut close_requested = self.closable && ui.input(|i| i.key_pressed(Key::Escape));
+
+        // Trap focus: layers below the backdrop can neither be focused nor clicked
+        let content_id = self.content_id();
+        let backdrop_id = content_id.with("backdrop");
+        ui.ctx().memory_mut(|m| {
+            m.set_modal_layer(LayerId::new(Order::Foreground, backdrop_id));
+        });
+
+        // Move focus into the modal on the first frame it is shown
+        let pass_id = content_id.with("last_pass");
+        let pass = ui.ctx().cumulative_pass_nr();
+        let last_pass = ui.ctx().data(|d| d.get_temp::<u64>(pass_id));
+        if last_pass.map_or(true, |last| last + 1 < pass) {
+            ui.ctx().memory_mut(|m| {
+                if let Some(focused) = m.focused() {
+                    m.surrender_focus(focused);
+                }
+                m.move_focus(FocusDirection::Next);
+            });
+        }
+        ui.ctx().data_mut(|d| d.insert_temp(pass_id, pass));
 
         // Backdrop - use theme's overlay_dim
         let screen_rect = ui.ctx().content_rect();
+        let fullscreen = self.size == Some(ModalSize::Fullscreen);
+        let outer_margin = theme.spacing_lg;
+        let padding = theme.spacing_md * 2.0;
+        // Widths below are content widths; presets are outer widths, so drop the padding
+        let max_width = (screen_rect.width() - outer_margin * 2.0 - padding).max(0.0);
+        let width = match (self.width, self.size) {
+            (Some(width), _) => width,
+            (None, Some(size)) => size.width().map_or(max_width, |w| w - padding),
+            (None, None) => DEFAULT_CONTENT_WIDTH,
+        }
+        .min(max_width);
+        // Height left for the frame's content after margins
+        let max_height =
+            (screen_rect.height() - outer_margin * 2.0 - theme.spacing_md * 2.0).max(0.0);
         let backdrop_alpha = (theme.overlay_dim * 255.0) as u8;
         let backdrop_color = Color32::from_rgba_unmultiplied(0, 0, 0, backdrop_alpha);
 
-        Area::new(Id::new("modal_backdrop"))
+        Area::new(backdrop_id)
             .fixed_pos(screen_rect.min)
             .order(Order::Foreground)
             .show(ui.ctx(), |ui| {
-                let response = ui.allocate_response(screen_rect.size(), egui::Sense::click());
+                // Clickable but not focusable, so Tab never lands on the backdrop
+                let response = ui.allocate_response(screen_rect.size(), Sense::CLICK);
                 ui.painter().rect_filled(screen_rect, 0.0, backdrop_color);
 
                 // Close on backdrop click if closable
@@ -79,7 +177,7 @@ impl<'a> Modal<'a> {
             Color32::from_rgb(229, 231, 235)
         };
 
-        Area::new(Id::new("modal_content"))
+        Area::new(content_id)
             .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
             .order(Order::Tooltip)
             .show(ui.ctx(), |ui| {
@@ -89,7 +187,7 @@ impl<'a> Modal<'a> {
                     .corner_radius(theme.radius_md)
                     .inner_margin(egui::Margin::same(theme.spacing_md as i8))
                     .show(ui, |ui| {
-                        ui.set_width(self.width);
+                        ui.set_width(width);
 
                         // Header
                         if self.title.is_some() || self.closable {
@@ -115,8 +213,14 @@ impl<'a> Modal<'a> {
                             ui.add_space(theme.spacing_sm);
                         }
 
-                        // Content
-                        content(ui);
+                        // Content (scrolls when taller than the window)
+                        let body_height = (max_height - ui.min_rect().height()).max(0.0);
+                        ScrollArea::vertical()
+                            .id_salt("modal_body")
+                            .max_height(body_height)
+                            .min_scrolled_height(if fullscreen { body_height } else { 0.0 })
+                            .auto_shrink([false, !fullscreen])
+                            .show(ui, content);
                     });
             });
 
@@ -210,3 +314,345 @@ impl<'a> ConfirmDialog<'a> {
         result
     }
 }
+
+/// Single-line text prompt (e.g. "Rename to…")
+///
+/// The typed text is kept in egui memory while the dialog is open and is
+/// reset to `initial` each time it opens.
+pub struct PromptDialog<'a> {
+    title: &'a str,
+    label: &'a str,
+    initial: &'a str,
+    placeholder: &'a str,
+    submit_text: &'a str,
+    cancel_text: &'a str,
+    required: bool,
+}
+
+/// Result of showing a prompt dialog
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PromptResult {
+    None,
+    Submitted(String),
+    Cancelled,
+}
+
+impl<'a> PromptDialog<'a> {
+    pub fn new(title: &'a str, label: &'a str, initial: &'a str) -> Self {
+        Self {
+            title,
+            label,
+            initial,
+            placeholder: "",
+            submit_text: "OK",
+            cancel_text: "Cancel",
+            required: false,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    pub fn submit_text(mut self, text: &'a str) -> Self {
+        self.submit_text = text;
+        self
+    }
+
+    pub fn cancel_text(mut self, text: &'a str) -> Self {
+        self.cancel_text = text;
+        self
+    }
+
+    /// Block submission while the (trimmed) text is empty
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Show prompt dialog; Enter in the text field or the submit button commits the text
+    pub fn show(self, ui: &mut Ui, open: bool) -> PromptResult {
+        if !open {
+            return PromptResult::None;
+        }
+
+        use crate::atoms::{Button, Input};
+
+        let theme = Theme::current(ui.ctx());
+        let state_id = Id::new("prompt_dialog").with(self.title);
+
+        // (text, pass last shown); reset when the dialog was not shown last pass
+        let pass = ui.ctx().cumulative_pass_nr();
+        let (mut text, just_opened) = match ui.ctx().data(|d| d.get_temp::<(String, u64)>(state_id))
+        {
+            Some((text, last_pass)) if last_pass + 1 >= pass => (text, false),
+            _ => (self.initial.to_string(), true),
+        };
+
+        let mut result = PromptResult::None;
+        let close_requested = Modal::titled(self.title).show(ui, open, |ui| {
+            ui.label(self.label);
+            ui.add_space(theme.spacing_xs);
+
+            let response = Input::new()
+                .placeholder(self.placeholder)
+                .desired_width(f32::INFINITY)
+                .show(ui, &mut text);
+            if just_opened {
+                response.request_focus();
+            }
+            // Only Enter typed into the field submits, not Enter on a focused button
+            let enter = response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter));
+
+            let valid = !self.required || !text.trim().is_empty();
+            ui.add_space(theme.spacing_md);
+
+            ui.horizontal(|ui| {
+                let submit = Button::primary(self.submit_text).disabled(!valid).show(ui);
+                if valid && (submit || enter) {
+                    result = PromptResult::Submitted(text.clone());
+                }
+
+                if Button::outline(self.cancel_text).show(ui) {
+                    result = PromptResult::Cancelled;
+                }
+            });
+        });
+
+        if close_requested && result == PromptResult::None {
+            result = PromptResult::Cancelled;
+        }
+
+        ui.ctx().data_mut(|d| d.insert_temp(state_id, (text, pass)));
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_context;
+    use egui::{Context, Event, Modifiers, Pos2, RawInput, Rect};
+
+    fn run_modal(ctx: &Context, modal: impl Fn() -> Modal<'static>, lines: usize) {
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::new(1280.0, 720.0))),
+            ..Default::default()
+        };
+        // Two frames so the anchored area settles on its measured size
+        for _ in 0..2 {
+            let _ = ctx.run(input.clone(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    modal().show(ui, true, |ui| {
+                        for i in 0..lines {
+                            ui.label(format!("Line {i}"));
+                        }
+                    });
+                });
+            });
+        }
+    }
+
+    fn modal_rect(ctx: &Context) -> Rect {
+        ctx.memory(|m| m.area_rect(Modal::new().content_id()))
+            .expect("modal area")
+    }
+
+    #[test]
+    fn modals_use_their_own_layers() {
+        let ctx = test_context();
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                Modal::titled("Settings").show(ui, true, |ui| {
+                    ui.label("Settings");
+                });
+                Modal::titled("Confirm").show(ui, true, |ui| {
+                    ui.label("Confirm");
+                });
+            });
+        });
+
+        let settings = Modal::titled("Settings").content_id();
+        let confirm = Modal::titled("Confirm").content_id();
+        assert_ne!(settings, confirm);
+        assert_ne!(
+            Modal::new().id_salt("a").content_id(),
+            Modal::new().id_salt("b").content_id()
+        );
+        ctx.memory(|m| {
+            assert!(m.area_rect(settings).is_some());
+            assert!(m.area_rect(confirm).is_some());
+        });
+    }
+
+    #[test]
+    fn large_is_wider_than_small() {
+        let ctx = test_context();
+        run_modal(&ctx, || Modal::new().size(ModalSize::Small), 1);
+        let small = modal_rect(&ctx);
+        run_modal(&ctx, || Modal::new().size(ModalSize::Large), 1);
+        let large = modal_rect(&ctx);
+
+        assert!(large.width() > small.width());
+    }
+
+    #[test]
+    fn presets_are_outer_widths_and_width_is_content() {
+        let ctx = test_context();
+        let padding = Theme::current(&ctx).spacing_md * 2.0;
+        // The frame's 1px stroke sits outside the content on both sides
+        let close = |a: f32, b: f32| (a - b).abs() <= 2.0;
+
+        run_modal(&ctx, || Modal::new().size(ModalSize::Small), 1);
+        let small = modal_rect(&ctx).width();
+        assert!(close(small, 320.0), "small modal is {small} wide");
+
+        run_modal(&ctx, || Modal::new().width(500.0), 1);
+        let explicit = modal_rect(&ctx).width();
+        assert!(close(explicit, 500.0 + padding), "modal is {explicit} wide");
+
+        run_modal(&ctx, Modal::new, 1);
+        let default = modal_rect(&ctx).width();
+        assert!(
+            close(default, 400.0 + padding),
+            "default modal is {default} wide"
+        );
+    }
+
+    #[test]
+    fn tall_content_scrolls_within_window() {
+        let ctx = test_context();
+        run_modal(&ctx, Modal::new, 200);
+
+        // 200 lines are far taller than the window; the modal stays inside it
+        let rect = modal_rect(&ctx);
+        assert!(rect.height() <= 720.0, "modal is {} tall", rect.height());
+        assert!(rect.min.y >= 0.0);
+    }
+
+    fn key(key: Key, modifiers: Modifiers) -> Event {
+        Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers,
+        }
+    }
+
+    /// Page with a button behind an open modal holding two buttons.
+    /// Returns (close requested, behind button id, modal button ids).
+    fn run_page(ctx: &Context, events: Vec<Event>) -> (bool, Id, Vec<Id>) {
+        let mut result = (false, Id::NULL, Vec::new());
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                result.1 = ui.button("Behind").id;
+                let mut ids = Vec::new();
+                result.0 = Modal::titled("Test").show(ui, true, |ui| {
+                    ids.push(ui.button("First").id);
+                    ids.push(ui.button("Last").id);
+                });
+                result.2 = ids;
+            });
+        });
+        result
+    }
+
+    #[test]
+    fn escape_requests_close() {
+        let ctx = test_context();
+        let (close, ..) = run_page(&ctx, Vec::new());
+        assert!(!close);
+
+        let (close, ..) = run_page(&ctx, vec![key(Key::Escape, Modifiers::NONE)]);
+        assert!(close);
+    }
+
+    #[test]
+    fn tab_stays_inside_modal() {
+        let ctx = test_context();
+        run_page(&ctx, Vec::new());
+        let (_, behind, modal_ids) = run_page(&ctx, Vec::new());
+        let focused = ctx.memory(|m| m.focused());
+        assert!(focused.is_some(), "modal takes focus on open");
+
+        // Cycle well past the last widget in both directions
+        for modifiers in [Modifiers::NONE, Modifiers::SHIFT] {
+            for _ in 0..6 {
+                run_page(&ctx, vec![key(Key::Tab, modifiers)]);
+                run_page(&ctx, Vec::new());
+                let focused = ctx.memory(|m| m.focused());
+                assert_ne!(focused, Some(behind));
+                assert!(focused.is_some());
+            }
+        }
+
+        // Every modal button is reachable
+        let mut seen = Vec::new();
+        for _ in 0..6 {
+            run_page(&ctx, vec![key(Key::Tab, Modifiers::NONE)]);
+            run_page(&ctx, Vec::new());
+            seen.extend(ctx.memory(|m| m.focused()));
+        }
+        assert!(modal_ids.iter().all(|id| seen.contains(id)));
+    }
+
+    fn run_prompt(ctx: &Context, required: bool, events: Vec<Event>) -> PromptResult {
+        let mut result = PromptResult::None;
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                result = PromptDialog::new("Rename", "New name", "")
+                    .required(required)
+                    .show(ui, true);
+            });
+        });
+        result
+    }
+
+    #[test]
+    fn prompt_submits_typed_text_on_enter() {
+        let ctx = test_context();
+        run_prompt(&ctx, true, Vec::new());
+        run_prompt(&ctx, true, vec![Event::Text("foo".into())]);
+        let result = run_prompt(&ctx, true, vec![key(Key::Enter, Modifiers::NONE)]);
+
+        assert_eq!(result, PromptResult::Submitted("foo".into()));
+    }
+
+    #[test]
+    fn enter_outside_the_field_does_not_submit() {
+        let ctx = test_context();
+        run_prompt(&ctx, false, Vec::new());
+        run_prompt(&ctx, false, vec![Event::Text("foo".into())]);
+
+        // Shift+Tab moves focus from the field back to the close button
+        run_prompt(&ctx, false, vec![key(Key::Tab, Modifiers::SHIFT)]);
+        run_prompt(&ctx, false, Vec::new());
+        let result = run_prompt(&ctx, false, vec![key(Key::Enter, Modifiers::NONE)]);
+
+        assert_eq!(result, PromptResult::Cancelled);
+    }
+
+    #[test]
+    fn required_prompt_blocks_empty_submit() {
+        let ctx = test_context();
+        run_prompt(&ctx, true, Vec::new());
+        let result = run_prompt(&ctx, true, vec![key(Key::Enter, Modifiers::NONE)]);
+        assert_eq!(result, PromptResult::None);
+
+        // Optional prompts accept empty text
+        let ctx = test_context();
+        run_prompt(&ctx, false, Vec::new());
+        let result = run_prompt(&ctx, false, vec![key(Key::Enter, Modifiers::NONE)]);
+        assert_eq!(result, PromptResult::Submitted(String::new()));
+    }
+}
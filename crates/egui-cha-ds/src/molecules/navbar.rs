@@ -1,7 +1,9 @@
 //! Navbar molecule - Navigation bar with router integration
 
-use crate::atoms::icons;
-use egui::{Color32, RichText};
+use crate::atoms::{icons, Badge};
+use crate::molecules::Menu;
+use crate::Theme;
+use egui::{Color32, Response, RichText, Ui};
 use egui_cha::router::{Router, RouterMsg};
 use egui_cha::ViewCtx;
 
@@ -33,10 +35,61 @@ impl NavbarColors {
     }
 }
 
+/// A navigation entry with an optional icon and badge
+///
+/// Plain `(label, page)` tuples convert into items without icon or badge.
+///
+/// # Example
+/// ```ignore
+/// navbar(ctx, &model.router, &[
+///     NavItem::new("Home", Page::Home).icon(icons::HOUSE),
+///     NavItem::new("Messages", Page::Messages).badge(model.unread.to_string()),
+/// ], Msg::Router);
+/// ```
+#[derive(Clone, Debug)]
+pub struct NavItem<'a, P> {
+    pub label: &'a str,
+    pub page: P,
+    /// Phosphor icon shown before the label
+    pub icon: Option<&'static str>,
+    /// Badge text shown after the label (e.g. an unread count)
+    pub badge: Option<String>,
+}
+
+impl<'a, P> NavItem<'a, P> {
+    pub fn new(label: &'a str, page: P) -> Self {
+        Self {
+            label,
+            page,
+            icon: None,
+            badge: None,
+        }
+    }
+
+    /// Show an icon before the label
+    pub fn icon(mut self, icon: &'static str) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Show a badge after the label
+    pub fn badge(mut self, badge: impl Into<String>) -> Self {
+        self.badge = Some(badge.into());
+        self
+    }
+}
+
+impl<'a, P> From<(&'a str, P)> for NavItem<'a, P> {
+    fn from((label, page): (&'a str, P)) -> Self {
+        Self::new(label, page)
+    }
+}
+
 /// A navigation bar component
 pub struct Navbar<'a> {
     title: Option<&'a str>,
     show_back: bool,
+    responsive: bool,
 }
 
 impl<'a> Navbar<'a> {
@@ -44,6 +97,7 @@ impl<'a> Navbar<'a> {
         Self {
             title: None,
             show_back: false,
+            responsive: false,
         }
     }
 
@@ -57,23 +111,44 @@ impl<'a> Navbar<'a> {
         self
     }
 
-    /// Show navbar with navigation items
-    pub fn show<P, Msg>(
+    /// Collapse items that don't fit the available width into a trailing
+    /// "More" menu
+    pub fn responsive(mut self, responsive: bool) -> Self {
+        self.responsive = responsive;
+        self
+    }
+
+    /// Show navbar with navigation items (`(label, page)` tuples or [`NavItem`]s)
+    pub fn show<'i, P, Msg, I>(
         self,
         ctx: &mut ViewCtx<'_, Msg>,
         router: &Router<P>,
-        items: &[(&str, P)],
-        _to_msg: impl Fn(RouterMsg<P>) -> Msg + Clone,
+        items: &[I],
+        to_msg: impl Fn(RouterMsg<P>) -> Msg + Clone,
     ) where
         P: Clone + PartialEq,
+        I: Clone + Into<NavItem<'i, P>>,
     {
-        let is_dark = ctx.ui.ctx().style().visuals.dark_mode;
+        if let Some(action) = self.render(ctx.ui, router, items) {
+            ctx.emit(to_msg(action));
+        }
+    }
+
+    /// Render navbar and return the navigation action if any
+    fn render<'i, P, I>(self, ui: &mut Ui, router: &Router<P>, items: &[I]) -> Option<RouterMsg<P>>
+    where
+        P: Clone + PartialEq,
+        I: Clone + Into<NavItem<'i, P>>,
+    {
+        let items = nav_items(items);
+        let is_dark = ui.ctx().style().visuals.dark_mode;
         let colors = NavbarColors::from_dark_mode(is_dark);
+        let mut nav_action: Option<RouterMsg<P>> = None;
 
         egui::Frame::new()
             .fill(colors.bg)
             .inner_margin(egui::Margin::symmetric(16, 8))
-            .show(ctx.ui, |ui| {
+            .show(ui, |ui| {
                 ui.horizontal(|ui| {
                     // Back button
                     if self.show_back {
@@ -84,7 +159,7 @@ impl<'a> Navbar<'a> {
                             .add_enabled(enabled, egui::Button::new(back_icon))
                             .clicked()
                         {
-                            // Need to emit outside
+                            nav_action = Some(RouterMsg::Back);
                         }
                     }
 
@@ -94,14 +169,30 @@ impl<'a> Navbar<'a> {
                         ui.add_space(16.0);
                     }
 
+                    // Items that don't fit go into the overflow menu
+                    let shown = if self.responsive {
+                        let widths: Vec<f32> =
+                            items.iter().map(|item| item_width(ui, item)).collect();
+                        visible_count(
+                            &widths,
+                            ui.spacing().item_spacing.x,
+                            ui.available_width(),
+                            more_width(ui),
+                        )
+                    } else {
+                        items.len()
+                    };
+                    let (inline, overflow) = items.split_at(shown);
+
                     // Nav items
-                    for (label, page) in items {
-                        let is_active = router.is_at(page);
-                        let text = RichText::new(*label).color(if is_active {
+                    for item in inline {
+                        let is_active = router.is_at(&item.page);
+                        let color = if is_active {
                             colors.text_active
                         } else {
                             colors.text_inactive
-                        });
+                        };
+                        let text = item_atoms(item, |text| text.color(color));
 
                         let button = if is_active {
                             egui::Button::new(text).fill(colors.button_active_bg)
@@ -110,11 +201,37 @@ impl<'a> Navbar<'a> {
                         };
 
                         if ui.add(button).clicked() && !is_active {
-                            // Store for later emit
+                            nav_action = Some(RouterMsg::Navigate(item.page.clone()));
                         }
+                        show_badge(ui, item);
+                    }
+
+                    // Overflow menu
+                    if !overflow.is_empty() {
+                        let active = overflow.iter().position(|item| router.is_at(&item.page));
+                        let fill = if active.is_some() {
+                            colors.button_active_bg
+                        } else {
+                            Color32::TRANSPARENT
+                        };
+                        let more =
+                            ui.add(egui::Button::new(more_label(colors.text_inactive)).fill(fill));
+
+                        egui::Popup::menu(&more).show(|ui| {
+                            let labels: Vec<&str> =
+                                overflow.iter().map(|item| item.label).collect();
+                            let mut selected = active.unwrap_or(labels.len());
+                            Menu::new(&labels).compact().show(ui, &mut selected);
+                            if Some(selected) != active && selected < labels.len() {
+                                nav_action =
+                                    Some(RouterMsg::Navigate(overflow[selected].page.clone()));
+                            }
+                        });
                     }
                 });
             });
+
+        nav_action
     }
 }
 
@@ -124,6 +241,115 @@ impl<'a> Default for Navbar<'a> {
     }
 }
 
+/// Icon and text of the overflow button
+fn more_label(color: Color32) -> (RichText, RichText) {
+    (
+        RichText::new(icons::DOTS_THREE)
+            .family(egui::FontFamily::Name("icons".into()))
+            .color(color),
+        RichText::new("More").color(color),
+    )
+}
+
+/// Width of a text button, as laid out by `egui::Button`
+fn text_width(ui: &Ui, text: impl Into<egui::WidgetText>) -> f32 {
+    text.into()
+        .into_galley(
+            ui,
+            Some(egui::TextWrapMode::Extend),
+            f32::INFINITY,
+            egui::TextStyle::Button,
+        )
+        .size()
+        .x
+}
+
+/// Convert nav items given as tuples or [`NavItem`]s
+fn nav_items<'i, P, I>(items: &[I]) -> Vec<NavItem<'i, P>>
+where
+    I: Clone + Into<NavItem<'i, P>>,
+{
+    items.iter().cloned().map(Into::into).collect()
+}
+
+/// Button contents for a nav item: icon (if any) followed by the label,
+/// both passed through `style`
+fn item_atoms<'a, P>(
+    item: &NavItem<'_, P>,
+    style: impl Fn(RichText) -> RichText,
+) -> egui::Atoms<'a> {
+    let mut atoms = egui::Atoms::default();
+    if let Some(icon) = item.icon {
+        atoms.push_right(style(
+            RichText::new(icon).family(egui::FontFamily::Name("icons".into())),
+        ));
+    }
+    atoms.push_right(style(RichText::new(item.label.to_owned())));
+    atoms
+}
+
+/// Show a nav item's badge, if any
+fn show_badge<P>(ui: &mut Ui, item: &NavItem<'_, P>) {
+    if let Some(badge) = &item.badge {
+        Badge::info(badge).show(ui);
+    }
+}
+
+/// Show a nav item's badge right-aligned inside a full-width row
+fn show_row_badge<P>(ui: &mut Ui, row: &Response, item: &NavItem<'_, P>) {
+    if item.badge.is_some() {
+        let rect = row.rect.shrink2(ui.spacing().button_padding);
+        let mut badge_ui = ui.new_child(
+            egui::UiBuilder::new()
+                .max_rect(rect)
+                .layout(egui::Layout::right_to_left(egui::Align::Center)),
+        );
+        show_badge(&mut badge_ui, item);
+    }
+}
+
+/// Width of a nav item button, including its badge
+fn item_width<P>(ui: &Ui, item: &NavItem<'_, P>) -> f32 {
+    let mut width = text_width(ui, item.label) + 2.0 * ui.spacing().button_padding.x;
+    if let Some(icon) = item.icon {
+        let icon = RichText::new(icon).family(egui::FontFamily::Name("icons".into()));
+        width += text_width(ui, icon) + ui.spacing().icon_spacing;
+    }
+    if let Some(badge) = &item.badge {
+        let theme = Theme::current(ui.ctx());
+        let text = RichText::new(badge).size(theme.font_size_xs);
+        width += ui.spacing().item_spacing.x + text_width(ui, text) + 2.0 * theme.spacing_sm;
+    }
+    width
+}
+
+/// Width of the overflow button
+fn more_width(ui: &Ui) -> f32 {
+    let (icon, text) = more_label(Color32::PLACEHOLDER);
+    text_width(ui, icon)
+        + ui.spacing().icon_spacing
+        + text_width(ui, text)
+        + 2.0 * ui.spacing().button_padding.x
+}
+
+/// Number of leading items that fit in `available`, reserving room for the
+/// overflow button when not all of them do
+fn visible_count(widths: &[f32], spacing: f32, available: f32, more_width: f32) -> usize {
+    let total: f32 = widths.iter().sum::<f32>() + spacing * widths.len().saturating_sub(1) as f32;
+    if total <= available {
+        return widths.len();
+    }
+
+    let mut used = more_width;
+    widths
+        .iter()
+        .take_while(|width| {
+            used += *width + spacing;
+            used <= available
+        })
+        .count()
+}
+
 /// Get primary color for active nav items based on theme
 fn nav_active_color(is_dark: bool) -> Color32 {
     if is_dark {
@@ -143,14 +369,16 @@ fn nav_active_text_color(is_dark: bool) -> Color32 {
 }
 
 /// Simple horizontal nav bar (functional style)
-pub fn navbar<P, Msg>(
+pub fn navbar<'i, P, Msg, I>(
     ctx: &mut ViewCtx<'_, Msg>,
     router: &Router<P>,
-    items: &[(&str, P)],
+    items: &[I],
     to_msg: impl Fn(RouterMsg<P>) -> Msg,
 ) where
     P: Clone + PartialEq,
+    I: Clone + Into<NavItem<'i, P>>,
 {
+    let items = nav_items(items);
     let is_dark = ctx.ui.ctx().style().visuals.dark_mode;
     let active_bg = nav_active_color(is_dark);
     let active_text = nav_active_text_color(is_dark);
@@ -170,18 +398,20 @@ pub fn navbar<P, Msg>(
         ui.separator();
 
         // Nav items
-        for (label, page) in items {
-            let is_active = router.is_at(page);
+        for item in &items {
+            let is_active = router.is_at(&item.page);
 
             let response = if is_active {
-                ui.add(egui::Button::new(RichText::new(*label).color(active_text)).fill(active_bg))
+                let text = item_atoms(item, |text| text.color(active_text));
+                ui.add(egui::Button::new(text).fill(active_bg))
             } else {
-                ui.button(*label)
+                ui.button(item_atoms(item, |text| text))
             };
 
             if response.clicked() && !is_active {
-                nav_action = Some(RouterMsg::Navigate(page.clone()));
+                nav_action = Some(RouterMsg::Navigate(item.page.clone()));
             }
+            show_badge(ui, item);
         }
     });
 
@@ -191,14 +421,16 @@ pub fn navbar<P, Msg>(
 }
 
 /// Sidebar navigation (vertical)
-pub fn sidebar<P, Msg>(
+pub fn sidebar<'i, P, Msg, I>(
     ctx: &mut ViewCtx<'_, Msg>,
     router: &Router<P>,
-    items: &[(&str, P)],
+    items: &[I],
     to_msg: impl Fn(RouterMsg<P>) -> Msg,
 ) where
     P: Clone + PartialEq,
+    I: Clone + Into<NavItem<'i, P>>,
 {
+    let items = nav_items(items);
     let is_dark = ctx.ui.ctx().style().visuals.dark_mode;
     let active_bg = nav_active_color(is_dark);
     let active_text = nav_active_text_color(is_dark);
@@ -206,26 +438,28 @@ pub fn sidebar<P, Msg>(
     let mut nav_action: Option<RouterMsg<P>> = None;
 
     ctx.ui.vertical(|ui| {
-        for (label, page) in items {
-            let is_active = router.is_at(page);
+        for item in &items {
+            let is_active = router.is_at(&item.page);
 
             let response = if is_active {
+                let text = item_atoms(item, |text| text.strong().color(active_text));
                 ui.add(
-                    egui::Button::new(RichText::new(*label).strong().color(active_text))
+                    egui::Button::new(text)
                         .fill(active_bg)
                         .min_size(egui::vec2(ui.available_width(), 0.0)),
                 )
             } else {
                 ui.add(
-                    egui::Button::new(RichText::new(*label))
+                    egui::Button::new(item_atoms(item, |text| text))
                         .fill(Color32::TRANSPARENT)
                         .min_size(egui::vec2(ui.available_width(), 0.0)),
                 )
             };
 
             if response.clicked() && !is_active {
-                nav_action = Some(RouterMsg::Navigate(page.clone()));
+                nav_action = Some(RouterMsg::Navigate(item.page.clone()));
             }
+            show_row_badge(ui, &response, item);
         }
     });
 
@@ -233,3 +467,80 @@ pub fn sidebar<P, Msg>(
         ctx.emit(to_msg(action));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, painted_texts, run_ui, test_context};
+    use egui::{Pos2, Vec2};
+
+    const ITEMS: [(&str, usize); 4] = [("Home", 0), ("About", 1), ("Settings", 2), ("Help", 3)];
+
+    #[test]
+    fn visible_count_reserves_room_for_overflow() {
+        assert_eq!(visible_count(&[50.0; 4], 10.0, 230.0, 40.0), 4);
+        assert_eq!(visible_count(&[50.0; 4], 10.0, 160.0, 40.0), 2);
+        assert_eq!(visible_count(&[50.0; 4], 10.0, 30.0, 40.0), 0);
+    }
+
+    #[test]
+    fn overflow_items_move_into_more_menu() {
+        let ctx = test_context();
+        let router = Router::new(0usize);
+
+        // Room for the first two items plus the overflow button
+        let mut width = 0.0;
+        let mut more = Pos2::ZERO;
+        run_ui(&ctx, |ui| {
+            let spacing = ui.spacing().item_spacing.x;
+            let inline = item_width(ui, &NavItem::new("Home", 0))
+                + item_width(ui, &NavItem::new("About", 1))
+                + 2.0 * spacing;
+            width = 32.0 + inline + more_width(ui) + 1.0;
+            more = ui.max_rect().min + Vec2::new(16.0 + inline + more_width(ui) / 2.0, 18.0);
+        });
+        let mut action = None;
+        let mut frame = |ui: &mut Ui| {
+            ui.allocate_ui(Vec2::new(width, 40.0), |ui| {
+                action = Navbar::new().responsive(true).render(ui, &router, &ITEMS);
+            });
+        };
+
+        let output = run_ui(&ctx, &mut frame);
+        let texts = painted_texts(&output);
+        assert!(texts.iter().any(|t| t == "Home"));
+        assert!(texts.iter().any(|t| t == "About"));
+        assert!(texts.iter().any(|t| t == "More"));
+        assert!(!texts.iter().any(|t| t == "Settings" || t == "Help"));
+
+        click_at(&ctx, more, &mut frame);
+        let texts = painted_texts(&run_ui(&ctx, &mut frame));
+        assert!(texts.iter().any(|t| t == "Settings"));
+        assert!(texts.iter().any(|t| t == "Help"));
+        assert_eq!(action, None);
+    }
+
+    #[test]
+    fn tuple_converts_to_plain_item() {
+        let item: NavItem<'_, usize> = ("Home", 0).into();
+        assert_eq!(item.label, "Home");
+        assert_eq!(item.page, 0);
+        assert!(item.icon.is_none() && item.badge.is_none());
+    }
+
+    #[test]
+    fn badge_renders_next_to_label() {
+        let ctx = test_context();
+        let router = Router::new(0usize);
+        let items = [
+            NavItem::new("Home", 0).icon(icons::HOUSE),
+            NavItem::new("Messages", 1).badge("3"),
+        ];
+        let output = run_ui(&ctx, |ui| {
+            Navbar::new().render(ui, &router, &items);
+        });
+        let texts = painted_texts(&output);
+        let label = texts.iter().position(|t| t == "Messages").unwrap();
+        assert_eq!(texts.get(label + 1).map(String::as_str), Some("3"));
+    }
+}
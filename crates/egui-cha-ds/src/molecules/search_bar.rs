@@ -1,12 +1,39 @@
 //! SearchBar molecule
 
-use egui::Ui;
+use crate::atoms::ListItem;
+use crate::fuzzy::fuzzy_filter;
+use crate::Theme;
+use egui::{Id, Key, Modifiers, Rect, Ui};
 use egui_cha::ViewCtx;
 
+/// Events emitted by [`SearchBar`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SearchBarEvent {
+    /// The query text was edited
+    QueryChanged(String),
+    /// A suggestion was picked (index into the suggestions slice)
+    Selected(usize),
+    /// Enter was pressed without a highlighted suggestion, or the button was clicked
+    Submitted(String),
+}
+
+/// Dropdown state kept in egui temp data
+#[derive(Clone, Debug, Default)]
+struct SuggestionState {
+    /// Highlighted row among the filtered suggestions
+    highlighted: Option<usize>,
+    /// Closed with Escape or a selection, until the query changes
+    dismissed: bool,
+    /// Dropdown rect from the last frame, keeps it open while clicking a row
+    popup_rect: Option<Rect>,
+}
+
 /// A search bar with input and button
 pub struct SearchBar<'a> {
     placeholder: &'a str,
     button_text: &'a str,
+    suggestions: &'a [&'a str],
+    id_salt: Option<Id>,
 }
 
 impl<'a> SearchBar<'a> {
@@ -14,6 +41,8 @@ impl<'a> SearchBar<'a> {
         Self {
             placeholder: "Search...",
             button_text: "Search",
+            suggestions: &[],
+            id_salt: None,
         }
     }
 
@@ -27,6 +56,20 @@ impl<'a> SearchBar<'a> {
         self
     }
 
+    /// Show a dropdown of suggestions fuzzily matching the query, best first
+    ///
+    /// Arrow keys move the highlight, Enter or a click picks it.
+    pub fn suggestions(mut self, suggestions: &'a [&'a str]) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
+    /// Set the id used for focus and dropdown state (defaults to the placeholder)
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
     /// Show search bar with callbacks for input change and search submit
     ///
     /// - `on_change`: Called when text changes (for updating model)
@@ -39,23 +82,7 @@ impl<'a> SearchBar<'a> {
         on_submit: impl FnOnce(String) -> Msg,
     ) {
         let mut current = value.to_string();
-        let mut should_search = false;
-
-        ctx.horizontal(|ctx| {
-            let response = ctx.ui.add(
-                egui::TextEdit::singleline(&mut current)
-                    .hint_text(self.placeholder)
-                    .desired_width(200.0),
-            );
-
-            if response.lost_focus() && ctx.ui.input(|i| i.key_pressed(egui::Key::Enter)) {
-                should_search = true;
-            }
-
-            if ctx.ui.button(self.button_text).clicked() {
-                should_search = true;
-            }
-        });
+        let events = self.render(ctx.ui, &mut current);
 
         // Emit change if text was modified
         if current != value {
@@ -63,60 +90,147 @@ impl<'a> SearchBar<'a> {
         }
 
         // Emit submit if search triggered
-        if should_search && !current.is_empty() {
+        if events
+            .iter()
+            .any(|e| matches!(e, SearchBarEvent::Submitted(_)))
+        {
             ctx.emit(on_submit(current));
         }
     }
 
+    /// TEA-style: Show search bar and emit every event
+    pub fn show_events_with<Msg>(
+        self,
+        ctx: &mut ViewCtx<'_, Msg>,
+        value: &str,
+        on_event: impl Fn(SearchBarEvent) -> Msg,
+    ) {
+        let mut current = value.to_string();
+        for event in self.render(ctx.ui, &mut current) {
+            ctx.emit(on_event(event));
+        }
+    }
+
     /// Show search bar with callback on search (legacy - value must be mutable ref)
     pub fn on_search<Msg, F>(self, ctx: &mut ViewCtx<'_, Msg>, value: &mut String, to_msg: F)
     where
         F: FnOnce(String) -> Msg,
     {
-        let mut should_search = false;
-
-        ctx.horizontal(|ctx| {
-            let response = ctx.ui.add(
-                egui::TextEdit::singleline(value)
-                    .hint_text(self.placeholder)
-                    .desired_width(200.0),
-            );
-
-            if response.lost_focus() && ctx.ui.input(|i| i.key_pressed(egui::Key::Enter)) {
-                should_search = true;
-            }
-
-            if ctx.ui.button(self.button_text).clicked() {
-                should_search = true;
-            }
-        });
-
-        if should_search && !value.is_empty() {
+        let events = self.render(ctx.ui, value);
+        if events
+            .iter()
+            .any(|e| matches!(e, SearchBarEvent::Submitted(_)))
+        {
             ctx.emit(to_msg(value.clone()));
         }
     }
 
     /// Show without ctx (basic version)
     pub fn show(self, ui: &mut Ui, value: &mut String) -> bool {
+        self.render(ui, value)
+            .iter()
+            .any(|e| matches!(e, SearchBarEvent::Submitted(_)))
+    }
+
+    /// Show search bar and return its events (value is edited in place)
+    pub fn show_events(self, ui: &mut Ui, value: &mut String) -> Vec<SearchBarEvent> {
+        self.render(ui, value)
+    }
+
+    /// Render search bar and dropdown, returning events
+    fn render(self, ui: &mut Ui, value: &mut String) -> Vec<SearchBarEvent> {
+        let mut events = Vec::new();
+        let id = ui.make_persistent_id(self.id_salt.unwrap_or_else(|| Id::new(self.placeholder)));
+        let mut state: SuggestionState = ui.ctx().data_mut(|d| d.get_temp(id).unwrap_or_default());
+
+        // Keyboard navigation happens before the text edit sees the keys
+        let matches = filter_suggestions(self.suggestions, value);
+        let focused = ui.memory(|m| m.has_focus(id));
+        let open = !state.dismissed && !matches.is_empty();
+        if focused && open {
+            ui.input_mut(|i| {
+                if i.consume_key(Modifiers::NONE, Key::ArrowDown) {
+                    state.highlighted =
+                        Some(state.highlighted.map_or(0, |h| (h + 1) % matches.len()));
+                }
+                if i.consume_key(Modifiers::NONE, Key::ArrowUp) {
+                    state.highlighted = Some(state.highlighted.map_or(matches.len() - 1, |h| {
+                        (h + matches.len() - 1) % matches.len()
+                    }));
+                }
+                if i.consume_key(Modifiers::NONE, Key::Escape) {
+                    state.dismissed = true;
+                }
+                if let Some(h) = state.highlighted.filter(|h| *h < matches.len()) {
+                    if i.consume_key(Modifiers::NONE, Key::Enter) {
+                        events.push(SearchBarEvent::Selected(matches[h]));
+                        state.dismissed = true;
+                    }
+                }
+            });
+        }
+
         let mut submitted = false;
+        let response = ui
+            .horizontal(|ui| {
+                let response = ui.add(
+                    egui::TextEdit::singleline(value)
+                        .id(id)
+                        .hint_text(self.placeholder)
+                        .desired_width(200.0),
+                );
 
-        ui.horizontal(|ui| {
-            let response = ui.add(
-                egui::TextEdit::singleline(value)
-                    .hint_text(self.placeholder)
-                    .desired_width(200.0),
-            );
+                if response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter)) {
+                    submitted = true;
+                }
 
-            if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
-                submitted = true;
-            }
+                if ui.button(self.button_text).clicked() {
+                    submitted = true;
+                }
+                response
+            })
+            .inner;
 
-            if ui.button(self.button_text).clicked() {
-                submitted = true;
-            }
-        });
+        if response.changed() {
+            events.push(SearchBarEvent::QueryChanged(value.clone()));
+            state.highlighted = None;
+            state.dismissed = false;
+        }
+        if submitted && !value.is_empty() {
+            events.push(SearchBarEvent::Submitted(value.clone()));
+        }
 
-        submitted && !value.is_empty()
+        // Suggestions dropdown
+        let matches = filter_suggestions(self.suggestions, value);
+        let pointer_in_popup = ui
+            .input(|i| i.pointer.hover_pos())
+            .is_some_and(|pos| state.popup_rect.is_some_and(|rect| rect.contains(pos)));
+        state.popup_rect = None;
+        if !state.dismissed && !matches.is_empty() && (response.has_focus() || pointer_in_popup) {
+            let theme = Theme::current(ui.ctx());
+            let popup = egui::Area::new(id.with("suggestions"))
+                .order(egui::Order::Foreground)
+                .fixed_pos(response.rect.left_bottom() + egui::vec2(0.0, theme.spacing_xs))
+                .show(ui.ctx(), |ui| {
+                    egui::Frame::popup(ui.style()).show(ui, |ui| {
+                        ui.set_width(response.rect.width());
+                        ui.spacing_mut().item_spacing.y = 0.0;
+                        for (row, index) in matches.iter().enumerate() {
+                            let item = ListItem::new(self.suggestions[*index])
+                                .compact()
+                                .selected(state.highlighted == Some(row));
+                            if item.show(ui).clicked() {
+                                events.push(SearchBarEvent::Selected(*index));
+                                state.dismissed = true;
+                            }
+                        }
+                    });
+                });
+            state.popup_rect = Some(popup.response.rect);
+        }
+
+        ui.ctx().data_mut(|d| d.insert_temp(id, state));
+        events
     }
 }
 
@@ -125,3 +239,80 @@ impl<'a> Default for SearchBar<'a> {
         Self::new()
     }
 }
+
+/// Indices of suggestions fuzzily matching `query`, best first; none for an empty query
+fn filter_suggestions(suggestions: &[&str], query: &str) -> Vec<usize> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    fuzzy_filter(query, suggestions)
+        .into_iter()
+        .map(|(i, _)| i)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, painted_texts, run_ui_with_events, test_context};
+    use egui::{Event, Pos2};
+
+    const FRUITS: [&str; 4] = ["apple", "banana", "apricot", "grape"];
+
+    fn key(key: Key) -> Event {
+        Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn typing_filters_and_arrow_enter_selects() {
+        let ctx = test_context();
+        let mut value = String::new();
+        let mut events = Vec::new();
+        let mut frame = |ui: &mut Ui| {
+            events.extend(
+                SearchBar::new()
+                    .suggestions(&FRUITS)
+                    .show_events(ui, &mut value),
+            );
+        };
+
+        // Focus the field, then type
+        click_at(&ctx, Pos2::new(40.0, 18.0), &mut frame);
+        run_ui_with_events(&ctx, vec![Event::Text("ap".into())], &mut frame);
+        let output = run_ui_with_events(&ctx, Vec::new(), &mut frame);
+
+        let texts = painted_texts(&output);
+        for shown in ["apple", "apricot", "grape"] {
+            assert!(
+                texts.iter().any(|t| t == shown),
+                "{shown} should be suggested"
+            );
+        }
+        assert!(!texts.iter().any(|t| t == "banana"));
+
+        run_ui_with_events(&ctx, vec![key(Key::ArrowDown)], &mut frame);
+        run_ui_with_events(&ctx, vec![key(Key::ArrowDown)], &mut frame);
+        run_ui_with_events(&ctx, vec![key(Key::Enter)], &mut frame);
+
+        assert_eq!(
+            events,
+            vec![
+                SearchBarEvent::QueryChanged("ap".into()),
+                SearchBarEvent::Selected(2),
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_is_case_insensitive() {
+        assert_eq!(filter_suggestions(&FRUITS, "AP"), vec![0, 2, 3]);
+        assert_eq!(filter_suggestions(&FRUITS, "gp"), vec![3]);
+        assert!(filter_suggestions(&FRUITS, "").is_empty());
+    }
+}
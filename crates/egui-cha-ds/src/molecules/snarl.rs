@@ -7,7 +7,9 @@
 //! - Context menus for nodes and graph background
 //! - Action system for custom menu items
 //! - Multi-connection support
-//! - Serde serialization support
+//! - Minimap overview and fit-to-view
+//! - Type-checked connections
+//! - Serde serialization support via [`GraphData`] (`serde` feature)
 //!
 //! # Example
 //! ```ignore
@@ -61,11 +63,21 @@
 //!
 //! // In view
 //! NodeGraphArea::new(&mut model.graph)
+//!     .with_minimap(true)
+//!     .show(ui, &mut MyViewer::default());
+//!
+//! // Frame all nodes on the next show
+//! model.graph.fit_to_nodes();
+//!
+//! // Only allow audio outputs to feed audio inputs
+//! NodeGraphArea::new(&mut model.graph)
+//!     .connect_allowed(|from, to, snarl| pin_type(snarl, from.id.node) == pin_type(snarl, to.id.node))
 //!     .show(ui, &mut MyViewer::default());
 //! ```
 
 use crate::Theme;
-use egui::{Color32, Pos2, Ui};
+use egui::emath::TSTransform;
+use egui::{Color32, Pos2, Rect, Sense, Stroke, Ui, Vec2};
 
 // Re-export core types
 pub use egui_snarl::ui::{PinInfo, SnarlViewer};
@@ -185,10 +197,30 @@ impl NodeGraphStyle {
     }
 }
 
+/// Approximate size of a node in graph space, used for framing and the minimap
+/// (node positions are their top-left corner)
+const NODE_EXTENT: Vec2 = Vec2::new(120.0, 60.0);
+
+/// Zoom range used by [`NodeGraph::fit_transform`]
+const FIT_SCALE_RANGE: std::ops::RangeInclusive<f32> = 0.1..=1.0;
+
+/// Margin around the nodes when fitting, in screen points
+const FIT_MARGIN: f32 = 24.0;
+
+/// Minimap size in screen points
+const MINIMAP_SIZE: Vec2 = Vec2::new(160.0, 110.0);
+
+/// How close (in screen points) the pointer must be to a pin row to count as on it
+const PIN_REACH: f32 = 16.0;
+
+/// Rule deciding whether an output may connect to an input
+type ConnectRule<'a, T> = dyn Fn(&OutPin, &InPin, &Snarl<T>) -> bool + 'a;
+
 /// A themed node graph (wraps egui_snarl::Snarl)
 pub struct NodeGraph<T> {
     inner: Snarl<T>,
     style: NodeGraphStyle,
+    fit_requested: bool,
 }
 
 impl<T> Default for NodeGraph<T> {
@@ -203,6 +235,7 @@ impl<T> NodeGraph<T> {
         Self {
             inner: Snarl::new(),
             style: NodeGraphStyle::default(),
+            fit_requested: false,
         }
     }
 
@@ -211,6 +244,7 @@ impl<T> NodeGraph<T> {
         Self {
             inner: snarl,
             style: NodeGraphStyle::default(),
+            fit_requested: false,
         }
     }
 
@@ -288,6 +322,131 @@ impl<T> NodeGraph<T> {
     pub fn node_count(&self) -> usize {
         self.inner.node_ids().count()
     }
+
+    /// Graph-space bounds of all nodes, or None for an empty graph
+    pub fn node_bounds(&self) -> Option<Rect> {
+        self.inner
+            .node_ids()
+            .filter_map(|(id, _)| self.inner.get_node_info(id))
+            .map(|node| Rect::from_min_size(node.pos, NODE_EXTENT))
+            .reduce(|a, b| a.union(b))
+    }
+
+    /// Adjust pan/zoom on the next show so all nodes are visible
+    pub fn fit_to_nodes(&mut self) {
+        self.fit_requested = true;
+    }
+
+    /// Graph-to-screen transform that frames all nodes inside `viewport`
+    ///
+    /// Zoom is limited to 100% so small graphs are centered rather than magnified.
+    pub fn fit_transform(&self, viewport: Rect) -> TSTransform {
+        let Some(bounds) = self.node_bounds() else {
+            return TSTransform::from_translation(viewport.min.to_vec2());
+        };
+        let target = viewport.shrink(FIT_MARGIN);
+        let scale = (target.width() / bounds.width())
+            .min(target.height() / bounds.height())
+            .clamp(*FIT_SCALE_RANGE.start(), *FIT_SCALE_RANGE.end());
+        TSTransform::new(
+            viewport.center().to_vec2() - bounds.center().to_vec2() * scale,
+            scale,
+        )
+    }
+}
+
+/// Serializable snapshot of a [`NodeGraph`]: node positions, payloads and connections
+///
+/// Nodes are stored in order and connections refer to them by index, so the
+/// snapshot does not depend on snarl's internal ids.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GraphData<T> {
+    pub nodes: Vec<GraphNodeData<T>>,
+    pub connections: Vec<GraphConnection>,
+}
+
+/// A node in [`GraphData`]
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GraphNodeData<T> {
+    /// Graph-space position
+    pub pos: [f32; 2],
+    pub data: T,
+}
+
+/// A wire in [`GraphData`], with nodes given as indices into `nodes`
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GraphConnection {
+    pub from_node: usize,
+    pub from_output: usize,
+    pub to_node: usize,
+    pub to_input: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<T: Clone> NodeGraph<T> {
+    /// Capture node positions, payloads and connections for saving
+    pub fn to_serializable(&self) -> GraphData<T> {
+        let mut index = std::collections::HashMap::new();
+        let mut nodes = Vec::new();
+        for (id, data) in self.inner.node_ids() {
+            if let Some(node) = self.inner.get_node_info(id) {
+                index.insert(id, nodes.len());
+                nodes.push(GraphNodeData {
+                    pos: [node.pos.x, node.pos.y],
+                    data: data.clone(),
+                });
+            }
+        }
+
+        let mut connections: Vec<GraphConnection> = self
+            .inner
+            .wires()
+            .filter_map(|(from, to)| {
+                Some(GraphConnection {
+                    from_node: *index.get(&from.node)?,
+                    from_output: from.output,
+                    to_node: *index.get(&to.node)?,
+                    to_input: to.input,
+                })
+            })
+            .collect();
+        connections.sort_by_key(|c| (c.from_node, c.from_output, c.to_node, c.to_input));
+
+        GraphData { nodes, connections }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> NodeGraph<T> {
+    /// Rebuild a graph saved with [`Self::to_serializable`]
+    ///
+    /// Connections referring to missing nodes are skipped.
+    pub fn from_serializable(data: GraphData<T>) -> Self {
+        let mut graph = Self::new();
+        let ids: Vec<NodeId> = data
+            .nodes
+            .into_iter()
+            .map(|node| graph.insert(Pos2::new(node.pos[0], node.pos[1]), node.data))
+            .collect();
+        for c in data.connections {
+            if let (Some(&from), Some(&to)) = (ids.get(c.from_node), ids.get(c.to_node)) {
+                graph.connect(
+                    OutPinId {
+                        node: from,
+                        output: c.from_output,
+                    },
+                    InPinId {
+                        node: to,
+                        input: c.to_input,
+                    },
+                );
+            }
+        }
+        graph
+    }
 }
 
 /// Themed node graph area widget
@@ -297,6 +456,8 @@ pub struct NodeGraphArea<'a, T> {
     graph_actions: Vec<MenuAction>,
     /// Actions for node context menu (right-click on node)
     node_actions: Vec<MenuAction>,
+    minimap: bool,
+    connect_allowed: Option<Box<ConnectRule<'a, T>>>,
 }
 
 impl<'a, T> NodeGraphArea<'a, T> {
@@ -306,9 +467,29 @@ impl<'a, T> NodeGraphArea<'a, T> {
             graph,
             graph_actions: Vec::new(),
             node_actions: Vec::new(),
+            minimap: false,
+            connect_allowed: None,
         }
     }
 
+    /// Only create connections for which `rule` returns true
+    ///
+    /// Consulted before the viewer's `connect`; while a wire is dragged toward
+    /// a rejected pin the cursor shows as not-allowed.
+    pub fn connect_allowed(
+        mut self,
+        rule: impl Fn(&OutPin, &InPin, &Snarl<T>) -> bool + 'a,
+    ) -> Self {
+        self.connect_allowed = Some(Box::new(rule));
+        self
+    }
+
+    /// Show a scaled overview in the bottom-right corner; drag it to pan
+    pub fn with_minimap(mut self, show: bool) -> Self {
+        self.minimap = show;
+        self
+    }
+
     /// Add an action to the graph context menu
     pub fn graph_action(mut self, action: MenuAction) -> Self {
         self.graph_actions.push(action);
@@ -334,21 +515,19 @@ impl<'a, T> NodeGraphArea<'a, T> {
     }
 
     /// Show the node graph with a viewer
-    pub fn show<V>(self, ui: &mut Ui, viewer: &mut V)
+    pub fn show<V>(mut self, ui: &mut Ui, viewer: &mut V)
     where
         V: SnarlViewer<T>,
     {
-        let theme = Theme::current(ui.ctx());
-        let snarl_style = self.graph.style.build_snarl_style(&theme);
-
-        self.graph
-            .inner
-            .show(viewer, &snarl_style, egui::Id::new("node_graph"), ui);
+        // Context menu actions only make sense with events, see `show_with`
+        let rule = self.connect_allowed.take();
+        let mut event_viewer = EventCapturingViewer::new(viewer, &[], &[], rule.as_deref());
+        self.show_viewer(ui, &mut event_viewer);
     }
 
     /// Show the node graph with events (TEA-style)
     pub fn show_with<V, Msg>(
-        self,
+        mut self,
         ctx: &mut egui_cha::ViewCtx<'_, Msg>,
         viewer: &mut V,
         on_event: impl Fn(NodeGraphEvent<T>) -> Msg,
@@ -356,43 +535,208 @@ impl<'a, T> NodeGraphArea<'a, T> {
         V: SnarlViewer<T>,
         T: Clone,
     {
-        let theme = Theme::current(ctx.ui.ctx());
-        let snarl_style = self.graph.style.build_snarl_style(&theme);
-
         // Wrap viewer to capture events
-        let mut event_viewer = EventCapturingViewer {
-            inner: viewer,
-            events: Vec::new(),
-            graph_actions: &self.graph_actions,
-            node_actions: &self.node_actions,
-        };
+        let graph_actions = std::mem::take(&mut self.graph_actions);
+        let node_actions = std::mem::take(&mut self.node_actions);
+        let rule = self.connect_allowed.take();
+        let mut event_viewer =
+            EventCapturingViewer::new(viewer, &graph_actions, &node_actions, rule.as_deref());
 
-        self.graph.inner.show(
-            &mut event_viewer,
-            &snarl_style,
-            egui::Id::new("node_graph"),
-            ctx.ui,
-        );
+        self.show_viewer(ctx.ui, &mut event_viewer);
 
         // Emit captured events
         for event in event_viewer.events {
             ctx.emit(on_event(event));
         }
     }
+
+    /// Show the graph through the wrapping viewer, applying any pending
+    /// pan/zoom and drawing the minimap
+    fn show_viewer<V>(self, ui: &mut Ui, viewer: &mut EventCapturingViewer<'_, V, T>)
+    where
+        V: SnarlViewer<T>,
+    {
+        let theme = Theme::current(ui.ctx());
+        let snarl_style = self.graph.style.build_snarl_style(&theme);
+        let id = egui::Id::new("node_graph");
+        let pending_id = id.with("pending_transform");
+        let viewport = ui.available_rect_before_wrap();
+
+        viewer.pending = ui
+            .ctx()
+            .data_mut(|d| d.remove_temp::<TSTransform>(pending_id));
+        if std::mem::take(&mut self.graph.fit_requested) {
+            viewer.pending = Some(self.graph.fit_transform(viewport));
+        }
+
+        self.graph.inner.show(viewer, &snarl_style, id, ui);
+
+        if let (Some(rule), Some(to_global)) = (viewer.connect_allowed, viewer.current) {
+            if self.dragging_toward_rejected(ui, rule, to_global, &viewer.in_rows, &viewer.out_rows)
+            {
+                ui.ctx().set_cursor_icon(egui::CursorIcon::NotAllowed);
+                if let Some(pos) = ui.input(|i| i.pointer.hover_pos()) {
+                    ui.painter()
+                        .circle_stroke(pos, 6.0, Stroke::new(2.0, theme.state_danger));
+                }
+            }
+        }
+
+        if self.minimap {
+            if let Some(to_global) = viewer.current {
+                if let Some(pan) = self.show_minimap(ui, &theme, id, viewport, to_global) {
+                    ui.ctx().data_mut(|d| d.insert_temp(pending_id, pan));
+                    ui.ctx().request_repaint();
+                }
+            }
+        }
+    }
+
+    /// Whether a wire is being dragged from one pin onto a pin `rule` rejects.
+    /// Pin rows are in graph space and mapped with `to_global`.
+    fn dragging_toward_rejected(
+        &self,
+        ui: &Ui,
+        rule: &ConnectRule<'_, T>,
+        to_global: TSTransform,
+        in_rows: &[(InPinId, Rect)],
+        out_rows: &[(OutPinId, Rect)],
+    ) -> bool {
+        let Some((origin, pointer)) = ui.input(|i| {
+            (i.pointer.primary_down() && i.pointer.is_decidedly_dragging())
+                .then(|| Some((i.pointer.press_origin()?, i.pointer.hover_pos()?)))
+                .flatten()
+        }) else {
+            return false;
+        };
+
+        fn nearest<P: Copy>(rows: &[(P, Rect)], to_global: TSTransform, pos: Pos2) -> Option<P> {
+            rows.iter()
+                .map(|(pin, rect)| (*pin, (to_global * *rect).distance_to_pos(pos)))
+                .filter(|(_, distance)| *distance <= PIN_REACH)
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(pin, _)| pin)
+        }
+
+        // Wires can be dragged from either end
+        let pair = match nearest(out_rows, to_global, origin) {
+            Some(from) => nearest(in_rows, to_global, pointer).map(|to| (from, to)),
+            None => nearest(in_rows, to_global, origin)
+                .zip(nearest(out_rows, to_global, pointer))
+                .map(|(to, from)| (from, to)),
+        };
+        pair.is_some_and(|(from, to)| {
+            let snarl = &self.graph.inner;
+            !rule(&snarl.out_pin(from), &snarl.in_pin(to), snarl)
+        })
+    }
+
+    /// Draw the overview; returns a new transform when it was clicked or dragged
+    fn show_minimap(
+        &self,
+        ui: &mut Ui,
+        theme: &Theme,
+        id: egui::Id,
+        viewport: Rect,
+        to_global: TSTransform,
+    ) -> Option<TSTransform> {
+        let visible = to_global.inverse() * viewport;
+        let scene = self
+            .graph
+            .node_bounds()
+            .map_or(visible, |bounds| bounds.union(visible));
+
+        let map_rect = Rect::from_min_size(
+            viewport.max - MINIMAP_SIZE - Vec2::splat(theme.spacing_sm),
+            MINIMAP_SIZE,
+        );
+        let inner = map_rect.shrink(theme.spacing_xs);
+        let scale = (inner.width() / scene.width()).min(inner.height() / scene.height());
+        let offset = inner.center().to_vec2() - scene.center().to_vec2() * scale;
+        let to_map = TSTransform::new(offset, scale);
+
+        let response = ui
+            .interact(map_rect, id.with("minimap"), Sense::click_and_drag())
+            .on_hover_cursor(egui::CursorIcon::Grab);
+
+        let painter = ui.painter().with_clip_rect(map_rect);
+        painter.rect_filled(
+            map_rect,
+            theme.radius_sm,
+            theme.bg_secondary.gamma_multiply(0.9),
+        );
+        painter.rect_stroke(
+            map_rect,
+            theme.radius_sm,
+            Stroke::new(theme.border_width, theme.border),
+            egui::StrokeKind::Inside,
+        );
+        for (node_id, _) in self.graph.inner.node_ids() {
+            if let Some(node) = self.graph.inner.get_node_info(node_id) {
+                let node_rect = to_map * Rect::from_min_size(node.pos, NODE_EXTENT);
+                painter.rect_filled(node_rect, 1.0, theme.text_muted);
+            }
+        }
+        painter.rect_stroke(
+            to_map * visible,
+            1.0,
+            Stroke::new(1.5, theme.primary),
+            egui::StrokeKind::Outside,
+        );
+
+        // Center the view on the graph point under the pointer
+        if !(response.clicked() || response.dragged()) {
+            return None;
+        }
+        let pointer = response.interact_pointer_pos()?;
+        let target = to_map.inverse() * pointer;
+        Some(TSTransform::new(
+            viewport.center().to_vec2() - target.to_vec2() * to_global.scaling,
+            to_global.scaling,
+        ))
+    }
 }
 
-/// Internal viewer wrapper that captures events
+/// Internal viewer wrapper that captures events and the view transform
 struct EventCapturingViewer<'a, V, T> {
     inner: &'a mut V,
     events: Vec<NodeGraphEvent<T>>,
     graph_actions: &'a [MenuAction],
     node_actions: &'a [MenuAction],
+    /// Transform to apply this frame (fit or minimap pan)
+    pending: Option<TSTransform>,
+    /// Transform snarl used this frame
+    current: Option<TSTransform>,
+    connect_allowed: Option<&'a ConnectRule<'a, T>>,
+    /// Pin rows drawn this frame (graph space), for the drag-validity cursor
+    in_rows: Vec<(InPinId, Rect)>,
+    out_rows: Vec<(OutPinId, Rect)>,
+}
+
+impl<'a, V, T> EventCapturingViewer<'a, V, T> {
+    fn new(
+        inner: &'a mut V,
+        graph_actions: &'a [MenuAction],
+        node_actions: &'a [MenuAction],
+        connect_allowed: Option<&'a ConnectRule<'a, T>>,
+    ) -> Self {
+        Self {
+            inner,
+            events: Vec::new(),
+            graph_actions,
+            node_actions,
+            pending: None,
+            current: None,
+            connect_allowed,
+            in_rows: Vec::new(),
+            out_rows: Vec::new(),
+        }
+    }
 }
 
 impl<'a, V, T> SnarlViewer<T> for EventCapturingViewer<'a, V, T>
 where
     V: SnarlViewer<T>,
-    T: Clone,
 {
     fn title(&mut self, node: &T) -> String {
         self.inner.title(node)
@@ -406,12 +750,22 @@ where
         self.inner.outputs(node)
     }
 
+    fn current_transform(&mut self, to_global: &mut TSTransform, snarl: &mut Snarl<T>) {
+        if let Some(pending) = self.pending.take() {
+            *to_global = pending;
+        }
+        self.inner.current_transform(to_global, snarl);
+        self.current = Some(*to_global);
+    }
+
     fn show_input(
         &mut self,
         pin: &InPin,
         ui: &mut Ui,
         snarl: &mut Snarl<T>,
     ) -> impl egui_snarl::ui::SnarlPin + 'static {
+        // The returned pin borrows `ui`, so record the row area first
+        self.in_rows.push((pin.id, ui.max_rect()));
         self.inner.show_input(pin, ui, snarl)
     }
 
@@ -421,10 +775,17 @@ where
         ui: &mut Ui,
         snarl: &mut Snarl<T>,
     ) -> impl egui_snarl::ui::SnarlPin + 'static {
+        // The returned pin borrows `ui`, so record the row area first
+        self.out_rows.push((pin.id, ui.max_rect()));
         self.inner.show_output(pin, ui, snarl)
     }
 
     fn connect(&mut self, from: &OutPin, to: &InPin, snarl: &mut Snarl<T>) {
+        if let Some(rule) = self.connect_allowed {
+            if !rule(from, to, snarl) {
+                return;
+            }
+        }
         self.events.push(NodeGraphEvent::Connected {
             from: from.id,
             to: to.id,
@@ -559,3 +920,182 @@ pub mod presets {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_transform_encloses_nodes() {
+        let mut graph = NodeGraph::new();
+        let a = Pos2::new(50.0, 100.0);
+        let b = Pos2::new(450.0, 100.0);
+        graph.insert(a, "a");
+        graph.insert(b, "b");
+        graph.fit_to_nodes();
+        assert!(graph.fit_requested);
+
+        let viewport = Rect::from_min_size(Pos2::new(10.0, 20.0), Vec2::new(300.0, 200.0));
+        let transform = graph.fit_transform(viewport);
+        let visible = transform.inverse() * viewport;
+        assert!(visible.contains(a), "{visible:?} should contain {a:?}");
+        assert!(visible.contains(b), "{visible:?} should contain {b:?}");
+        // The graph is wider than the viewport, so the view zooms out
+        assert!(transform.scaling < 1.0);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Port {
+        Audio,
+        Control,
+    }
+
+    /// One input and one output per node, typed by the node
+    struct PortViewer;
+
+    impl PortViewer {
+        /// Only audio feeds audio
+        fn allows(from: &OutPin, to: &InPin, snarl: &Snarl<Port>) -> bool {
+            snarl.get_node(from.id.node) == Some(&Port::Audio)
+                && snarl.get_node(to.id.node) == Some(&Port::Audio)
+        }
+    }
+
+    impl SnarlViewer<Port> for PortViewer {
+        fn title(&mut self, node: &Port) -> String {
+            format!("{node:?}")
+        }
+
+        fn inputs(&mut self, _node: &Port) -> usize {
+            1
+        }
+
+        fn outputs(&mut self, _node: &Port) -> usize {
+            1
+        }
+
+        fn show_input(&mut self, _pin: &InPin, _ui: &mut Ui, _snarl: &mut Snarl<Port>) -> PinInfo {
+            PinInfo::circle()
+        }
+
+        fn show_output(
+            &mut self,
+            _pin: &OutPin,
+            _ui: &mut Ui,
+            _snarl: &mut Snarl<Port>,
+        ) -> PinInfo {
+            PinInfo::circle()
+        }
+    }
+
+    #[test]
+    fn test_connect_allowed_rejects_mismatched_pins() {
+        let mut graph = NodeGraph::new();
+        let source = graph.insert(Pos2::ZERO, Port::Audio);
+        let audio = graph.insert(Pos2::new(200.0, 0.0), Port::Audio);
+        let control = graph.insert(Pos2::new(200.0, 100.0), Port::Control);
+
+        let rule = PortViewer::allows;
+        let mut inner = PortViewer;
+        let mut viewer = EventCapturingViewer::new(&mut inner, &[], &[], Some(&rule));
+        let snarl = graph.inner_mut();
+        let from = snarl.out_pin(OutPinId {
+            node: source,
+            output: 0,
+        });
+        let audio_in = InPinId {
+            node: audio,
+            input: 0,
+        };
+        let control_in = InPinId {
+            node: control,
+            input: 0,
+        };
+
+        viewer.connect(&from, &snarl.in_pin(control_in), snarl);
+        viewer.connect(&from, &snarl.in_pin(audio_in), snarl);
+
+        assert!(snarl.in_pin(control_in).remotes.is_empty());
+        assert_eq!(snarl.in_pin(audio_in).remotes, vec![from.id]);
+        assert!(matches!(
+            viewer.events.as_slice(),
+            [NodeGraphEvent::Connected { to, .. }] if *to == audio_in
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_graph_data_round_trip() {
+        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+        enum DemoNode {
+            Source { name: String },
+            Effect { intensity: f32 },
+            Output,
+        }
+
+        // Same graph as the storybook demo: source -> effect -> output
+        let mut graph = NodeGraph::new();
+        let source = graph.insert(
+            Pos2::new(50.0, 100.0),
+            DemoNode::Source {
+                name: "Audio".into(),
+            },
+        );
+        let effect = graph.insert(Pos2::new(250.0, 100.0), DemoNode::Effect { intensity: 0.5 });
+        let output = graph.insert(Pos2::new(450.0, 100.0), DemoNode::Output);
+        for (from, to) in [(source, effect), (effect, output)] {
+            graph.connect(
+                OutPinId {
+                    node: from,
+                    output: 0,
+                },
+                InPinId { node: to, input: 0 },
+            );
+        }
+
+        let data = graph.to_serializable();
+        let text = toml::to_string(&data).unwrap();
+        let restored: GraphData<DemoNode> = toml::from_str(&text).unwrap();
+        assert_eq!(restored, data);
+
+        let rebuilt = NodeGraph::from_serializable(restored);
+        assert_eq!(rebuilt.node_count(), 3);
+        let positions: Vec<Pos2> = rebuilt
+            .nodes()
+            .filter_map(|(id, _)| rebuilt.inner().get_node_info(id))
+            .map(|node| node.pos)
+            .collect();
+        assert_eq!(
+            positions,
+            vec![
+                Pos2::new(50.0, 100.0),
+                Pos2::new(250.0, 100.0),
+                Pos2::new(450.0, 100.0)
+            ]
+        );
+        assert_eq!(
+            rebuilt.to_serializable().connections,
+            vec![
+                GraphConnection {
+                    from_node: 0,
+                    from_output: 0,
+                    to_node: 1,
+                    to_input: 0
+                },
+                GraphConnection {
+                    from_node: 1,
+                    from_output: 0,
+                    to_node: 2,
+                    to_input: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fit_transform_empty_graph() {
+        let graph = NodeGraph::<()>::new();
+        let viewport = Rect::from_min_size(Pos2::new(10.0, 20.0), Vec2::new(300.0, 200.0));
+        assert_eq!(graph.fit_transform(viewport).scaling, 1.0);
+    }
+}
@@ -1,16 +1,138 @@
 //! Tabs molecule
+//!
+//! # Example
+//!
+//! ```ignore
+//! // Fixed tabs
+//! Tabs::new(&["First", "Second"]).show_with(ctx, model.tab, Msg::TabChanged);
+//!
+//! // Browser-style tabs with close (×) and add (+) buttons
+//! Tabs::new(&titles)
+//!     .closable(true)
+//!     .with_add_button(true)
+//!     .reorderable(true)
+//!     .show_events_with(ctx, model.tab, Msg::TabEvent);
+//!
+//! // In update
+//! Msg::TabEvent(event) => {
+//!     model.tab = event.next_active(model.tab, model.docs.len());
+//!     match event {
+//!         TabEvent::Close(i) => { model.docs.remove(i); }
+//!         TabEvent::Add => model.docs.push(Doc::default()),
+//!         TabEvent::Reorder { from, to } => {
+//!             let doc = model.docs.remove(from);
+//!             model.docs.insert(to, doc);
+//!         }
+//!         TabEvent::Select(_) => {}
+//!     }
+//! }
+//! ```
 
-use egui::{Color32, RichText, Ui};
+use egui::{Color32, Id, RichText, Sense, Stroke, Ui, Vec2};
+use egui_cha::drag_drop::{Reorder, ReorderDrag};
 use egui_cha::ViewCtx;
 
+use crate::icons;
+
+/// Size of the close (×) and add (+) buttons
+const TAB_BUTTON_SIZE: f32 = 16.0;
+
+/// Events emitted by the tab bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabEvent {
+    /// A tab was clicked
+    Select(usize),
+    /// The close (×) button of a tab was clicked
+    Close(usize),
+    /// The add (+) button was clicked
+    Add,
+    /// A tab was dragged to a new position
+    ///
+    /// `to` is the index the tab ends up at, as in [`Reorder`].
+    Reorder { from: usize, to: usize },
+}
+
+impl TabEvent {
+    /// Active index after applying this event to a bar of `len` tabs
+    ///
+    /// Closing a tab left of the active one shifts the index so the same
+    /// content stays selected. `Add` selects the new (last) tab. `Reorder`
+    /// keeps the same content selected, following the moved tab.
+    pub fn next_active(&self, active: usize, len: usize) -> usize {
+        match *self {
+            TabEvent::Reorder { from, to } => {
+                if active == from {
+                    return to;
+                }
+                let after_remove = if active > from { active - 1 } else { active };
+                if after_remove >= to {
+                    after_remove + 1
+                } else {
+                    after_remove
+                }
+            }
+            TabEvent::Select(index) => index,
+            TabEvent::Close(index) if index < active => active - 1,
+            TabEvent::Close(index) if index == active => active.min(len.saturating_sub(2)),
+            TabEvent::Close(_) => active,
+            TabEvent::Add => len,
+        }
+    }
+}
+
 /// Tab bar component
 pub struct Tabs<'a> {
     tabs: &'a [&'a str],
+    closable: bool,
+    add_button: bool,
+    reorderable: bool,
+    id_salt: Option<Id>,
 }
 
 impl<'a> Tabs<'a> {
     pub fn new(tabs: &'a [&'a str]) -> Self {
-        Self { tabs }
+        Self {
+            tabs,
+            closable: false,
+            add_button: false,
+            reorderable: false,
+            id_salt: None,
+        }
+    }
+
+    /// Show a close (×) button on each tab, emitting [`TabEvent::Close`]
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Show an add (+) button after the tabs, emitting [`TabEvent::Add`]
+    pub fn with_add_button(mut self, add_button: bool) -> Self {
+        self.add_button = add_button;
+        self
+    }
+
+    /// Allow dragging tab headers to reorder, emitting [`TabEvent::Reorder`]
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Set the id used for hover, drag and button state
+    ///
+    /// Needed when several tab bars share a parent, like
+    /// [`egui::ScrollArea::id_salt`].
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// Base of all ids of this tab bar
+    fn base_id(&self, ui: &Ui) -> Id {
+        match self.id_salt {
+            Some(salt) => ui.id().with(salt),
+            None => ui.id().with("tabs"),
+        }
     }
 
     /// TEA-style: Show tabs with current index, emit Msg on tab change
@@ -20,20 +142,37 @@ impl<'a> Tabs<'a> {
         active: usize,
         on_change: impl Fn(usize) -> Msg,
     ) {
-        if let Some(new_idx) = self.render(ctx.ui, active) {
+        if let Some(TabEvent::Select(new_idx)) = self.render(ctx.ui, active) {
             ctx.emit(on_change(new_idx));
         }
     }
 
+    /// TEA-style: Show tabs and emit every [`TabEvent`] (select, close, add, reorder)
+    pub fn show_events_with<Msg>(
+        self,
+        ctx: &mut ViewCtx<'_, Msg>,
+        active: usize,
+        on_event: impl Fn(TabEvent) -> Msg,
+    ) {
+        if let Some(event) = self.render(ctx.ui, active) {
+            ctx.emit(on_event(event));
+        }
+    }
+
     /// Show tabs (modifies index in place)
     pub fn show(self, ui: &mut Ui, active: &mut usize) {
-        if let Some(new_idx) = self.render(ui, *active) {
+        if let Some(TabEvent::Select(new_idx)) = self.render(ui, *active) {
             *active = new_idx;
         }
     }
 
-    /// Render tabs and return clicked index if any
-    fn render(self, ui: &mut Ui, active: usize) -> Option<usize> {
+    /// Show tabs and return the event if any
+    pub fn show_events(self, ui: &mut Ui, active: usize) -> Option<TabEvent> {
+        self.render(ui, active)
+    }
+
+    /// Render tabs and return the event if any
+    fn render(self, ui: &mut Ui, active: usize) -> Option<TabEvent> {
         let is_dark = ui.ctx().style().visuals.dark_mode;
 
         let active_color = if is_dark {
@@ -54,7 +193,10 @@ impl<'a> Tabs<'a> {
             Color32::from_rgb(229, 231, 235)
         };
 
-        let mut clicked_idx: Option<usize> = None;
+        let mut event: Option<TabEvent> = None;
+        let base_id = self.base_id(ui);
+
+        let mut drag = ReorderDrag::new(ui, base_id.with("drag")).horizontal(true);
 
         ui.horizontal(|ui| {
             for (i, tab) in self.tabs.iter().enumerate() {
@@ -70,11 +212,36 @@ impl<'a> Tabs<'a> {
                     ui.selectable_label(is_active, RichText::new(*tab).color(text_color));
 
                 if response.clicked() && !is_active {
-                    clicked_idx = Some(i);
+                    event = Some(TabEvent::Select(i));
+                }
+
+                if self.reorderable {
+                    let drag_response =
+                        ui.interact(response.rect, tab_drag_id(base_id, i), Sense::drag());
+                    drag.item(response.rect, drag_response.drag_started());
                 }
+
+                if self.closable
+                    && icon_button(ui, close_button_id(base_id, i), icons::X, text_color)
+                {
+                    event = Some(TabEvent::Close(i));
+                }
+            }
+
+            if self.add_button
+                && icon_button(ui, add_button_id(base_id), icons::PLUS, inactive_color)
+            {
+                event = Some(TabEvent::Add);
             }
         });
 
+        if self.reorderable {
+            if let Some(Reorder { from, to }) = drag.update(ui) {
+                event = Some(TabEvent::Reorder { from, to });
+            }
+            drag.paint_drop_marker(ui.painter(), Stroke::new(2.0, active_color));
+        }
+
         // Bottom border
         ui.painter().hline(
             ui.available_rect_before_wrap().x_range(),
@@ -82,10 +249,47 @@ impl<'a> Tabs<'a> {
             egui::Stroke::new(1.0, border_color),
         );
 
-        clicked_idx
+        event
     }
 }
 
+fn tab_drag_id(base_id: Id, index: usize) -> Id {
+    base_id.with(("drag", index))
+}
+
+fn close_button_id(base_id: Id, index: usize) -> Id {
+    base_id.with(("close", index))
+}
+
+fn add_button_id(base_id: Id) -> Id {
+    base_id.with("add")
+}
+
+/// Small icon button with a stable id, returns true if clicked
+fn icon_button(ui: &mut Ui, id: Id, icon: &str, color: Color32) -> bool {
+    let (rect, _) = ui.allocate_exact_size(Vec2::splat(TAB_BUTTON_SIZE), Sense::hover());
+    let response = ui
+        .interact(rect, id, Sense::click())
+        .on_hover_cursor(egui::CursorIcon::PointingHand);
+
+    if response.hovered() {
+        ui.painter()
+            .rect_filled(rect, 2.0, ui.visuals().widgets.hovered.bg_fill);
+    }
+    ui.painter().text(
+        rect.center(),
+        egui::Align2::CENTER_CENTER,
+        icon,
+        egui::FontId::new(
+            TAB_BUTTON_SIZE * 0.75,
+            egui::FontFamily::Name("icons".into()),
+        ),
+        color,
+    );
+
+    response.clicked()
+}
+
 /// Tab panel - content container that shows based on active tab
 pub struct TabPanel;
 
@@ -109,3 +313,160 @@ impl TabPanel {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, drag, run_ui, test_context};
+
+    #[test]
+    fn test_close_left_of_active_keeps_content() {
+        let mut docs = vec!["a", "b", "c"];
+        let active = 1;
+        let selected = docs[active];
+
+        let event = TabEvent::Close(0);
+        let active = event.next_active(active, docs.len());
+        docs.remove(0);
+
+        assert_eq!(active, 0);
+        assert_eq!(docs[active], selected);
+    }
+
+    #[test]
+    fn test_close_active_stays_in_range() {
+        // Closing the last tab while it is active selects the new last tab
+        assert_eq!(TabEvent::Close(2).next_active(2, 3), 1);
+        // Closing the active middle tab selects its right neighbour
+        assert_eq!(TabEvent::Close(1).next_active(1, 3), 1);
+        // Closing right of active doesn't move it
+        assert_eq!(TabEvent::Close(2).next_active(0, 3), 0);
+        assert_eq!(TabEvent::Close(0).next_active(0, 1), 0);
+    }
+
+    #[test]
+    fn test_reorder_follows_selected_tab() {
+        let mut docs = vec!["a", "b", "c"];
+        let active = 2;
+        let event = TabEvent::Reorder { from: 2, to: 0 };
+        let active = event.next_active(active, docs.len());
+        let doc = docs.remove(2);
+        docs.insert(0, doc);
+        assert_eq!(docs[active], "c");
+
+        // Active tab shifted by a move across it
+        let mut docs = vec!["a", "b", "c"];
+        let active = 1;
+        let event = TabEvent::Reorder { from: 0, to: 2 };
+        let active = event.next_active(active, docs.len());
+        let doc = docs.remove(0);
+        docs.insert(2, doc);
+        assert_eq!(docs[active], "b");
+    }
+
+    #[test]
+    fn test_drag_tab_past_last_reports_final_index() {
+        let ctx = test_context();
+        let tabs = ["One", "Two", "Three"];
+        let mut ids = Vec::new();
+
+        run_ui(&ctx, |ui| {
+            let base_id = Tabs::new(&tabs).base_id(ui);
+            ids = vec![tab_drag_id(base_id, 0), tab_drag_id(base_id, 2)];
+            Tabs::new(&tabs).reorderable(true).show_events(ui, 0);
+        });
+
+        let first = ctx.read_response(ids[0]).unwrap().rect;
+        let third = ctx.read_response(ids[1]).unwrap().rect;
+        let mut events = Vec::new();
+        drag(
+            &ctx,
+            first.center(),
+            third.right_center() - Vec2::new(2.0, 0.0),
+            |ui| {
+                events.extend(Tabs::new(&tabs).reorderable(true).show_events(ui, 0));
+            },
+        );
+
+        assert_eq!(events, vec![TabEvent::Reorder { from: 0, to: 2 }]);
+        assert_eq!(events[0].next_active(0, tabs.len()), 2);
+    }
+
+    #[test]
+    fn test_drag_tab_before_first_emits_reorder() {
+        let ctx = test_context();
+        let tabs = ["One", "Two", "Three"];
+        let mut ids = Vec::new();
+
+        run_ui(&ctx, |ui| {
+            let base_id = Tabs::new(&tabs).base_id(ui);
+            ids = vec![tab_drag_id(base_id, 0), tab_drag_id(base_id, 2)];
+            Tabs::new(&tabs).reorderable(true).show_events(ui, 0);
+        });
+
+        let first = ctx.read_response(ids[0]).unwrap().rect;
+        let third = ctx.read_response(ids[1]).unwrap().rect;
+        let mut events = Vec::new();
+        drag(
+            &ctx,
+            third.center(),
+            first.left_center() + Vec2::new(2.0, 0.0),
+            |ui| {
+                events.extend(Tabs::new(&tabs).reorderable(true).show_events(ui, 0));
+            },
+        );
+
+        assert_eq!(events, vec![TabEvent::Reorder { from: 2, to: 0 }]);
+    }
+
+    #[test]
+    fn test_add_button_emits_add() {
+        let ctx = test_context();
+        let tabs = ["One", "Two"];
+        let mut add_id = None;
+
+        run_ui(&ctx, |ui| {
+            add_id = Some(add_button_id(Tabs::new(&tabs).base_id(ui)));
+            Tabs::new(&tabs).with_add_button(true).show_events(ui, 0);
+        });
+
+        let rect = ctx.read_response(add_id.unwrap()).unwrap().rect;
+        let mut events = Vec::new();
+        click_at(&ctx, rect.center(), |ui| {
+            events.extend(Tabs::new(&tabs).with_add_button(true).show_events(ui, 0));
+        });
+
+        assert_eq!(events, vec![TabEvent::Add]);
+    }
+
+    #[test]
+    fn test_sibling_tab_bars_with_salts_keep_state_apart() {
+        let ctx = test_context();
+        let tabs = ["One", "Two"];
+        let bar = |salt: &str| Tabs::new(&tabs).with_add_button(true).id_salt(salt);
+        let mut add_ids = Vec::new();
+
+        run_ui(&ctx, |ui| {
+            add_ids = vec![
+                add_button_id(bar("left").base_id(ui)),
+                add_button_id(bar("right").base_id(ui)),
+            ];
+            bar("left").show_events(ui, 0);
+            bar("right").show_events(ui, 0);
+        });
+        assert_ne!(add_ids[0], add_ids[1]);
+
+        let rect = ctx.read_response(add_ids[1]).unwrap().rect;
+        let mut events = Vec::new();
+        click_at(&ctx, rect.center(), |ui| {
+            let left = bar("left").show_events(ui, 0);
+            let right = bar("right").show_events(ui, 0);
+            events.push((left, right));
+        });
+
+        assert!(events.iter().all(|(left, _)| left.is_none()));
+        assert!(events
+            .iter()
+            .any(|(_, right)| *right == Some(TabEvent::Add)));
+    }
+}
@@ -37,9 +37,26 @@
 //!     model.toasts.show(ctx, Msg::DismissToast);
 //! }
 //! ```
+//!
+//! # Actions
+//!
+//! A toast can carry an inline action button (e.g. "Undo"). Clicking it
+//! emits the action message and dismisses the toast:
+//!
+//! ```ignore
+//! // Dedicated constructor
+//! model.toasts.action("Item deleted", "Undo", Msg::Undo, Duration::from_secs(5), Msg::DismissToast)
+//!
+//! // Or chain onto any variant
+//! model.toasts
+//!     .with_action("Retry", Msg::Retry)
+//!     .error("Upload failed", Duration::from_secs(5), Msg::DismissToast)
+//! ```
 
 use egui::{Align2, Area, Color32, CornerRadius, Frame, Id, Order, RichText, Vec2};
 use egui_cha::{Cmd, ViewCtx};
+use std::any::Any;
+use std::fmt;
 use std::time::Duration;
 
 use crate::{icons, Theme, ThemeVariant};
@@ -167,19 +184,112 @@ impl ToastPosition {
     }
 }
 
+/// Type-erased action message (the container is not generic over `Msg`)
+trait ActionMsg: Any + Send {
+    fn clone_box(&self) -> Box<dyn ActionMsg>;
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Any + Send + Clone> ActionMsg for T {
+    fn clone_box(&self) -> Box<dyn ActionMsg> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Inline action button on a toast (e.g. "Undo")
+struct ToastAction {
+    label: String,
+    msg: Box<dyn ActionMsg>,
+    /// Type name of `msg`, for the mismatch assertion
+    msg_type: &'static str,
+}
+
+impl ToastAction {
+    /// Get the action message if it matches the view's `Msg` type
+    ///
+    /// A mismatch is a bug in the app (the action was added with a
+    /// different `Msg` than the toasts are shown with), so it asserts in
+    /// debug builds and is skipped in release builds.
+    fn msg<Msg: Clone + 'static>(&self) -> Option<Msg> {
+        let msg = self.msg.as_any().downcast_ref::<Msg>().cloned();
+        debug_assert!(
+            msg.is_some(),
+            "toast action message is a `{}`, but the toasts are shown with `{}`",
+            self.msg_type,
+            std::any::type_name::<Msg>()
+        );
+        msg
+    }
+}
+
+impl Clone for ToastAction {
+    fn clone(&self) -> Self {
+        Self {
+            label: self.label.clone(),
+            msg: self.msg.clone_box(),
+            msg_type: self.msg_type,
+        }
+    }
+}
+
+impl fmt::Debug for ToastAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToastAction")
+            .field("label", &self.label)
+            .finish_non_exhaustive()
+    }
+}
+
 /// A single toast notification
 #[derive(Debug, Clone)]
 struct Toast {
     id: ToastId,
     message: String,
     variant: ToastVariant,
+    action: Option<ToastAction>,
+    /// Number of coalesced duplicates (1 = single toast)
+    count: u32,
+}
+
+impl Toast {
+    /// Messages to emit when the action button is clicked: action, then dismiss
+    fn action_clicked<Msg: Clone + 'static>(
+        &self,
+        dismiss_msg: impl Fn(ToastId) -> Msg,
+    ) -> Vec<Msg> {
+        self.action
+            .as_ref()
+            .and_then(|action| action.msg::<Msg>())
+            .into_iter()
+            .chain(std::iter::once(dismiss_msg(self.id)))
+            .collect()
+    }
 }
 
 /// Container for managing toast notifications
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct ToastContainer {
     toasts: Vec<Toast>,
     position: ToastPosition,
+    /// Action attached to the next pushed toast (see `with_action`)
+    pending_action: Option<ToastAction>,
+    /// Coalesce identical live toasts into one with a count badge
+    dedup: bool,
+}
+
+impl Default for ToastContainer {
+    fn default() -> Self {
+        Self {
+            toasts: Vec::new(),
+            position: ToastPosition::default(),
+            pending_action: None,
+            dedup: true,
+        }
+    }
 }
 
 impl ToastContainer {
@@ -194,6 +304,15 @@ impl ToastContainer {
         self
     }
 
+    /// Coalesce duplicate toasts (same variant and message) (default: true)
+    ///
+    /// A duplicate increments the live toast's count badge ("×3") and
+    /// restarts its timer instead of adding a new entry.
+    pub fn dedup(mut self, dedup: bool) -> Self {
+        self.dedup = dedup;
+        self
+    }
+
     /// Add an info toast
     pub fn info<Msg, F>(
         &mut self,
@@ -250,6 +369,44 @@ impl ToastContainer {
         self.push(message, ToastVariant::Error, duration, dismiss_msg)
     }
 
+    /// Add an info toast with an inline action button
+    ///
+    /// Clicking the button emits `action_msg` and dismisses the toast.
+    pub fn action<Msg, F>(
+        &mut self,
+        message: impl Into<String>,
+        label: impl Into<String>,
+        action_msg: Msg,
+        duration: Duration,
+        dismiss_msg: F,
+    ) -> Cmd<Msg>
+    where
+        Msg: 'static + Send + Clone,
+        F: Fn(ToastId) -> Msg + 'static + Send,
+    {
+        self.with_action(label, action_msg)
+            .push(message, ToastVariant::Info, duration, dismiss_msg)
+    }
+
+    /// Attach an action button to the next toast added
+    ///
+    /// ```ignore
+    /// model.toasts
+    ///     .with_action("Undo", Msg::Undo)
+    ///     .success("Deleted", Duration::from_secs(5), Msg::DismissToast)
+    /// ```
+    pub fn with_action<Msg>(&mut self, label: impl Into<String>, msg: Msg) -> &mut Self
+    where
+        Msg: 'static + Send + Clone,
+    {
+        self.pending_action = Some(ToastAction {
+            label: label.into(),
+            msg: Box::new(msg),
+            msg_type: std::any::type_name::<Msg>(),
+        });
+        self
+    }
+
     /// Add a toast with custom variant
     fn push<Msg, F>(
         &mut self,
@@ -263,11 +420,30 @@ impl ToastContainer {
         F: Fn(ToastId) -> Msg + 'static + Send,
     {
         let id = ToastId::new();
-        self.toasts.push(Toast {
-            id,
-            message: message.into(),
-            variant,
-        });
+        let message = message.into();
+        let action = self.pending_action.take();
+
+        let existing = self
+            .toasts
+            .iter_mut()
+            .find(|t| self.dedup && t.variant == variant && t.message == message);
+
+        if let Some(toast) = existing {
+            // Fresh ID restarts the timer: the previous dismiss Cmd becomes a no-op
+            toast.id = id;
+            toast.count += 1;
+            if action.is_some() {
+                toast.action = action;
+            }
+        } else {
+            self.toasts.push(Toast {
+                id,
+                message,
+                variant,
+                action,
+                count: 1,
+            });
+        }
 
         // Return command to auto-dismiss after duration
         Cmd::delay(duration, dismiss_msg(id))
@@ -289,16 +465,28 @@ impl ToastContainer {
     }
 
     /// Show all toasts
-    pub fn show<Msg, F>(&self, ctx: &mut ViewCtx<'_, Msg>, _dismiss_msg: F)
+    pub fn show<Msg, F>(&self, ctx: &mut ViewCtx<'_, Msg>, dismiss_msg: F)
     where
+        Msg: Clone + 'static,
         F: Fn(ToastId) -> Msg + Clone,
     {
+        let messages = self.render(ctx.ui.ctx(), dismiss_msg);
+        ctx.emit_all(messages);
+    }
+
+    /// Render all toasts and return the messages of clicked actions
+    fn render<Msg, F>(&self, egui_ctx: &egui::Context, dismiss_msg: F) -> Vec<Msg>
+    where
+        Msg: Clone + 'static,
+        F: Fn(ToastId) -> Msg + Clone,
+    {
+        let mut messages = Vec::new();
         if self.toasts.is_empty() {
-            return;
+            return messages;
         }
 
-        let theme = Theme::current(ctx.ui.ctx());
-        let screen_rect = ctx.ui.ctx().content_rect();
+        let theme = Theme::current(egui_ctx);
+        let screen_rect = egui_ctx.content_rect();
 
         // Calculate starting position
         let anchor = self.position.anchor();
@@ -321,7 +509,7 @@ impl ToastContainer {
             Area::new(Id::new("toast").with(toast.id.0))
                 .anchor(anchor, pos - anchor.pos_in_rect(&screen_rect))
                 .order(Order::Foreground)
-                .show(ctx.ui.ctx(), |ui| {
+                .show(egui_ctx, |ui| {
                     let (bg, fg, icon) = toast.variant.colors(&theme);
 
                     Frame::new()
@@ -343,8 +531,32 @@ impl ToastContainer {
                                 // Message
                                 ui.label(RichText::new(&toast.message).color(fg));
 
+                                // Duplicate count badge
+                                if toast.count > 1 {
+                                    ui.label(
+                                        RichText::new(format!("×{}", toast.count))
+                                            .color(fg)
+                                            .small()
+                                            .strong(),
+                                    );
+                                }
+
                                 ui.add_space(theme.spacing_md);
 
+                                // Action button
+                                if let Some(action) = &toast.action {
+                                    let action_response = ui.add(
+                                        egui::Button::new(
+                                            RichText::new(&action.label).color(fg).strong(),
+                                        )
+                                        .fill(Color32::TRANSPARENT)
+                                        .stroke(egui::Stroke::new(theme.border_width, fg)),
+                                    );
+                                    if action_response.clicked() {
+                                        messages.extend(toast.action_clicked(&dismiss_msg));
+                                    }
+                                }
+
                                 // Close button
                                 let _close_response = ui.add(
                                     egui::Button::new(
@@ -362,6 +574,8 @@ impl ToastContainer {
                         });
                 });
         }
+
+        messages
     }
 }
 
@@ -390,6 +604,7 @@ fn darken(color: Color32, amount: f32) -> Color32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::{click_at, painted_text_rect, run_ui, test_context};
 
     #[test]
     fn test_toast_container() {
@@ -404,6 +619,131 @@ mod tests {
         assert!(container.is_empty());
     }
 
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestMsg {
+        Undo,
+        Dismiss(ToastId),
+    }
+
+    #[test]
+    fn test_action_click_emits_and_dismisses() {
+        let mut container = ToastContainer::new();
+        let _cmd = container.action(
+            "Deleted",
+            "Undo",
+            TestMsg::Undo,
+            Duration::from_secs(5),
+            TestMsg::Dismiss,
+        );
+        assert_eq!(container.len(), 1);
+
+        let id = container.toasts[0].id;
+        let msgs = container.toasts[0].action_clicked(TestMsg::Dismiss);
+        assert_eq!(msgs, vec![TestMsg::Undo, TestMsg::Dismiss(id)]);
+
+        // Update handles the dismiss message
+        for msg in msgs {
+            if let TestMsg::Dismiss(id) = msg {
+                container.dismiss(id);
+            }
+        }
+        assert!(container.is_empty());
+    }
+
+    #[test]
+    fn test_action_button_click_emits_message() {
+        let ctx = test_context();
+        let mut container = ToastContainer::new();
+        let _cmd = container.action(
+            "Deleted",
+            "Undo",
+            TestMsg::Undo,
+            Duration::from_secs(5),
+            TestMsg::Dismiss,
+        );
+        let id = container.toasts[0].id;
+
+        // Let the anchored area settle on its size before clicking
+        run_ui(&ctx, |ui| {
+            container.render(ui.ctx(), TestMsg::Dismiss);
+        });
+        let output = run_ui(&ctx, |ui| {
+            container.render(ui.ctx(), TestMsg::Dismiss);
+        });
+        let button = painted_text_rect(&output, "Undo").unwrap();
+
+        let mut msgs = Vec::new();
+        click_at(&ctx, button.center(), |ui| {
+            msgs.extend(container.render(ui.ctx(), TestMsg::Dismiss));
+        });
+        assert_eq!(msgs, vec![TestMsg::Undo, TestMsg::Dismiss(id)]);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "toast action message")]
+    fn test_mismatched_action_msg_asserts() {
+        let mut container = ToastContainer::new();
+        let _cmd = container.action(
+            "Deleted",
+            "Undo",
+            TestMsg::Undo,
+            Duration::from_secs(5),
+            TestMsg::Dismiss,
+        );
+        container.toasts[0].action_clicked(|_| "other view Msg");
+    }
+
+    #[test]
+    fn test_with_action_applies_to_next_toast_only() {
+        let mut container = ToastContainer::new();
+        let _cmd = container.with_action("Retry", TestMsg::Undo).error(
+            "Failed",
+            Duration::from_secs(5),
+            TestMsg::Dismiss,
+        );
+        let _cmd = container.info("Plain", Duration::from_secs(5), TestMsg::Dismiss);
+
+        assert!(container.toasts[0].action.is_some());
+        assert!(container.toasts[1].action.is_none());
+    }
+
+    #[test]
+    fn test_dedup_coalesces_duplicates() {
+        let mut container = ToastContainer::new();
+        for _ in 0..3 {
+            let _cmd: Cmd<()> = container.error("Network error", Duration::from_secs(3), |_| ());
+        }
+
+        assert_eq!(container.len(), 1);
+        assert_eq!(container.toasts[0].count, 3);
+    }
+
+    #[test]
+    fn test_dedup_restarts_timer() {
+        let mut container = ToastContainer::new();
+        let _cmd = container.error("Network error", Duration::from_secs(3), TestMsg::Dismiss);
+        let first_id = container.toasts[0].id;
+        let _cmd = container.error("Network error", Duration::from_secs(3), TestMsg::Dismiss);
+
+        // The first auto-dismiss no longer matches the live toast
+        container.dismiss(first_id);
+        assert_eq!(container.len(), 1);
+    }
+
+    #[test]
+    fn test_dedup_disabled_and_distinct_variants() {
+        let mut container = ToastContainer::new().dedup(false);
+        let _cmd: Cmd<()> = container.info("Same", Duration::from_secs(3), |_| ());
+        let _cmd: Cmd<()> = container.info("Same", Duration::from_secs(3), |_| ());
+        assert_eq!(container.len(), 2);
+
+        let mut container = ToastContainer::new();
+        let _cmd: Cmd<()> = container.info("Same", Duration::from_secs(3), |_| ());
+        let _cmd: Cmd<()> = container.error("Same", Duration::from_secs(3), |_| ());
+        assert_eq!(container.len(), 2);
+    }
+
     #[test]
     fn test_toast_id_uniqueness() {
         let id1 = ToastId::new();
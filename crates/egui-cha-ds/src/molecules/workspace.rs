@@ -8,6 +8,7 @@
 //! - **Free Mode**: Drag panes freely with snap-to-edge/grid
 //! - **Lock/Unlock**: Prevent accidental layout changes (ideal for Live mode)
 //! - **Snap System**: Magnetic snapping to edges and other panes
+//! - **Tab Docking**: Drop a pane onto another's title bar to group them as tabs
 //!
 //! # Usage Patterns
 //!
@@ -65,6 +66,13 @@ pub struct WorkspacePane {
     pub visible: bool,
     /// Whether the pane is minimized
     pub minimized: bool,
+    /// Whether the pane is maximized to fill the canvas
+    pub maximized: bool,
+    /// Tab group this pane belongs to. Panes sharing a group share one frame,
+    /// laid out from the pane whose id equals the group (or the first member).
+    pub tab_group: Option<String>,
+    /// Whether this pane is the shown tab of its group
+    pub tab_active: bool,
     /// Order in the layout (for Tile mode)
     pub order: usize,
     /// Weight for proportional sizing in Tile mode (default: 1.0)
@@ -82,6 +90,9 @@ impl WorkspacePane {
             min_size: Vec2::new(100.0, 80.0),
             visible: true,
             minimized: false,
+            maximized: false,
+            tab_group: None,
+            tab_active: false,
             order: 0,
             weight: 1.0,
         }
@@ -117,6 +128,12 @@ impl WorkspacePane {
         self
     }
 
+    /// Set tab group (see [`WorkspacePane::tab_group`])
+    pub fn with_tab_group(mut self, group: impl Into<String>) -> Self {
+        self.tab_group = Some(group.into());
+        self
+    }
+
     /// Set weight for proportional sizing (default: 1.0)
     pub fn with_weight(mut self, weight: f32) -> Self {
         self.weight = weight.max(0.1); // Minimum weight to prevent zero-size
@@ -135,6 +152,12 @@ pub enum WorkspaceEvent {
     PaneClosed(String),
     /// Pane was minimized/restored
     PaneMinimized { id: String, minimized: bool },
+    /// Pane was maximized/restored
+    PaneMaximized { id: String, maximized: bool },
+    /// Pane was dropped onto another pane's title bar to join its tab group
+    PanesTabbed { moved: String, target: String },
+    /// Tab was selected in a tab group
+    PaneActivated(String),
     /// Pane order changed (drag reorder in Tile mode)
     PaneReordered { from: usize, to: usize },
     /// Pane weights changed (divider drag in Tile mode)
@@ -187,6 +210,10 @@ struct DragState {
     original_pos: Option<Pos2>,
     /// Current snap target (for visual feedback)
     snap_target: Option<SnapTarget>,
+    /// Alignment guides for the current snap
+    snap_guides: Vec<SnapGuide>,
+    /// Last snapped position, emitted again when the drag ends (Free mode)
+    snapped_pos: Option<Pos2>,
     /// Resizing pane ID and edge
     resizing: Option<(String, ResizeEdge)>,
     /// Tile reorder: source pane order
@@ -195,6 +222,18 @@ struct DragState {
     tile_drop_target: Option<usize>,
     /// Divider drag: which divider is being dragged
     divider_drag: Option<DividerDrag>,
+    /// Tab docking: (moved pane ID, target pane ID) under the pointer
+    tab_drop: Option<(String, String)>,
+}
+
+/// Panes sharing a tab group, drawn as one frame
+struct TabGroup {
+    /// Pane that provides the frame geometry
+    host: usize,
+    /// Member panes in order
+    members: Vec<usize>,
+    /// Pane whose content is shown
+    active: usize,
 }
 
 /// State for divider dragging
@@ -217,17 +256,92 @@ enum ResizeEdge {
     BottomRight,
 }
 
+/// Maximum width of a tab in a tab group's title bar
+const TAB_MAX_WIDTH: f32 = 120.0;
+
+/// Outcome of snapping a dragged pane
+struct SnapResult {
+    /// Canvas-relative position after snapping
+    position: Pos2,
+    target: Option<SnapTarget>,
+    guides: Vec<SnapGuide>,
+}
+
+/// Alignment guide line drawn while dragging (screen space)
+#[derive(Clone, Debug, PartialEq)]
+struct SnapGuide {
+    /// Vertical line at x = `at`, otherwise horizontal at y = `at`
+    vertical: bool,
+    at: f32,
+    /// Extent along the line
+    span: egui::Rangef,
+}
+
+/// A snap candidate on one axis
+struct AxisCandidate {
+    /// Shift to apply to the moving pane
+    shift: f32,
+    /// Coordinate of the line being snapped to
+    line: f32,
+    target: Option<SnapTarget>,
+    /// Pane snapped against (None for the canvas)
+    other: Option<Rect>,
+}
+
+/// Nearest snap candidate within the threshold on one axis
+struct AxisSnap {
+    threshold: f32,
+    best: Option<AxisCandidate>,
+}
+
+impl AxisSnap {
+    fn new(threshold: f32) -> Self {
+        Self {
+            threshold,
+            best: None,
+        }
+    }
+
+    /// Consider moving `from` onto `line`
+    fn offer(&mut self, from: f32, line: f32, (target, other): (Option<SnapTarget>, Option<Rect>)) {
+        let shift = line - from;
+        let closer = self
+            .best
+            .as_ref()
+            .map_or(true, |best| shift.abs() < best.shift.abs());
+        if shift.abs() < self.threshold && closer {
+            self.best = Some(AxisCandidate {
+                shift,
+                line,
+                target,
+                other,
+            });
+        }
+    }
+
+    fn shift(&self) -> f32 {
+        self.best.as_ref().map_or(0.0, |best| best.shift)
+    }
+}
+
 /// Internal struct for collecting pane interactions
 struct PaneInteraction {
     idx: usize,
+    /// Pane whose content is shown (the active tab of a group)
+    content_idx: usize,
     rect: Rect,
     title_rect: Rect,
     close_rect: Option<Rect>,
     minimize_rect: Option<Rect>,
+    maximize_rect: Option<Rect>,
     title_hovered: bool,
     title_dragged: bool,
     close_clicked: bool,
     minimize_clicked: bool,
+    maximize_clicked: bool,
+    /// Tab strip for grouped panes: (pane index, tab rect)
+    tabs: Vec<(usize, Rect)>,
+    tab_clicked: Option<usize>,
     resize_edge: Option<ResizeEdge>,
 }
 
@@ -243,6 +357,7 @@ pub struct WorkspaceCanvas<'a> {
     title_bar_height: f32,
     show_close_buttons: bool,
     show_minimize_buttons: bool,
+    show_maximize_buttons: bool,
 }
 
 impl<'a> WorkspaceCanvas<'a> {
@@ -259,6 +374,7 @@ impl<'a> WorkspaceCanvas<'a> {
             title_bar_height: 24.0,
             show_close_buttons: true,
             show_minimize_buttons: true,
+            show_maximize_buttons: true,
         }
     }
 
@@ -316,6 +432,12 @@ impl<'a> WorkspaceCanvas<'a> {
         self
     }
 
+    /// Show/hide maximize buttons
+    pub fn show_maximize_buttons(mut self, show: bool) -> Self {
+        self.show_maximize_buttons = show;
+        self
+    }
+
     /// Show workspace and render pane contents
     pub fn show<F>(self, ui: &mut Ui, mut content: F) -> Vec<WorkspaceEvent>
     where
@@ -371,12 +493,41 @@ impl<'a> WorkspaceCanvas<'a> {
             .collect();
         visible_panes.sort_by_key(|(_, p)| p.order);
 
+        // Tab groups are laid out as their host pane only
+        let tab_groups = Self::tab_groups(&visible_panes);
+        visible_panes.retain(|(idx, _)| {
+            tab_groups
+                .iter()
+                .all(|group| group.host == *idx || !group.members.contains(idx))
+        });
+        let content_of = |idx: usize| {
+            tab_groups
+                .iter()
+                .find(|group| group.host == idx)
+                .map_or(idx, |group| group.active)
+        };
+
+        // A maximized pane fills the canvas and hides the others
+        let maximized = visible_panes
+            .iter()
+            .find(|(idx, _)| self.panes[content_of(*idx)].maximized)
+            .copied();
+        if let Some(pane) = maximized {
+            visible_panes = vec![pane];
+        }
+        // Layout is fixed while a pane is maximized
+        let locked = self.locked || maximized.is_some();
+
         // Calculate layout based on mode
         let columns = match self.layout_mode {
             LayoutMode::Tile { columns } => columns,
             LayoutMode::Free => None,
         };
         let pane_rects: Vec<(usize, Rect)> = match self.layout_mode {
+            _ if maximized.is_some() => visible_panes
+                .iter()
+                .map(|(idx, _)| (*idx, rect.shrink(self.gap)))
+                .collect(),
             LayoutMode::Tile { columns } => {
                 self.calculate_tile_layout(&visible_panes, rect, columns)
             }
@@ -391,14 +542,14 @@ impl<'a> WorkspaceCanvas<'a> {
         };
 
         // Calculate dividers for Tile mode
-        let dividers = if matches!(self.layout_mode, LayoutMode::Tile { .. }) && !self.locked {
+        let dividers = if matches!(self.layout_mode, LayoutMode::Tile { .. }) && !locked {
             self.calculate_dividers(&visible_panes, rect, columns)
         } else {
             Vec::new()
         };
 
         // Handle divider interactions
-        if !self.locked && !dividers.is_empty() {
+        if !locked && !dividers.is_empty() {
             let pointer_pos = ui.input(|i| i.pointer.hover_pos());
 
             // Check for divider hover and start drag
@@ -472,8 +623,6 @@ impl<'a> WorkspaceCanvas<'a> {
         let mut interactions: Vec<PaneInteraction> = Vec::new();
 
         for (idx, pane_rect) in &pane_rects {
-            let _pane = &self.panes[*idx];
-
             // Title bar rect
             let title_rect = Rect::from_min_size(
                 pane_rect.min,
@@ -494,6 +643,16 @@ impl<'a> WorkspaceCanvas<'a> {
                 None
             };
 
+            let maximize_rect = if self.show_maximize_buttons {
+                button_x -= button_size + 2.0;
+                Some(Rect::from_min_size(
+                    Pos2::new(button_x, pane_rect.min.y + 4.0),
+                    Vec2::splat(button_size),
+                ))
+            } else {
+                None
+            };
+
             let minimize_rect = if self.show_minimize_buttons {
                 button_x -= button_size + 2.0;
                 Some(Rect::from_min_size(
@@ -504,13 +663,41 @@ impl<'a> WorkspaceCanvas<'a> {
                 None
             };
 
+            // Tab strip between the title bar start and the buttons
+            let tabs: Vec<(usize, Rect)> = tab_groups
+                .iter()
+                .find(|group| group.host == *idx)
+                .map(|group| {
+                    let left = title_rect.min.x + 4.0;
+                    let width = ((button_x - 4.0 - left) / group.members.len() as f32)
+                        .clamp(0.0, TAB_MAX_WIDTH);
+                    group
+                        .members
+                        .iter()
+                        .enumerate()
+                        .map(|(i, member)| {
+                            let min = Pos2::new(left + i as f32 * width, title_rect.min.y + 2.0);
+                            let size = Vec2::new(width - 2.0, self.title_bar_height - 2.0);
+                            (*member, Rect::from_min_size(min, size))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
             // Allocate interaction areas
             let title_response = ui.allocate_rect(title_rect, Sense::click_and_drag());
+            let mut tab_clicked = None;
+            for (member, tab_rect) in &tabs {
+                if ui.allocate_rect(*tab_rect, Sense::click()).clicked() {
+                    tab_clicked = Some(*member);
+                }
+            }
             let close_response = close_rect.map(|r| ui.allocate_rect(r, Sense::click()));
             let minimize_response = minimize_rect.map(|r| ui.allocate_rect(r, Sense::click()));
+            let maximize_response = maximize_rect.map(|r| ui.allocate_rect(r, Sense::click()));
 
             // Check resize edge hover (only in Free mode and unlocked)
-            let resize_edge = if !self.locked && matches!(self.layout_mode, LayoutMode::Free) {
+            let resize_edge = if !locked && matches!(self.layout_mode, LayoutMode::Free) {
                 self.check_resize_edge(ui, *pane_rect)
             } else {
                 None
@@ -518,14 +705,19 @@ impl<'a> WorkspaceCanvas<'a> {
 
             interactions.push(PaneInteraction {
                 idx: *idx,
+                content_idx: content_of(*idx),
                 rect: *pane_rect,
                 title_rect,
                 close_rect,
                 minimize_rect,
+                maximize_rect,
                 title_hovered: title_response.hovered(),
-                title_dragged: title_response.dragged() && !self.locked,
+                title_dragged: title_response.dragged() && !locked,
                 close_clicked: close_response.map_or(false, |r| r.clicked()),
                 minimize_clicked: minimize_response.map_or(false, |r| r.clicked()),
+                maximize_clicked: maximize_response.map_or(false, |r| r.clicked()),
+                tabs,
+                tab_clicked,
                 resize_edge,
             });
         }
@@ -533,20 +725,36 @@ impl<'a> WorkspaceCanvas<'a> {
         // Process interactions (before drawing)
         for interaction in &interactions {
             let pane = &self.panes[interaction.idx];
+            let content = &self.panes[interaction.content_idx];
 
             // Handle close
             if interaction.close_clicked {
-                events.push(WorkspaceEvent::PaneClosed(pane.id.clone()));
+                events.push(WorkspaceEvent::PaneClosed(content.id.clone()));
             }
 
             // Handle minimize
             if interaction.minimize_clicked {
                 events.push(WorkspaceEvent::PaneMinimized {
-                    id: pane.id.clone(),
-                    minimized: !pane.minimized,
+                    id: content.id.clone(),
+                    minimized: !content.minimized,
+                });
+            }
+
+            // Handle maximize/restore
+            if interaction.maximize_clicked {
+                events.push(WorkspaceEvent::PaneMaximized {
+                    id: content.id.clone(),
+                    maximized: !content.maximized,
                 });
             }
 
+            // Handle tab selection
+            if let Some(member) = interaction.tab_clicked {
+                if member != interaction.content_idx {
+                    events.push(WorkspaceEvent::PaneActivated(self.panes[member].id.clone()));
+                }
+            }
+
             // Handle drag (Tile mode - reorder)
             if interaction.title_dragged && matches!(self.layout_mode, LayoutMode::Tile { .. }) {
                 // Start drag
@@ -568,6 +776,10 @@ impl<'a> WorkspaceCanvas<'a> {
                             }
                         }
                         drag_state.tile_drop_target = new_target;
+                        drag_state.tab_drop = self.tab_drop_target(&interactions, interaction, pos);
+                        if drag_state.tab_drop.is_some() {
+                            drag_state.tile_drop_target = None;
+                        }
                     }
                 }
             }
@@ -580,18 +792,28 @@ impl<'a> WorkspaceCanvas<'a> {
                 }
 
                 if drag_state.dragging.as_ref() == Some(&pane.id) {
-                    let delta = ui.input(|i| i.pointer.delta());
-                    let new_pos = pane.position + delta;
+                    // Follow the pointer from the drag start so a snap doesn't stick
+                    let moved =
+                        ui.input(|i| Some(i.pointer.interact_pos()? - i.pointer.press_origin()?));
+                    let new_pos = match (drag_state.original_pos, moved) {
+                        (Some(original), Some(moved)) => original + moved,
+                        _ => pane.position + ui.input(|i| i.pointer.delta()),
+                    };
 
                     // Apply snapping
-                    let (snapped_pos, snap_target) =
+                    let snap =
                         self.apply_snap(new_pos, pane.size, rect, &pane_rects, interaction.idx);
 
-                    drag_state.snap_target = snap_target;
+                    drag_state.snap_target = snap.target;
+                    drag_state.snap_guides = snap.guides;
+                    drag_state.snapped_pos = Some(snap.position);
+                    drag_state.tab_drop = ui
+                        .input(|i| i.pointer.interact_pos())
+                        .and_then(|pos| self.tab_drop_target(&interactions, interaction, pos));
 
                     events.push(WorkspaceEvent::PaneMoved {
                         id: pane.id.clone(),
-                        position: snapped_pos,
+                        position: snap.position,
                     });
                 }
             }
@@ -620,7 +842,6 @@ impl<'a> WorkspaceCanvas<'a> {
 
         // Draw pane content (AFTER background and frames)
         for interaction in &interactions {
-            let pane = &self.panes[interaction.idx];
             let content_rect = Rect::from_min_max(
                 Pos2::new(
                     interaction.rect.min.x,
@@ -629,7 +850,7 @@ impl<'a> WorkspaceCanvas<'a> {
                 interaction.rect.max,
             );
             let mut child_ui = ui.new_child(egui::UiBuilder::new().max_rect(content_rect));
-            content(&mut child_ui, pane);
+            content(&mut child_ui, &self.panes[interaction.content_idx]);
         }
 
         // Draw overlays (dividers, snap guides) AFTER content
@@ -637,7 +858,7 @@ impl<'a> WorkspaceCanvas<'a> {
             let painter = ui.painter();
 
             // Draw dividers (Tile mode only)
-            if !self.locked {
+            if !locked {
                 for divider in &dividers {
                     let is_active = drag_state
                         .divider_drag
@@ -674,6 +895,14 @@ impl<'a> WorkspaceCanvas<'a> {
             if let Some(ref target) = drag_state.snap_target {
                 self.draw_snap_guide(painter, target, rect, &theme);
             }
+            for guide in &drag_state.snap_guides {
+                let stroke = Stroke::new(1.0, theme.primary.gamma_multiply(0.8));
+                if guide.vertical {
+                    painter.vline(guide.at, guide.span, stroke);
+                } else {
+                    painter.hline(guide.span, guide.at, stroke);
+                }
+            }
 
             // Draw lock indicator
             if self.locked {
@@ -683,18 +912,34 @@ impl<'a> WorkspaceCanvas<'a> {
 
         // Handle drag end (mouse released)
         if !ui.input(|i| i.pointer.any_down()) {
+            // Tab docking: dropped onto another pane's title bar
+            let tab_drop = drag_state.tab_drop.take();
+            let docked = tab_drop.is_some();
+            if let Some((moved, target)) = tab_drop {
+                events.push(WorkspaceEvent::PanesTabbed { moved, target });
+            }
+
             // Tile reorder: emit event if we have a valid drop target
             if let (Some(from), Some(to)) =
                 (drag_state.tile_drag_source, drag_state.tile_drop_target)
             {
-                if from != to {
+                if from != to && !docked {
                     events.push(WorkspaceEvent::PaneReordered { from, to });
                 }
             }
 
+            // Free move: report the final snapped position
+            if let (Some(id), Some(position), false) =
+                (drag_state.dragging.clone(), drag_state.snapped_pos, docked)
+            {
+                events.push(WorkspaceEvent::PaneMoved { id, position });
+            }
+
             // Clear drag state
             drag_state.dragging = None;
             drag_state.snap_target = None;
+            drag_state.snap_guides.clear();
+            drag_state.snapped_pos = None;
             drag_state.resizing = None;
             drag_state.tile_drag_source = None;
             drag_state.tile_drop_target = None;
@@ -707,6 +952,66 @@ impl<'a> WorkspaceCanvas<'a> {
         events
     }
 
+    /// Collect tab groups with more than one visible member
+    fn tab_groups(visible_panes: &[(usize, &WorkspacePane)]) -> Vec<TabGroup> {
+        let mut groups: Vec<(&str, Vec<usize>)> = Vec::new();
+        for (idx, pane) in visible_panes {
+            let Some(group) = pane.tab_group.as_deref() else {
+                continue;
+            };
+            match groups.iter_mut().find(|(name, _)| *name == group) {
+                Some((_, members)) => members.push(*idx),
+                None => groups.push((group, vec![*idx])),
+            }
+        }
+
+        let pane = |idx: usize| {
+            visible_panes
+                .iter()
+                .find(|(i, _)| *i == idx)
+                .map(|(_, p)| *p)
+        };
+        groups
+            .into_iter()
+            .filter(|(_, members)| members.len() > 1)
+            .map(|(name, members)| {
+                let host = members
+                    .iter()
+                    .copied()
+                    .find(|m| pane(*m).is_some_and(|p| p.id == name))
+                    .unwrap_or(members[0]);
+                let active = members
+                    .iter()
+                    .copied()
+                    .find(|m| pane(*m).is_some_and(|p| p.tab_active))
+                    .unwrap_or(host);
+                TabGroup {
+                    host,
+                    members,
+                    active,
+                }
+            })
+            .collect()
+    }
+
+    /// Tab docking target: the shown pane whose title bar is under `pos`
+    fn tab_drop_target(
+        &self,
+        interactions: &[PaneInteraction],
+        dragged: &PaneInteraction,
+        pos: Pos2,
+    ) -> Option<(String, String)> {
+        interactions
+            .iter()
+            .find(|other| other.idx != dragged.idx && other.title_rect.contains(pos))
+            .map(|other| {
+                (
+                    self.panes[dragged.content_idx].id.clone(),
+                    self.panes[other.content_idx].id.clone(),
+                )
+            })
+    }
+
     fn calculate_tile_layout(
         &self,
         visible_panes: &[(usize, &WorkspacePane)],
@@ -922,6 +1227,8 @@ impl<'a> WorkspaceCanvas<'a> {
         }
     }
 
+    /// Snap a pane at canvas-relative `pos` to canvas edges and to the edges and
+    /// centers of other panes, each axis independently; falls back to the grid
     fn apply_snap(
         &self,
         pos: Pos2,
@@ -929,85 +1236,128 @@ impl<'a> WorkspaceCanvas<'a> {
         canvas_rect: Rect,
         pane_rects: &[(usize, Rect)],
         current_idx: usize,
-    ) -> (Pos2, Option<SnapTarget>) {
-        let mut snapped_pos = pos;
-        let mut snap_target = None;
+    ) -> SnapResult {
+        let moving = Rect::from_min_size(canvas_rect.min + pos.to_vec2(), size);
+        let mut x = AxisSnap::new(self.snap_threshold);
+        let mut y = AxisSnap::new(self.snap_threshold);
 
         // Snap to canvas edges
-        if (pos.x - canvas_rect.min.x).abs() < self.snap_threshold {
-            snapped_pos.x = canvas_rect.min.x + self.gap;
-            snap_target = Some(SnapTarget::CanvasEdge(Edge::Left));
-        }
-        if (pos.x + size.x - canvas_rect.max.x).abs() < self.snap_threshold {
-            snapped_pos.x = canvas_rect.max.x - size.x - self.gap;
-            snap_target = Some(SnapTarget::CanvasEdge(Edge::Right));
-        }
-        if (pos.y - canvas_rect.min.y).abs() < self.snap_threshold {
-            snapped_pos.y = canvas_rect.min.y + self.gap;
-            snap_target = Some(SnapTarget::CanvasEdge(Edge::Top));
-        }
-        if (pos.y + size.y - canvas_rect.max.y).abs() < self.snap_threshold {
-            snapped_pos.y = canvas_rect.max.y - size.y - self.gap;
-            snap_target = Some(SnapTarget::CanvasEdge(Edge::Bottom));
-        }
+        let canvas_target = |edge| (Some(SnapTarget::CanvasEdge(edge)), None);
+        x.offer(
+            moving.min.x,
+            canvas_rect.min.x + self.gap,
+            canvas_target(Edge::Left),
+        );
+        x.offer(
+            moving.max.x,
+            canvas_rect.max.x - self.gap,
+            canvas_target(Edge::Right),
+        );
+        y.offer(
+            moving.min.y,
+            canvas_rect.min.y + self.gap,
+            canvas_target(Edge::Top),
+        );
+        y.offer(
+            moving.max.y,
+            canvas_rect.max.y - self.gap,
+            canvas_target(Edge::Bottom),
+        );
 
-        // Snap to other panes
-        for (idx, other_rect) in pane_rects {
+        // Snap to other panes: flush against an edge, aligned edges, or centers
+        for (idx, other) in pane_rects {
             if *idx == current_idx {
                 continue;
             }
+            let pane_target = |edge| {
+                (
+                    Some(SnapTarget::Pane {
+                        id: self.panes[*idx].id.clone(),
+                        edge,
+                    }),
+                    Some(*other),
+                )
+            };
+            let center = || (None, Some(*other));
+
+            x.offer(
+                moving.max.x,
+                other.min.x - self.gap,
+                pane_target(Edge::Left),
+            );
+            x.offer(
+                moving.min.x,
+                other.max.x + self.gap,
+                pane_target(Edge::Right),
+            );
+            x.offer(moving.min.x, other.min.x, pane_target(Edge::Left));
+            x.offer(moving.max.x, other.max.x, pane_target(Edge::Right));
+            x.offer(moving.center().x, other.center().x, center());
+
+            y.offer(moving.max.y, other.min.y - self.gap, pane_target(Edge::Top));
+            y.offer(
+                moving.min.y,
+                other.max.y + self.gap,
+                pane_target(Edge::Bottom),
+            );
+            y.offer(moving.min.y, other.min.y, pane_target(Edge::Top));
+            y.offer(moving.max.y, other.max.y, pane_target(Edge::Bottom));
+            y.offer(moving.center().y, other.center().y, center());
+        }
 
-            let pane = &self.panes[*idx];
+        let snapped = moving.translate(Vec2::new(x.shift(), y.shift()));
+        let mut position = pos + (snapped.min - moving.min);
+        let mut guides = Vec::new();
+        let mut target = None;
 
-            // Snap right edge to left edge of other
-            if (pos.x + size.x - other_rect.min.x).abs() < self.snap_threshold {
-                snapped_pos.x = other_rect.min.x - size.x - self.gap;
-                snap_target = Some(SnapTarget::Pane {
-                    id: pane.id.clone(),
-                    edge: Edge::Left,
-                });
-            }
-            // Snap left edge to right edge of other
-            if (pos.x - other_rect.max.x).abs() < self.snap_threshold {
-                snapped_pos.x = other_rect.max.x + self.gap;
-                snap_target = Some(SnapTarget::Pane {
-                    id: pane.id.clone(),
-                    edge: Edge::Right,
-                });
-            }
-            // Snap bottom edge to top edge of other
-            if (pos.y + size.y - other_rect.min.y).abs() < self.snap_threshold {
-                snapped_pos.y = other_rect.min.y - size.y - self.gap;
-                snap_target = Some(SnapTarget::Pane {
-                    id: pane.id.clone(),
-                    edge: Edge::Top,
-                });
-            }
-            // Snap top edge to bottom edge of other
-            if (pos.y - other_rect.max.y).abs() < self.snap_threshold {
-                snapped_pos.y = other_rect.max.y + self.gap;
-                snap_target = Some(SnapTarget::Pane {
-                    id: pane.id.clone(),
-                    edge: Edge::Bottom,
-                });
-            }
+        if let Some(best) = x.best {
+            let span = best.other.map_or(canvas_rect.y_range(), |other| {
+                egui::Rangef::new(
+                    snapped.min.y.min(other.min.y),
+                    snapped.max.y.max(other.max.y),
+                )
+            });
+            guides.push(SnapGuide {
+                vertical: true,
+                at: best.line,
+                span,
+            });
+            target = best.target;
+        }
+        if let Some(best) = y.best {
+            let span = best.other.map_or(canvas_rect.x_range(), |other| {
+                egui::Rangef::new(
+                    snapped.min.x.min(other.min.x),
+                    snapped.max.x.max(other.max.x),
+                )
+            });
+            guides.push(SnapGuide {
+                vertical: false,
+                at: best.line,
+                span,
+            });
+            target = target.or(best.target);
         }
 
         // Grid snap
         if let Some(grid_size) = self.grid_size {
-            if snap_target.is_none() {
-                let grid_x = (snapped_pos.x / grid_size).round() as i32;
-                let grid_y = (snapped_pos.y / grid_size).round() as i32;
-                snapped_pos.x = grid_x as f32 * grid_size;
-                snapped_pos.y = grid_y as f32 * grid_size;
-                snap_target = Some(SnapTarget::Grid {
+            if guides.is_empty() {
+                let grid_x = (position.x / grid_size).round() as i32;
+                let grid_y = (position.y / grid_size).round() as i32;
+                position.x = grid_x as f32 * grid_size;
+                position.y = grid_y as f32 * grid_size;
+                target = Some(SnapTarget::Grid {
                     x: grid_x,
                     y: grid_y,
                 });
             }
         }
 
-        (snapped_pos, snap_target)
+        SnapResult {
+            position,
+            target,
+            guides,
+        }
     }
 
     fn draw_pane(
@@ -1023,6 +1373,11 @@ impl<'a> WorkspaceCanvas<'a> {
         let is_drop_target = drag_state.tile_drop_target == Some(pane.order)
             && drag_state.tile_drag_source.is_some()
             && drag_state.tile_drag_source != Some(pane.order);
+        let content = &self.panes[interaction.content_idx];
+        let is_tab_target = drag_state
+            .tab_drop
+            .as_ref()
+            .is_some_and(|(_, target)| *target == content.id);
 
         // Pane background
         let bg_color = if is_dragging {
@@ -1048,17 +1403,58 @@ impl<'a> WorkspaceCanvas<'a> {
         };
         painter.rect_filled(interaction.title_rect, theme.radius_md, title_bg);
 
-        // Title text
-        painter.text(
-            Pos2::new(
-                interaction.title_rect.min.x + theme.spacing_sm,
-                interaction.title_rect.center().y,
-            ),
-            egui::Align2::LEFT_CENTER,
-            &pane.title,
-            egui::FontId::proportional(theme.font_size_sm),
-            theme.text_primary,
-        );
+        if interaction.tabs.is_empty() {
+            // Title text
+            painter.text(
+                Pos2::new(
+                    interaction.title_rect.min.x + theme.spacing_sm,
+                    interaction.title_rect.center().y,
+                ),
+                egui::Align2::LEFT_CENTER,
+                &content.title,
+                egui::FontId::proportional(theme.font_size_sm),
+                theme.text_primary,
+            );
+        } else {
+            // Tab strip
+            for (member, tab_rect) in &interaction.tabs {
+                let is_active = *member == interaction.content_idx;
+                if is_active {
+                    painter.rect_filled(*tab_rect, theme.radius_sm, theme.bg_secondary);
+                    painter.hline(
+                        tab_rect.x_range(),
+                        tab_rect.max.y - 1.0,
+                        Stroke::new(2.0, theme.primary),
+                    );
+                }
+                painter.with_clip_rect(tab_rect.shrink(2.0)).text(
+                    Pos2::new(tab_rect.min.x + theme.spacing_sm, tab_rect.center().y),
+                    egui::Align2::LEFT_CENTER,
+                    &self.panes[*member].title,
+                    egui::FontId::proportional(theme.font_size_sm),
+                    if is_active {
+                        theme.text_primary
+                    } else {
+                        theme.text_muted
+                    },
+                );
+            }
+        }
+
+        // Tab docking drop indicator
+        if is_tab_target {
+            painter.rect_filled(
+                interaction.title_rect,
+                theme.radius_md,
+                theme.primary.gamma_multiply(0.25),
+            );
+            painter.rect_stroke(
+                interaction.title_rect,
+                theme.radius_md,
+                Stroke::new(2.0, theme.primary),
+                egui::StrokeKind::Inside,
+            );
+        }
 
         // Lock icon if locked
         if locked {
@@ -1101,6 +1497,22 @@ impl<'a> WorkspaceCanvas<'a> {
             );
         }
 
+        // Maximize/restore button
+        if let Some(maximize_rect) = interaction.maximize_rect {
+            let stroke = Stroke::new(1.0, theme.text_muted);
+            let icon = Rect::from_center_size(maximize_rect.center(), Vec2::splat(8.0));
+            if content.maximized {
+                // Two stacked windows
+                let back = icon.translate(Vec2::new(2.0, -2.0)).shrink(1.0);
+                let front = icon.translate(Vec2::new(-1.0, 1.0)).shrink(1.0);
+                painter.rect_stroke(back, 0.0, stroke, egui::StrokeKind::Middle);
+                painter.rect_filled(front, 0.0, theme.bg_primary);
+                painter.rect_stroke(front, 0.0, stroke, egui::StrokeKind::Middle);
+            } else {
+                painter.rect_stroke(icon, 0.0, stroke, egui::StrokeKind::Middle);
+            }
+        }
+
         // Border
         let border_color = if is_dragging || is_drop_target {
             theme.primary
@@ -1170,12 +1582,12 @@ impl<'a> WorkspaceCanvas<'a> {
                 painter.line_segment([start, end], guide_stroke);
             }
             SnapTarget::Pane { .. } => {
-                // Draw connection indicator between panes
-                // (Could be enhanced with more visual feedback)
+                // Drawn as alignment guides
             }
             SnapTarget::Grid { x, y } => {
                 if let Some(grid_size) = self.grid_size {
-                    let pos = Pos2::new(*x as f32 * grid_size, *y as f32 * grid_size);
+                    let pos =
+                        canvas_rect.min + Vec2::new(*x as f32 * grid_size, *y as f32 * grid_size);
                     painter.circle_filled(pos, 4.0, guide_color);
                 }
             }
@@ -1234,3 +1646,206 @@ impl<'a> WorkspaceCanvas<'a> {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, drag, run_ui, test_context};
+
+    #[test]
+    fn free_pane_snaps_flush_to_neighbor() {
+        let ctx = test_context();
+        let mut panes = vec![
+            WorkspacePane::new("a", "A")
+                .with_position(0.0, 50.0)
+                .with_size(100.0, 100.0),
+            WorkspacePane::new("b", "B")
+                .with_position(200.0, 50.0)
+                .with_size(100.0, 100.0),
+        ];
+        let mut origin = Pos2::ZERO;
+        run_ui(&ctx, |ui| origin = ui.max_rect().min);
+
+        let mut last_b = None;
+        let from = origin + Vec2::new(250.0, 62.0);
+        drag(&ctx, from, from - Vec2::new(97.0, 0.0), |ui| {
+            let events = WorkspaceCanvas::new(&mut panes)
+                .layout(LayoutMode::Free)
+                .gap(0.0)
+                .show(ui, |_, _| {});
+            for event in events {
+                if let WorkspaceEvent::PaneMoved { id, position } = event {
+                    let pane = panes.iter_mut().find(|p| p.id == id).unwrap();
+                    pane.position = position;
+                    if id == "b" {
+                        last_b = Some(position);
+                    }
+                }
+            }
+        });
+
+        let position = last_b.expect("pane b was moved");
+        assert_eq!(position.x, 100.0);
+        assert_eq!(panes[1].position.x, 100.0);
+    }
+
+    /// Render a Free canvas, applying maximize events and recording content rects
+    fn show_free(
+        ui: &mut Ui,
+        panes: &mut Vec<WorkspacePane>,
+        rendered: &mut Vec<(String, Rect)>,
+        maximized_events: &mut Vec<(String, bool)>,
+    ) {
+        rendered.clear();
+        let events = WorkspaceCanvas::new(panes)
+            .layout(LayoutMode::Free)
+            .show(ui, |ui, pane| {
+                rendered.push((pane.id.clone(), ui.max_rect()))
+            });
+        for event in events {
+            if let WorkspaceEvent::PaneMaximized { id, maximized } = event {
+                let pane = panes.iter_mut().find(|p| p.id == id).unwrap();
+                pane.maximized = maximized;
+                maximized_events.push((id, maximized));
+            }
+        }
+    }
+
+    #[test]
+    fn maximize_fills_canvas_and_restore_keeps_geometry() {
+        let ctx = test_context();
+        let mut panes = vec![
+            WorkspacePane::new("a", "A")
+                .with_position(0.0, 0.0)
+                .with_size(150.0, 100.0),
+            WorkspacePane::new("b", "B")
+                .with_position(200.0, 50.0)
+                .with_size(150.0, 100.0),
+        ];
+        let mut rendered = Vec::new();
+        let mut maximized_events = Vec::new();
+        let mut canvas = Rect::NOTHING;
+        run_ui(&ctx, |ui| {
+            canvas = ui.available_rect_before_wrap();
+            show_free(ui, &mut panes, &mut rendered, &mut maximized_events);
+        });
+        let maximized = canvas.shrink(4.0);
+        let pane_b = rendered[1].1;
+
+        // Maximize button sits left of the close button (16px buttons, 2px apart)
+        let maximize_button = |pane: Rect| Pos2::new(pane.max.x - 30.0, pane.min.y + 12.0);
+        let frame_b = Rect::from_min_size(canvas.min + Vec2::new(200.0, 50.0), panes[1].size);
+        click_at(&ctx, maximize_button(frame_b), |ui| {
+            show_free(ui, &mut panes, &mut rendered, &mut maximized_events)
+        });
+        run_ui(&ctx, |ui| {
+            show_free(ui, &mut panes, &mut rendered, &mut maximized_events)
+        });
+
+        assert_eq!(maximized_events, vec![("b".to_string(), true)]);
+        assert_eq!(rendered.len(), 1);
+        assert_eq!(rendered[0].0, "b");
+        assert_eq!(rendered[0].1.min, maximized.min + Vec2::new(0.0, 24.0));
+        assert_eq!(rendered[0].1.max, maximized.max);
+
+        click_at(&ctx, maximize_button(maximized), |ui| {
+            show_free(ui, &mut panes, &mut rendered, &mut maximized_events)
+        });
+        run_ui(&ctx, |ui| {
+            show_free(ui, &mut panes, &mut rendered, &mut maximized_events)
+        });
+
+        assert_eq!(maximized_events.last(), Some(&("b".to_string(), false)));
+        assert_eq!(rendered.len(), 2);
+        assert_eq!(rendered[1].1, pane_b);
+        assert_eq!(panes[1].position, Pos2::new(200.0, 50.0));
+        assert_eq!(panes[1].size, Vec2::new(150.0, 100.0));
+    }
+
+    #[test]
+    fn dropping_pane_on_header_tabs_it() {
+        let ctx = test_context();
+        let mut panes = vec![
+            WorkspacePane::new("A", "A")
+                .with_position(0.0, 0.0)
+                .with_size(150.0, 100.0),
+            WorkspacePane::new("B", "B")
+                .with_position(200.0, 150.0)
+                .with_size(150.0, 100.0),
+        ];
+        let mut origin = Pos2::ZERO;
+        run_ui(&ctx, |ui| origin = ui.max_rect().min);
+
+        let mut events = Vec::new();
+        drag(
+            &ctx,
+            origin + Vec2::new(250.0, 162.0),
+            origin + Vec2::new(50.0, 12.0),
+            |ui| {
+                for event in WorkspaceCanvas::new(&mut panes)
+                    .layout(LayoutMode::Free)
+                    .show(ui, |_, _| {})
+                {
+                    if let WorkspaceEvent::PaneMoved { id, position } = &event {
+                        let pane = panes.iter_mut().find(|p| p.id == *id).unwrap();
+                        pane.position = *position;
+                    }
+                    events.push(event);
+                }
+            },
+        );
+
+        let tabbed: Vec<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                WorkspaceEvent::PanesTabbed { moved, target } => {
+                    Some((moved.as_str(), target.as_str()))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(tabbed, vec![("B", "A")]);
+    }
+
+    #[test]
+    fn tab_group_shows_active_pane_and_activates_on_click() {
+        let ctx = test_context();
+        let mut panes = vec![
+            WorkspacePane::new("A", "A")
+                .with_position(0.0, 0.0)
+                .with_size(300.0, 100.0)
+                .with_tab_group("A"),
+            WorkspacePane::new("B", "B")
+                .with_position(400.0, 0.0)
+                .with_tab_group("A"),
+        ];
+        let mut origin = Pos2::ZERO;
+        let mut rendered = Vec::new();
+        run_ui(&ctx, |ui| {
+            origin = ui.max_rect().min;
+            WorkspaceCanvas::new(&mut panes)
+                .layout(LayoutMode::Free)
+                .show(ui, |ui, pane| {
+                    rendered.push((pane.id.clone(), ui.max_rect()))
+                });
+        });
+        // One frame at the host's geometry, showing the host's content
+        assert_eq!(rendered.len(), 1);
+        assert_eq!(rendered[0].0, "A");
+        assert_eq!(rendered[0].1.min, origin + Vec2::new(0.0, 24.0));
+
+        // Second tab starts after the first (4px inset, 120px wide)
+        let mut activated = Vec::new();
+        click_at(&ctx, origin + Vec2::new(4.0 + 120.0 + 20.0, 12.0), |ui| {
+            for event in WorkspaceCanvas::new(&mut panes)
+                .layout(LayoutMode::Free)
+                .show(ui, |_, _| {})
+            {
+                if let WorkspaceEvent::PaneActivated(id) = event {
+                    activated.push(id);
+                }
+            }
+        });
+        assert_eq!(activated, vec!["B".to_string()]);
+    }
+}
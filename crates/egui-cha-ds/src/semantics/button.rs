@@ -1,7 +1,8 @@
 //! Semantic buttons - Domain-specific button components
 //!
 //! These buttons carry specific meaning and have **fixed labels/icons**.
-//! This ensures UI consistency across the entire application.
+//! This ensures UI consistency across the entire application. Labels can be
+//! localized app-wide with [`set_labels`]; icons never change.
 //!
 //! ## Design Philosophy
 //!
@@ -22,11 +23,14 @@
 //! if semantics::delete(ButtonStyle::Both).show(ui) {
 //!     // handle delete
 //! }
+//!
+//! // Localized labels (once, e.g. at startup or on language change)
+//! semantics::set_labels(ctx.ui.ctx(), SemanticLabels::japanese());
 //! ```
 
 use crate::atoms::icons;
 use egui::text::{LayoutJob, TextFormat};
-use egui::{Color32, FontId, RichText, Stroke, Ui};
+use egui::{Color32, FontId, Id, RichText, Stroke, Ui};
 use egui_cha::ViewCtx;
 
 /// Button display style
@@ -41,12 +45,119 @@ pub enum ButtonStyle {
     Both,
 }
 
+/// Text labels of the semantic buttons, used by [`ButtonStyle::Text`] and [`ButtonStyle::Both`]
+///
+/// Defaults to English. Install with [`set_labels`]; non-Latin labels need a
+/// font covering their glyphs.
+///
+/// ```ignore
+/// semantics::set_labels(ctx, SemanticLabels {
+///     save: "Speichern".into(),
+///     ..SemanticLabels::english()
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SemanticLabels {
+    pub save: String,
+    pub close: String,
+    pub delete: String,
+    pub edit: String,
+    pub add: String,
+    pub remove: String,
+    pub search: String,
+    pub refresh: String,
+    pub settings: String,
+    pub play: String,
+    pub pause: String,
+    pub stop: String,
+    pub record: String,
+    pub copy: String,
+    pub back: String,
+    pub forward: String,
+    pub confirm: String,
+    pub cancel: String,
+}
+
+impl SemanticLabels {
+    /// ID used for storing labels in egui context
+    const STORAGE_ID: &'static str = "egui_cha_ds_semantic_labels";
+
+    /// English labels (default)
+    pub fn english() -> Self {
+        Self {
+            save: "Save".into(),
+            close: "Close".into(),
+            delete: "Delete".into(),
+            edit: "Edit".into(),
+            add: "Add".into(),
+            remove: "Remove".into(),
+            search: "Search".into(),
+            refresh: "Refresh".into(),
+            settings: "Settings".into(),
+            play: "Play".into(),
+            pause: "Pause".into(),
+            stop: "Stop".into(),
+            record: "Record".into(),
+            copy: "Copy".into(),
+            back: "Back".into(),
+            forward: "Forward".into(),
+            confirm: "Confirm".into(),
+            cancel: "Cancel".into(),
+        }
+    }
+
+    /// Japanese labels
+    pub fn japanese() -> Self {
+        Self {
+            save: "保存".into(),
+            close: "閉じる".into(),
+            delete: "削除".into(),
+            edit: "編集".into(),
+            add: "追加".into(),
+            remove: "除去".into(),
+            search: "検索".into(),
+            refresh: "更新".into(),
+            settings: "設定".into(),
+            play: "再生".into(),
+            pause: "一時停止".into(),
+            stop: "停止".into(),
+            record: "録音".into(),
+            copy: "コピー".into(),
+            back: "戻る".into(),
+            forward: "進む".into(),
+            confirm: "確定".into(),
+            cancel: "キャンセル".into(),
+        }
+    }
+
+    /// Get the installed labels from egui context (fallback to English if not set)
+    pub fn current(ctx: &egui::Context) -> Self {
+        ctx.data(|d| d.get_temp::<SemanticLabels>(Id::new(Self::STORAGE_ID)))
+            .unwrap_or_default()
+    }
+}
+
+impl Default for SemanticLabels {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+/// Install the labels used by all semantic buttons rendered in `ctx`
+pub fn set_labels(ctx: &egui::Context, labels: SemanticLabels) {
+    ctx.data_mut(|d| d.insert_temp(Id::new(SemanticLabels::STORAGE_ID), labels));
+}
+
+/// Picks one label out of [`SemanticLabels`]
+type LabelFn = fn(&SemanticLabels) -> &str;
+
 /// A semantic button with fixed label and icon
 pub struct SemanticButton {
     icon: &'static str,
-    label: &'static str,
+    label: LabelFn,
     style: ButtonStyle,
     variant: SemanticVariant,
+    active: Option<bool>,
 }
 
 /// Semantic variant determines the visual style
@@ -60,12 +171,13 @@ enum SemanticVariant {
 }
 
 impl SemanticButton {
-    fn new(icon: &'static str, label: &'static str, style: ButtonStyle) -> Self {
+    fn new(icon: &'static str, label: LabelFn, style: ButtonStyle) -> Self {
         Self {
             icon,
             label,
             style,
             variant: SemanticVariant::Primary,
+            active: None,
         }
     }
 
@@ -74,6 +186,14 @@ impl SemanticButton {
         self
     }
 
+    /// Render as a toggle: filled when `active`, outlined when not
+    ///
+    /// For buttons that switch a mode on and off, e.g. record.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
     /// Show the button and emit msg on click (TEA style)
     pub fn on_click<Msg>(self, ctx: &mut ViewCtx<'_, Msg>, msg: Msg) -> bool {
         let clicked = self.show(ctx.ui);
@@ -83,10 +203,16 @@ impl SemanticButton {
         clicked
     }
 
+    /// Resolve the text label from `labels`
+    fn label<'l>(&self, labels: &'l SemanticLabels) -> &'l str {
+        (self.label)(labels)
+    }
+
     /// Show the button (returns true if clicked)
     pub fn show(self, ui: &mut Ui) -> bool {
-        let is_dark = ui.ctx().style().visuals.dark_mode;
-        let (fill, text_color, stroke) = self.variant_style(is_dark);
+        let labels = SemanticLabels::current(ui.ctx());
+        let label = self.label(&labels);
+        let (fill, text_color, stroke) = self.colors(ui.visuals());
 
         let button = match self.style {
             ButtonStyle::Icon => {
@@ -98,7 +224,7 @@ impl SemanticButton {
             }
             ButtonStyle::Text => {
                 // Text only - use default font
-                let content = RichText::new(self.label).color(text_color);
+                let content = RichText::new(label).color(text_color);
                 egui::Button::new(content).fill(fill)
             }
             ButtonStyle::Both => {
@@ -129,7 +255,7 @@ impl SemanticButton {
 
                 // Text part (default font)
                 job.append(
-                    self.label,
+                    label,
                     0.0,
                     TextFormat {
                         font_id: FontId::default(),
@@ -150,6 +276,19 @@ impl SemanticButton {
         ui.add(button).clicked()
     }
 
+    /// Colors for the current variant and toggle state: (fill, text, stroke)
+    fn colors(&self, visuals: &egui::Visuals) -> (Color32, Color32, Option<Stroke>) {
+        let (fill, text_color, stroke) = self.variant_style(visuals.dark_mode);
+        match self.active {
+            Some(false) => (
+                visuals.widgets.inactive.weak_bg_fill,
+                fill,
+                Some(Stroke::new(1.0, fill)),
+            ),
+            _ => (fill, text_color, stroke),
+        }
+    }
+
     /// Get style colors for variant
     fn variant_style(&self, is_dark: bool) -> (Color32, Color32, Option<Stroke>) {
         match self.variant {
@@ -215,22 +354,22 @@ impl SemanticButton {
 
 /// Save button
 pub fn save(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::FLOPPY_DISK, "Save", style)
+    SemanticButton::new(icons::FLOPPY_DISK, |l| &l.save, style)
 }
 
 /// Close button
 pub fn close(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::X, "Close", style).with_variant(SemanticVariant::Secondary)
+    SemanticButton::new(icons::X, |l| &l.close, style).with_variant(SemanticVariant::Secondary)
 }
 
 /// Delete button (danger styled)
 pub fn delete(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::TRASH, "Delete", style).with_variant(SemanticVariant::Danger)
+    SemanticButton::new(icons::TRASH, |l| &l.delete, style).with_variant(SemanticVariant::Danger)
 }
 
 /// Edit button
 pub fn edit(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::PENCIL_SIMPLE, "Edit", style)
+    SemanticButton::new(icons::PENCIL_SIMPLE, |l| &l.edit, style)
 }
 
 // =============================================================================
@@ -239,28 +378,29 @@ pub fn edit(style: ButtonStyle) -> SemanticButton {
 
 /// Add button
 pub fn add(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::PLUS, "Add", style)
+    SemanticButton::new(icons::PLUS, |l| &l.add, style)
 }
 
 /// Remove button (danger styled)
 pub fn remove(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::MINUS, "Remove", style).with_variant(SemanticVariant::Danger)
+    SemanticButton::new(icons::MINUS, |l| &l.remove, style).with_variant(SemanticVariant::Danger)
 }
 
 /// Search button
 pub fn search(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::MAGNIFYING_GLASS, "Search", style)
+    SemanticButton::new(icons::MAGNIFYING_GLASS, |l| &l.search, style)
 }
 
 /// Refresh button
 pub fn refresh(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::ARROWS_CLOCKWISE, "Refresh", style)
+    SemanticButton::new(icons::ARROWS_CLOCKWISE, |l| &l.refresh, style)
         .with_variant(SemanticVariant::Secondary)
 }
 
 /// Settings button
 pub fn settings(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::GEAR, "Settings", style).with_variant(SemanticVariant::Secondary)
+    SemanticButton::new(icons::GEAR, |l| &l.settings, style)
+        .with_variant(SemanticVariant::Secondary)
 }
 
 // =============================================================================
@@ -269,17 +409,22 @@ pub fn settings(style: ButtonStyle) -> SemanticButton {
 
 /// Play button
 pub fn play(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::PLAY, "Play", style).with_variant(SemanticVariant::Success)
+    SemanticButton::new(icons::PLAY, |l| &l.play, style).with_variant(SemanticVariant::Success)
 }
 
 /// Pause button
 pub fn pause(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::PAUSE, "Pause", style).with_variant(SemanticVariant::Secondary)
+    SemanticButton::new(icons::PAUSE, |l| &l.pause, style).with_variant(SemanticVariant::Secondary)
 }
 
 /// Stop button
 pub fn stop(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::STOP, "Stop", style).with_variant(SemanticVariant::Danger)
+    SemanticButton::new(icons::STOP, |l| &l.stop, style).with_variant(SemanticVariant::Danger)
+}
+
+/// Record button
+pub fn record(style: ButtonStyle) -> SemanticButton {
+    SemanticButton::new(icons::RECORD, |l| &l.record, style).with_variant(SemanticVariant::Danger)
 }
 
 // =============================================================================
@@ -288,7 +433,7 @@ pub fn stop(style: ButtonStyle) -> SemanticButton {
 
 /// Copy button
 pub fn copy(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::COPY, "Copy", style).with_variant(SemanticVariant::Secondary)
+    SemanticButton::new(icons::COPY, |l| &l.copy, style).with_variant(SemanticVariant::Secondary)
 }
 
 // =============================================================================
@@ -297,12 +442,13 @@ pub fn copy(style: ButtonStyle) -> SemanticButton {
 
 /// Back button
 pub fn back(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::ARROW_LEFT, "Back", style).with_variant(SemanticVariant::Secondary)
+    SemanticButton::new(icons::ARROW_LEFT, |l| &l.back, style)
+        .with_variant(SemanticVariant::Secondary)
 }
 
 /// Forward button
 pub fn forward(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::ARROW_RIGHT, "Forward", style)
+    SemanticButton::new(icons::ARROW_RIGHT, |l| &l.forward, style)
         .with_variant(SemanticVariant::Secondary)
 }
 
@@ -312,12 +458,12 @@ pub fn forward(style: ButtonStyle) -> SemanticButton {
 
 /// Confirm/OK button
 pub fn confirm(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::CHECK, "Confirm", style).with_variant(SemanticVariant::Success)
+    SemanticButton::new(icons::CHECK, |l| &l.confirm, style).with_variant(SemanticVariant::Success)
 }
 
 /// Cancel button
 pub fn cancel(style: ButtonStyle) -> SemanticButton {
-    SemanticButton::new(icons::X, "Cancel", style).with_variant(SemanticVariant::Secondary)
+    SemanticButton::new(icons::X, |l| &l.cancel, style).with_variant(SemanticVariant::Secondary)
 }
 
 #[cfg(test)]
@@ -329,16 +475,16 @@ mod tests {
         // Icon style
         let btn = save(ButtonStyle::Icon);
         assert_eq!(btn.icon, icons::FLOPPY_DISK);
-        assert_eq!(btn.label, "Save");
+        assert_eq!(btn.label(&SemanticLabels::default()), "Save");
 
         // Text style
         let btn = delete(ButtonStyle::Text);
-        assert_eq!(btn.label, "Delete");
+        assert_eq!(btn.label(&SemanticLabels::default()), "Delete");
 
         // Both style
         let btn = edit(ButtonStyle::Both);
         assert_eq!(btn.icon, icons::PENCIL_SIMPLE);
-        assert_eq!(btn.label, "Edit");
+        assert_eq!(btn.label(&SemanticLabels::default()), "Edit");
     }
 
     #[test]
@@ -347,4 +493,39 @@ mod tests {
         assert_eq!(delete(ButtonStyle::Icon).variant, SemanticVariant::Danger);
         assert_eq!(close(ButtonStyle::Icon).variant, SemanticVariant::Secondary);
     }
+
+    #[test]
+    fn test_active_toggles_between_outline_and_fill() {
+        let visuals = egui::Visuals::dark();
+        let plain = record(ButtonStyle::Icon).colors(&visuals);
+        let on = record(ButtonStyle::Icon).active(true).colors(&visuals);
+        let (off_fill, off_text, off_stroke) =
+            record(ButtonStyle::Icon).active(false).colors(&visuals);
+
+        assert_eq!(on, plain);
+        assert_eq!(off_fill, visuals.widgets.inactive.weak_bg_fill);
+        assert_eq!(off_text, plain.0);
+        assert_eq!(off_stroke, Some(Stroke::new(1.0, plain.0)));
+    }
+
+    #[test]
+    fn installed_labels_override_text() {
+        let ctx = crate::test_utils::test_context();
+        set_labels(
+            &ctx,
+            SemanticLabels {
+                save: "Speichern".into(),
+                ..SemanticLabels::english()
+            },
+        );
+        let output = crate::test_utils::run_ui(&ctx, |ui| {
+            save(ButtonStyle::Text).show(ui);
+            delete(ButtonStyle::Both).show(ui);
+        });
+
+        let texts = crate::test_utils::painted_texts(&output);
+        assert!(texts.iter().any(|t| t == "Speichern"), "{texts:?}");
+        assert!(!texts.iter().any(|t| t == "Save"));
+        assert!(texts.iter().any(|t| t.ends_with("Delete")));
+    }
 }
@@ -10,7 +10,8 @@
 //!
 //! The key difference is that semantic components have **fixed labels and icons**,
 //! ensuring UI consistency across the entire application. You can only choose
-//! the display style (icon-only, text-only, or both).
+//! the display style (icon-only, text-only, or both). Labels can be localized
+//! app-wide with [`set_labels`].
 //!
 //! ## Example
 //!
@@ -47,15 +48,20 @@ pub use button::{
     pause,
     // Media
     play,
+    record,
     refresh,
     remove,
     // File operations
     save,
     search,
+    // Localization
+    set_labels,
     settings,
     stop,
     // Display style
     ButtonStyle,
+    SemanticLabels,
 };
 
+pub(crate) use severity_log::details_toggle;
 pub use severity_log::{LogSeverity, SeverityLog};
@@ -2,9 +2,11 @@
 //!
 //! Uses Theme's log_* colors for consistent severity visualization.
 
-use crate::atoms::icons;
+use crate::atoms::{icons, Code};
 use crate::Theme;
-use egui::{Color32, FontFamily, RichText, Ui};
+use egui::text::{LayoutJob, TextFormat};
+use egui::{Color32, FontFamily, FontId, Id, RichText, Sense, Style, TextStyle, Ui};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 /// Log severity level
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -62,6 +64,10 @@ impl LogSeverity {
 pub struct SeverityLog {
     severity: LogSeverity,
     message: String,
+    timestamp: Option<Instant>,
+    source: Option<String>,
+    details: Option<String>,
+    id_salt: Option<Id>,
     show_icon: bool,
     show_label: bool,
 }
@@ -72,6 +78,10 @@ impl SeverityLog {
         Self {
             severity,
             message: message.into(),
+            timestamp: None,
+            source: None,
+            details: None,
+            id_salt: None,
             show_icon: true,
             show_label: false,
         }
@@ -102,6 +112,30 @@ impl SeverityLog {
         Self::new(LogSeverity::Critical, message)
     }
 
+    /// Prefix the message with the time it was logged, as `[HH:MM:SS]` (UTC)
+    pub fn at(mut self, timestamp: Instant) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Prefix the message with a source/module tag, as `[source]`
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Attach a detail body (stack trace, context) behind a collapsed "▶ details" toggle
+    pub fn with_details(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    /// Set the id used to remember the details toggle (defaults to the message)
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
     /// Show/hide the severity icon
     pub fn with_icon(mut self, show: bool) -> Self {
         self.show_icon = show;
@@ -117,29 +151,15 @@ impl SeverityLog {
     /// Display the log entry
     pub fn show(self, ui: &mut Ui) {
         let theme = Theme::current(ui.ctx());
-        let color = self.severity.color(&theme);
-
-        ui.horizontal(|ui| {
-            if self.show_icon {
-                ui.label(
-                    RichText::new(self.severity.icon())
-                        .family(FontFamily::Name("icons".into()))
-                        .color(color),
-                );
-            }
-
-            if self.show_label {
-                ui.label(RichText::new(self.severity.label()).strong().color(color));
-            }
-
-            ui.label(RichText::new(&self.message).color(color));
+        ui.vertical(|ui| {
+            ui.horizontal(|ui| self.show_row(ui, &theme));
+            self.show_details(ui);
         });
     }
 
     /// Display in a framed box with background
     pub fn show_framed(self, ui: &mut Ui) {
         let theme = Theme::current(ui.ctx());
-        let color = self.severity.color(&theme);
         let bg_color = self.severity.bg_color(&theme);
 
         egui::Frame::new()
@@ -147,21 +167,153 @@ impl SeverityLog {
             .corner_radius(4.0)
             .inner_margin(egui::Margin::symmetric(8, 4))
             .show(ui, |ui| {
-                ui.horizontal(|ui| {
-                    if self.show_icon {
-                        ui.label(
-                            RichText::new(self.severity.icon())
-                                .family(FontFamily::Name("icons".into()))
-                                .color(color),
-                        );
-                    }
-
-                    if self.show_label {
-                        ui.label(RichText::new(self.severity.label()).strong().color(color));
-                    }
-
-                    ui.label(RichText::new(&self.message).color(color));
-                });
+                ui.horizontal(|ui| self.show_row(ui, &theme));
+                self.show_details(ui);
             });
     }
+
+    fn show_details(&self, ui: &mut Ui) {
+        if let Some(details) = &self.details {
+            let salt = self
+                .id_salt
+                .unwrap_or_else(|| Id::new(("severity_log_details", &self.message)));
+            details_toggle(ui, ui.make_persistent_id(salt), details);
+        }
+    }
+
+    /// Icon, label and message laid out in the current row
+    fn show_row(&self, ui: &mut Ui, theme: &Theme) {
+        let color = self.severity.color(theme);
+
+        if self.show_icon {
+            ui.label(
+                RichText::new(self.severity.icon())
+                    .family(FontFamily::Name("icons".into()))
+                    .color(color),
+            );
+        }
+
+        if self.show_label {
+            ui.label(RichText::new(self.severity.label()).strong().color(color));
+        }
+
+        let wall_time = self.timestamp.map(|t| SystemTime::now() - t.elapsed());
+        ui.label(self.message_job(ui.style(), theme, wall_time));
+    }
+
+    /// Timestamp and source prefixes (muted) followed by the message
+    ///
+    /// The timestamp is monospace so stacked entries stay aligned.
+    fn message_job(
+        &self,
+        style: &Style,
+        theme: &Theme,
+        wall_time: Option<SystemTime>,
+    ) -> LayoutJob {
+        let mut job = LayoutJob::default();
+        let muted = |font: FontId| TextFormat::simple(font, theme.text_muted);
+        let body = FontId::proportional(style.text_styles[&TextStyle::Body].size);
+
+        if let Some(time) = wall_time {
+            let mono = FontId::monospace(body.size);
+            job.append(&format!("[{}]", format_clock(time)), 0.0, muted(mono));
+            job.append(" ", 0.0, muted(body.clone()));
+        }
+        if let Some(source) = &self.source {
+            job.append(&format!("[{source}]"), 0.0, muted(body.clone()));
+            job.append(" ", 0.0, muted(body.clone()));
+        }
+        job.append(
+            &self.message,
+            0.0,
+            TextFormat::simple(body, self.severity.color(theme)),
+        );
+        job
+    }
+}
+
+/// A "▶ details" toggle expanding `details` in a monospace block below it
+///
+/// The open state is kept in egui memory under `id`, collapsed by default.
+pub(crate) fn details_toggle(ui: &mut Ui, id: Id, details: &str) {
+    let theme = Theme::current(ui.ctx());
+    let mut open = ui.data(|d| d.get_temp::<bool>(id).unwrap_or(false));
+    let arrow = if open { "▼" } else { "▶" };
+    let toggle = ui
+        .add(
+            egui::Label::new(
+                RichText::new(format!("{arrow} details"))
+                    .size(theme.font_size_sm)
+                    .color(theme.text_muted),
+            )
+            .sense(Sense::click()),
+        )
+        .on_hover_cursor(egui::CursorIcon::PointingHand);
+    if toggle.clicked() {
+        open = !open;
+        ui.data_mut(|d| d.insert_temp(id, open));
+    }
+    if open {
+        Code::new(details).show(ui);
+    }
+}
+
+/// Format the UTC time of day as `HH:MM:SS`
+fn format_clock(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()) % 86_400;
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{click_at, painted_text_rect, painted_texts, run_ui, test_context};
+    use std::time::Duration;
+
+    #[test]
+    fn timestamp_and_source_prefix_the_message() {
+        let theme = Theme::default();
+        let log = SeverityLog::error("connection lost")
+            .at(Instant::now())
+            .with_source("net");
+        let time = UNIX_EPOCH + Duration::from_secs(12 * 3600 + 3 * 60 + 45);
+        let job = log.message_job(&Style::default(), &theme, Some(time));
+
+        assert_eq!(job.text, "[12:03:45] [net] connection lost");
+        let message = job.sections.last().unwrap();
+        assert_eq!(&job.text[message.byte_range.clone()], "connection lost");
+        assert_eq!(message.format.color, theme.log_error);
+        for prefix in &job.sections[..job.sections.len() - 1] {
+            assert_eq!(prefix.format.color, theme.text_muted);
+        }
+    }
+
+    #[test]
+    fn plain_message_has_no_prefix() {
+        let theme = Theme::default();
+        let job = SeverityLog::info("ready").message_job(&Style::default(), &theme, None);
+        assert_eq!(job.text, "ready");
+    }
+
+    #[test]
+    fn details_are_hidden_until_toggled() {
+        let ctx = test_context();
+        let trace = "at main.rs:42";
+        let frame = |ui: &mut Ui| {
+            SeverityLog::error("panicked")
+                .with_details(trace)
+                .show_framed(ui)
+        };
+
+        let output = run_ui(&ctx, frame);
+        let texts = painted_texts(&output);
+        assert!(!texts.iter().any(|t| t == trace));
+        let toggle = painted_text_rect(&output, "▶ details").expect("toggle shown");
+
+        click_at(&ctx, toggle.center(), frame);
+        let output = run_ui(&ctx, frame);
+        let texts = painted_texts(&output);
+        assert!(texts.iter().any(|t| t == trace), "{texts:?}");
+        assert!(texts.iter().any(|t| t == "▼ details"));
+    }
 }
@@ -0,0 +1,123 @@
+//! Helpers for rendering components headlessly in unit tests
+
+use egui::{Context, Event, FullOutput, Modifiers, PointerButton, Pos2, RawInput, Rect, Shape, Ui};
+
+/// Run one frame with a central panel and call `f` with its Ui
+pub(crate) fn run_ui(ctx: &Context, f: impl FnMut(&mut Ui)) -> FullOutput {
+    run_ui_with_events(ctx, Vec::new(), f)
+}
+
+/// Run one frame with the given input events
+pub(crate) fn run_ui_with_events(
+    ctx: &Context,
+    events: Vec<Event>,
+    f: impl FnMut(&mut Ui),
+) -> FullOutput {
+    run_ui_with_modifiers(ctx, events, Modifiers::NONE, f)
+}
+
+/// Run one frame with the given input events while `modifiers` are held
+pub(crate) fn run_ui_with_modifiers(
+    ctx: &Context,
+    events: Vec<Event>,
+    modifiers: Modifiers,
+    mut f: impl FnMut(&mut Ui),
+) -> FullOutput {
+    let input = RawInput {
+        events,
+        modifiers,
+        ..Default::default()
+    };
+    ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| f(ui));
+    })
+}
+
+/// Run the frames needed to click at `pos` (move, press, release)
+pub(crate) fn click_at(ctx: &Context, pos: Pos2, mut f: impl FnMut(&mut Ui)) {
+    for events in [
+        vec![Event::PointerMoved(pos)],
+        vec![pointer_button(pos, true)],
+        vec![pointer_button(pos, false)],
+    ] {
+        run_ui_with_events(ctx, events, &mut f);
+    }
+}
+
+/// Run the frames needed to drag from `from` to `to` with the primary button
+pub(crate) fn drag(ctx: &Context, from: Pos2, to: Pos2, f: impl FnMut(&mut Ui)) {
+    drag_with_modifiers(ctx, from, to, Modifiers::NONE, f);
+}
+
+/// Like [`drag`], with `modifiers` held for the whole gesture
+pub(crate) fn drag_with_modifiers(
+    ctx: &Context,
+    from: Pos2,
+    to: Pos2,
+    modifiers: Modifiers,
+    mut f: impl FnMut(&mut Ui),
+) {
+    let mid = from + (to - from) * 0.5;
+    let button = |pos, pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers,
+    };
+    for events in [
+        vec![Event::PointerMoved(from)],
+        vec![button(from, true)],
+        vec![Event::PointerMoved(mid)],
+        vec![Event::PointerMoved(to)],
+        vec![button(to, false)],
+    ] {
+        run_ui_with_modifiers(ctx, events, modifiers, &mut f);
+    }
+}
+
+/// Collect the text of every text shape painted in a frame
+pub(crate) fn painted_texts(output: &FullOutput) -> Vec<String> {
+    fn collect(shape: &Shape, out: &mut Vec<String>) {
+        match shape {
+            Shape::Text(text) => out.push(text.galley.text().to_string()),
+            Shape::Vec(shapes) => shapes.iter().for_each(|s| collect(s, out)),
+            _ => {}
+        }
+    }
+    let mut texts = Vec::new();
+    for clipped in &output.shapes {
+        collect(&clipped.shape, &mut texts);
+    }
+    texts
+}
+
+/// Bounds of the first painted text shape whose text is exactly `text`
+pub(crate) fn painted_text_rect(output: &FullOutput, text: &str) -> Option<Rect> {
+    fn find(shape: &Shape, text: &str) -> Option<Rect> {
+        match shape {
+            Shape::Text(shape) if shape.galley.text() == text => Some(shape.visual_bounding_rect()),
+            Shape::Vec(shapes) => shapes.iter().find_map(|s| find(s, text)),
+            _ => None,
+        }
+    }
+    output
+        .shapes
+        .iter()
+        .find_map(|clipped| find(&clipped.shape, text))
+}
+
+fn pointer_button(pos: Pos2, pressed: bool) -> Event {
+    Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    }
+}
+
+/// Create a context with icon fonts registered
+pub(crate) fn test_context() -> Context {
+    let ctx = Context::default();
+    crate::setup_fonts(&ctx);
+    ctx
+}
@@ -4,6 +4,8 @@
 //! - Design tokens (colors, spacing, radii)
 //! - `Theme::current()` for component access
 //! - `ThemeProvider` trait for external theme integration
+//! - Scoped per-component overrides (`Theme::with_override`, `ThemeOverrideExt`)
+//! - Animated theme switching (`Theme::apply_animated`)
 //! - TOML-based theme configuration (with `serde` feature)
 //!
 //! # TOML Configuration Example
@@ -31,7 +33,9 @@
 //! let theme = Theme::from_config(&config);
 //! ```
 
-use egui::{Color32, FontId, Id, TextStyle};
+use egui::{Color32, FontId, Id, TextStyle, Ui};
+use egui_cha::ViewCtx;
+use std::time::Duration;
 
 /// Theme variant
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -49,7 +53,7 @@ pub trait ThemeProvider {
 }
 
 /// Design system theme containing all style tokens
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Theme {
     pub variant: ThemeVariant,
 
@@ -349,12 +353,166 @@ impl Theme {
     /// ID used for storing theme in egui context
     const STORAGE_ID: &'static str = "egui_cha_ds_theme";
 
+    /// ID used for storing an in-progress `apply_animated` transition
+    const TRANSITION_ID: &'static str = "egui_cha_ds_theme_transition";
+
     /// Get current theme from egui context (fallback to default if not set)
     pub fn current(ctx: &egui::Context) -> Self {
         ctx.data(|d| d.get_temp::<Theme>(Id::new(Self::STORAGE_ID)))
             .unwrap_or_default()
     }
 
+    /// Apply theme, animating from the currently applied theme over `duration`
+    ///
+    /// Call every frame in place of `apply()`. When the target changes, all colors
+    /// and numeric tokens interpolate from the theme currently on screen (even
+    /// mid-transition) and repaints are requested until the transition finishes.
+    ///
+    /// ```ignore
+    /// model.theme.apply_animated(ctx.ui.ctx(), Duration::from_millis(250));
+    /// ```
+    pub fn apply_animated(&self, ctx: &egui::Context, duration: Duration) {
+        let id = Id::new(Self::TRANSITION_ID);
+        let now = ctx.input(|i| i.time);
+        let current = ctx.data(|d| d.get_temp::<Theme>(Id::new(Self::STORAGE_ID)));
+
+        let transition = match ctx.data(|d| d.get_temp::<ThemeTransition>(id)) {
+            Some(t) if t.to == *self => t,
+            _ => match current {
+                Some(from) if from != *self => ThemeTransition {
+                    from,
+                    to: self.clone(),
+                    start: now,
+                },
+                // Nothing on screen yet (or already there): apply directly
+                _ => {
+                    self.apply(ctx);
+                    return;
+                }
+            },
+        };
+
+        let t = if duration.is_zero() {
+            1.0
+        } else {
+            ((now - transition.start) / duration.as_secs_f64()).clamp(0.0, 1.0) as f32
+        };
+
+        if t < 1.0 {
+            transition.from.lerp(&transition.to, t).apply(ctx);
+            ctx.data_mut(|d| d.insert_temp(id, transition));
+            ctx.request_repaint();
+        } else {
+            self.apply(ctx);
+            ctx.data_mut(|d| d.remove::<ThemeTransition>(id));
+        }
+    }
+
+    /// Interpolate every color and numeric token toward `other` (`t` in 0.0 - 1.0)
+    ///
+    /// Discrete values (variant, flags, optional tokens) switch at the midpoint.
+    pub fn lerp(&self, other: &Theme, t: f32) -> Theme {
+        let mut out = if t < 0.5 { self.clone() } else { other.clone() };
+
+        macro_rules! lerp_colors {
+            ($($field:ident),* $(,)?) => {
+                $(out.$field = self.$field.lerp_to_gamma(other.$field, t);)*
+            };
+        }
+        macro_rules! lerp_numbers {
+            ($($field:ident),* $(,)?) => {
+                $(out.$field = egui::lerp(self.$field..=other.$field, t);)*
+            };
+        }
+
+        lerp_colors!(
+            primary,
+            primary_hover,
+            primary_text,
+            secondary,
+            secondary_hover,
+            secondary_text,
+            bg_primary,
+            bg_secondary,
+            bg_tertiary,
+            text_primary,
+            text_secondary,
+            text_muted,
+            state_success,
+            state_warning,
+            state_danger,
+            state_info,
+            state_success_text,
+            state_warning_text,
+            state_danger_text,
+            state_info_text,
+            state_success_hover,
+            state_warning_hover,
+            state_danger_hover,
+            state_info_hover,
+            log_debug,
+            log_info,
+            log_warn,
+            log_error,
+            log_critical,
+            border,
+            border_focus,
+        );
+        lerp_numbers!(
+            spacing_xs,
+            spacing_sm,
+            spacing_md,
+            spacing_lg,
+            spacing_xl,
+            radius_sm,
+            radius_md,
+            radius_lg,
+            border_width,
+            stroke_width,
+            font_size_xs,
+            font_size_sm,
+            font_size_md,
+            font_size_lg,
+            font_size_xl,
+            font_size_2xl,
+            font_size_3xl,
+            line_height,
+            overlay_dim,
+            surface_alpha,
+            glass_opacity,
+            glass_blur_radius,
+            titlebar_height,
+        );
+
+        if let (Some(a), Some(b)) = (self.shadow_blur, other.shadow_blur) {
+            out.shadow_blur = Some(egui::lerp(a..=b, t));
+        }
+        if let (Some(a), Some(b)) = (self.glass_tint, other.glass_tint) {
+            out.glass_tint = Some(a.lerp_to_gamma(b, t));
+        }
+
+        out
+    }
+
+    /// Run `f` with a modified copy of the current theme (egui style)
+    ///
+    /// Components inside read the overridden theme via `Theme::current()`;
+    /// the previous theme is restored afterward. Overrides nest.
+    ///
+    /// ```ignore
+    /// Theme::with_override(ui, |t| t.primary = Color32::from_rgb(236, 72, 153), |ui| {
+    ///     Button::primary("Pink").show(ui);
+    /// });
+    /// ```
+    pub fn with_override<R>(
+        ui: &mut Ui,
+        mutate: impl FnOnce(&mut Theme),
+        f: impl FnOnce(&mut Ui) -> R,
+    ) -> R {
+        let _guard = ThemeOverride::push(ui.ctx(), mutate);
+        f(ui)
+    }
+
     /// Create theme from external provider
     pub fn from_provider(provider: impl ThemeProvider) -> Self {
         provider.to_ds_theme()
@@ -877,6 +1035,278 @@ impl Theme {
             titlebar_height: 32.0,
         }
     }
+
+    /// High contrast theme - AAA (7:1) text contrast on near-black surfaces
+    ///
+    /// Thicker borders and strokes make focus and outlines easier to see.
+    pub fn high_contrast() -> Self {
+        Self {
+            variant: ThemeVariant::Dark,
+
+            // Primary - Signal yellow
+            primary: Color32::from_rgb(255, 221, 0),
+            primary_hover: Color32::from_rgb(255, 236, 102),
+            primary_text: Color32::BLACK,
+
+            // Secondary - Near white
+            secondary: Color32::from_rgb(230, 230, 230),
+            secondary_hover: Color32::WHITE,
+            secondary_text: Color32::BLACK,
+
+            // Background - Black
+            bg_primary: Color32::BLACK,
+            bg_secondary: Color32::from_rgb(18, 18, 18),
+            bg_tertiary: Color32::from_rgb(32, 32, 32),
+
+            // Text
+            text_primary: Color32::WHITE,
+            text_secondary: Color32::from_rgb(230, 230, 230),
+            text_muted: Color32::from_rgb(200, 200, 200),
+
+            // UI State (for buttons, badges, alerts)
+            state_success: Color32::from_rgb(0, 230, 118),
+            state_warning: Color32::from_rgb(255, 176, 0),
+            state_danger: Color32::from_rgb(255, 128, 128),
+            state_info: Color32::from_rgb(102, 204, 255),
+
+            // UI State text (on state background)
+            state_success_text: Color32::BLACK,
+            state_warning_text: Color32::BLACK,
+            state_danger_text: Color32::BLACK,
+            state_info_text: Color32::BLACK,
+
+            // UI State hover
+            state_success_hover: Color32::from_rgb(105, 240, 174),
+            state_warning_hover: Color32::from_rgb(255, 202, 87),
+            state_danger_hover: Color32::from_rgb(255, 168, 168),
+            state_info_hover: Color32::from_rgb(153, 221, 255),
+
+            // Log Severity (for log viewers, console output)
+            log_debug: Color32::from_rgb(200, 200, 200),
+            log_info: Color32::from_rgb(102, 204, 255),
+            log_warn: Color32::from_rgb(255, 176, 0),
+            log_error: Color32::from_rgb(255, 128, 128),
+            log_critical: Color32::from_rgb(255, 102, 255),
+
+            // Border
+            border: Color32::from_rgb(160, 160, 160),
+            border_focus: Color32::from_rgb(255, 221, 0),
+
+            // Spacing (same as dark)
+            spacing_xs: 6.0,
+            spacing_sm: 12.0,
+            spacing_md: 20.0,
+            spacing_lg: 32.0,
+            spacing_xl: 48.0,
+
+            // Radius (same as dark)
+            radius_sm: 4.0,
+            radius_md: 8.0,
+            radius_lg: 12.0,
+
+            // Stroke / Border width - Heavier outlines
+            border_width: 2.0,
+            stroke_width: 1.5,
+
+            // Typography (same as dark)
+            font_size_xs: 10.0,
+            font_size_sm: 12.0,
+            font_size_md: 14.0,
+            font_size_lg: 16.0,
+            font_size_xl: 20.0,
+            font_size_2xl: 24.0,
+            font_size_3xl: 30.0,
+            line_height: 1.4,
+
+            // Overlay / Surface (opaque for legibility)
+            overlay_dim: 0.8,
+            surface_alpha: 1.0,
+            shadow_blur: None, // Lightweight: no shadow
+
+            // Glass / Transparency (mostly opaque)
+            glass_opacity: 0.95,
+            glass_blur_radius: 8.0,
+            glass_tint: None,
+            glass_border: true,
+            titlebar_height: 32.0,
+        }
+    }
+
+    /// Colorblind-safe theme - Okabe-Ito palette on the light theme
+    ///
+    /// State colors stay distinguishable under deuteranopia/protanopia
+    /// (blue/orange/vermillion instead of green/red).
+    pub fn colorblind_safe() -> Self {
+        Self {
+            // Primary - Okabe-Ito blue
+            primary: Color32::from_rgb(0, 114, 178),
+            primary_hover: Color32::from_rgb(0, 90, 140),
+            primary_text: Color32::WHITE,
+            border_focus: Color32::from_rgb(0, 114, 178),
+
+            // UI State - Okabe-Ito
+            state_success: Color32::from_rgb(0, 158, 115), // bluish green
+            state_warning: Color32::from_rgb(230, 159, 0), // orange
+            state_danger: Color32::from_rgb(213, 94, 0),   // vermillion
+            state_info: Color32::from_rgb(86, 180, 233),   // sky blue
+
+            // UI State text (dark text reads better on the lighter hues)
+            state_success_text: Color32::WHITE,
+            state_warning_text: Color32::from_rgb(17, 24, 39),
+            state_danger_text: Color32::WHITE,
+            state_info_text: Color32::from_rgb(17, 24, 39),
+
+            // UI State hover
+            state_success_hover: Color32::from_rgb(0, 126, 92),
+            state_warning_hover: Color32::from_rgb(196, 135, 0),
+            state_danger_hover: Color32::from_rgb(181, 80, 0),
+            state_info_hover: Color32::from_rgb(58, 150, 204),
+
+            // Log Severity
+            log_debug: Color32::from_rgb(107, 114, 128),
+            log_info: Color32::from_rgb(0, 114, 178),
+            log_warn: Color32::from_rgb(230, 159, 0),
+            log_error: Color32::from_rgb(213, 94, 0),
+            log_critical: Color32::from_rgb(204, 121, 167), // reddish purple
+
+            ..Self::light()
+        }
+    }
+
+    /// Derive a full palette from a single accent color
+    ///
+    /// Sets `primary` (+ hover and contrasting text), tints the background,
+    /// text and border ramps toward the accent for the given variant, and
+    /// keeps the variant's default state and log colors.
+    ///
+    /// ```ignore
+    /// let theme = Theme::from_accent(Color32::from_rgb(139, 92, 246), ThemeVariant::Dark);
+    /// ```
+    pub fn from_accent(accent: Color32, variant: ThemeVariant) -> Self {
+        let base = match variant {
+            ThemeVariant::Light => Self::light(),
+            ThemeVariant::Dark => Self::dark(),
+        };
+        let tint = |color: Color32, amount: f32| color.lerp_to_gamma(accent, amount);
+
+        let (bg, text) = match variant {
+            ThemeVariant::Light => (Color32::WHITE, Color32::from_rgb(17, 17, 17)),
+            ThemeVariant::Dark => (
+                Color32::from_rgb(12, 12, 14),
+                Color32::from_rgb(250, 250, 250),
+            ),
+        };
+        let (bg_secondary, bg_tertiary, border) = match variant {
+            ThemeVariant::Light => (tint(bg, 0.04), tint(bg, 0.08), tint(darken(bg, 0.1), 0.15)),
+            ThemeVariant::Dark => (
+                tint(lighten_abs(bg, 10), 0.08),
+                tint(lighten_abs(bg, 24), 0.12),
+                tint(lighten_abs(bg, 40), 0.15),
+            ),
+        };
+        let secondary = tint(base.secondary, 0.2);
+
+        Self {
+            primary: accent,
+            primary_hover: darken(accent, 0.15),
+            primary_text: contrast_text(accent),
+
+            secondary,
+            secondary_hover: darken(secondary, 0.15),
+            secondary_text: contrast_text(secondary),
+
+            bg_primary: tint(bg, 0.02),
+            bg_secondary,
+            bg_tertiary,
+
+            text_primary: tint(text, 0.05),
+            text_secondary: tint(base.text_secondary, 0.1),
+            text_muted: tint(base.text_muted, 0.1),
+
+            border,
+            border_focus: accent,
+
+            ..base
+        }
+    }
+
+    /// WCAG contrast ratio between two colors (1.0 - 21.0)
+    ///
+    /// AA requires 4.5:1 for body text, AAA requires 7:1.
+    pub fn contrast_ratio(a: Color32, b: Color32) -> f32 {
+        let (la, lb) = (relative_luminance(a), relative_luminance(b));
+        (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+    }
+}
+
+/// In-progress theme transition kept in egui memory
+#[derive(Clone)]
+struct ThemeTransition {
+    from: Theme,
+    to: Theme,
+    start: f64,
+}
+
+// ============================================================================
+// Scoped overrides
+// ============================================================================
+
+/// Restores the previously stored theme when dropped
+struct ThemeOverride {
+    ctx: egui::Context,
+    previous: Option<Theme>,
+}
+
+impl ThemeOverride {
+    fn push(ctx: &egui::Context, mutate: impl FnOnce(&mut Theme)) -> Self {
+        let id = Id::new(Theme::STORAGE_ID);
+        let previous = ctx.data(|d| d.get_temp::<Theme>(id));
+        let mut theme = previous.clone().unwrap_or_default();
+        mutate(&mut theme);
+        ctx.data_mut(|d| d.insert_temp(id, theme));
+        Self {
+            ctx: ctx.clone(),
+            previous,
+        }
+    }
+}
+
+impl Drop for ThemeOverride {
+    fn drop(&mut self) {
+        let id = Id::new(Theme::STORAGE_ID);
+        let previous = self.previous.take();
+        self.ctx.data_mut(|d| match previous {
+            Some(theme) => d.insert_temp(id, theme),
+            None => d.remove::<Theme>(id),
+        });
+    }
+}
+
+/// Extension trait for scoped theme overrides in TEA views
+pub trait ThemeOverrideExt<Msg> {
+    /// Run `f` with a modified copy of the current theme (TEA style)
+    ///
+    /// ```ignore
+    /// ctx.with_theme_override(|t| t.primary = theme.state_danger, |ctx| {
+    ///     Button::primary("Delete").on_click(ctx, Msg::Delete);
+    /// });
+    /// ```
+    fn with_theme_override<R>(
+        &mut self,
+        mutate: impl FnOnce(&mut Theme),
+        f: impl FnOnce(&mut ViewCtx<'_, Msg>) -> R,
+    ) -> R;
+}
+
+impl<Msg> ThemeOverrideExt<Msg> for ViewCtx<'_, Msg> {
+    fn with_theme_override<R>(
+        &mut self,
+        mutate: impl FnOnce(&mut Theme),
+        f: impl FnOnce(&mut ViewCtx<'_, Msg>) -> R,
+    ) -> R {
+        let _guard = ThemeOverride::push(self.ui.ctx(), mutate);
+        f(self)
+    }
 }
 
 // ============================================================================
@@ -907,7 +1337,7 @@ impl Theme {
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 #[serde(default)]
 pub struct ThemeConfig {
-    /// Base theme: "light", "dark", "pastel", "pastel_dark"
+    /// Base theme: "light", "dark", "pastel", "pastel_dark", "high_contrast", "colorblind_safe"
     pub base: Option<String>,
 
     // Colors - Primary
@@ -1046,6 +1476,8 @@ impl Theme {
             Some("dark") => Self::dark(),
             Some("pastel") => Self::pastel(),
             Some("pastel_dark") => Self::pastel_dark(),
+            Some("high_contrast") => Self::high_contrast(),
+            Some("colorblind_safe") => Self::colorblind_safe(),
             _ => Self::light(), // default
         };
 
@@ -1390,11 +1822,36 @@ fn darken(color: Color32, amount: f32) -> Color32 {
     )
 }
 
+/// Add `amount` to each channel (useful for lifting near-black colors)
+fn lighten_abs(color: Color32, amount: u8) -> Color32 {
+    let [r, g, b, a] = color.to_array();
+    Color32::from_rgba_unmultiplied(
+        r.saturating_add(amount),
+        g.saturating_add(amount),
+        b.saturating_add(amount),
+        a,
+    )
+}
+
 fn with_alpha(color: Color32, alpha: f32) -> Color32 {
     let [r, g, b, _] = color.to_array();
     Color32::from_rgba_unmultiplied(r, g, b, (alpha * 255.0) as u8)
 }
 
+/// WCAG relative luminance of an opaque color
+fn relative_luminance(color: Color32) -> f32 {
+    let channel = |c: u8| {
+        let c = c as f32 / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    let [r, g, b, _] = color.to_array();
+    0.2126 * channel(r) + 0.7152 * channel(g) + 0.0722 * channel(b)
+}
+
 fn contrast_text(bg: Color32) -> Color32 {
     let [r, g, b, _] = bg.to_array();
     let luminance = 0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32;
@@ -1485,6 +1942,137 @@ mod tests {
         assert_eq!(original.bg_primary, restored.bg_primary);
     }
 
+    #[test]
+    fn test_high_contrast_meets_aaa() {
+        let t = Theme::high_contrast();
+        let mut pairs = vec![
+            (t.primary_text, t.primary),
+            (t.secondary_text, t.secondary),
+            (t.state_success_text, t.state_success),
+            (t.state_warning_text, t.state_warning),
+            (t.state_danger_text, t.state_danger),
+            (t.state_info_text, t.state_info),
+        ];
+        for text in [t.text_primary, t.text_secondary, t.text_muted] {
+            for bg in [t.bg_primary, t.bg_secondary, t.bg_tertiary] {
+                pairs.push((text, bg));
+            }
+        }
+
+        for (fg, bg) in pairs {
+            let ratio = Theme::contrast_ratio(fg, bg);
+            assert!(ratio > 7.0, "{fg:?} on {bg:?} is only {ratio:.2}:1");
+        }
+    }
+
+    #[test]
+    fn test_contrast_ratio_extremes() {
+        assert!((Theme::contrast_ratio(Color32::BLACK, Color32::WHITE) - 21.0).abs() < 0.01);
+        assert_eq!(Theme::contrast_ratio(Color32::RED, Color32::RED), 1.0);
+    }
+
+    #[test]
+    fn test_override_is_scoped() {
+        use crate::test_utils::{run_ui, test_context};
+        use crate::Button;
+
+        let ctx = test_context();
+        let base = Theme::light();
+        base.apply(&ctx);
+        let accent = Color32::from_rgb(236, 72, 153);
+
+        let mut inner = None;
+        let output = run_ui(&ctx, |ui| {
+            Theme::with_override(
+                ui,
+                |t| t.primary = accent,
+                |ui| {
+                    Theme::with_override(
+                        ui,
+                        |t| t.radius_sm = 0.0,
+                        |ui| {
+                            inner = Some(Theme::current(ui.ctx()));
+                        },
+                    );
+                    Button::primary("Inside").show(ui);
+                },
+            );
+            Button::primary("Outside").show(ui);
+        });
+
+        // Nested override inherits the outer one
+        let inner = inner.unwrap();
+        assert_eq!(inner.primary, accent);
+        assert_eq!(inner.radius_sm, 0.0);
+        assert_eq!(Theme::current(&ctx).primary, base.primary);
+
+        let fills: Vec<Color32> = output
+            .shapes
+            .iter()
+            .filter_map(|c| match &c.shape {
+                egui::Shape::Rect(rect) => Some(rect.fill),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(fills.iter().filter(|c| **c == accent).count(), 1);
+        assert_eq!(fills.iter().filter(|c| **c == base.primary).count(), 1);
+    }
+
+    #[test]
+    fn test_apply_animated_interpolates() {
+        let ctx = egui::Context::default();
+        let frame = |time: f64, f: &dyn Fn(&egui::Context)| {
+            let input = egui::RawInput {
+                time: Some(time),
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| f(ctx));
+        };
+        let (light, dark) = (Theme::light(), Theme::dark());
+        let duration = Duration::from_secs(1);
+
+        frame(0.0, &|ctx| light.apply(ctx));
+        frame(1.0, &|ctx| dark.apply_animated(ctx, duration));
+        frame(1.5, &|ctx| dark.apply_animated(ctx, duration));
+
+        let mid = Theme::current(&ctx);
+        assert_eq!(mid.primary, light.primary.lerp_to_gamma(dark.primary, 0.5));
+        assert_eq!(
+            mid.bg_primary,
+            light.bg_primary.lerp_to_gamma(dark.bg_primary, 0.5)
+        );
+
+        frame(2.5, &|ctx| dark.apply_animated(ctx, duration));
+        assert_eq!(Theme::current(&ctx), dark);
+    }
+
+    #[test]
+    fn test_from_accent_hover_is_darker() {
+        let accent = Color32::from_rgb(139, 92, 246);
+        for variant in [ThemeVariant::Light, ThemeVariant::Dark] {
+            let theme = Theme::from_accent(accent, variant);
+            assert_eq!(theme.primary, accent);
+            assert_eq!(theme.variant, variant);
+
+            let [r, g, b, _] = theme.primary_hover.to_array();
+            let [ar, ag, ab, _] = accent.to_array();
+            assert!(r < ar && g < ag && b < ab);
+            assert!(relative_luminance(theme.primary_hover) < relative_luminance(accent));
+        }
+    }
+
+    #[test]
+    fn test_from_accent_text_contrast() {
+        let yellow = Theme::from_accent(Color32::from_rgb(250, 204, 21), ThemeVariant::Dark);
+        assert_eq!(yellow.primary_text, Color32::from_rgb(17, 24, 39));
+
+        let navy = Theme::from_accent(Color32::from_rgb(30, 58, 138), ThemeVariant::Light);
+        assert_eq!(navy.primary_text, Color32::WHITE);
+
+        // State colors stay at the variant defaults
+        assert_eq!(navy.state_danger, Theme::light().state_danger);
+    }
+
     #[test]
     fn test_lightweight_theme() {
         struct TestTheme;
@@ -90,4 +90,39 @@ pub trait App: Sized + 'static {
         err.log();
         Cmd::none()
     }
+
+    /// Handle a panic caught in `update` or `view`
+    ///
+    /// Only called when panic catching is enabled with
+    /// `RunConfig::with_catch_panics(true)`. The error is `Critical`, with
+    /// `ErrorSource::Update` or `ErrorSource::View`. After a caught `update`
+    /// panic the model may be partially updated; after a `view` panic the
+    /// frame's messages are dropped. The app keeps running either way.
+    ///
+    /// Default implementation forwards to [`App::on_framework_error`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// fn on_panic(model: &mut Model, err: FrameworkError) -> Cmd<Msg> {
+    ///     model.errors.push_with_level(err.format_message(), err.severity.into());
+    ///     Cmd::none()
+    /// }
+    /// ```
+    fn on_panic(model: &mut Self::Model, err: FrameworkError) -> Cmd<Self::Msg> {
+        Self::on_framework_error(model, err)
+    }
+
+    /// Called once when the app shuts down (e.g. the window is closed)
+    ///
+    /// Override this to flush state or stop background work. Commands can
+    /// no longer run at this point. Default implementation does nothing.
+    ///
+    /// # Example
+    /// ```ignore
+    /// fn on_exit(model: &mut Model) {
+    ///     model.settings.save();
+    ///     model.worker.stop();
+    /// }
+    /// ```
+    fn on_exit(_model: &mut Self::Model) {}
 }
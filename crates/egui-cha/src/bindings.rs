@@ -11,13 +11,16 @@
 //! │  ActionBindings<A>                                      │
 //! │  Maps application actions to shortcuts                  │
 //! │  - rebind(), reset(), find_conflicts()                  │
+//! │  - with_sequence() for multi-key chords ("G then H")    │
 //! ├─────────────────────────────────────────────────────────┤
 //! │  DynamicShortcut                                        │
 //! │  Runtime-modifiable keyboard shortcut                   │
-//! │  - Modifiers + Key, serde support                       │
+//! │  - Modifiers + Key, "Ctrl+Shift+S" string form          │
+//! │  - serde support (feature = "serde")                    │
 //! ├─────────────────────────────────────────────────────────┤
 //! │  InputBinding trait                                     │
-//! │  Abstraction over KeyboardShortcut, DynamicShortcut     │
+//! │  Abstraction over KeyboardShortcut, DynamicShortcut,    │
+//! │  ShortcutGroup, ShortcutSequence                        │
 //! └─────────────────────────────────────────────────────────┘
 //! ```
 //!
@@ -52,9 +55,12 @@
 //! }
 //! ```
 
-use egui::{Context, Key, KeyboardShortcut, Modifiers};
-use std::collections::HashMap;
+use crate::shortcuts::{SequenceMatcher, ShortcutSequence};
+use egui::{Context, Event, Key, KeyboardShortcut, Modifiers};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
 use std::hash::Hash;
+use std::str::FromStr;
 
 /// Abstraction over different types of input bindings.
 ///
@@ -104,8 +110,9 @@ impl InputBinding for KeyboardShortcut {
 ///
 /// # Serialization
 ///
-/// When the `serde` feature is enabled, this type can be serialized/deserialized
-/// for saving user preferences.
+/// `Display` and `FromStr` use a stable, platform-independent form such as
+/// `"Ctrl+Shift+S"` or `"Cmd+Plus"`. When the `serde` feature is enabled,
+/// this type is serialized as that string for saving user preferences.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct DynamicShortcut {
     /// The modifier keys (Ctrl, Shift, Alt, etc.)
@@ -131,6 +138,86 @@ impl DynamicShortcut {
     }
 }
 
+/// Modifier names in the order they are written
+const MODIFIER_NAMES: [&str; 5] = ["Cmd", "Ctrl", "Alt", "Shift", "MacCmd"];
+
+fn modifier_flag(modifiers: &mut Modifiers, index: usize) -> &mut bool {
+    match index {
+        0 => &mut modifiers.command,
+        1 => &mut modifiers.ctrl,
+        2 => &mut modifiers.alt,
+        3 => &mut modifiers.shift,
+        _ => &mut modifiers.mac_cmd,
+    }
+}
+
+impl fmt::Display for DynamicShortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut modifiers = self.modifiers;
+        for (index, name) in MODIFIER_NAMES.iter().enumerate() {
+            if *modifier_flag(&mut modifiers, index) {
+                write!(f, "{name}+")?;
+            }
+        }
+        f.write_str(self.key.name())
+    }
+}
+
+/// Error parsing a [`DynamicShortcut`] from its string form
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseShortcutError {
+    /// The string that failed to parse
+    pub input: String,
+}
+
+impl fmt::Display for ParseShortcutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid shortcut \"{}\"", self.input)
+    }
+}
+
+impl std::error::Error for ParseShortcutError {}
+
+impl FromStr for DynamicShortcut {
+    type Err = ParseShortcutError;
+
+    /// Parse the string form, e.g. `"Ctrl+Shift+S"`.
+    ///
+    /// Modifier names are case-insensitive; the key uses egui's key names.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseShortcutError {
+            input: s.to_string(),
+        };
+        let mut parts: Vec<&str> = s.split('+').map(str::trim).collect();
+        let key = parts.pop().and_then(Key::from_name).ok_or_else(err)?;
+
+        let mut modifiers = Modifiers::NONE;
+        for part in parts {
+            let index = MODIFIER_NAMES
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(part))
+                .ok_or_else(err)?;
+            *modifier_flag(&mut modifiers, index) = true;
+        }
+        Ok(Self::new(modifiers, key))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DynamicShortcut {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DynamicShortcut {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 impl From<KeyboardShortcut> for DynamicShortcut {
     fn from(shortcut: KeyboardShortcut) -> Self {
         Self {
@@ -199,6 +286,8 @@ pub struct ActionBindings<A> {
     bindings: HashMap<A, DynamicShortcut>,
     /// Default bindings (used for reset)
     defaults: HashMap<A, DynamicShortcut>,
+    /// Multi-key sequence bindings
+    sequences: HashMap<A, ShortcutSequence>,
 }
 
 impl<A> Default for ActionBindings<A> {
@@ -213,6 +302,7 @@ impl<A> ActionBindings<A> {
         Self {
             bindings: HashMap::new(),
             defaults: HashMap::new(),
+            sequences: HashMap::new(),
         }
     }
 }
@@ -255,6 +345,35 @@ impl<A: Eq + Hash + Clone> ActionBindings<A> {
         }
     }
 
+    /// Bind an action to a key sequence (builder pattern).
+    ///
+    /// # Example
+    /// ```ignore
+    /// let bindings = ActionBindings::new()
+    ///     .with_sequence(Action::GoHome, ShortcutSequence::keys([Key::G, Key::H]));
+    /// ```
+    pub fn with_sequence(mut self, action: A, sequence: ShortcutSequence) -> Self {
+        self.bind_sequence(action, sequence);
+        self
+    }
+
+    /// Bind an action to a key sequence.
+    ///
+    /// The sequence works alongside any shortcut bound to the same action.
+    /// Returns the previous sequence, if any.
+    pub fn bind_sequence(
+        &mut self,
+        action: A,
+        sequence: ShortcutSequence,
+    ) -> Option<ShortcutSequence> {
+        self.sequences.insert(action, sequence)
+    }
+
+    /// Get the key sequence bound to an action.
+    pub fn get_sequence(&self, action: &A) -> Option<&ShortcutSequence> {
+        self.sequences.get(action)
+    }
+
     /// Rebind an action to a new shortcut.
     ///
     /// Returns the previous binding, if any.
@@ -325,42 +444,211 @@ impl<A: Eq + Hash + Clone> ActionBindings<A> {
         conflicts
     }
 
+    /// List pairs of actions bound to the same shortcut.
+    ///
+    /// Owned version of [`find_conflicts`](Self::find_conflicts), convenient
+    /// for storing in a model or showing in a keybinding UI.
+    pub fn conflicts(&self) -> Vec<(A, A)> {
+        self.find_conflicts()
+            .into_iter()
+            .map(|(a, b)| (a.clone(), b.clone()))
+            .collect()
+    }
+
+    /// Rebind an action, refusing shortcuts already used by another action.
+    ///
+    /// Returns the previous binding on success, or `Err` with the action
+    /// that holds `shortcut`, leaving the bindings unchanged.
+    ///
+    /// # Example
+    /// ```ignore
+    /// match bindings.try_rebind(&Action::Save, shortcut) {
+    ///     Ok(_) => {}
+    ///     Err(other) => model.error = Some(format!("Already bound to {other:?}")),
+    /// }
+    /// ```
+    pub fn try_rebind(
+        &mut self,
+        action: &A,
+        shortcut: DynamicShortcut,
+    ) -> Result<Option<DynamicShortcut>, A> {
+        if let Some(other) = self
+            .bindings
+            .iter()
+            .find(|(a, s)| *a != action && **s == shortcut)
+            .map(|(a, _)| a.clone())
+        {
+            return Err(other);
+        }
+        Ok(self.rebind(action, shortcut))
+    }
+
     /// Get an iterator over all (action, shortcut) pairs.
     pub fn iter(&self) -> impl Iterator<Item = (&A, &DynamicShortcut)> {
         self.bindings.iter()
     }
 
-    /// Get the number of registered bindings.
+    /// Get an iterator over all (action, sequence) pairs.
+    pub fn iter_sequences(&self) -> impl Iterator<Item = (&A, &ShortcutSequence)> {
+        self.sequences.iter()
+    }
+
+    /// Get the number of registered bindings (shortcuts and sequences).
     pub fn len(&self) -> usize {
-        self.bindings.len()
+        self.bindings.len() + self.sequences.len()
     }
 
     /// Check if there are no bindings.
     pub fn is_empty(&self) -> bool {
-        self.bindings.is_empty()
+        self.bindings.is_empty() && self.sequences.is_empty()
     }
 
     /// Remove a binding entirely.
     ///
-    /// This removes both the current binding and the default.
+    /// This removes the current binding, the default, and any sequence.
     pub fn remove(&mut self, action: &A) -> Option<DynamicShortcut> {
         self.defaults.remove(action);
+        self.sequences.remove(action);
         self.bindings.remove(action)
     }
 
     /// Check if the given shortcut was triggered and consume it.
     ///
     /// Returns Some(action) if a bound action was triggered, None otherwise.
+    /// Sequences are tracked across frames, so call this every frame.
     pub fn check_triggered(&self, ctx: &Context) -> Option<&A> {
         for (action, shortcut) in &self.bindings {
             if shortcut.consume(ctx) {
                 return Some(action);
             }
         }
+        for (action, sequence) in &self.sequences {
+            if sequence.consume(ctx) {
+                return Some(action);
+            }
+        }
         None
     }
 }
 
+/// Button that records the next key combo pressed while it is focused.
+///
+/// Click it to start recording ("Press keys…"), then press a combo such
+/// as Ctrl+S. Returns the combo on that keypress and stops recording.
+/// Holding modifiers alone does nothing; Escape cancels.
+///
+/// # Example
+/// ```ignore
+/// if let Some(shortcut) = capture_shortcut(ctx.ui) {
+///     ctx.emit(Msg::Rebind(Action::Save, shortcut));
+/// }
+/// ```
+pub fn capture_shortcut(ui: &mut egui::Ui) -> Option<DynamicShortcut> {
+    let id = ui.next_auto_id();
+    let recording = ui.memory(|m| m.has_focus(id));
+    let text = if recording {
+        "Press keys…"
+    } else {
+        "Record shortcut"
+    };
+
+    let response = ui.add(egui::Button::new(text).selected(recording));
+    if response.clicked() {
+        response.request_focus();
+    }
+    if !recording {
+        return None;
+    }
+
+    // Keep Tab, arrows and Escape from moving focus away while recording
+    ui.memory_mut(|m| {
+        m.set_focus_lock_filter(
+            response.id,
+            egui::EventFilter {
+                tab: true,
+                horizontal_arrows: true,
+                vertical_arrows: true,
+                escape: true,
+            },
+        )
+    });
+
+    let pressed = ui.input(|i| {
+        i.events.iter().find_map(|event| match event {
+            Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+                ..
+            } => Some(DynamicShortcut::new(*modifiers, *key)),
+            _ => None,
+        })
+    })?;
+
+    ui.input_mut(|i| i.consume_key(pressed.modifiers, pressed.key));
+    response.surrender_focus();
+    if pressed == DynamicShortcut::key_only(Key::Escape) {
+        None
+    } else {
+        Some(pressed)
+    }
+}
+
+/// Saved user keymap: action name → shortcut string
+///
+/// Produced by [`ActionBindings::to_config`] and restored with
+/// [`ActionBindings::apply_config`]. Only bindings that differ from the
+/// defaults are stored, so changing a default in a later release still
+/// reaches users who never touched it.
+///
+/// With the `serde` feature this serializes as a plain map, e.g. in TOML:
+///
+/// ```toml
+/// Save = "Ctrl+Shift+S"
+/// Undo = "Cmd+Y"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct BindingsConfig {
+    /// Shortcut strings keyed by action name
+    pub bindings: BTreeMap<String, String>,
+}
+
+impl<A: Eq + Hash + Clone + fmt::Debug> ActionBindings<A> {
+    /// Export the bindings that differ from their defaults.
+    ///
+    /// Actions are keyed by their `Debug` name, so unit enum variants
+    /// are stored as e.g. `"Save"`.
+    pub fn to_config(&self) -> BindingsConfig {
+        let bindings = self
+            .bindings
+            .iter()
+            .filter(|(action, shortcut)| self.defaults.get(*action) != Some(*shortcut))
+            .map(|(action, shortcut)| (format!("{action:?}"), shortcut.to_string()))
+            .collect();
+        BindingsConfig { bindings }
+    }
+
+    /// Rebind actions from a saved config.
+    ///
+    /// Names that match no known action are ignored, so keymaps saved by
+    /// an older version still load. If any shortcut fails to parse, nothing
+    /// is applied.
+    pub fn apply_config(&mut self, config: &BindingsConfig) -> Result<(), ParseShortcutError> {
+        let mut updates = Vec::new();
+        for action in self.bindings.keys().chain(self.defaults.keys()) {
+            if let Some(shortcut) = config.bindings.get(&format!("{action:?}")) {
+                updates.push((action.clone(), shortcut.parse::<DynamicShortcut>()?));
+            }
+        }
+        for (action, shortcut) in updates {
+            self.rebind(&action, shortcut);
+        }
+        Ok(())
+    }
+}
+
 /// Helper to get modifier key names.
 /// Always uses text names (Cmd, Ctrl, etc.) instead of symbols (⌘, ⌃)
 /// to avoid font rendering issues.
@@ -435,6 +723,87 @@ impl InputBinding for ShortcutGroup {
     }
 }
 
+/// Per-sequence progress, kept in egui temp memory between frames
+#[derive(Clone, Debug, Default)]
+struct SequenceState {
+    matcher: SequenceMatcher,
+    /// Pass the key presses were last fed in, so repeated checks
+    /// within one frame don't feed them twice
+    pass: Option<u64>,
+    completed: bool,
+    consumed: bool,
+}
+
+/// Feed this frame's key presses to the sequence's matcher
+fn update_sequence(sequence: &ShortcutSequence, ctx: &Context, consume: bool) -> bool {
+    let id = egui::Id::new(("shortcut_sequence", sequence));
+    let pass = ctx.cumulative_pass_nr();
+    let mut state: SequenceState = ctx.data_mut(|d| d.get_temp(id).unwrap_or_default());
+
+    if state.pass != Some(pass) {
+        state.pass = Some(pass);
+        state.completed = false;
+        state.consumed = false;
+
+        if ctx.wants_keyboard_input() {
+            // Keys typed into a text field never advance a sequence
+            state.matcher.reset();
+        } else {
+            ctx.input(|i| {
+                for event in &i.events {
+                    if let Event::Key {
+                        key,
+                        pressed: true,
+                        repeat: false,
+                        modifiers,
+                        ..
+                    } = event
+                    {
+                        if state.matcher.press(sequence, *modifiers, *key, i.time) {
+                            state.completed = true;
+                        }
+                    }
+                }
+            });
+        }
+    }
+
+    let triggered = state.completed && !state.consumed;
+    if triggered && consume {
+        state.consumed = true;
+        if let Some(last) = sequence.steps().last() {
+            ctx.input_mut(|i| i.consume_shortcut(last));
+        }
+    }
+    ctx.data_mut(|d| d.insert_temp(id, state));
+    triggered
+}
+
+impl InputBinding for ShortcutSequence {
+    fn matches(&self, ctx: &Context) -> bool {
+        update_sequence(self, ctx, false)
+    }
+
+    fn consume(&self, ctx: &Context) -> bool {
+        update_sequence(self, ctx, true)
+    }
+
+    fn display(&self) -> String {
+        self.steps()
+            .iter()
+            .map(|s| s.display())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn as_keyboard_shortcut(&self) -> Option<KeyboardShortcut> {
+        match self.steps() {
+            [single] => Some(*single),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -445,6 +814,35 @@ mod tests {
         Undo,
         Redo,
         Copy,
+        GoHome,
+    }
+
+    /// Run one frame at `time` with the given keys pressed
+    fn press_keys(
+        ctx: &Context,
+        bindings: &ActionBindings<TestAction>,
+        time: f64,
+        keys: &[Key],
+    ) -> Option<TestAction> {
+        let input = egui::RawInput {
+            time: Some(time),
+            events: keys
+                .iter()
+                .map(|&key| Event::Key {
+                    key,
+                    physical_key: None,
+                    pressed: true,
+                    repeat: false,
+                    modifiers: Modifiers::NONE,
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let mut triggered = None;
+        let _ = ctx.run(input, |ctx| {
+            triggered = bindings.check_triggered(ctx).cloned();
+        });
+        triggered
     }
 
     #[test]
@@ -571,6 +969,204 @@ mod tests {
         assert_eq!(conflicts.len(), 1);
     }
 
+    #[test]
+    fn test_conflicts_lists_shared_shortcut() {
+        let mut bindings = ActionBindings::new()
+            .with_default(
+                TestAction::Copy,
+                DynamicShortcut::new(Modifiers::COMMAND, Key::C),
+            )
+            .with_default(
+                TestAction::Redo,
+                DynamicShortcut::new(Modifiers::COMMAND, Key::Y),
+            );
+        assert!(bindings.conflicts().is_empty());
+
+        bindings.rebind(
+            &TestAction::Redo,
+            DynamicShortcut::new(Modifiers::COMMAND, Key::C),
+        );
+        let conflicts = bindings.conflicts();
+        assert_eq!(conflicts.len(), 1);
+        let (a, b) = &conflicts[0];
+        assert!(
+            (*a == TestAction::Copy && *b == TestAction::Redo)
+                || (*a == TestAction::Redo && *b == TestAction::Copy)
+        );
+    }
+
+    #[test]
+    fn test_try_rebind_refuses_taken_shortcut() {
+        let save = DynamicShortcut::new(Modifiers::COMMAND, Key::S);
+        let undo = DynamicShortcut::new(Modifiers::COMMAND, Key::Z);
+        let mut bindings = ActionBindings::new()
+            .with_default(TestAction::Save, save.clone())
+            .with_default(TestAction::Undo, undo.clone());
+
+        assert_eq!(
+            bindings.try_rebind(&TestAction::Undo, save.clone()),
+            Err(TestAction::Save)
+        );
+        assert_eq!(bindings.get(&TestAction::Undo), Some(&undo));
+        assert!(bindings.conflicts().is_empty());
+
+        // Rebinding an action to its own shortcut is not a conflict
+        assert_eq!(
+            bindings.try_rebind(&TestAction::Save, save.clone()),
+            Ok(Some(save))
+        );
+    }
+
+    #[test]
+    fn test_shortcut_string_form() {
+        let shortcut = DynamicShortcut::new(Modifiers::CTRL.plus(Modifiers::SHIFT), Key::S);
+        assert_eq!(shortcut.to_string(), "Ctrl+Shift+S");
+        assert_eq!("Ctrl+Shift+S".parse(), Ok(shortcut));
+        assert_eq!(
+            "ctrl + shift + S".parse::<DynamicShortcut>().unwrap().key,
+            Key::S
+        );
+
+        let zoom = DynamicShortcut::new(Modifiers::COMMAND, Key::Plus);
+        assert_eq!(zoom.to_string(), "Cmd+Plus");
+        assert_eq!("Cmd+Plus".parse(), Ok(zoom));
+
+        assert_eq!("F1".parse(), Ok(DynamicShortcut::key_only(Key::F1)));
+        assert!("Hyper+S".parse::<DynamicShortcut>().is_err());
+        assert!("Ctrl+".parse::<DynamicShortcut>().is_err());
+    }
+
+    #[test]
+    fn test_config_round_trip() {
+        let defaults = ActionBindings::new()
+            .with_default(
+                TestAction::Save,
+                DynamicShortcut::new(Modifiers::COMMAND, Key::S),
+            )
+            .with_default(
+                TestAction::Undo,
+                DynamicShortcut::new(Modifiers::COMMAND, Key::Z),
+            );
+        let custom = DynamicShortcut::new(Modifiers::CTRL.plus(Modifiers::SHIFT), Key::S);
+
+        let mut bindings = defaults.clone();
+        bindings.rebind(&TestAction::Save, custom.clone());
+        let config = bindings.to_config();
+        assert_eq!(config.bindings.len(), 1);
+        assert_eq!(config.bindings["Save"], "Ctrl+Shift+S");
+
+        bindings.reset_all();
+        assert!(!bindings.is_modified(&TestAction::Save));
+
+        bindings.apply_config(&config).unwrap();
+        assert_eq!(bindings.get(&TestAction::Save), Some(&custom));
+        assert!(!bindings.is_modified(&TestAction::Undo));
+    }
+
+    #[test]
+    fn test_apply_config_rejects_invalid_shortcut() {
+        let mut bindings = ActionBindings::new().with_default(
+            TestAction::Save,
+            DynamicShortcut::new(Modifiers::COMMAND, Key::S),
+        );
+        let mut config = BindingsConfig::default();
+        config.bindings.insert("Save".into(), "Ctrl+Nope".into());
+        config.bindings.insert("Removed".into(), "Ctrl+R".into());
+
+        assert!(bindings.apply_config(&config).is_err());
+        assert!(!bindings.is_modified(&TestAction::Save));
+    }
+
+    /// Run one frame showing a capture button and return its rect and result
+    fn run_capture(
+        ctx: &Context,
+        modifiers: Modifiers,
+        events: Vec<Event>,
+    ) -> (egui::Rect, Option<DynamicShortcut>) {
+        let input = egui::RawInput {
+            modifiers,
+            events,
+            ..Default::default()
+        };
+        let mut rect = egui::Rect::NOTHING;
+        let mut captured = None;
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                captured = capture_shortcut(ui);
+                rect = ui.min_rect();
+            });
+        });
+        (rect, captured)
+    }
+
+    fn start_recording(ctx: &Context) {
+        let (rect, _) = run_capture(ctx, Modifiers::NONE, vec![]);
+        let pos = rect.left_top() + egui::vec2(8.0, 8.0);
+        let button = |pressed| Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+        run_capture(ctx, Modifiers::NONE, vec![Event::PointerMoved(pos)]);
+        run_capture(ctx, Modifiers::NONE, vec![button(true)]);
+        run_capture(ctx, Modifiers::NONE, vec![button(false)]);
+    }
+
+    fn key_press(key: Key, modifiers: Modifiers) -> Event {
+        Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers,
+        }
+    }
+
+    #[test]
+    fn test_capture_shortcut_records_combo() {
+        let ctx = Context::default();
+        start_recording(&ctx);
+
+        let (_, captured) = run_capture(
+            &ctx,
+            Modifiers::CTRL,
+            vec![key_press(Key::S, Modifiers::CTRL)],
+        );
+        assert_eq!(
+            captured,
+            Some(DynamicShortcut::new(Modifiers::CTRL, Key::S))
+        );
+
+        // Recording stops after the first combo
+        let (_, captured) = run_capture(
+            &ctx,
+            Modifiers::NONE,
+            vec![key_press(Key::A, Modifiers::NONE)],
+        );
+        assert_eq!(captured, None);
+    }
+
+    #[test]
+    fn test_capture_shortcut_ignores_lone_modifier() {
+        let ctx = Context::default();
+        start_recording(&ctx);
+
+        let (_, captured) = run_capture(&ctx, Modifiers::CTRL, vec![]);
+        assert_eq!(captured, None);
+
+        // Still recording: the next real key is captured
+        let (_, captured) = run_capture(
+            &ctx,
+            Modifiers::CTRL,
+            vec![key_press(Key::K, Modifiers::CTRL)],
+        );
+        assert_eq!(
+            captured,
+            Some(DynamicShortcut::new(Modifiers::CTRL, Key::K))
+        );
+    }
+
     #[test]
     fn test_shortcut_group() {
         let group = ShortcutGroup::new()
@@ -586,4 +1182,38 @@ mod tests {
         );
         assert!(display.contains("Z"), "Expected key Z in: {}", display);
     }
+
+    #[test]
+    fn test_sequence_triggers_within_timeout() {
+        let ctx = Context::default();
+        let bindings = ActionBindings::new()
+            .with_sequence(TestAction::GoHome, ShortcutSequence::keys([Key::G, Key::H]));
+
+        assert_eq!(press_keys(&ctx, &bindings, 0.0, &[Key::G]), None);
+        assert_eq!(press_keys(&ctx, &bindings, 0.1, &[]), None);
+        assert_eq!(
+            press_keys(&ctx, &bindings, 0.5, &[Key::H]),
+            Some(TestAction::GoHome)
+        );
+
+        // Completing resets the buffer
+        assert_eq!(press_keys(&ctx, &bindings, 0.6, &[Key::H]), None);
+    }
+
+    #[test]
+    fn test_sequence_expires_after_timeout() {
+        let ctx = Context::default();
+        let bindings = ActionBindings::new()
+            .with_sequence(TestAction::GoHome, ShortcutSequence::keys([Key::G, Key::H]));
+
+        assert_eq!(press_keys(&ctx, &bindings, 0.0, &[Key::G]), None);
+        assert_eq!(press_keys(&ctx, &bindings, 2.0, &[Key::H]), None);
+    }
+
+    #[test]
+    fn test_sequence_display() {
+        let seq = ShortcutSequence::keys([Key::G, Key::H]);
+        assert_eq!(seq.display(), "G H");
+        assert_eq!(seq.as_keyboard_shortcut(), None);
+    }
 }
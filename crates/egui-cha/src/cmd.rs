@@ -1,7 +1,11 @@
 //! Command type for side effects (async tasks, HTTP, timers, etc.)
 
+use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
+use std::time::Duration;
+
+use crate::helpers::{Clock, SystemClock};
 
 /// A command representing a side effect to be executed
 ///
@@ -19,6 +23,34 @@ pub enum Cmd<Msg> {
 
     /// Emit a message immediately (next frame)
     Msg(Msg),
+
+    /// Emit a message after a delay (timed by the runtime)
+    Delay {
+        /// Time to wait before emitting
+        delay: Duration,
+        /// Message to emit
+        msg: Msg,
+    },
+
+    /// Emit a message once `delay` has passed without another debounce for `key`
+    Debounce {
+        /// Timer key; a new debounce with the same key resets the timer
+        key: String,
+        /// Quiet period before the message fires
+        delay: Duration,
+        /// Message to emit
+        msg: Msg,
+    },
+
+    /// Emit a message unless one was already emitted for `key` within `interval`
+    Throttle {
+        /// Timer key shared by throttles that limit each other
+        key: String,
+        /// Minimum time between emissions
+        interval: Duration,
+        /// Message to emit
+        msg: Msg,
+    },
 }
 
 impl<Msg> Cmd<Msg> {
@@ -53,6 +85,54 @@ impl<Msg> Cmd<Msg> {
         Cmd::Msg(msg)
     }
 
+    /// Create a delayed message (timer)
+    ///
+    /// The runtime fires the message once `duration` has passed, so delays
+    /// also follow the virtual clock of [`Harness`](crate::testing::Harness).
+    pub fn delay(duration: Duration, msg: Msg) -> Self {
+        Cmd::Delay {
+            delay: duration,
+            msg,
+        }
+    }
+
+    /// Emit `msg` after `delay`, restarting the timer on every call with the same key
+    ///
+    /// Only the last of a burst of calls fires. For state held in the model,
+    /// see [`Debouncer`](crate::helpers::Debouncer).
+    ///
+    /// # Example
+    /// ```ignore
+    /// Msg::QueryChanged(q) => {
+    ///     model.query = q;
+    ///     Cmd::debounce("search", Duration::from_millis(300), Msg::Search)
+    /// }
+    /// ```
+    pub fn debounce(key: impl Into<String>, delay: Duration, msg: Msg) -> Self {
+        Cmd::Debounce {
+            key: key.into(),
+            delay,
+            msg,
+        }
+    }
+
+    /// Emit `msg` now unless the same key emitted within the last `interval`
+    ///
+    /// Calls inside the interval are dropped. For state held in the model,
+    /// see [`Throttler`](crate::helpers::Throttler).
+    ///
+    /// # Example
+    /// ```ignore
+    /// Msg::Scrolled(pos) => Cmd::throttle("scroll", Duration::from_millis(100), Msg::LoadMore)
+    /// ```
+    pub fn throttle(key: impl Into<String>, interval: Duration, msg: Msg) -> Self {
+        Cmd::Throttle {
+            key: key.into(),
+            interval,
+            msg,
+        }
+    }
+
     /// Map the message type
     pub fn map<F, NewMsg>(self, f: F) -> Cmd<NewMsg>
     where
@@ -68,6 +148,17 @@ impl<Msg> Cmd<Msg> {
                 Cmd::Task(Box::pin(async move { f(fut.await) }))
             }
             Cmd::Msg(msg) => Cmd::Msg(f(msg)),
+            Cmd::Delay { delay, msg } => Cmd::Delay { delay, msg: f(msg) },
+            Cmd::Debounce { key, delay, msg } => Cmd::Debounce {
+                key,
+                delay,
+                msg: f(msg),
+            },
+            Cmd::Throttle { key, interval, msg } => Cmd::Throttle {
+                key,
+                interval,
+                msg: f(msg),
+            },
         }
     }
 }
@@ -117,6 +208,9 @@ impl<Msg> Cmd<Msg> {
             Cmd::None => panic!("called unwrap_msg on Cmd::None"),
             Cmd::Task(_) => panic!("called unwrap_msg on Cmd::Task"),
             Cmd::Batch(_) => panic!("called unwrap_msg on Cmd::Batch"),
+            Cmd::Delay { .. } => panic!("called unwrap_msg on Cmd::Delay"),
+            Cmd::Debounce { .. } => panic!("called unwrap_msg on Cmd::Debounce"),
+            Cmd::Throttle { .. } => panic!("called unwrap_msg on Cmd::Throttle"),
         }
     }
 
@@ -198,12 +292,49 @@ impl<Msg: std::fmt::Debug> Cmd<Msg> {
             Cmd::Task(_) => "Cmd::Task",
             Cmd::Msg(_) => "Cmd::Msg",
             Cmd::Batch(_) => "Cmd::Batch",
+            Cmd::Delay { .. } => "Cmd::Delay",
+            Cmd::Debounce { .. } => "Cmd::Debounce",
+            Cmd::Throttle { .. } => "Cmd::Throttle",
         }
     }
 }
 
 // Utility functions for common patterns
 impl<Msg: Send + 'static> Cmd<Msg> {
+    /// Run a future and map its output to a message
+    ///
+    /// # Example
+    /// ```ignore
+    /// Cmd::perform(load_thumbnail(path), Msg::ThumbnailLoaded)
+    /// ```
+    pub fn perform<F, T>(future: F, f: impl FnOnce(T) -> Msg + Send + 'static) -> Self
+    where
+        F: Future<Output = T> + Send + 'static,
+    {
+        Cmd::task(async move { f(future.await) })
+    }
+
+    /// Run a future returning `Result` and map each outcome to a message
+    ///
+    /// Same as [`try_task`](Self::try_task), named to pair with [`perform`](Self::perform).
+    ///
+    /// # Example
+    /// ```ignore
+    /// Cmd::perform_result(
+    ///     api::save(doc),
+    ///     |_| Msg::Saved,
+    ///     |err| Msg::SaveFailed(err.to_string()),
+    /// )
+    /// ```
+    pub fn perform_result<F, T, E, FnOk, FnErr>(future: F, on_ok: FnOk, on_err: FnErr) -> Self
+    where
+        F: Future<Output = Result<T, E>> + Send + 'static,
+        FnOk: FnOnce(T) -> Msg + Send + 'static,
+        FnErr: FnOnce(E) -> Msg + Send + 'static,
+    {
+        Self::try_task(future, on_ok, on_err)
+    }
+
     /// Create a command from an async task that returns Result
     ///
     /// # Example
@@ -253,19 +384,6 @@ impl<Msg: Send + 'static> Cmd<Msg> {
 // Tokio-dependent utility functions
 #[cfg(feature = "tokio")]
 impl<Msg: Send + 'static> Cmd<Msg> {
-    /// Create a delayed message (timer)
-    ///
-    /// Requires the `tokio` feature.
-    pub fn delay(duration: std::time::Duration, msg: Msg) -> Self
-    where
-        Msg: Clone,
-    {
-        Cmd::task(async move {
-            tokio::time::sleep(duration).await;
-            msg
-        })
-    }
-
     /// Create an async task with a timeout
     ///
     /// If the task completes before the timeout, `on_ok` is called with the result.
@@ -451,3 +569,204 @@ impl<Msg: Send + 'static> Cmd<Msg> {
         })
     }
 }
+
+// ============================================================
+// Keyed timers
+// ============================================================
+
+/// Timers backing `Cmd::Delay`, `Cmd::Debounce` and `Cmd::Throttle`
+///
+/// The runtime feeds timed commands in and polls for due messages every frame.
+#[cfg_attr(not(feature = "eframe"), allow(dead_code))]
+pub(crate) struct CmdTimers<Msg, C: Clock = SystemClock> {
+    clock: C,
+    /// Pending delays: (deadline, message)
+    delays: Vec<(Duration, Msg)>,
+    /// Pending debounces: key -> (deadline, message)
+    debounces: HashMap<String, (Duration, Msg)>,
+    /// End of the current throttle window per key; dropped once passed
+    throttles: HashMap<String, Duration>,
+}
+
+#[cfg_attr(not(feature = "eframe"), allow(dead_code))]
+impl<Msg> CmdTimers<Msg> {
+    pub(crate) fn new() -> Self {
+        Self::with_clock(SystemClock::new())
+    }
+}
+
+#[cfg_attr(not(feature = "eframe"), allow(dead_code))]
+impl<Msg, C: Clock> CmdTimers<Msg, C> {
+    pub(crate) fn with_clock(clock: C) -> Self {
+        Self {
+            clock,
+            delays: Vec::new(),
+            debounces: HashMap::new(),
+            throttles: HashMap::new(),
+        }
+    }
+
+    /// Schedule `msg` after `delay`
+    pub(crate) fn delay(&mut self, delay: Duration, msg: Msg) {
+        let deadline = self.clock.now() + delay;
+        self.delays.push((deadline, msg));
+    }
+
+    /// (Re)start the debounce timer for `key`, replacing any pending message
+    pub(crate) fn debounce(&mut self, key: String, delay: Duration, msg: Msg) {
+        let deadline = self.clock.now() + delay;
+        self.debounces.insert(key, (deadline, msg));
+    }
+
+    /// Return `msg` if `key` is not inside the window of its last emission
+    pub(crate) fn throttle(&mut self, key: String, interval: Duration, msg: Msg) -> Option<Msg> {
+        let now = self.clock.now();
+        match self.throttles.get(&key) {
+            Some(until) if now < *until => None,
+            _ => {
+                self.throttles.insert(key, now + interval);
+                Some(msg)
+            }
+        }
+    }
+
+    /// Collect delayed and debounced messages whose delay has passed
+    ///
+    /// Also forgets throttle keys whose window has ended.
+    pub(crate) fn poll(&mut self) -> Vec<Msg> {
+        let now = self.clock.now();
+        self.throttles.retain(|_, until| now < *until);
+
+        let (due, pending) = std::mem::take(&mut self.delays)
+            .into_iter()
+            .partition::<Vec<_>, _>(|(deadline, _)| *deadline <= now);
+        self.delays = pending;
+        let mut msgs: Vec<Msg> = due.into_iter().map(|(_, msg)| msg).collect();
+
+        let due: Vec<String> = self
+            .debounces
+            .iter()
+            .filter(|(_, (deadline, _))| *deadline <= now)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        msgs.extend(
+            due.into_iter()
+                .filter_map(|key| self.debounces.remove(&key).map(|(_, msg)| msg)),
+        );
+        msgs
+    }
+
+    /// Time until the next delay or debounce fires, if any are pending
+    pub(crate) fn next_deadline(&self) -> Option<Duration> {
+        let now = self.clock.now();
+        self.delays
+            .iter()
+            .chain(self.debounces.values())
+            .map(|(deadline, _)| deadline.saturating_sub(now))
+            .min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::FakeClock;
+
+    #[test]
+    fn test_debounce_fires_once_after_burst() {
+        let clock = FakeClock::new();
+        let mut timers = CmdTimers::with_clock(clock.clone());
+        let delay = Duration::from_millis(100);
+
+        let mut fired = Vec::new();
+        for _ in 0..3 {
+            timers.debounce("search".into(), delay, "Search");
+            clock.advance(Duration::from_millis(30));
+            fired.extend(timers.poll());
+        }
+        assert!(fired.is_empty());
+        assert_eq!(timers.next_deadline(), Some(Duration::from_millis(70)));
+
+        clock.advance(Duration::from_millis(70));
+        fired.extend(timers.poll());
+        clock.advance(Duration::from_millis(500));
+        fired.extend(timers.poll());
+
+        assert_eq!(fired, vec!["Search"]);
+        assert_eq!(timers.next_deadline(), None);
+    }
+
+    #[test]
+    fn test_debounce_keys_are_independent() {
+        let clock = FakeClock::new();
+        let mut timers = CmdTimers::with_clock(clock.clone());
+
+        timers.debounce("a".into(), Duration::from_millis(50), 1);
+        timers.debounce("b".into(), Duration::from_millis(100), 2);
+
+        clock.advance(Duration::from_millis(50));
+        assert_eq!(timers.poll(), vec![1]);
+        clock.advance(Duration::from_millis(50));
+        assert_eq!(timers.poll(), vec![2]);
+    }
+
+    #[test]
+    fn test_delay_fires_after_duration() {
+        let clock = FakeClock::new();
+        let mut timers = CmdTimers::with_clock(clock.clone());
+
+        timers.delay(Duration::from_millis(200), 1);
+        timers.delay(Duration::from_millis(100), 2);
+        assert_eq!(timers.next_deadline(), Some(Duration::from_millis(100)));
+
+        clock.advance(Duration::from_millis(100));
+        assert_eq!(timers.poll(), vec![2]);
+        clock.advance(Duration::from_millis(100));
+        assert_eq!(timers.poll(), vec![1]);
+        assert!(timers.poll().is_empty());
+    }
+
+    #[test]
+    fn test_throttle_drops_within_interval() {
+        let clock = FakeClock::new();
+        let mut timers = CmdTimers::with_clock(clock.clone());
+        let interval = Duration::from_millis(100);
+
+        assert_eq!(timers.throttle("scroll".into(), interval, 1), Some(1));
+        clock.advance(Duration::from_millis(50));
+        assert_eq!(timers.throttle("scroll".into(), interval, 2), None);
+        assert_eq!(timers.throttle("other".into(), interval, 3), Some(3));
+        clock.advance(Duration::from_millis(50));
+        assert_eq!(timers.throttle("scroll".into(), interval, 4), Some(4));
+    }
+
+    #[test]
+    fn test_throttle_keys_expire() {
+        let clock = FakeClock::new();
+        let mut timers = CmdTimers::with_clock(clock.clone());
+
+        for i in 0..10 {
+            timers.throttle(format!("key-{i}"), Duration::from_millis(100), i);
+        }
+        timers.poll();
+        assert_eq!(timers.throttles.len(), 10);
+
+        clock.advance(Duration::from_millis(100));
+        timers.poll();
+        assert!(timers.throttles.is_empty());
+    }
+
+    #[test]
+    fn test_map_keeps_key() {
+        let cmd = Cmd::debounce("k", Duration::from_millis(10), 2).map(|n| n * 10);
+        match cmd {
+            Cmd::Debounce { key, delay, msg } => {
+                assert_eq!(key, "k");
+                assert_eq!(delay, Duration::from_millis(10));
+                assert_eq!(msg, 20);
+            }
+            _ => panic!("expected Cmd::Debounce"),
+        }
+    }
+}
@@ -38,6 +38,30 @@ use crate::{Cmd, ViewCtx};
 ///     }
 /// }
 /// ```
+///
+/// # Composing into a parent
+///
+/// The parent keeps the child's state in its model, wraps the child's
+/// messages in one of its own variants, and lifts them with
+/// [`ViewCtx::mount`]. In `update`, the wrapped message is handed back to the
+/// child and its `Cmd` is mapped into the parent's message type.
+///
+/// ```ignore
+/// enum AppMsg {
+///     Counter(CounterMsg),
+///     // ...
+/// }
+///
+/// fn update(model: &mut Model, msg: AppMsg) -> Cmd<AppMsg> {
+///     match msg {
+///         AppMsg::Counter(msg) => Counter::update(&mut model.counter, msg).map(AppMsg::Counter),
+///     }
+/// }
+///
+/// fn view(model: &Model, ctx: &mut ViewCtx<AppMsg>) {
+///     ctx.mount::<Counter>(&0, &model.counter, AppMsg::Counter);
+/// }
+/// ```
 pub trait Component: Sized {
     /// Props passed from parent (immutable, for display/config)
     type Props;
@@ -117,3 +141,98 @@ impl<'a, ParentMsg> ViewCtx<'a, ParentMsg> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum CounterMsg {
+        Increment,
+    }
+
+    struct Counter;
+
+    impl Component for Counter {
+        type Props = ();
+        type State = i32;
+        type Msg = CounterMsg;
+
+        fn init(_props: &()) -> i32 {
+            0
+        }
+
+        fn update(state: &mut i32, msg: CounterMsg) -> Cmd<CounterMsg> {
+            match msg {
+                CounterMsg::Increment => *state += 1,
+            }
+            Cmd::none()
+        }
+
+        fn view(_props: &(), state: &i32, ctx: &mut ViewCtx<CounterMsg>) {
+            ctx.button(format!("+ {state}"), CounterMsg::Increment);
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum ParentMsg {
+        Counter(CounterMsg),
+    }
+
+    /// Render the parent with a mounted counter, returning its messages and the counter's rect
+    fn frame(
+        ctx: &egui::Context,
+        state: &i32,
+        events: Vec<egui::Event>,
+    ) -> (Vec<ParentMsg>, egui::Rect) {
+        let mut msgs = Vec::new();
+        let mut rect = egui::Rect::NOTHING;
+        let input = egui::RawInput {
+            events,
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                rect = ui
+                    .scope(|ui| {
+                        let mut view_ctx = ViewCtx::new(ui, &mut msgs);
+                        view_ctx.mount::<Counter>(&(), state, ParentMsg::Counter);
+                    })
+                    .response
+                    .rect;
+            });
+        });
+        (msgs, rect)
+    }
+
+    #[test]
+    fn mounted_child_click_emits_mapped_parent_msg() {
+        let ctx = egui::Context::default();
+        let mut state = Counter::init(&());
+        let (msgs, rect) = frame(&ctx, &state, Vec::new());
+        assert!(msgs.is_empty());
+
+        let pos = rect.center();
+        let button = |pressed| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        };
+        let mut delivered = Vec::new();
+        for events in [
+            vec![egui::Event::PointerMoved(pos)],
+            vec![button(true)],
+            vec![button(false)],
+        ] {
+            delivered.extend(frame(&ctx, &state, events).0);
+        }
+        assert_eq!(delivered, vec![ParentMsg::Counter(CounterMsg::Increment)]);
+
+        // Parent hands the message back to the child
+        for ParentMsg::Counter(msg) in delivered {
+            Counter::update(&mut state, msg);
+        }
+        assert_eq!(state, 1);
+    }
+}
@@ -1,7 +1,25 @@
 //! Drag & Drop support for TEA pattern
 //!
 //! Provides type-safe drag and drop with message emission.
+//!
+//! # Moving items between zones
+//!
+//! Wrap the dragged data in a [`DragPayload`] naming the zone it comes from,
+//! and accept it with a [`DropZone`] of the same type:
+//!
+//! ```ignore
+//! for item in &model.todo {
+//!     ctx.drag_source(item.id, DragPayload::new("todo", item.clone()), |ctx| {
+//!         ctx.ui.label(&item.name);
+//!     });
+//! }
+//!
+//! DropZone::<Item>::new("done")
+//!     .show(ctx, |ctx| ctx.ui.label("Drop here"))
+//!     .on_drop(ctx, |d| Msg::Move { item: d.payload, from: d.source_zone, to: d.target_zone });
+//! ```
 
+use std::marker::PhantomData;
 use std::sync::Arc;
 
 use crate::ViewCtx;
@@ -77,3 +95,494 @@ impl<P, R> DropZoneResponse<P, R> {
         }
     }
 }
+
+// ============================================================
+// Typed multi-zone drag & drop
+// ============================================================
+
+/// Typed drag payload that remembers the zone it was dragged from
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragPayload<T> {
+    /// The dragged data
+    pub data: T,
+    /// ID of the zone the drag started in
+    pub source_zone: String,
+}
+
+impl<T> DragPayload<T> {
+    /// Create a payload dragged from `source_zone`
+    pub fn new(source_zone: impl Into<String>, data: T) -> Self {
+        Self {
+            data,
+            source_zone: source_zone.into(),
+        }
+    }
+}
+
+/// A [`DragPayload`] dropped onto a [`DropZone`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dropped<T> {
+    /// The dropped data
+    pub payload: T,
+    /// ID of the zone the drag started in
+    pub source_zone: String,
+    /// ID of the zone it was dropped on
+    pub target_zone: String,
+}
+
+/// Drop zone accepting [`DragPayload<T>`]
+///
+/// Payloads of any other type are refused (the zone is drawn disabled
+/// while they are dragged).
+pub struct DropZone<T> {
+    id: String,
+    frame: egui::Frame,
+    _payload: PhantomData<fn() -> T>,
+}
+
+impl<T: Clone + Send + Sync + 'static> DropZone<T> {
+    /// Create a drop zone with the given zone ID
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            frame: egui::Frame::default(),
+            _payload: PhantomData,
+        }
+    }
+
+    /// Set the frame drawn around the zone
+    pub fn frame(mut self, frame: egui::Frame) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    /// Show the zone
+    pub fn show<Msg, R>(
+        self,
+        ctx: &mut ViewCtx<'_, Msg>,
+        content: impl FnOnce(&mut ViewCtx<'_, Msg>) -> R,
+    ) -> ZoneDropResponse<T, R> {
+        let mut child_msgs = Vec::new();
+
+        let (response, payload) = ctx.ui.dnd_drop_zone::<DragPayload<T>, _>(self.frame, |ui| {
+            let mut ctx = ViewCtx::new(ui, &mut child_msgs);
+            content(&mut ctx)
+        });
+        ctx.emit_all(child_msgs);
+
+        let is_being_dragged_over = response
+            .response
+            .dnd_hover_payload::<DragPayload<T>>()
+            .is_some();
+
+        ZoneDropResponse {
+            inner: response.inner,
+            response: response.response,
+            dropped: payload.map(|payload| Dropped {
+                payload: payload.data.clone(),
+                source_zone: payload.source_zone.clone(),
+                target_zone: self.id,
+            }),
+            is_being_dragged_over,
+        }
+    }
+}
+
+/// Response from a [`DropZone`]
+pub struct ZoneDropResponse<T, R> {
+    /// The return value from the content closure
+    pub inner: R,
+    /// The egui response
+    pub response: egui::Response,
+    /// The drop, if a payload was released on this zone this frame
+    pub dropped: Option<Dropped<T>>,
+    /// Whether a compatible payload is hovering over this zone
+    pub is_being_dragged_over: bool,
+}
+
+impl<T, R> ZoneDropResponse<T, R> {
+    /// Emit a message when a payload is dropped
+    pub fn on_drop<Msg>(self, ctx: &mut ViewCtx<'_, Msg>, f: impl FnOnce(Dropped<T>) -> Msg) -> Self
+    where
+        T: Clone,
+    {
+        if let Some(dropped) = &self.dropped {
+            ctx.emit(f(dropped.clone()));
+        }
+        self
+    }
+}
+
+// ============================================================
+// Reorder within a list
+// ============================================================
+
+/// A drag-to-reorder move reported by [`reorderable_list`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reorder {
+    /// Index of the dragged item
+    pub from: usize,
+    /// Index the item ends up at once moved
+    pub to: usize,
+}
+
+impl Reorder {
+    /// Apply the move to `items`
+    pub fn apply<T>(self, items: &mut Vec<T>) {
+        if self.from < items.len() && self.to < items.len() {
+            let item = items.remove(self.from);
+            items.insert(self.to, item);
+        }
+    }
+}
+
+/// Persistent part of a [`ReorderDrag`]
+#[derive(Clone, Debug, Default)]
+struct ReorderDragState {
+    /// Index of the item being dragged
+    dragging: Option<usize>,
+    /// Insertion index in the original order (`0..=len`)
+    drop_target: Option<usize>,
+}
+
+/// Drag-to-reorder tracking for widgets that lay out their own items
+///
+/// Register every item in order with [`item`](Self::item), then call
+/// [`update`](Self::update) once per frame and draw the drop marker with
+/// [`paint_drop_marker`](Self::paint_drop_marker). [`reorderable_list`]
+/// is built on this.
+///
+/// ```ignore
+/// let mut drag = ReorderDrag::new(ui, ui.id().with("chips")).horizontal(true);
+/// for (i, chip) in chips.iter().enumerate() {
+///     let response = ui.add(chip_widget(chip).sense(egui::Sense::click_and_drag()));
+///     drag.item(response.rect, response.drag_started());
+/// }
+/// if let Some(reorder) = drag.update(ui) {
+///     ctx.emit(Msg::MoveChip(reorder));
+/// }
+/// drag.paint_drop_marker(ui.painter(), ui.visuals().selection.stroke);
+/// ```
+pub struct ReorderDrag {
+    id: egui::Id,
+    horizontal: bool,
+    state: ReorderDragState,
+    rects: Vec<egui::Rect>,
+}
+
+impl ReorderDrag {
+    /// Load the drag state stored under `id`
+    pub fn new(ui: &egui::Ui, id: egui::Id) -> Self {
+        Self {
+            id,
+            horizontal: false,
+            state: ui.ctx().data_mut(|d| d.get_temp(id).unwrap_or_default()),
+            rects: Vec::new(),
+        }
+    }
+
+    /// Items run left to right instead of top to bottom
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.horizontal = horizontal;
+        self
+    }
+
+    /// Register the next item; `drag_started` picks it up
+    pub fn item(&mut self, rect: egui::Rect, drag_started: bool) {
+        if drag_started && self.state.dragging.is_none() {
+            self.state.dragging = Some(self.rects.len());
+        }
+        self.rects.push(rect);
+    }
+
+    /// Index of the item being dragged
+    pub fn dragging(&self) -> Option<usize> {
+        self.state.dragging
+    }
+
+    /// Track the pointer and return the move once the item is released
+    ///
+    /// Dropping an item back onto its own position returns `None`.
+    /// Call after every item is registered; the state is stored here.
+    pub fn update(&mut self, ui: &egui::Ui) -> Option<Reorder> {
+        if self.state.dragging.is_some() {
+            if let Some(pos) = ui.input(|i| i.pointer.hover_pos()) {
+                self.state.drop_target = Some(self.drop_index(pos));
+            }
+            ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+        }
+
+        let mut reorder = None;
+        if !ui.input(|i| i.pointer.any_down()) {
+            if let (Some(from), Some(target)) = (self.state.dragging, self.state.drop_target) {
+                // Removing the item first shifts later insertion points up by one
+                let to = if target > from { target - 1 } else { target };
+                if to != from {
+                    reorder = Some(Reorder { from, to });
+                }
+            }
+            self.state = ReorderDragState::default();
+        }
+
+        let state = self.state.clone();
+        ui.ctx().data_mut(|d| d.insert_temp(self.id, state));
+        reorder
+    }
+
+    /// Draw a line in the gap where the dragged item would land
+    pub fn paint_drop_marker(&self, painter: &egui::Painter, stroke: egui::Stroke) {
+        let (Some(_), Some(target)) = (self.state.dragging, self.state.drop_target) else {
+            return;
+        };
+        let Some(last) = self.rects.last() else {
+            return;
+        };
+        let gap = painter.ctx().style().spacing.item_spacing * 0.5;
+        let rect = self.rects.get(target).unwrap_or(last);
+        if self.horizontal {
+            let x = match self.rects.get(target) {
+                Some(rect) => rect.left() - gap.x,
+                None => last.right() + gap.x,
+            };
+            painter.vline(x, rect.y_range(), stroke);
+        } else {
+            let y = match self.rects.get(target) {
+                Some(rect) => rect.top() - gap.y,
+                None => last.bottom() + gap.y,
+            };
+            painter.hline(rect.x_range(), y, stroke);
+        }
+    }
+
+    /// Insertion index for a pointer position over the items
+    fn drop_index(&self, pos: egui::Pos2) -> usize {
+        self.rects
+            .iter()
+            .position(|rect| {
+                if self.horizontal {
+                    pos.x < rect.center().x
+                } else {
+                    pos.y < rect.center().y
+                }
+            })
+            .unwrap_or(self.rects.len())
+    }
+}
+
+/// Vertical list whose rows can be dragged to reorder them
+///
+/// `draw_item` renders row `i`; the whole row acts as the drag handle.
+/// A drop line marks where the row will land, and the move is returned
+/// on release. Dropping a row back onto its own position returns `None`.
+///
+/// ```ignore
+/// if let Some(reorder) = drag_drop::reorderable_list(ui, model.items.len(), |ui, i| {
+///     ui.label(&model.items[i]);
+/// }) {
+///     ctx.emit(Msg::Move(reorder));
+/// }
+/// ```
+pub fn reorderable_list(
+    ui: &mut egui::Ui,
+    items_len: usize,
+    mut draw_item: impl FnMut(&mut egui::Ui, usize),
+) -> Option<Reorder> {
+    let base_id = ui.id().with("reorderable_list");
+    let mut drag = ReorderDrag::new(ui, base_id.with("drag"));
+
+    for i in 0..items_len {
+        let rect = ui.scope(|ui| draw_item(ui, i)).response.rect;
+        let response = ui.interact(rect, base_id.with(("item", i)), egui::Sense::drag());
+        drag.item(rect, response.drag_started());
+    }
+
+    let reorder = drag.update(ui);
+
+    let stroke = ui.visuals().selection.stroke;
+    if let Some(rect) = drag.dragging().and_then(|i| drag.rects.get(i)) {
+        ui.painter()
+            .rect_filled(*rect, 2.0, stroke.color.gamma_multiply(0.15));
+    }
+    drag.paint_drop_marker(ui.painter(), egui::Stroke::new(2.0, stroke.color));
+
+    reorder
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Item(u32);
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Other;
+
+    #[test]
+    fn test_payload_does_not_need_clone() {
+        struct Handle(u32);
+
+        let payload = DragPayload::new("a", Handle(3));
+        assert_eq!(payload.data.0, 3);
+        assert_eq!(payload.source_zone, "a");
+    }
+
+    #[derive(Default)]
+    struct Frame {
+        rects: Vec<egui::Rect>,
+        drops: Vec<Dropped<Item>>,
+        other_drops: Vec<Dropped<Other>>,
+    }
+
+    /// Zone "a" holds a draggable Item; "b" accepts Items, "c" accepts Other
+    fn run(ctx: &egui::Context, events: Vec<egui::Event>) -> Frame {
+        let mut out = Frame::default();
+        let input = egui::RawInput {
+            events,
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let mut msgs: Vec<()> = Vec::new();
+                let mut ctx = ViewCtx::new(ui, &mut msgs);
+                ctx.horizontal(|ctx| {
+                    let a = DropZone::<Item>::new("a").show(ctx, |ctx| {
+                        ctx.drag_source("item", DragPayload::new("a", Item(7)), |ctx| {
+                            ctx.ui.label("item 7");
+                        });
+                    });
+                    let b = DropZone::<Item>::new("b").show(ctx, |ctx| {
+                        ctx.ui.add_space(40.0);
+                    });
+                    let c = DropZone::<Other>::new("c").show(ctx, |ctx| {
+                        ctx.ui.add_space(40.0);
+                    });
+                    out.rects = vec![a.response.rect, b.response.rect, c.response.rect];
+                    out.drops.extend(a.dropped);
+                    out.drops.extend(b.dropped);
+                    out.other_drops.extend(c.dropped);
+                });
+            });
+        });
+        out
+    }
+
+    fn drag(ctx: &egui::Context, to: egui::Pos2) -> Frame {
+        let layout = run(ctx, vec![]);
+        let from = layout.rects[0].center();
+        let button = |pos, pressed| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        };
+        run(ctx, vec![egui::Event::PointerMoved(from)]);
+        run(ctx, vec![button(from, true)]);
+        run(
+            ctx,
+            vec![egui::Event::PointerMoved(from + egui::vec2(10.0, 0.0))],
+        );
+        run(ctx, vec![egui::Event::PointerMoved(to)]);
+        run(ctx, vec![button(to, false)])
+    }
+
+    #[test]
+    fn test_drop_reports_payload_and_zones() {
+        let ctx = egui::Context::default();
+        let layout = run(&ctx, vec![]);
+
+        let frame = drag(&ctx, layout.rects[1].center());
+        assert_eq!(
+            frame.drops,
+            vec![Dropped {
+                payload: Item(7),
+                source_zone: "a".to_string(),
+                target_zone: "b".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_mismatched_zone_refuses_payload() {
+        let ctx = egui::Context::default();
+        let layout = run(&ctx, vec![]);
+
+        let frame = drag(&ctx, layout.rects[2].center());
+        assert!(frame.drops.is_empty());
+        assert!(frame.other_drops.is_empty());
+    }
+
+    /// Five labelled rows; returns the row rects and the reported move
+    fn run_list(
+        ctx: &egui::Context,
+        events: Vec<egui::Event>,
+    ) -> (Vec<egui::Rect>, Option<Reorder>) {
+        let mut rects = Vec::new();
+        let mut reorder = None;
+        let input = egui::RawInput {
+            events,
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                reorder = reorderable_list(ui, 5, |ui, i| {
+                    rects.push(ui.label(format!("item {i}")).rect);
+                });
+            });
+        });
+        (rects, reorder)
+    }
+
+    fn drag_list(ctx: &egui::Context, from: egui::Pos2, to: egui::Pos2) -> Option<Reorder> {
+        let button = |pos, pressed| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        };
+        let mut reorder = None;
+        for events in [
+            vec![egui::Event::PointerMoved(from)],
+            vec![button(from, true)],
+            vec![egui::Event::PointerMoved(from + egui::vec2(10.0, 0.0))],
+            vec![egui::Event::PointerMoved(to)],
+            vec![button(to, false)],
+        ] {
+            reorder = reorder.or(run_list(ctx, events).1);
+        }
+        reorder
+    }
+
+    #[test]
+    fn test_reorder_item_above() {
+        let ctx = egui::Context::default();
+        let (rects, _) = run_list(&ctx, vec![]);
+
+        let above_item_1 = rects[1].center_top() + egui::vec2(0.0, 1.0);
+        let reorder = drag_list(&ctx, rects[3].center(), above_item_1);
+        assert_eq!(reorder, Some(Reorder { from: 3, to: 1 }));
+    }
+
+    #[test]
+    fn test_reorder_item_below() {
+        let ctx = egui::Context::default();
+        let (rects, _) = run_list(&ctx, vec![]);
+
+        let below_item_3 = rects[3].center_bottom() - egui::vec2(0.0, 1.0);
+        let reorder = drag_list(&ctx, rects[1].center(), below_item_3);
+        assert_eq!(reorder, Some(Reorder { from: 1, to: 3 }));
+
+        let mut items = vec!['a', 'b', 'c', 'd', 'e'];
+        reorder.unwrap().apply(&mut items);
+        assert_eq!(items, vec!['a', 'c', 'd', 'b', 'e']);
+    }
+
+    #[test]
+    fn test_drop_in_place_is_ignored() {
+        let ctx = egui::Context::default();
+        let (rects, _) = run_list(&ctx, vec![]);
+
+        let reorder = drag_list(&ctx, rects[2].center(), rects[2].center());
+        assert_eq!(reorder, None);
+    }
+}
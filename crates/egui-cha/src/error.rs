@@ -63,6 +63,8 @@ pub enum ErrorSource {
     Subscription,
     /// View rendering
     View,
+    /// Model update
+    Update,
 }
 
 impl std::fmt::Display for ErrorSource {
@@ -72,6 +74,7 @@ impl std::fmt::Display for ErrorSource {
             ErrorSource::Runtime => write!(f, "Runtime"),
             ErrorSource::Subscription => write!(f, "Subscription"),
             ErrorSource::View => write!(f, "View"),
+            ErrorSource::Update => write!(f, "Update"),
         }
     }
 }
@@ -144,6 +147,20 @@ impl FrameworkError {
         Self::new(severity, ErrorSource::View, message)
     }
 
+    /// Create a critical error from a caught panic payload
+    pub fn panic(source: ErrorSource, payload: Box<dyn std::any::Any + Send>) -> Self {
+        let reason = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic payload".to_string());
+        Self::new(
+            Severity::Critical,
+            source,
+            format!("{} panicked: {}", source, reason),
+        )
+    }
+
     /// Log this error to tracing
     pub fn log(&self) {
         let msg = self.format_message();
@@ -174,6 +191,15 @@ impl std::fmt::Display for FrameworkError {
 
 impl std::error::Error for FrameworkError {}
 
+/// Run `f`, turning a panic into a [`FrameworkError`] from `source`
+pub(crate) fn catch_panic<R>(
+    source: ErrorSource,
+    f: impl FnOnce() -> R,
+) -> Result<R, FrameworkError> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f))
+        .map_err(|payload| FrameworkError::panic(source, payload))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -206,4 +232,14 @@ mod tests {
             "[Command] Task failed (user_id=123)"
         );
     }
+
+    #[test]
+    fn caught_panic_becomes_critical_error() {
+        let err = catch_panic(ErrorSource::View, || panic!("bad index {}", 3)).unwrap_err();
+        assert_eq!(err.severity, Severity::Critical);
+        assert_eq!(err.source, ErrorSource::View);
+        assert_eq!(err.message, "View panicked: bad index 3");
+
+        assert_eq!(catch_panic(ErrorSource::Update, || 7).unwrap(), 7);
+    }
 }
@@ -1,7 +1,8 @@
 //! Clock abstraction for time-based helpers
 //!
 //! This module provides a `Clock` trait that abstracts time access,
-//! enabling testable time-dependent code with `FakeClock`.
+//! enabling testable time-dependent code with `FakeClock` or `MockClock`
+//! (both in the `testing` module and the `test_prelude`).
 
 use std::time::{Duration, Instant};
 
@@ -9,7 +10,7 @@ use std::time::{Duration, Instant};
 ///
 /// This trait abstracts time access, allowing for:
 /// - Normal operation with `SystemClock`
-/// - Testing with `FakeClock` (see `testing` module)
+/// - Testing with `FakeClock` or `MockClock` (see `testing` module)
 pub trait Clock: Clone {
     /// Get the current time as a duration since the clock's epoch
     fn now(&self) -> Duration;
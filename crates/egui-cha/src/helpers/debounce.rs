@@ -8,6 +8,20 @@ use super::clock::Clock;
 use crate::Cmd;
 use std::time::{Duration, Instant};
 
+/// Which edge of a burst of triggers a [`Debouncer`] fires on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DebounceMode {
+    /// Fire once input has been quiet for the delay (default)
+    #[default]
+    Trailing,
+    /// Fire on the first trigger, then stay quiet until input has
+    /// stopped for the delay
+    Leading,
+    /// Fire on the first trigger, and again after the last one if the
+    /// burst had more than one trigger
+    Both,
+}
+
 /// Debouncer - delays action until input stops
 ///
 /// # How it works
@@ -53,9 +67,21 @@ use std::time::{Duration, Instant};
 ///     }
 /// }
 /// ```
+///
+/// # Modes
+/// Use [`with_mode`](Self::with_mode) to react immediately instead:
+/// with [`DebounceMode::Leading`] the first trigger of a burst is delivered
+/// at once and the rest are dropped, with [`DebounceMode::Both`] the last
+/// trigger is delivered after the delay as well. `should_fire()` is used
+/// the same way in every mode.
 #[derive(Debug, Clone)]
 pub struct Debouncer {
+    mode: DebounceMode,
     pending_until: Option<Instant>,
+    /// End of the current burst's quiet window (leading modes)
+    window_until: Option<Instant>,
+    /// A leading-edge fire is waiting for `should_fire()`
+    leading_ready: bool,
 }
 
 impl Default for Debouncer {
@@ -68,8 +94,37 @@ impl Debouncer {
     /// Create a new debouncer
     pub fn new() -> Self {
         Self {
+            mode: DebounceMode::Trailing,
             pending_until: None,
+            window_until: None,
+            leading_ready: false,
+        }
+    }
+
+    /// Set which edge of a burst fires (builder pattern)
+    pub fn with_mode(mut self, mode: DebounceMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Current debounce mode
+    pub fn mode(&self) -> DebounceMode {
+        self.mode
+    }
+
+    /// Record a trigger at `now`, returning true if it fires on the leading edge
+    fn mark(&mut self, now: Instant, delay: Duration) -> bool {
+        let in_window = self.window_until.is_some_and(|until| now < until);
+        self.window_until = Some(now + delay);
+
+        let leading = self.mode != DebounceMode::Trailing && !in_window;
+        if leading {
+            self.leading_ready = true;
+            self.pending_until = None;
+        } else if self.mode != DebounceMode::Leading {
+            self.pending_until = Some(now + delay);
         }
+        leading
     }
 
     /// Trigger a debounced action
@@ -81,24 +136,33 @@ impl Debouncer {
     /// When the delayed message arrives in `update()`, call `should_fire()`
     /// to check if this is the latest trigger.
     ///
+    /// In the leading modes the first trigger of a burst returns `Cmd::msg`
+    /// instead, and later triggers in `Leading` mode return `Cmd::none()`.
+    ///
     /// Requires the `tokio` feature.
     #[cfg(feature = "tokio")]
     pub fn trigger<Msg>(&mut self, delay: Duration, msg: Msg) -> Cmd<Msg>
     where
         Msg: Clone + Send + 'static,
     {
-        let fire_at = Instant::now() + delay;
-        self.pending_until = Some(fire_at);
-        Cmd::delay(delay, msg)
+        if self.mark(Instant::now(), delay) {
+            Cmd::msg(msg)
+        } else if self.mode == DebounceMode::Leading {
+            Cmd::none()
+        } else {
+            Cmd::delay(delay, msg)
+        }
     }
 
     /// Mark trigger time without returning a Cmd
     ///
     /// Use this when you want to manage the delay yourself (e.g., with a different async runtime).
     /// Call `should_fire()` after the delay to check if it should fire.
-    pub fn mark_trigger(&mut self, delay: Duration) {
-        let fire_at = Instant::now() + delay;
-        self.pending_until = Some(fire_at);
+    ///
+    /// Returns `true` if this trigger fires on the leading edge, in which
+    /// case `should_fire()` returns `true` right away.
+    pub fn mark_trigger(&mut self, delay: Duration) -> bool {
+        self.mark(Instant::now(), delay)
     }
 
     /// Check if the debounced action should fire
@@ -108,6 +172,10 @@ impl Debouncer {
     ///
     /// This also clears the pending state if firing.
     pub fn should_fire(&mut self) -> bool {
+        if self.leading_ready {
+            self.leading_ready = false;
+            return true;
+        }
         match self.pending_until {
             Some(until) if Instant::now() >= until => {
                 self.pending_until = None;
@@ -120,7 +188,7 @@ impl Debouncer {
 
     /// Check if there's a pending debounce (without firing)
     pub fn is_pending(&self) -> bool {
-        self.pending_until.is_some()
+        self.pending_until.is_some() || self.leading_ready
     }
 
     /// Cancel any pending debounced action
@@ -128,13 +196,16 @@ impl Debouncer {
     /// The next delayed message will be ignored by `should_fire()`.
     pub fn cancel(&mut self) {
         self.pending_until = None;
+        self.leading_ready = false;
     }
 
     /// Reset the debouncer state
     ///
-    /// Same as `cancel()`, but semantically for cleanup.
+    /// Like `cancel()`, and also ends the current burst so the next
+    /// trigger counts as a first one.
     pub fn reset(&mut self) {
-        self.pending_until = None;
+        self.cancel();
+        self.window_until = None;
     }
 }
 
@@ -165,7 +236,10 @@ impl Debouncer {
 #[derive(Debug, Clone)]
 pub struct DebouncerWithClock<C: Clock> {
     clock: C,
+    mode: DebounceMode,
     pending_until: Option<Duration>,
+    window_until: Option<Duration>,
+    leading_ready: bool,
 }
 
 impl<C: Clock> DebouncerWithClock<C> {
@@ -173,14 +247,45 @@ impl<C: Clock> DebouncerWithClock<C> {
     pub fn new(clock: C) -> Self {
         Self {
             clock,
+            mode: DebounceMode::Trailing,
             pending_until: None,
+            window_until: None,
+            leading_ready: false,
+        }
+    }
+
+    /// Set which edge of a burst fires (builder pattern)
+    pub fn with_mode(mut self, mode: DebounceMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Current debounce mode
+    pub fn mode(&self) -> DebounceMode {
+        self.mode
+    }
+
+    /// Record a trigger now, returning true if it fires on the leading edge
+    fn mark(&mut self, delay: Duration) -> bool {
+        let now = self.clock.now();
+        let in_window = self.window_until.is_some_and(|until| now < until);
+        self.window_until = Some(now + delay);
+
+        let leading = self.mode != DebounceMode::Trailing && !in_window;
+        if leading {
+            self.leading_ready = true;
+            self.pending_until = None;
+        } else if self.mode != DebounceMode::Leading {
+            self.pending_until = Some(now + delay);
         }
+        leading
     }
 
     /// Trigger a debounced action
     ///
     /// Returns `Cmd::delay` that will deliver the message after the specified delay.
-    /// Each call resets the internal timer.
+    /// Each call resets the internal timer. See [`Debouncer::trigger`] for
+    /// the leading modes.
     ///
     /// Requires the `tokio` feature.
     #[cfg(feature = "tokio")]
@@ -188,24 +293,34 @@ impl<C: Clock> DebouncerWithClock<C> {
     where
         Msg: Clone + Send + 'static,
     {
-        let fire_at = self.clock.now() + delay;
-        self.pending_until = Some(fire_at);
-        Cmd::delay(delay, msg)
+        if self.mark(delay) {
+            Cmd::msg(msg)
+        } else if self.mode == DebounceMode::Leading {
+            Cmd::none()
+        } else {
+            Cmd::delay(delay, msg)
+        }
     }
 
     /// Mark trigger time without returning a Cmd
     ///
     /// Use this when you want to manage the delay yourself.
     /// Call `should_fire()` after the delay to check if it should fire.
-    pub fn mark_trigger(&mut self, delay: Duration) {
-        let fire_at = self.clock.now() + delay;
-        self.pending_until = Some(fire_at);
+    ///
+    /// Returns `true` if this trigger fires on the leading edge.
+    pub fn mark_trigger(&mut self, delay: Duration) -> bool {
+        self.mark(delay)
     }
 
     /// Check if the debounced action should fire
     ///
-    /// Returns `true` if enough time has passed since the last `trigger()` call.
+    /// Returns `true` if enough time has passed since the last `trigger()` call,
+    /// or once for a leading-edge trigger.
     pub fn should_fire(&mut self) -> bool {
+        if self.leading_ready {
+            self.leading_ready = false;
+            return true;
+        }
         match self.pending_until {
             Some(until) if self.clock.now() >= until => {
                 self.pending_until = None;
@@ -218,17 +333,19 @@ impl<C: Clock> DebouncerWithClock<C> {
 
     /// Check if there's a pending debounce
     pub fn is_pending(&self) -> bool {
-        self.pending_until.is_some()
+        self.pending_until.is_some() || self.leading_ready
     }
 
     /// Cancel any pending debounced action
     pub fn cancel(&mut self) {
         self.pending_until = None;
+        self.leading_ready = false;
     }
 
     /// Reset the debouncer state
     pub fn reset(&mut self) {
-        self.pending_until = None;
+        self.cancel();
+        self.window_until = None;
     }
 }
 
@@ -314,6 +431,83 @@ mod tests {
         assert!(!debouncer.is_pending());
     }
 
+    #[test]
+    fn test_debouncer_with_mock_clock() {
+        use crate::testing::MockClock;
+
+        let clock = MockClock::new();
+        let mut debouncer = DebouncerWithClock::new(clock.clone());
+
+        debouncer.mark_trigger(Duration::from_millis(300));
+        assert!(!debouncer.should_fire());
+
+        clock.advance(Duration::from_millis(299));
+        assert!(!debouncer.should_fire()); // 1ms short
+
+        // A new trigger restarts the delay from the current mock time
+        debouncer.mark_trigger(Duration::from_millis(300));
+        clock.advance(Duration::from_millis(200));
+        assert!(!debouncer.should_fire());
+
+        clock.advance(Duration::from_millis(100));
+        assert!(debouncer.should_fire());
+        assert!(!debouncer.should_fire());
+    }
+
+    #[test]
+    fn test_leading_mode_fires_first_and_suppresses_rest() {
+        use crate::testing::MockClock;
+
+        let clock = MockClock::new();
+        let mut debouncer = DebouncerWithClock::new(clock.clone()).with_mode(DebounceMode::Leading);
+        let delay = Duration::from_millis(300);
+
+        assert!(debouncer.mark_trigger(delay));
+        assert!(debouncer.should_fire()); // Immediately
+
+        // Triggers inside the window are dropped and keep extending it
+        for _ in 0..3 {
+            clock.advance(Duration::from_millis(200));
+            assert!(!debouncer.mark_trigger(delay));
+            assert!(!debouncer.should_fire());
+        }
+        clock.advance(Duration::from_secs(1));
+        assert!(!debouncer.should_fire()); // No trailing fire
+
+        // After a quiet window the next burst fires again
+        assert!(debouncer.mark_trigger(delay));
+        assert!(debouncer.should_fire());
+    }
+
+    #[test]
+    fn test_both_mode_fires_first_and_last() {
+        use crate::testing::MockClock;
+
+        let clock = MockClock::new();
+        let mut debouncer = DebouncerWithClock::new(clock.clone()).with_mode(DebounceMode::Both);
+        let delay = Duration::from_millis(300);
+
+        assert!(debouncer.mark_trigger(delay));
+        assert!(debouncer.should_fire());
+
+        clock.advance(Duration::from_millis(100));
+        assert!(!debouncer.mark_trigger(delay));
+        clock.advance(Duration::from_millis(100));
+        assert!(!debouncer.mark_trigger(delay));
+        assert!(!debouncer.should_fire());
+
+        clock.advance(delay);
+        assert!(debouncer.should_fire()); // Trailing edge of the last trigger
+        assert!(!debouncer.is_pending());
+
+        // A lone trigger fires only on the leading edge
+        clock.advance(Duration::from_secs(1));
+        assert!(debouncer.mark_trigger(delay));
+        assert!(debouncer.should_fire());
+        clock.advance(delay);
+        assert!(!debouncer.should_fire());
+    }
+
     #[test]
     fn test_debouncer_cancel_without_tokio() {
         let mut debouncer = Debouncer::new();
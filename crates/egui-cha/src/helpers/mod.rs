@@ -60,6 +60,7 @@
 //! | Pattern | Behavior | Use Case |
 //! |---------|----------|----------|
 //! | Debounce | Waits until input stops | Search input, form validation |
+//! | Debounce (`Leading`) | Fires at once, then waits for quiet | Buttons, instant feedback |
 //! | Throttle | Limits frequency | Scroll, resize, API calls |
 
 mod clock;
@@ -67,5 +68,5 @@ mod debounce;
 mod throttle;
 
 pub use clock::{Clock, SystemClock};
-pub use debounce::{Debouncer, DebouncerWithClock};
+pub use debounce::{DebounceMode, Debouncer, DebouncerWithClock};
 pub use throttle::{Throttler, ThrottlerWithClock, TrailingThrottler};
@@ -46,9 +46,17 @@ use std::time::{Duration, Instant};
 ///     }
 /// }
 /// ```
+///
+/// # Max wait
+/// With [`with_max_wait`](Self::with_max_wait), a call is never suppressed
+/// for longer than `max_wait`: once `max_wait` has passed since the first
+/// suppressed call, the next call runs even if the interval has not. This
+/// keeps things like progress bars moving under continuous input.
 #[derive(Debug, Clone)]
 pub struct Throttler {
     last_run: Option<Instant>,
+    first_suppressed: Option<Instant>,
+    max_wait: Option<Duration>,
 }
 
 impl Default for Throttler {
@@ -60,7 +68,27 @@ impl Default for Throttler {
 impl Throttler {
     /// Create a new throttler
     pub fn new() -> Self {
-        Self { last_run: None }
+        Self {
+            last_run: None,
+            first_suppressed: None,
+            max_wait: None,
+        }
+    }
+
+    /// Run a call once `max_wait` has passed since the first suppressed
+    /// call, even within the interval (builder pattern)
+    pub fn with_max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = Some(max_wait);
+        self
+    }
+
+    /// When the next call will run, or `None` if it runs right away
+    fn ready_at(&self, interval: Duration) -> Option<Instant> {
+        let ready = self.last_run? + interval;
+        match (self.first_suppressed, self.max_wait) {
+            (Some(first), Some(max_wait)) => Some(ready.min(first + max_wait)),
+            _ => Some(ready),
+        }
     }
 
     /// Run an action if not throttled
@@ -77,16 +105,14 @@ impl Throttler {
         F: FnOnce() -> Cmd<Msg>,
     {
         let now = Instant::now();
-
-        let should_run = match self.last_run {
-            None => true,
-            Some(last) => now.duration_since(last) >= interval,
-        };
+        let should_run = self.ready_at(interval).map_or(true, |at| now >= at);
 
         if should_run {
             self.last_run = Some(now);
+            self.first_suppressed = None;
             action()
         } else {
+            self.first_suppressed.get_or_insert(now);
             Cmd::none()
         }
     }
@@ -105,24 +131,17 @@ impl Throttler {
     ///
     /// Returns `true` if calling `run()` now would be throttled.
     pub fn is_throttled(&self, interval: Duration) -> bool {
-        match self.last_run {
-            None => false,
-            Some(last) => Instant::now().duration_since(last) < interval,
-        }
+        self.ready_at(interval)
+            .is_some_and(|at| Instant::now() < at)
     }
 
     /// Time remaining until throttle expires
     ///
     /// Returns `Some(duration)` if throttled, `None` if ready to run.
     pub fn time_remaining(&self, interval: Duration) -> Option<Duration> {
-        self.last_run.and_then(|last| {
-            let elapsed = Instant::now().duration_since(last);
-            if elapsed < interval {
-                Some(interval - elapsed)
-            } else {
-                None
-            }
-        })
+        self.ready_at(interval)
+            .and_then(|at| at.checked_duration_since(Instant::now()))
+            .filter(|remaining| !remaining.is_zero())
     }
 
     /// Reset the throttler state
@@ -130,6 +149,7 @@ impl Throttler {
     /// The next `run()` call will execute immediately.
     pub fn reset(&mut self) {
         self.last_run = None;
+        self.first_suppressed = None;
     }
 
     /// Force the next run to be throttled for the given duration
@@ -138,6 +158,7 @@ impl Throttler {
     /// some external event.
     pub fn suppress(&mut self) {
         self.last_run = Some(Instant::now());
+        self.first_suppressed = None;
     }
 }
 
@@ -176,6 +197,8 @@ impl Throttler {
 pub struct ThrottlerWithClock<C: Clock> {
     clock: C,
     last_run: Option<Duration>,
+    first_suppressed: Option<Duration>,
+    max_wait: Option<Duration>,
 }
 
 impl<C: Clock> ThrottlerWithClock<C> {
@@ -184,6 +207,24 @@ impl<C: Clock> ThrottlerWithClock<C> {
         Self {
             clock,
             last_run: None,
+            first_suppressed: None,
+            max_wait: None,
+        }
+    }
+
+    /// Run a call once `max_wait` has passed since the first suppressed
+    /// call (see [`Throttler::with_max_wait`])
+    pub fn with_max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = Some(max_wait);
+        self
+    }
+
+    /// When the next call will run, or `None` if it runs right away
+    fn ready_at(&self, interval: Duration) -> Option<Duration> {
+        let ready = self.last_run? + interval;
+        match (self.first_suppressed, self.max_wait) {
+            (Some(first), Some(max_wait)) => Some(ready.min(first + max_wait)),
+            _ => Some(ready),
         }
     }
 
@@ -193,16 +234,14 @@ impl<C: Clock> ThrottlerWithClock<C> {
         F: FnOnce() -> Cmd<Msg>,
     {
         let now = self.clock.now();
-
-        let should_run = match self.last_run {
-            None => true,
-            Some(last) => now >= last + interval,
-        };
+        let should_run = self.ready_at(interval).map_or(true, |at| now >= at);
 
         if should_run {
             self.last_run = Some(now);
+            self.first_suppressed = None;
             action()
         } else {
+            self.first_suppressed.get_or_insert(now);
             Cmd::none()
         }
     }
@@ -217,40 +256,37 @@ impl<C: Clock> ThrottlerWithClock<C> {
 
     /// Check if an action would be throttled
     pub fn is_throttled(&self, interval: Duration) -> bool {
-        match self.last_run {
-            None => false,
-            Some(last) => self.clock.now() < last + interval,
-        }
+        self.ready_at(interval)
+            .is_some_and(|at| self.clock.now() < at)
     }
 
     /// Time remaining until throttle expires
     pub fn time_remaining(&self, interval: Duration) -> Option<Duration> {
-        self.last_run.and_then(|last| {
-            let now = self.clock.now();
-            let expires_at = last + interval;
-            if now < expires_at {
-                Some(expires_at - now)
-            } else {
-                None
-            }
-        })
+        let now = self.clock.now();
+        self.ready_at(interval)
+            .filter(|at| now < *at)
+            .map(|at| at - now)
     }
 
     /// Reset the throttler state
     pub fn reset(&mut self) {
         self.last_run = None;
+        self.first_suppressed = None;
     }
 
     /// Force the next run to be throttled
     pub fn suppress(&mut self) {
         self.last_run = Some(self.clock.now());
+        self.first_suppressed = None;
     }
 }
 
 /// Throttler with trailing edge execution
 ///
 /// Like `Throttler`, but also fires once after the throttle period
-/// if there were any suppressed calls.
+/// if there were any suppressed calls. With
+/// [`with_max_wait`](Self::with_max_wait), that trailing edge is scheduled
+/// no later than `max_wait` after the first suppressed call.
 ///
 /// # Example
 /// ```ignore
@@ -281,6 +317,7 @@ pub struct TrailingThrottler {
     last_run: Option<Instant>,
     has_pending: bool,
     trailing_scheduled: bool,
+    max_wait: Option<Duration>,
 }
 
 impl Default for TrailingThrottler {
@@ -296,9 +333,26 @@ impl TrailingThrottler {
             last_run: None,
             has_pending: false,
             trailing_scheduled: false,
+            max_wait: None,
         }
     }
 
+    /// Fire the trailing edge at most `max_wait` after the first suppressed
+    /// call, even within the interval (builder pattern)
+    pub fn with_max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = Some(max_wait);
+        self
+    }
+
+    /// Delay until the trailing edge, from the first suppressed call at `now`
+    fn trailing_delay(&self, interval: Duration, now: Instant) -> Duration {
+        let remaining = self
+            .last_run
+            .map(|last| interval.saturating_sub(now.duration_since(last)))
+            .unwrap_or(interval);
+        self.max_wait.map_or(remaining, |max| remaining.min(max))
+    }
+
     /// Run with both immediate and trailing edge handling
     ///
     /// - If not throttled: executes immediately with `msg`
@@ -330,11 +384,7 @@ impl TrailingThrottler {
             // Schedule trailing if not already scheduled
             if !self.trailing_scheduled {
                 self.trailing_scheduled = true;
-                let remaining = self
-                    .last_run
-                    .map(|last| interval.saturating_sub(now.duration_since(last)))
-                    .unwrap_or(interval);
-                Cmd::delay(remaining, trailing_msg)
+                Cmd::delay(self.trailing_delay(interval, now), trailing_msg)
             } else {
                 Cmd::none()
             }
@@ -364,11 +414,7 @@ impl TrailingThrottler {
 
             if !self.trailing_scheduled {
                 self.trailing_scheduled = true;
-                let remaining = self
-                    .last_run
-                    .map(|last| interval.saturating_sub(now.duration_since(last)))
-                    .unwrap_or(interval);
-                Some(remaining)
+                Some(self.trailing_delay(interval, now))
             } else {
                 None
             }
@@ -470,6 +516,99 @@ mod tests {
         assert!(remaining.unwrap() <= interval);
     }
 
+    /// Call `run` every 10ms for `total`, returning the times it fired
+    fn fire_times(
+        throttler: &mut ThrottlerWithClock<crate::testing::MockClock>,
+        clock: &crate::testing::MockClock,
+        total: Duration,
+        interval: impl Fn(Duration) -> Duration,
+    ) -> Vec<Duration> {
+        let mut fired = Vec::new();
+        while clock.elapsed() <= total {
+            let now = clock.elapsed();
+            if throttler.run(interval(now), || Cmd::Msg(())).is_msg() {
+                fired.push(now);
+            }
+            clock.advance(Duration::from_millis(10));
+        }
+        fired
+    }
+
+    #[test]
+    fn test_max_wait_under_continuous_calls() {
+        use crate::testing::MockClock;
+
+        let clock = MockClock::new();
+        let max_wait = Duration::from_millis(250);
+        let mut throttler = ThrottlerWithClock::new(clock.clone()).with_max_wait(max_wait);
+
+        // Steady interval below max_wait: fires at the throttle interval
+        let fired = fire_times(&mut throttler, &clock, Duration::from_secs(1), |_| {
+            Duration::from_millis(100)
+        });
+        let gaps: Vec<_> = fired.windows(2).map(|w| w[1] - w[0]).collect();
+        assert_eq!(fired.len(), 11);
+        assert!(gaps.iter().all(|gap| *gap == Duration::from_millis(100)));
+
+        // Backing-off interval: max_wait still bounds every gap
+        throttler.reset();
+        let start = clock.elapsed();
+        let fired = fire_times(
+            &mut throttler,
+            &clock,
+            start + Duration::from_secs(3),
+            |now| (now - start) * 2,
+        );
+        assert!(fired.len() > 1);
+        let call_period = Duration::from_millis(10);
+        assert!(fired
+            .windows(2)
+            .all(|w| w[1] - w[0] <= call_period + max_wait));
+    }
+
+    #[test]
+    fn test_max_wait_fires_within_long_interval() {
+        use crate::testing::MockClock;
+
+        let clock = MockClock::new();
+        let interval = Duration::from_secs(10);
+        let max_wait = Duration::from_millis(250);
+        let mut throttler = ThrottlerWithClock::new(clock.clone()).with_max_wait(max_wait);
+
+        assert!(throttler.run(interval, || Cmd::Msg(0)).is_msg());
+
+        // Calls every 10ms, far faster than the interval
+        clock.advance(Duration::from_millis(10));
+        let first_suppressed = clock.elapsed();
+        let mut fired = None;
+        while clock.elapsed() <= first_suppressed + max_wait {
+            if throttler.run(interval, || Cmd::Msg(1)).is_msg() {
+                fired = Some(clock.elapsed());
+                break;
+            }
+            assert_eq!(
+                throttler.time_remaining(interval),
+                Some(first_suppressed + max_wait - clock.elapsed())
+            );
+            clock.advance(Duration::from_millis(10));
+        }
+
+        assert_eq!(fired, Some(first_suppressed + max_wait));
+        // The forced run restarts the interval
+        assert!(throttler.is_throttled(interval));
+        assert_eq!(throttler.time_remaining(interval), Some(interval));
+    }
+
+    #[test]
+    fn test_trailing_max_wait_caps_delay() {
+        let mut throttler = TrailingThrottler::new().with_max_wait(Duration::from_millis(100));
+        let interval = Duration::from_secs(10);
+
+        assert_eq!(throttler.mark_run(interval), None);
+        let delay = throttler.mark_run(interval).expect("trailing scheduled");
+        assert!(delay <= Duration::from_millis(100), "{delay:?}");
+    }
+
     #[test]
     #[cfg(feature = "tokio")]
     fn test_trailing_throttler_basic() {
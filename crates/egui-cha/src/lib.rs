@@ -38,7 +38,7 @@ pub use app::App;
 pub use cmd::Cmd;
 pub use component::Component;
 pub use error::{ErrorSource, FrameworkError, Severity};
-pub use router::{Router, RouterMsg};
+pub use router::{Router, RouterMsg, Transition};
 pub use scroll_area::{ScrollArea, ScrollDirection};
 pub use sub::Sub;
 pub use view_ctx::ViewCtx;
@@ -48,12 +48,20 @@ pub use runtime::{run, RepaintMode, RunConfig};
 
 /// Prelude for convenient imports
 pub mod prelude {
-    pub use crate::bindings::{ActionBindings, DynamicShortcut, InputBinding, ShortcutGroup};
-    pub use crate::drag_drop::{DragSourceResponse, DropZoneResponse};
+    pub use crate::bindings::{
+        ActionBindings, BindingsConfig, DynamicShortcut, InputBinding, ShortcutGroup,
+    };
+    pub use crate::drag_drop::{
+        DragPayload, DragSourceResponse, DropZone, DropZoneResponse, Dropped, Reorder, ReorderDrag,
+        ZoneDropResponse,
+    };
     pub use crate::error::{ErrorSource, FrameworkError, Severity};
-    pub use crate::helpers::{Debouncer, Throttler, TrailingThrottler};
-    pub use crate::router::{BackButton, NavLink, Router, RouterMsg};
+    pub use crate::helpers::{DebounceMode, Debouncer, Throttler, TrailingThrottler};
+    pub use crate::router::{BackButton, Breadcrumb, NavLink, Router, RouterMsg, Transition};
     pub use crate::shortcuts;
+    pub use crate::shortcuts::ShortcutSequence;
+    #[cfg(feature = "notify")]
+    pub use crate::sub::FileEvent;
     pub use crate::sub::Sub;
     pub use crate::{App, Cmd, Component, ScrollArea, ViewCtx};
     pub use egui;
@@ -65,6 +73,8 @@ pub mod prelude {
 
 /// Testing utilities prelude
 pub mod test_prelude {
-    pub use crate::testing::{CmdRecord, ModelAssert, TestRunner};
+    pub use crate::testing::{
+        run_headless, CmdRecord, FakeClock, Harness, MockClock, ModelAssert, TestRunner,
+    };
     pub use crate::{App, Cmd};
 }
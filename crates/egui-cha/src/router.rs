@@ -40,31 +40,151 @@
 //!     }
 //! }
 //! ```
+//!
+//! # Page transitions
+//!
+//! ```ignore
+//! let router = Router::new(Page::Home).with_transition(Transition::SlideLeft);
+//!
+//! fn view(model: &Model, ctx: &mut ViewCtx<Msg>) {
+//!     model.router.show(ctx, |ctx, page| match page {
+//!         Page::Home => home_page(model, ctx),
+//!         // ...
+//!     });
+//! }
+//! ```
+//!
+//! # Nested routers
+//!
+//! A parent page can own a child router for its own sub-pages.
+//! Child messages are wrapped in [`RouterMsg::Child`], and `Back`
+//! unwinds the child history first before bubbling up to the parent.
+//!
+//! ```ignore
+//! let router = Router::new(Page::Home)
+//!     .with_child(Page::Settings, Router::new(SettingsPage::General));
+//!
+//! // Msg::Router(RouterMsg<Page, SettingsPage>)
+//! ctx.emit(Msg::Router(RouterMsg::Child(Box::new(RouterMsg::Navigate(
+//!     SettingsPage::Audio,
+//! )))));
+//! ```
 
-use std::collections::VecDeque;
+use std::time::Duration;
 
 /// Router for managing page navigation with history
+///
+/// `C` is the page type of an optional child router (see [`Router::with_child`]).
 #[derive(Debug, Clone)]
-pub struct Router<P> {
+pub struct Router<P, C = ()> {
     current: P,
-    history: VecDeque<P>,
+    history: Vec<P>,
     forward_stack: Vec<P>,
     max_history: usize,
+    child: Option<Box<ChildRouter<P, C>>>,
+    transition: Transition,
+    transition_duration: Duration,
+    /// Incremented on every page change, used to detect a new transition
+    nav_seq: u64,
+    nav_direction: NavDirection,
+}
+
+/// A child router mounted on a parent page
+#[derive(Debug, Clone)]
+struct ChildRouter<P, C> {
+    host: P,
+    router: Router<C>,
 }
 
 /// Messages for router operations
 #[derive(Debug, Clone, PartialEq)]
-pub enum RouterMsg<P> {
+pub enum RouterMsg<P, C = ()> {
     /// Navigate to a new page
     Navigate(P),
     /// Go back in history
     Back,
     /// Go forward in history
     Forward,
+    /// Jump back to the page at `index` in [`Router::history`],
+    /// dropping later entries and forward history
+    NavigateTo(usize),
     /// Replace current page without adding to history
     Replace(P),
     /// Clear all history
     ClearHistory,
+    /// Message for the child router, applied only while its host page is current
+    Child(Box<RouterMsg<C>>),
+}
+
+/// Animation played when the router changes pages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Transition {
+    /// Switch instantly
+    #[default]
+    None,
+    /// Fade the incoming page in
+    Fade,
+    /// Incoming page enters from the right on navigate (from the left on back)
+    SlideLeft,
+    /// Incoming page enters from the left on navigate (from the right on back)
+    SlideRight,
+}
+
+/// Direction of the last page change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NavDirection {
+    /// Navigate, Forward or Replace
+    #[default]
+    Forward,
+    /// Back or jump to an earlier history entry
+    Back,
+}
+
+/// Opacity and horizontal offset of the incoming page at one point of a transition
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransitionFrame {
+    /// Opacity (0.0 - 1.0)
+    pub opacity: f32,
+    /// Horizontal offset as a fraction of the page width (-1.0 - 1.0)
+    pub offset: f32,
+}
+
+impl Transition {
+    /// Frame at progress `t` (0.0 - 1.0), eased out
+    pub fn frame(self, t: f32, direction: NavDirection) -> TransitionFrame {
+        let t = t.clamp(0.0, 1.0);
+        let eased = 1.0 - (1.0 - t).powi(3);
+        let sign = match direction {
+            NavDirection::Forward => 1.0,
+            NavDirection::Back => -1.0,
+        };
+
+        match self {
+            Transition::None => TransitionFrame {
+                opacity: 1.0,
+                offset: 0.0,
+            },
+            Transition::Fade => TransitionFrame {
+                opacity: eased,
+                offset: 0.0,
+            },
+            Transition::SlideLeft => TransitionFrame {
+                opacity: 1.0,
+                offset: sign * (1.0 - eased),
+            },
+            Transition::SlideRight => TransitionFrame {
+                opacity: 1.0,
+                offset: -sign * (1.0 - eased),
+            },
+        }
+    }
+}
+
+/// In-progress page transition kept in egui memory
+#[derive(Clone, Copy)]
+struct TransitionState {
+    seq: u64,
+    start: f64,
 }
 
 impl<P: Clone + PartialEq> Router<P> {
@@ -72,18 +192,73 @@ impl<P: Clone + PartialEq> Router<P> {
     pub fn new(initial: P) -> Self {
         Self {
             current: initial,
-            history: VecDeque::new(),
+            history: Vec::new(),
             forward_stack: Vec::new(),
             max_history: 50,
+            child: None,
+            transition: Transition::None,
+            transition_duration: Duration::from_millis(200),
+            nav_seq: 0,
+            nav_direction: NavDirection::Forward,
         }
     }
 
+    /// Mount a child router on the `host` page
+    ///
+    /// While `host` is current, `Back`/`Forward` are applied to the child
+    /// first and bubble up to this router once the child has no history left.
+    pub fn with_child<C: Clone + PartialEq>(self, host: P, child: Router<C>) -> Router<P, C> {
+        Router {
+            current: self.current,
+            history: self.history,
+            forward_stack: self.forward_stack,
+            max_history: self.max_history,
+            child: Some(Box::new(ChildRouter {
+                host,
+                router: child,
+            })),
+            transition: self.transition,
+            transition_duration: self.transition_duration,
+            nav_seq: self.nav_seq,
+            nav_direction: self.nav_direction,
+        }
+    }
+}
+
+impl<P: Clone + PartialEq, C: Clone + PartialEq> Router<P, C> {
     /// Set maximum history size
     pub fn with_max_history(mut self, max: usize) -> Self {
         self.max_history = max;
         self
     }
 
+    /// Animate page changes rendered through [`show`](Self::show)
+    pub fn with_transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// Set the transition duration (default: 200ms)
+    pub fn with_transition_duration(mut self, duration: Duration) -> Self {
+        self.transition_duration = duration;
+        self
+    }
+
+    /// Get the configured transition
+    pub fn transition(&self) -> Transition {
+        self.transition
+    }
+
+    /// Direction of the last page change
+    pub fn nav_direction(&self) -> NavDirection {
+        self.nav_direction
+    }
+
+    fn changed(&mut self, direction: NavDirection) {
+        self.nav_seq = self.nav_seq.wrapping_add(1);
+        self.nav_direction = direction;
+    }
+
     /// Get the current page
     pub fn current(&self) -> &P {
         &self.current
@@ -101,27 +276,81 @@ impl<P: Clone + PartialEq> Router<P> {
         }
 
         // Push current to history
-        self.history.push_back(self.current.clone());
+        self.history.push(self.current.clone());
         if self.history.len() > self.max_history {
-            self.history.pop_front();
+            self.history.remove(0);
         }
 
         // Clear forward stack on new navigation
         self.forward_stack.clear();
 
         self.current = page;
+        self.changed(NavDirection::Forward);
+    }
+
+    /// Jump back to the page at `index` in [`history`](Self::history)
+    ///
+    /// Later history entries and the forward stack are discarded.
+    /// Returns false if `index` is out of range.
+    pub fn navigate_to(&mut self, index: usize) -> bool {
+        if index >= self.history.len() {
+            return false;
+        }
+        self.current = self.history[index].clone();
+        self.history.truncate(index);
+        self.forward_stack.clear();
+        self.changed(NavDirection::Back);
+        true
     }
 
     /// Replace current page without affecting history
     pub fn replace(&mut self, page: P) {
-        self.current = page;
+        if self.current != page {
+            self.current = page;
+            self.changed(NavDirection::Forward);
+        }
+    }
+
+    /// Get the child router, if one is mounted
+    pub fn child(&self) -> Option<&Router<C>> {
+        self.child.as_ref().map(|c| &c.router)
+    }
+
+    /// Get the child router mutably, if one is mounted
+    pub fn child_mut(&mut self) -> Option<&mut Router<C>> {
+        self.child.as_mut().map(|c| &mut c.router)
+    }
+
+    /// Get the child router if its host page is current
+    pub fn active_child(&self) -> Option<&Router<C>> {
+        self.child
+            .as_ref()
+            .filter(|c| c.host == self.current)
+            .map(|c| &c.router)
+    }
+
+    fn active_child_mut(&mut self) -> Option<&mut Router<C>> {
+        let current = &self.current;
+        self.child
+            .as_mut()
+            .filter(|c| &c.host == current)
+            .map(|c| &mut c.router)
     }
 
     /// Go back in history
+    ///
+    /// An active child router is unwound first.
     pub fn back(&mut self) -> bool {
-        if let Some(prev) = self.history.pop_back() {
+        if let Some(child) = self.active_child_mut() {
+            if child.back() {
+                return true;
+            }
+        }
+
+        if let Some(prev) = self.history.pop() {
             self.forward_stack.push(self.current.clone());
             self.current = prev;
+            self.changed(NavDirection::Back);
             true
         } else {
             false
@@ -129,24 +358,33 @@ impl<P: Clone + PartialEq> Router<P> {
     }
 
     /// Go forward in history
+    ///
+    /// An active child router is moved forward first.
     pub fn forward(&mut self) -> bool {
+        if let Some(child) = self.active_child_mut() {
+            if child.forward() {
+                return true;
+            }
+        }
+
         if let Some(next) = self.forward_stack.pop() {
-            self.history.push_back(self.current.clone());
+            self.history.push(self.current.clone());
             self.current = next;
+            self.changed(NavDirection::Forward);
             true
         } else {
             false
         }
     }
 
-    /// Check if can go back
+    /// Check if can go back (in this router or its active child)
     pub fn can_back(&self) -> bool {
-        !self.history.is_empty()
+        !self.history.is_empty() || self.active_child().is_some_and(|c| c.can_back())
     }
 
-    /// Check if can go forward
+    /// Check if can go forward (in this router or its active child)
     pub fn can_forward(&self) -> bool {
-        !self.forward_stack.is_empty()
+        !self.forward_stack.is_empty() || self.active_child().is_some_and(|c| c.can_forward())
     }
 
     /// Get history length
@@ -154,6 +392,11 @@ impl<P: Clone + PartialEq> Router<P> {
         self.history.len()
     }
 
+    /// Pages visited before the current one (oldest first)
+    pub fn history(&self) -> &[P] {
+        &self.history
+    }
+
     /// Clear all history
     pub fn clear_history(&mut self) {
         self.history.clear();
@@ -161,9 +404,12 @@ impl<P: Clone + PartialEq> Router<P> {
     }
 
     /// Handle a router message
-    pub fn handle(&mut self, msg: RouterMsg<P>) {
+    pub fn handle(&mut self, msg: RouterMsg<P, C>) {
         match msg {
             RouterMsg::Navigate(page) => self.navigate(page),
+            RouterMsg::NavigateTo(index) => {
+                self.navigate_to(index);
+            }
             RouterMsg::Back => {
                 self.back();
             }
@@ -172,6 +418,11 @@ impl<P: Clone + PartialEq> Router<P> {
             }
             RouterMsg::Replace(page) => self.replace(page),
             RouterMsg::ClearHistory => self.clear_history(),
+            RouterMsg::Child(msg) => {
+                if let Some(child) = self.active_child_mut() {
+                    child.handle(*msg);
+                }
+            }
         }
     }
 }
@@ -188,19 +439,79 @@ impl<P: Default + Clone + PartialEq> Default for Router<P> {
 
 use crate::ViewCtx;
 
+impl<P: Clone + PartialEq, C: Clone + PartialEq> Router<P, C> {
+    /// Render the current page, animating page changes with the configured [`Transition`]
+    ///
+    /// Progress is driven by egui's input time; repaints are requested until
+    /// the transition finishes. Only the incoming page is drawn.
+    pub fn show<Msg>(
+        &self,
+        ctx: &mut ViewCtx<'_, Msg>,
+        render: impl FnOnce(&mut ViewCtx<'_, Msg>, &P),
+    ) {
+        if self.transition == Transition::None {
+            render(ctx, &self.current);
+            return;
+        }
+
+        let id = ctx.ui.id().with("egui_cha_router_transition");
+        let egui_ctx = ctx.ui.ctx().clone();
+        let now = egui_ctx.input(|i| i.time);
+        let duration = self.transition_duration.as_secs_f64();
+
+        let state = match egui_ctx.data(|d| d.get_temp::<TransitionState>(id)) {
+            Some(state) if state.seq == self.nav_seq => state,
+            Some(_) => TransitionState {
+                seq: self.nav_seq,
+                start: now,
+            },
+            // First frame: show the page as-is
+            None => TransitionState {
+                seq: self.nav_seq,
+                start: now - duration,
+            },
+        };
+        egui_ctx.data_mut(|d| d.insert_temp(id, state));
+
+        let t = if duration <= 0.0 {
+            1.0
+        } else {
+            ((now - state.start) / duration).clamp(0.0, 1.0) as f32
+        };
+        if t >= 1.0 {
+            render(ctx, &self.current);
+            return;
+        }
+        egui_ctx.request_repaint();
+
+        let frame = self.transition.frame(t, self.nav_direction);
+        let clip = ctx.ui.available_rect_before_wrap();
+        let rect = clip.translate(egui::vec2(frame.offset * clip.width(), 0.0));
+
+        let mut child_msgs = Vec::new();
+        ctx.ui
+            .scope_builder(egui::UiBuilder::new().max_rect(rect), |ui| {
+                ui.set_clip_rect(ui.clip_rect().intersect(clip));
+                ui.multiply_opacity(frame.opacity);
+                render(&mut ViewCtx::new(ui, &mut child_msgs), &self.current);
+            });
+        ctx.emit_all(child_msgs);
+    }
+}
+
 impl<'a, Msg> ViewCtx<'a, Msg> {
     /// Navigate to a page (convenience method)
-    pub fn navigate<P>(&mut self, page: P, to_msg: impl FnOnce(RouterMsg<P>) -> Msg) {
+    pub fn navigate<P, C>(&mut self, page: P, to_msg: impl FnOnce(RouterMsg<P, C>) -> Msg) {
         self.emit(to_msg(RouterMsg::Navigate(page)));
     }
 
     /// Go back in router history
-    pub fn router_back<P>(&mut self, to_msg: impl FnOnce(RouterMsg<P>) -> Msg) {
+    pub fn router_back<P, C>(&mut self, to_msg: impl FnOnce(RouterMsg<P, C>) -> Msg) {
         self.emit(to_msg(RouterMsg::Back));
     }
 
     /// Go forward in router history
-    pub fn router_forward<P>(&mut self, to_msg: impl FnOnce(RouterMsg<P>) -> Msg) {
+    pub fn router_forward<P, C>(&mut self, to_msg: impl FnOnce(RouterMsg<P, C>) -> Msg) {
         self.emit(to_msg(RouterMsg::Forward));
     }
 }
@@ -232,11 +543,11 @@ impl<'a, P: Clone + PartialEq> NavLink<'a, P> {
     }
 
     /// Show the nav link
-    pub fn show<Msg>(
+    pub fn show<C: Clone + PartialEq, Msg>(
         self,
         ctx: &mut ViewCtx<'_, Msg>,
-        router: &Router<P>,
-        to_msg: impl FnOnce(RouterMsg<P>) -> Msg,
+        router: &Router<P, C>,
+        to_msg: impl FnOnce(RouterMsg<P, C>) -> Msg,
     ) -> bool {
         let is_active = router.is_at(&self.page);
 
@@ -272,14 +583,15 @@ impl<'a> BackButton<'a> {
         self
     }
 
-    pub fn show<P, Msg>(
+    pub fn show<P, C, Msg>(
         self,
         ctx: &mut ViewCtx<'_, Msg>,
-        router: &Router<P>,
-        to_msg: impl FnOnce(RouterMsg<P>) -> Msg,
+        router: &Router<P, C>,
+        to_msg: impl FnOnce(RouterMsg<P, C>) -> Msg,
     ) -> bool
     where
         P: Clone + PartialEq,
+        C: Clone + PartialEq,
     {
         let enabled = router.can_back();
         let response = ctx.ui.add_enabled(enabled, egui::Button::new(self.label));
@@ -299,6 +611,63 @@ impl<'a> Default for BackButton<'a> {
     }
 }
 
+/// Breadcrumb trail of the router history (Home > Settings > Profile)
+///
+/// Past pages are clickable and emit [`RouterMsg::NavigateTo`];
+/// the current page is shown as plain text.
+pub struct Breadcrumb<'a> {
+    separator: &'a str,
+}
+
+impl<'a> Breadcrumb<'a> {
+    pub fn new() -> Self {
+        Self { separator: ">" }
+    }
+
+    pub fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Show the breadcrumb, returning true if a crumb was clicked
+    pub fn show<P, C, Msg>(
+        self,
+        ctx: &mut ViewCtx<'_, Msg>,
+        router: &Router<P, C>,
+        label: impl Fn(&P) -> String,
+        to_msg: impl FnOnce(RouterMsg<P, C>) -> Msg,
+    ) -> bool
+    where
+        P: Clone + PartialEq,
+        C: Clone + PartialEq,
+    {
+        let mut clicked = None;
+
+        ctx.ui.horizontal(|ui| {
+            for (index, page) in router.history().iter().enumerate() {
+                if ui.link(label(page)).clicked() {
+                    clicked = Some(index);
+                }
+                ui.weak(self.separator);
+            }
+            ui.strong(label(router.current()));
+        });
+
+        if let Some(index) = clicked {
+            ctx.emit(to_msg(RouterMsg::NavigateTo(index)));
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<'a> Default for Breadcrumb<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -370,6 +739,137 @@ mod tests {
         assert!(router.is_at(&TestPage::Home));
     }
 
+    #[test]
+    fn test_history_and_navigate_to() {
+        let mut router = Router::new(TestPage::Home);
+        router.navigate(TestPage::Settings);
+        router.navigate(TestPage::Profile(7));
+        assert_eq!(router.history(), &[TestPage::Home, TestPage::Settings]);
+
+        router.handle(RouterMsg::NavigateTo(0));
+        assert!(router.is_at(&TestPage::Home));
+        assert!(router.history().is_empty());
+        assert!(!router.can_forward());
+
+        // Out of range is ignored
+        assert!(!router.navigate_to(3));
+        assert!(router.is_at(&TestPage::Home));
+    }
+
+    #[derive(Clone, PartialEq, Debug)]
+    enum SettingsPage {
+        General,
+        Audio,
+        Video,
+    }
+
+    #[test]
+    fn test_child_router_back_unwinds_child_first() {
+        let mut router = Router::new(TestPage::Home)
+            .with_child(TestPage::Settings, Router::new(SettingsPage::General));
+
+        // Child messages are ignored while the host page is not current
+        router.handle(RouterMsg::Child(Box::new(RouterMsg::Navigate(
+            SettingsPage::Video,
+        ))));
+        assert_eq!(router.child().unwrap().current(), &SettingsPage::General);
+        assert!(router.active_child().is_none());
+
+        // parent -> child -> child
+        router.handle(RouterMsg::Navigate(TestPage::Settings));
+        router.handle(RouterMsg::Child(Box::new(RouterMsg::Navigate(
+            SettingsPage::Audio,
+        ))));
+        router.handle(RouterMsg::Child(Box::new(RouterMsg::Navigate(
+            SettingsPage::Video,
+        ))));
+        assert_eq!(
+            router.active_child().unwrap().current(),
+            &SettingsPage::Video
+        );
+
+        router.handle(RouterMsg::Back);
+        assert!(router.is_at(&TestPage::Settings));
+        assert_eq!(router.child().unwrap().current(), &SettingsPage::Audio);
+
+        router.handle(RouterMsg::Back);
+        assert!(router.is_at(&TestPage::Settings));
+        assert_eq!(router.child().unwrap().current(), &SettingsPage::General);
+
+        // Child exhausted: bubbles to the parent
+        assert!(router.can_back());
+        router.handle(RouterMsg::Back);
+        assert!(router.is_at(&TestPage::Home));
+        assert!(!router.can_back());
+
+        // Forward re-enters the host page, then replays the child
+        assert!(router.forward());
+        assert!(router.is_at(&TestPage::Settings));
+        assert!(router.forward());
+        assert_eq!(router.child().unwrap().current(), &SettingsPage::Audio);
+    }
+
+    #[test]
+    fn test_transition_frame() {
+        let mid = Transition::Fade.frame(0.5, NavDirection::Forward);
+        assert!(mid.opacity > 0.0 && mid.opacity < 1.0);
+        assert_eq!(
+            Transition::Fade.frame(1.0, NavDirection::Forward).opacity,
+            1.0
+        );
+
+        // Slide direction flips on back
+        let fwd = Transition::SlideLeft.frame(0.0, NavDirection::Forward);
+        let back = Transition::SlideLeft.frame(0.0, NavDirection::Back);
+        assert_eq!(fwd.offset, 1.0);
+        assert_eq!(back.offset, -1.0);
+        assert_eq!(
+            Transition::SlideRight
+                .frame(0.0, NavDirection::Forward)
+                .offset,
+            -1.0
+        );
+    }
+
+    #[test]
+    fn test_fade_opacity_partway() {
+        let ctx = egui::Context::default();
+        let mut router = Router::new(TestPage::Home)
+            .with_transition(Transition::Fade)
+            .with_transition_duration(Duration::from_secs(1));
+
+        let frame = |time: f64, router: &Router<TestPage>| {
+            let mut seen = None;
+            let input = egui::RawInput {
+                time: Some(time),
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let mut msgs: Vec<()> = Vec::new();
+                    let mut view_ctx = ViewCtx::new(ui, &mut msgs);
+                    router.show(&mut view_ctx, |ctx, page| {
+                        seen = Some((page.clone(), ctx.ui.opacity()));
+                    });
+                });
+            });
+            seen.unwrap()
+        };
+
+        // Initial page is shown without animating
+        assert_eq!(frame(0.0, &router), (TestPage::Home, 1.0));
+
+        router.navigate(TestPage::Settings);
+        let (page, start) = frame(1.0, &router);
+        assert_eq!(page, TestPage::Settings);
+        assert_eq!(start, 0.0);
+
+        let (_, mid) = frame(1.5, &router);
+        assert!(mid > 0.0 && mid < 1.0, "opacity {mid}");
+
+        assert_eq!(frame(2.5, &router).1, 1.0);
+    }
+
     #[test]
     fn test_handle_msg() {
         let mut router = Router::new(TestPage::Home);
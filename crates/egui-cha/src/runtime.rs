@@ -1,15 +1,14 @@
 //! Runtime - Integration with eframe
 
 use crate::{
-    error::{FrameworkError, Severity},
-    sub::Sub,
+    cmd::CmdTimers,
+    error::{catch_panic, ErrorSource, FrameworkError, Severity},
+    sub::SubDriver,
     App, Cmd, ViewCtx,
 };
-use std::collections::{HashMap, HashSet};
 use std::sync::mpsc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::runtime::Runtime as TokioRuntime;
-use tokio::task::JoinHandle;
 
 /// Repaint mode for controlling frame rate
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -23,6 +22,65 @@ pub enum RepaintMode {
     /// VSync - repaint at monitor refresh rate.
     /// Smooth rendering synced to display, uses more resources.
     VSync,
+    /// On input - repaint continuously while the user interacts or messages
+    /// are pending, then sleep once no input arrived for `idle_after`.
+    /// Timers (`Sub::interval`, `Cmd::delay`, ...) still wake it when due.
+    /// Best for battery-sensitive applications.
+    OnInput {
+        /// Time without input before continuous repainting stops
+        idle_after: Duration,
+    },
+}
+
+/// Repaint scheduled at the end of a frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RepaintRequest {
+    /// Repaint on the next frame
+    Now,
+    /// Repaint once the duration has passed
+    After(Duration),
+    /// Let egui sleep until the next input event
+    Idle,
+}
+
+/// What happened during a frame, used to decide the next repaint
+#[derive(Debug, Clone, Copy)]
+struct FrameActivity {
+    /// Messages are waiting for the next update
+    pending_msgs: bool,
+    /// Time since the last input event
+    since_input: Duration,
+    /// Time until the next subscription or command timer fires
+    next_timer: Option<Duration>,
+}
+
+impl RepaintMode {
+    fn request(self, activity: FrameActivity) -> RepaintRequest {
+        let reactive = || {
+            if activity.pending_msgs {
+                RepaintRequest::Now
+            } else if let Some(wait) = activity.next_timer {
+                RepaintRequest::After(wait)
+            } else {
+                RepaintRequest::Idle
+            }
+        };
+
+        match self {
+            RepaintMode::Reactive => reactive(),
+            RepaintMode::FixedFps(fps) => {
+                RepaintRequest::After(Duration::from_secs_f64(1.0 / fps as f64))
+            }
+            RepaintMode::VSync => RepaintRequest::Now,
+            RepaintMode::OnInput { idle_after } => {
+                if activity.since_input < idle_after {
+                    RepaintRequest::Now
+                } else {
+                    reactive()
+                }
+            }
+        }
+    }
 }
 
 impl Default for RepaintMode {
@@ -41,6 +99,10 @@ pub struct RunConfig {
     pub persistence: bool,
     /// Repaint mode
     pub repaint_mode: RepaintMode,
+    /// Catch panics in `update`/`view` and report them via `App::on_panic`
+    pub catch_panics: bool,
+    /// Draw an FPS / frame-time overlay in the top-right corner
+    pub perf_overlay: bool,
 }
 
 impl Default for RunConfig {
@@ -50,6 +112,8 @@ impl Default for RunConfig {
             initial_size: Some([800.0, 600.0]),
             persistence: false,
             repaint_mode: RepaintMode::default(),
+            catch_panics: false,
+            perf_overlay: false,
         }
     }
 }
@@ -80,6 +144,74 @@ impl RunConfig {
         self.repaint_mode = mode;
         self
     }
+
+    /// Catch panics in `update` and `view` instead of aborting the app
+    ///
+    /// Caught panics are passed to `App::on_panic` and the app keeps running.
+    pub fn with_catch_panics(mut self, catch: bool) -> Self {
+        self.catch_panics = catch;
+        self
+    }
+
+    /// Show FPS, frame time and repaint mode in a top-right overlay
+    ///
+    /// Useful when tuning [`RepaintMode`]. Nothing is drawn or measured when disabled.
+    pub fn with_perf_overlay(mut self, show: bool) -> Self {
+        self.perf_overlay = show;
+        self
+    }
+}
+
+/// Top-right panel with FPS, frame time and repaint mode
+#[derive(Debug, Default)]
+struct PerfOverlay {
+    /// Smoothed time between frames, in seconds
+    avg_dt: Option<f32>,
+}
+
+impl PerfOverlay {
+    /// Area id of the overlay
+    const ID: &'static str = "egui_cha_perf_overlay";
+
+    /// Weight of the newest frame in the moving average
+    const SMOOTHING: f32 = 0.1;
+
+    /// Draw the overlay for this frame; `cpu_ms` is the previous frame's update time
+    fn show(&mut self, ctx: &egui::Context, cpu_ms: Option<f32>, mode: RepaintMode) {
+        let dt = ctx.input(|i| i.unstable_dt);
+        let avg_dt = match self.avg_dt {
+            Some(avg) => avg + (dt - avg) * Self::SMOOTHING,
+            None => dt,
+        };
+        self.avg_dt = Some(avg_dt);
+
+        let fps = if avg_dt > 0.0 { 1.0 / avg_dt } else { 0.0 };
+        let frame = cpu_ms.map_or("-".to_string(), |ms| format!("{ms:.2} ms"));
+        egui::Area::new(egui::Id::new(Self::ID))
+            .anchor(egui::Align2::RIGHT_TOP, egui::vec2(-8.0, 8.0))
+            .order(egui::Order::Foreground)
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.label(
+                        egui::RichText::new(format!("{fps:.1} FPS"))
+                            .monospace()
+                            .strong(),
+                    );
+                    ui.label(
+                        egui::RichText::new(format!("frame {frame}"))
+                            .monospace()
+                            .small(),
+                    );
+                    ui.label(
+                        egui::RichText::new(format!("{mode:?}"))
+                            .monospace()
+                            .small()
+                            .weak(),
+                    );
+                });
+            });
+    }
 }
 
 /// Run the TEA application
@@ -91,11 +223,20 @@ pub fn run<A: App>(config: RunConfig) -> eframe::Result<()> {
     };
 
     let repaint_mode = config.repaint_mode;
+    let catch_panics = config.catch_panics;
+    let perf_overlay = config.perf_overlay;
 
     eframe::run_native(
         &config.title,
         options,
-        Box::new(move |cc| Ok(Box::new(TeaRuntime::<A>::new(cc, repaint_mode)))),
+        Box::new(move |cc| {
+            Ok(Box::new(TeaRuntime::<A>::new(
+                cc,
+                repaint_mode,
+                catch_panics,
+                perf_overlay,
+            )))
+        }),
     )
 }
 
@@ -110,15 +251,19 @@ struct TeaRuntime<A: App> {
     err_sender: mpsc::Sender<FrameworkError>,
     tokio_runtime: TokioRuntime,
     /// Active interval subscriptions
-    active_intervals: HashMap<&'static str, IntervalHandle>,
+    subs: SubDriver<A::Msg>,
+    /// Keyed debounce/throttle timers from commands
+    timers: CmdTimers<A::Msg>,
     /// Repaint mode
     repaint_mode: RepaintMode,
-}
-
-/// Handle for a running interval
-struct IntervalHandle {
-    /// Task handle for cancellation via abort()
-    handle: JoinHandle<()>,
+    /// Route panics in update/view to `App::on_panic`
+    catch_panics: bool,
+    /// Performance overlay, when enabled
+    perf_overlay: Option<PerfOverlay>,
+    /// When the last input event arrived (for `RepaintMode::OnInput`)
+    last_input: Instant,
+    /// Used to wake the UI when async tasks finish
+    egui_ctx: egui::Context,
 }
 
 /// Phosphor Icons font (embedded)
@@ -158,7 +303,12 @@ pub fn setup_icon_fonts(ctx: &egui::Context) {
 }
 
 impl<A: App> TeaRuntime<A> {
-    fn new(cc: &eframe::CreationContext<'_>, repaint_mode: RepaintMode) -> Self {
+    fn new(
+        cc: &eframe::CreationContext<'_>,
+        repaint_mode: RepaintMode,
+        catch_panics: bool,
+        perf_overlay: bool,
+    ) -> Self {
         // Set up fonts
         setup_icon_fonts(&cc.egui_ctx);
 
@@ -168,7 +318,16 @@ impl<A: App> TeaRuntime<A> {
 
         let tokio_runtime = TokioRuntime::new().expect("Failed to create tokio runtime");
 
-        let runtime = Self {
+        #[allow(unused_mut)]
+        let mut subs = SubDriver::new();
+        #[cfg(feature = "notify")]
+        {
+            // File watchers wake the UI so their messages are picked up promptly
+            let ctx = cc.egui_ctx.clone();
+            subs.set_waker(move || ctx.request_repaint());
+        }
+
+        let mut runtime = Self {
             model,
             pending_msgs: Vec::new(),
             msg_receiver,
@@ -176,8 +335,13 @@ impl<A: App> TeaRuntime<A> {
             err_receiver,
             err_sender,
             tokio_runtime,
-            active_intervals: HashMap::new(),
+            subs,
+            timers: CmdTimers::new(),
             repaint_mode,
+            catch_panics,
+            perf_overlay: perf_overlay.then(PerfOverlay::default),
+            last_input: Instant::now(),
+            egui_ctx: cc.egui_ctx.clone(),
         };
 
         // Execute initial command
@@ -186,7 +350,7 @@ impl<A: App> TeaRuntime<A> {
         runtime
     }
 
-    fn execute_cmd(&self, cmd: Cmd<A::Msg>) {
+    fn execute_cmd(&mut self, cmd: Cmd<A::Msg>) {
         match cmd {
             Cmd::None => {}
             Cmd::Batch(cmds) => {
@@ -197,6 +361,7 @@ impl<A: App> TeaRuntime<A> {
             Cmd::Task(future) => {
                 let msg_sender = self.msg_sender.clone();
                 let err_sender = self.err_sender.clone();
+                let egui_ctx = self.egui_ctx.clone();
 
                 self.tokio_runtime.spawn(async move {
                     // Catch panics in async tasks
@@ -222,11 +387,24 @@ impl<A: App> TeaRuntime<A> {
                             let _ = err_sender.send(err);
                         }
                     }
+                    // Wake the UI so the result is processed without waiting for input
+                    egui_ctx.request_repaint();
                 });
             }
             Cmd::Msg(msg) => {
                 let _ = self.msg_sender.send(msg);
             }
+            Cmd::Delay { delay, msg } => {
+                self.timers.delay(delay, msg);
+            }
+            Cmd::Debounce { key, delay, msg } => {
+                self.timers.debounce(key, delay, msg);
+            }
+            Cmd::Throttle { key, interval, msg } => {
+                if let Some(msg) = self.timers.throttle(key, interval, msg) {
+                    let _ = self.msg_sender.send(msg);
+                }
+            }
         }
     }
 
@@ -239,7 +417,12 @@ impl<A: App> TeaRuntime<A> {
         // Process all pending messages
         let msgs = std::mem::take(&mut self.pending_msgs);
         for msg in msgs {
-            let cmd = A::update(&mut self.model, msg);
+            let cmd = if self.catch_panics {
+                catch_panic(ErrorSource::Update, || A::update(&mut self.model, msg))
+                    .unwrap_or_else(|err| A::on_panic(&mut self.model, err))
+            } else {
+                A::update(&mut self.model, msg)
+            };
             self.execute_cmd(cmd);
         }
     }
@@ -251,63 +434,10 @@ impl<A: App> TeaRuntime<A> {
             self.execute_cmd(cmd);
         }
     }
-
-    /// Process subscription changes
-    fn process_subscriptions(&mut self, sub: Sub<A::Msg>) {
-        // Collect current subscription IDs
-        let mut current_ids = HashSet::new();
-        sub.collect_interval_ids(&mut current_ids);
-
-        // Stop intervals that are no longer in subscriptions
-        let to_stop: Vec<_> = self
-            .active_intervals
-            .keys()
-            .filter(|id| !current_ids.contains(*id))
-            .copied()
-            .collect();
-
-        for id in to_stop {
-            self.stop_interval(id);
-        }
-
-        // Start new intervals
-        for (id, duration, msg) in sub.intervals() {
-            if !self.active_intervals.contains_key(id) {
-                self.start_interval(id, duration, msg);
-            }
-        }
-    }
-
-    /// Start a new interval
-    fn start_interval(&mut self, id: &'static str, duration: Duration, msg: A::Msg) {
-        let sender = self.msg_sender.clone();
-
-        let handle = self.tokio_runtime.spawn(async move {
-            let mut interval = tokio::time::interval(duration);
-            // Skip first tick (fires immediately)
-            interval.tick().await;
-
-            loop {
-                interval.tick().await;
-                if sender.send(msg.clone()).is_err() {
-                    break; // Channel closed, stop interval
-                }
-            }
-        });
-
-        self.active_intervals.insert(id, IntervalHandle { handle });
-    }
-
-    /// Stop an interval by ID
-    fn stop_interval(&mut self, id: &'static str) {
-        if let Some(handle) = self.active_intervals.remove(id) {
-            handle.handle.abort();
-        }
-    }
 }
 
 impl<A: App> eframe::App for TeaRuntime<A> {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
         // Process any pending messages from commands
         self.process_pending_messages();
 
@@ -316,36 +446,151 @@ impl<A: App> eframe::App for TeaRuntime<A> {
 
         // Process subscriptions (start/stop intervals based on model state)
         let sub = A::subscriptions(&self.model);
-        self.process_subscriptions(sub);
+        self.subs.sync(&sub);
+        self.pending_msgs.extend(self.subs.poll());
+
+        // Fire delayed and debounced commands whose delay has passed
+        self.pending_msgs.extend(self.timers.poll());
 
         // Collect messages from view
         let mut view_msgs = Vec::new();
+        let mut view_panic = None;
 
         egui::CentralPanel::default().show(ctx, |ui| {
             let mut view_ctx = ViewCtx::new(ui, &mut view_msgs);
-            A::view(&self.model, &mut view_ctx);
+            if self.catch_panics {
+                view_panic =
+                    catch_panic(ErrorSource::View, || A::view(&self.model, &mut view_ctx)).err();
+            } else {
+                A::view(&self.model, &mut view_ctx);
+            }
         });
 
+        // Drop messages from a view that panicked halfway
+        if let Some(err) = view_panic {
+            view_msgs.clear();
+            let cmd = A::on_panic(&mut self.model, err);
+            self.execute_cmd(cmd);
+        }
+
         // Queue view messages for next frame
         self.pending_msgs.extend(view_msgs);
 
+        if let Some(overlay) = &mut self.perf_overlay {
+            let cpu_ms = frame.info().cpu_usage.map(|secs| secs * 1000.0);
+            overlay.show(ctx, cpu_ms, self.repaint_mode);
+        }
+
         // Handle repaint based on mode
-        match self.repaint_mode {
-            RepaintMode::Reactive => {
-                // Only repaint if there are pending messages or active intervals
-                if !self.pending_msgs.is_empty() || !self.active_intervals.is_empty() {
-                    ctx.request_repaint();
+        let had_input =
+            ctx.input(|i| !i.events.is_empty() || i.pointer.is_moving() || i.pointer.any_down());
+        if had_input {
+            self.last_input = Instant::now();
+        }
+        let next_timer = match (self.subs.next_deadline(), self.timers.next_deadline()) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        let activity = FrameActivity {
+            pending_msgs: !self.pending_msgs.is_empty(),
+            since_input: self.last_input.elapsed(),
+            next_timer,
+        };
+
+        match self.repaint_mode.request(activity) {
+            RepaintRequest::Now => ctx.request_repaint(),
+            RepaintRequest::After(wait) => ctx.request_repaint_after(wait),
+            RepaintRequest::Idle => {}
+        }
+    }
+
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        A::on_exit(&mut self.model);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ON_INPUT: RepaintMode = RepaintMode::OnInput {
+        idle_after: Duration::from_secs(2),
+    };
+
+    fn idle(next_timer: Option<Duration>) -> FrameActivity {
+        FrameActivity {
+            pending_msgs: false,
+            since_input: Duration::from_secs(10),
+            next_timer,
+        }
+    }
+
+    #[test]
+    fn test_on_input_sleeps_when_idle() {
+        assert_eq!(ON_INPUT.request(idle(None)), RepaintRequest::Idle);
+    }
+
+    #[test]
+    fn test_on_input_interval_keeps_awake() {
+        let wait = Duration::from_millis(250);
+        assert_eq!(
+            ON_INPUT.request(idle(Some(wait))),
+            RepaintRequest::After(wait)
+        );
+    }
+
+    #[test]
+    fn test_on_input_repaints_during_interaction() {
+        let recent = FrameActivity {
+            since_input: Duration::from_millis(500),
+            ..idle(None)
+        };
+        assert_eq!(ON_INPUT.request(recent), RepaintRequest::Now);
+
+        let pending = FrameActivity {
+            pending_msgs: true,
+            ..idle(None)
+        };
+        assert_eq!(ON_INPUT.request(pending), RepaintRequest::Now);
+    }
+
+    /// Run two frames, drawing the overlay if given, and report whether its layer was shown
+    fn overlay_layer_visible(mut overlay: Option<PerfOverlay>) -> bool {
+        let ctx = egui::Context::default();
+        for _ in 0..2 {
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    ui.label("app");
+                });
+                if let Some(overlay) = &mut overlay {
+                    overlay.show(ctx, Some(1.5), RepaintMode::Reactive);
                 }
-            }
-            RepaintMode::FixedFps(fps) => {
-                // Request repaint after fixed interval
-                let interval = Duration::from_secs_f64(1.0 / fps as f64);
-                ctx.request_repaint_after(interval);
-            }
-            RepaintMode::VSync => {
-                // Always request repaint for next vsync
-                ctx.request_repaint();
-            }
+            });
         }
+        let layer = egui::LayerId::new(egui::Order::Foreground, egui::Id::new(PerfOverlay::ID));
+        ctx.memory(|m| m.areas().is_visible(&layer))
+    }
+
+    #[test]
+    fn test_perf_overlay_adds_layer_only_when_enabled() {
+        assert!(overlay_layer_visible(Some(PerfOverlay::default())));
+        assert!(!overlay_layer_visible(None));
+
+        let config = RunConfig::default();
+        assert!(!config.perf_overlay);
+        assert!(config.with_perf_overlay(true).perf_overlay);
+    }
+
+    #[test]
+    fn test_other_modes() {
+        assert_eq!(
+            RepaintMode::Reactive.request(idle(None)),
+            RepaintRequest::Idle
+        );
+        assert_eq!(RepaintMode::VSync.request(idle(None)), RepaintRequest::Now);
+        assert_eq!(
+            RepaintMode::FixedFps(10).request(idle(None)),
+            RepaintRequest::After(Duration::from_millis(100))
+        );
     }
 }
@@ -13,6 +13,14 @@
 //!     ctx.on_shortcut(shortcuts::COPY, Msg::Copy);
 //! }
 //! ```
+//!
+//! # Sequences
+//!
+//! A [`ShortcutSequence`] is a chord pressed one step at a time, like
+//! "G then H". Bind it with `ActionBindings::with_sequence` or check it
+//! directly with `ctx.on_binding(&sequence, msg)`.
+
+use std::time::Duration;
 
 use egui::{Key, KeyboardShortcut, Modifiers};
 
@@ -73,6 +81,136 @@ pub const DEV_TOOLS: KeyboardShortcut =
 pub const CONSOLE: KeyboardShortcut =
     KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::ALT), Key::J);
 
+// Sequences
+
+/// Default time allowed between two steps of a [`ShortcutSequence`]
+pub const SEQUENCE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Shortcuts pressed one after another, like "G then H"
+///
+/// # Example
+/// ```ignore
+/// let go_home = ShortcutSequence::keys([Key::G, Key::H]);
+/// // Cmd+K, then Cmd+W
+/// let close_all = ShortcutSequence::new([KeyboardShortcut::new(Modifiers::COMMAND, Key::K), CLOSE]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ShortcutSequence {
+    steps: Vec<KeyboardShortcut>,
+    timeout: Duration,
+}
+
+impl ShortcutSequence {
+    /// Create a sequence from its steps
+    pub fn new(steps: impl IntoIterator<Item = KeyboardShortcut>) -> Self {
+        Self {
+            steps: steps.into_iter().collect(),
+            timeout: SEQUENCE_TIMEOUT,
+        }
+    }
+
+    /// Create a sequence of plain keys (no modifiers)
+    pub fn keys(keys: impl IntoIterator<Item = Key>) -> Self {
+        Self::new(
+            keys.into_iter()
+                .map(|key| KeyboardShortcut::new(Modifiers::NONE, key)),
+        )
+    }
+
+    /// Append a step
+    pub fn then(mut self, step: KeyboardShortcut) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Set the time allowed between two steps
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The steps in order
+    pub fn steps(&self) -> &[KeyboardShortcut] {
+        &self.steps
+    }
+
+    /// Time allowed between two steps
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+/// Tracks progress through a [`ShortcutSequence`] across frames
+///
+/// Feed every key press with [`press`](Self::press). A press that doesn't
+/// continue the sequence starts over, and so does a gap longer than the
+/// sequence timeout.
+#[derive(Clone, Debug, Default)]
+pub struct SequenceMatcher {
+    progress: usize,
+    last_press: Option<f64>,
+}
+
+impl SequenceMatcher {
+    /// Create a matcher with no steps pressed
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed a key press at `time` (seconds), returning true when it
+    /// completes `sequence`
+    pub fn press(
+        &mut self,
+        sequence: &ShortcutSequence,
+        modifiers: Modifiers,
+        key: Key,
+        time: f64,
+    ) -> bool {
+        if self
+            .last_press
+            .is_some_and(|last| time - last > sequence.timeout.as_secs_f64())
+        {
+            self.reset();
+        }
+
+        let step_matches = |step: usize| {
+            sequence
+                .steps
+                .get(step)
+                .is_some_and(|s| s.logical_key == key && modifiers.matches_logically(s.modifiers))
+        };
+        self.progress = if step_matches(self.progress) {
+            self.progress + 1
+        } else if step_matches(0) {
+            1
+        } else {
+            0
+        };
+
+        if self.progress == 0 {
+            self.reset();
+            false
+        } else if self.progress == sequence.steps.len() {
+            self.reset();
+            true
+        } else {
+            self.last_press = Some(time);
+            false
+        }
+    }
+
+    /// Whether some leading steps have been pressed
+    pub fn is_pending(&self) -> bool {
+        self.progress > 0
+    }
+
+    /// Forget any pressed steps
+    pub fn reset(&mut self) {
+        self.progress = 0;
+        self.last_press = None;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,4 +237,30 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn sequence_matcher_restarts_on_wrong_key() {
+        let seq = ShortcutSequence::keys([Key::G, Key::H]);
+        let mut matcher = SequenceMatcher::new();
+
+        assert!(!matcher.press(&seq, Modifiers::NONE, Key::G, 0.0));
+        assert!(matcher.is_pending());
+        assert!(!matcher.press(&seq, Modifiers::NONE, Key::X, 0.1));
+        assert!(!matcher.is_pending());
+
+        // "g g h" still completes: the second g restarts the sequence
+        assert!(!matcher.press(&seq, Modifiers::NONE, Key::G, 0.2));
+        assert!(!matcher.press(&seq, Modifiers::NONE, Key::G, 0.3));
+        assert!(matcher.press(&seq, Modifiers::NONE, Key::H, 0.4));
+    }
+
+    #[test]
+    fn sequence_matcher_times_out() {
+        let seq = ShortcutSequence::keys([Key::G, Key::H]).with_timeout(Duration::from_millis(500));
+        let mut matcher = SequenceMatcher::new();
+
+        assert!(!matcher.press(&seq, Modifiers::NONE, Key::G, 0.0));
+        assert!(!matcher.press(&seq, Modifiers::NONE, Key::H, 0.6));
+        assert!(!matcher.is_pending());
+    }
 }
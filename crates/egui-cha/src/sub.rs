@@ -16,10 +16,28 @@
 //!     }
 //! }
 //! ```
+//!
+//! Combine several subscriptions with [`Sub::batch`]:
+//!
+//! ```ignore
+//! fn subscriptions(model: &Model) -> Sub<Msg> {
+//!     Sub::batch([
+//!         Sub::interval("tick", Duration::from_millis(100), Msg::Tick),
+//!         Sub::interval("autosave", Duration::from_secs(30), Msg::AutoSave),
+//!     ])
+//! }
+//! ```
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::time::Duration;
 
+#[cfg(feature = "notify")]
+use std::path::PathBuf;
+#[cfg(feature = "notify")]
+use std::sync::{mpsc, Arc};
+
+use crate::helpers::{Clock, SystemClock};
+
 /// A subscription representing a continuous effect
 ///
 /// Subscriptions are declared each frame based on model state.
@@ -46,6 +64,31 @@ pub enum Sub<Msg> {
         /// Message to emit
         msg: Msg,
     },
+
+    /// File watcher (requires the `notify` feature)
+    ///
+    /// Emits a message when the watched path is created, modified or removed.
+    #[cfg(feature = "notify")]
+    WatchFile {
+        /// Unique identifier for this watcher
+        id: &'static str,
+        /// Watched file path
+        path: PathBuf,
+        /// Maps a file event to a message
+        on_event: Arc<dyn Fn(FileEvent) -> Msg + Send + Sync>,
+    },
+}
+
+/// Change reported by a [`Sub::watch_file`] subscription
+#[cfg(feature = "notify")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileEvent {
+    /// The file was created
+    Created,
+    /// The file contents or metadata changed
+    Modified,
+    /// The file was removed
+    Removed,
 }
 
 impl<Msg> Default for Sub<Msg> {
@@ -62,6 +105,10 @@ impl<Msg: Clone> Sub<Msg> {
     }
 
     /// Create a batch of subscriptions
+    ///
+    /// The runtime flattens nested batches and drives every child each frame.
+    /// Children are tracked by their ID, so adding or removing one across frames
+    /// only starts or stops that child. Duplicate IDs are ignored (first wins).
     pub fn batch(subs: impl IntoIterator<Item = Sub<Msg>>) -> Self {
         let subs: Vec<_> = subs.into_iter().collect();
         if subs.is_empty() {
@@ -97,6 +144,30 @@ impl<Msg: Clone> Sub<Msg> {
         Sub::Interval { id, duration, msg }
     }
 
+    /// Watch a file for changes (requires the `notify` feature)
+    ///
+    /// The watcher starts when the subscription appears and stops when it
+    /// disappears. Watchers with the same ID and path won't restart.
+    ///
+    /// # Example
+    /// ```ignore
+    /// fn subscriptions(model: &Model) -> Sub<Msg> {
+    ///     Sub::watch_file("config", &model.config_path, |_| Msg::ConfigChanged)
+    /// }
+    /// ```
+    #[cfg(feature = "notify")]
+    pub fn watch_file(
+        id: &'static str,
+        path: impl Into<PathBuf>,
+        on_event: impl Fn(FileEvent) -> Msg + Send + Sync + 'static,
+    ) -> Self {
+        Sub::WatchFile {
+            id,
+            path: path.into(),
+            on_event: Arc::new(on_event),
+        }
+    }
+
     /// Collect all interval IDs in this subscription tree
     pub(crate) fn collect_interval_ids(&self, ids: &mut HashSet<&'static str>) {
         match self {
@@ -109,29 +180,356 @@ impl<Msg: Clone> Sub<Msg> {
             Sub::Interval { id, .. } => {
                 ids.insert(id);
             }
+            #[cfg(feature = "notify")]
+            Sub::WatchFile { .. } => {}
         }
     }
 
-    /// Iterate over all intervals in this subscription
-    pub(crate) fn intervals(&self) -> Vec<(&'static str, Duration, Msg)> {
+    /// Iterate over all intervals in this subscription (first ID wins)
+    ///
+    /// IDs skipped as duplicates are added to `duplicates`.
+    pub(crate) fn intervals(
+        &self,
+        duplicates: &mut Vec<&'static str>,
+    ) -> Vec<(&'static str, Duration, Msg)> {
         let mut result = Vec::new();
-        self.collect_intervals(&mut result);
+        self.collect_intervals(&mut result, duplicates);
         result
     }
 
-    fn collect_intervals(&self, result: &mut Vec<(&'static str, Duration, Msg)>) {
+    fn collect_intervals(
+        &self,
+        result: &mut Vec<(&'static str, Duration, Msg)>,
+        duplicates: &mut Vec<&'static str>,
+    ) {
         match self {
             Sub::None => {}
             Sub::Batch(subs) => {
                 for sub in subs {
-                    sub.collect_intervals(result);
+                    sub.collect_intervals(result, duplicates);
                 }
             }
             Sub::Interval { id, duration, msg } => {
+                if result.iter().any(|(existing, _, _)| existing == id) {
+                    duplicates.push(id);
+                    return;
+                }
                 result.push((id, *duration, msg.clone()));
             }
+            #[cfg(feature = "notify")]
+            Sub::WatchFile { .. } => {}
+        }
+    }
+
+    /// Collect all file watchers in this subscription (first ID wins)
+    ///
+    /// IDs skipped as duplicates are added to `duplicates`.
+    #[cfg(feature = "notify")]
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn watchers(
+        &self,
+        duplicates: &mut Vec<&'static str>,
+    ) -> Vec<(
+        &'static str,
+        &PathBuf,
+        &Arc<dyn Fn(FileEvent) -> Msg + Send + Sync>,
+    )> {
+        let mut result = Vec::new();
+        self.collect_watchers(&mut result, duplicates);
+        result
+    }
+
+    #[cfg(feature = "notify")]
+    #[allow(clippy::type_complexity)]
+    fn collect_watchers<'s>(
+        &'s self,
+        result: &mut Vec<(
+            &'static str,
+            &'s PathBuf,
+            &'s Arc<dyn Fn(FileEvent) -> Msg + Send + Sync>,
+        )>,
+        duplicates: &mut Vec<&'static str>,
+    ) {
+        match self {
+            Sub::Batch(subs) => {
+                for sub in subs {
+                    sub.collect_watchers(result, duplicates);
+                }
+            }
+            Sub::WatchFile { id, path, on_event } => {
+                if result.iter().any(|(existing, _, _)| existing == id) {
+                    duplicates.push(id);
+                    return;
+                }
+                result.push((id, path, on_event));
+            }
+            Sub::None | Sub::Interval { .. } => {}
+        }
+    }
+}
+
+// ============================================================
+// Driver
+// ============================================================
+
+/// Drives interval subscriptions from a [`Clock`]
+///
+/// The runtime calls [`sync`](Self::sync) with the current `Sub` and
+/// [`poll`](Self::poll) every frame to collect due messages.
+#[cfg_attr(not(feature = "eframe"), allow(dead_code))]
+pub(crate) struct SubDriver<Msg, C: Clock = SystemClock> {
+    clock: C,
+    intervals: HashMap<&'static str, ActiveInterval<Msg>>,
+    /// Duplicate IDs already warned about, so `sync` doesn't warn every frame
+    reported_duplicates: HashSet<&'static str>,
+    #[cfg(feature = "notify")]
+    watchers: HashMap<&'static str, ActiveWatcher<Msg>>,
+    /// Watchers that failed to start, already warned about, with the time
+    /// of their next retry
+    #[cfg(feature = "notify")]
+    failed_watchers: HashMap<&'static str, Duration>,
+    #[cfg(feature = "notify")]
+    waker: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+/// How long to wait before retrying a watcher that failed to start
+#[cfg(feature = "notify")]
+const WATCH_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+struct ActiveInterval<Msg> {
+    period: Duration,
+    next: Duration,
+    msg: Msg,
+}
+
+#[cfg(feature = "notify")]
+struct ActiveWatcher<Msg> {
+    path: PathBuf,
+    on_event: Arc<dyn Fn(FileEvent) -> Msg + Send + Sync>,
+    events: mpsc::Receiver<FileEvent>,
+    /// Dropping the watcher stops it
+    _watcher: notify::RecommendedWatcher,
+}
+
+#[cfg(feature = "notify")]
+impl<Msg> ActiveWatcher<Msg> {
+    fn start(
+        path: PathBuf,
+        on_event: Arc<dyn Fn(FileEvent) -> Msg + Send + Sync>,
+        waker: Option<Arc<dyn Fn() + Send + Sync>>,
+    ) -> notify::Result<Self> {
+        use notify::{RecursiveMode, Watcher};
+
+        let (sender, events) = mpsc::channel();
+        let file_name = path.file_name().map(|n| n.to_os_string());
+
+        // Watch the parent directory so replaced or re-created files are still seen
+        let handler = move |res: notify::Result<notify::Event>| {
+            let Some(kind) = res
+                .ok()
+                .and_then(|event| file_event(&event, file_name.as_deref()))
+            else {
+                return;
+            };
+            if sender.send(kind).is_ok() {
+                if let Some(waker) = &waker {
+                    waker();
+                }
+            }
+        };
+
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        let mut watcher = notify::recommended_watcher(handler)?;
+        watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+
+        Ok(Self {
+            path,
+            on_event,
+            events,
+            _watcher: watcher,
+        })
+    }
+}
+
+/// The [`FileEvent`] a notify event means for the file named `file_name`
+#[cfg(feature = "notify")]
+fn file_event(event: &notify::Event, file_name: Option<&std::ffi::OsStr>) -> Option<FileEvent> {
+    use notify::EventKind;
+
+    if !event.paths.iter().any(|p| p.file_name() == file_name) {
+        return None;
+    }
+    match event.kind {
+        EventKind::Create(_) => Some(FileEvent::Created),
+        EventKind::Modify(_) => Some(FileEvent::Modified),
+        EventKind::Remove(_) => Some(FileEvent::Removed),
+        _ => None,
+    }
+}
+
+#[cfg_attr(not(feature = "eframe"), allow(dead_code))]
+impl<Msg: Clone> SubDriver<Msg> {
+    pub(crate) fn new() -> Self {
+        Self::with_clock(SystemClock::new())
+    }
+}
+
+#[cfg_attr(not(feature = "eframe"), allow(dead_code))]
+impl<Msg: Clone, C: Clock> SubDriver<Msg, C> {
+    pub(crate) fn with_clock(clock: C) -> Self {
+        Self {
+            clock,
+            intervals: HashMap::new(),
+            reported_duplicates: HashSet::new(),
+            #[cfg(feature = "notify")]
+            watchers: HashMap::new(),
+            #[cfg(feature = "notify")]
+            failed_watchers: HashMap::new(),
+            #[cfg(feature = "notify")]
+            waker: None,
+        }
+    }
+
+    /// Called from watcher threads when an event arrives (e.g. to request a repaint)
+    #[cfg(feature = "notify")]
+    pub(crate) fn set_waker(&mut self, waker: impl Fn() + Send + Sync + 'static) {
+        self.waker = Some(Arc::new(waker));
+    }
+
+    /// Start new intervals and stop those no longer present
+    ///
+    /// An interval whose period changed is restarted.
+    pub(crate) fn sync(&mut self, sub: &Sub<Msg>) {
+        let now = self.clock.now();
+
+        let mut ids = HashSet::new();
+        sub.collect_interval_ids(&mut ids);
+        self.intervals.retain(|id, _| ids.contains(id));
+
+        let mut duplicates = Vec::new();
+        for (id, period, msg) in sub.intervals(&mut duplicates) {
+            match self.intervals.get_mut(id) {
+                Some(active) if active.period == period => active.msg = msg,
+                _ => {
+                    self.intervals.insert(
+                        id,
+                        ActiveInterval {
+                            period,
+                            next: now + period,
+                            msg,
+                        },
+                    );
+                }
+            }
+        }
+
+        #[cfg(feature = "notify")]
+        {
+            let current = sub.watchers(&mut duplicates);
+            let is_current =
+                |id: &&'static str| current.iter().any(|(current_id, _, _)| current_id == id);
+            self.watchers.retain(|id, _| is_current(id));
+            self.failed_watchers.retain(|id, _| is_current(id));
+
+            for (id, path, on_event) in current {
+                match self.watchers.get_mut(id) {
+                    Some(active) if &active.path == path => active.on_event = on_event.clone(),
+                    _ if self.failed_watchers.get(id).is_some_and(|&at| now < at) => {}
+                    _ => {
+                        match ActiveWatcher::start(
+                            path.clone(),
+                            on_event.clone(),
+                            self.waker.clone(),
+                        ) {
+                            Ok(watcher) => {
+                                self.watchers.insert(id, watcher);
+                                self.failed_watchers.remove(id);
+                            }
+                            // Not kept, so a later sync tries again
+                            Err(err) => {
+                                self.watchers.remove(id);
+                                let retry_at = now + WATCH_RETRY_INTERVAL;
+                                if self.failed_watchers.insert(id, retry_at).is_none() {
+                                    tracing::warn!("failed to watch {}: {}", path.display(), err);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        self.report_duplicates(&duplicates);
+    }
+
+    /// Warn once about each duplicate ID for as long as it stays duplicated
+    fn report_duplicates(&mut self, duplicates: &[&'static str]) {
+        self.reported_duplicates
+            .retain(|id| duplicates.contains(id));
+        for &id in duplicates {
+            if self.reported_duplicates.insert(id) {
+                #[cfg(debug_assertions)]
+                tracing::warn!("duplicate subscription id {:?} ignored", id);
+            }
         }
     }
+
+    /// Collect messages of intervals that are due
+    ///
+    /// Each interval fires at most once per poll; missed ticks are skipped.
+    pub(crate) fn poll(&mut self) -> Vec<Msg> {
+        let now = self.clock.now();
+        let mut msgs = Vec::new();
+
+        for active in self.intervals.values_mut() {
+            if active.next <= now {
+                msgs.push(active.msg.clone());
+                if active.period.is_zero() {
+                    active.next = now;
+                } else {
+                    while active.next <= now {
+                        active.next += active.period;
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "notify")]
+        for active in self.watchers.values() {
+            msgs.extend(
+                active
+                    .events
+                    .try_iter()
+                    .map(|event| (active.on_event)(event)),
+            );
+        }
+
+        msgs
+    }
+
+    /// Time until the next interval fires, if any are active
+    pub(crate) fn next_deadline(&self) -> Option<Duration> {
+        let now = self.clock.now();
+        self.intervals
+            .values()
+            .map(|active| active.next.saturating_sub(now))
+            .min()
+    }
+
+    /// Number of active intervals
+    #[cfg(test)]
+    pub(crate) fn active_count(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Number of active file watchers
+    #[cfg(all(test, feature = "notify"))]
+    pub(crate) fn watcher_count(&self) -> usize {
+        self.watchers.len()
+    }
 }
 
 // ============================================================
@@ -163,6 +561,8 @@ impl<Msg> Sub<Msg> {
             Sub::None => 0,
             Sub::Batch(subs) => subs.iter().map(|s| s.len()).sum(),
             Sub::Interval { .. } => 1,
+            #[cfg(feature = "notify")]
+            Sub::WatchFile { .. } => 1,
         }
     }
 
@@ -175,6 +575,7 @@ impl<Msg> Sub<Msg> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::FakeClock;
 
     #[test]
     fn test_sub_none() {
@@ -227,6 +628,182 @@ mod tests {
         assert!(single.is_interval());
     }
 
+    #[test]
+    fn test_intervals_dedup() {
+        let sub = Sub::batch([
+            Sub::interval("a", Duration::from_secs(1), 10),
+            Sub::batch([
+                Sub::interval("a", Duration::from_secs(5), 99),
+                Sub::interval("b", Duration::from_secs(2), 20),
+            ]),
+        ]);
+
+        let mut duplicates = Vec::new();
+        let intervals = sub.intervals(&mut duplicates);
+        assert_eq!(
+            intervals,
+            vec![
+                ("a", Duration::from_secs(1), 10),
+                ("b", Duration::from_secs(2), 20)
+            ]
+        );
+        assert_eq!(duplicates, vec!["a"]);
+    }
+
+    #[test]
+    fn test_driver_reports_duplicate_once() {
+        let mut driver = SubDriver::with_clock(FakeClock::new());
+        let sub = Sub::batch([
+            Sub::interval("a", Duration::from_secs(1), 1),
+            Sub::interval("a", Duration::from_secs(2), 2),
+        ]);
+
+        driver.sync(&sub);
+        assert!(driver.reported_duplicates.contains("a"));
+        driver.sync(&sub);
+        assert_eq!(driver.reported_duplicates.len(), 1);
+
+        // Fixing the duplicate forgets it, so a new one is reported again
+        driver.sync(&Sub::interval("a", Duration::from_secs(1), 1));
+        assert!(driver.reported_duplicates.is_empty());
+        driver.sync(&sub);
+        assert!(driver.reported_duplicates.contains("a"));
+    }
+
+    #[test]
+    fn test_driver_batch_fires_at_each_rate() {
+        let clock = FakeClock::new();
+        let mut driver = SubDriver::with_clock(clock.clone());
+        let sub = Sub::batch([
+            Sub::interval("fast", Duration::from_millis(100), "fast"),
+            Sub::interval("slow", Duration::from_millis(250), "slow"),
+        ]);
+
+        let mut fired = Vec::new();
+        for _ in 0..100 {
+            driver.sync(&sub);
+            clock.advance(Duration::from_millis(10));
+            fired.extend(driver.poll());
+        }
+
+        // 1 second elapsed
+        assert_eq!(fired.iter().filter(|m| **m == "fast").count(), 10);
+        assert_eq!(fired.iter().filter(|m| **m == "slow").count(), 4);
+    }
+
+    #[test]
+    fn test_driver_stops_removed_intervals() {
+        let clock = FakeClock::new();
+        let mut driver = SubDriver::with_clock(clock.clone());
+
+        driver.sync(&Sub::batch([
+            Sub::interval("a", Duration::from_millis(100), 1),
+            Sub::interval("b", Duration::from_millis(100), 2),
+        ]));
+        assert_eq!(driver.active_count(), 2);
+        assert_eq!(driver.next_deadline(), Some(Duration::from_millis(100)));
+
+        driver.sync(&Sub::interval("a", Duration::from_millis(100), 1));
+        assert_eq!(driver.active_count(), 1);
+
+        clock.advance(Duration::from_millis(100));
+        assert_eq!(driver.poll(), vec![1]);
+
+        driver.sync(&Sub::none());
+        assert_eq!(driver.active_count(), 0);
+        assert_eq!(driver.next_deadline(), None);
+    }
+
+    #[cfg(feature = "notify")]
+    #[test]
+    fn test_file_event_matches_watched_file() {
+        use notify::event::{AccessKind, CreateKind, ModifyKind, RemoveKind};
+        use notify::{Event, EventKind};
+
+        let dir = PathBuf::from("/tmp/project");
+        let name = Some(std::ffi::OsStr::new("config.toml"));
+        let event = |kind, file: &str| Event::new(kind).add_path(dir.join(file));
+
+        let modify = EventKind::Modify(ModifyKind::Any);
+        assert_eq!(
+            file_event(&event(modify, "config.toml"), name),
+            Some(FileEvent::Modified)
+        );
+        assert_eq!(
+            file_event(
+                &event(EventKind::Create(CreateKind::File), "config.toml"),
+                name
+            ),
+            Some(FileEvent::Created)
+        );
+        assert_eq!(
+            file_event(
+                &event(EventKind::Remove(RemoveKind::File), "config.toml"),
+                name
+            ),
+            Some(FileEvent::Removed)
+        );
+
+        // Other files in the directory and non-changing events are ignored
+        assert_eq!(file_event(&event(modify, "other.toml"), name), None);
+        assert_eq!(
+            file_event(
+                &event(EventKind::Access(AccessKind::Any), "config.toml"),
+                name
+            ),
+            None
+        );
+    }
+
+    #[cfg(feature = "notify")]
+    #[test]
+    fn test_watch_file_starts_and_stops() {
+        let dir = std::env::temp_dir().join(format!("egui-cha-watch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+
+        let mut driver = SubDriver::with_clock(FakeClock::new());
+        driver.sync(&Sub::watch_file("config", &path, |event| event));
+        assert_eq!(driver.watcher_count(), 1);
+
+        // Dropping the subscription stops the watcher
+        driver.sync(&Sub::none());
+        assert_eq!(driver.watcher_count(), 0);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(feature = "notify")]
+    #[test]
+    fn test_failed_watch_is_retried_after_backoff() {
+        let dir = std::env::temp_dir().join(format!("egui-cha-retry-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let sub = Sub::watch_file("config", dir.join("config.toml"), |event| event);
+
+        // The directory doesn't exist yet, so the watcher can't start
+        let clock = FakeClock::new();
+        let mut driver = SubDriver::with_clock(clock.clone());
+        driver.sync(&sub);
+        assert_eq!(driver.watcher_count(), 0);
+        assert_eq!(
+            driver.failed_watchers.get("config"),
+            Some(&WATCH_RETRY_INTERVAL)
+        );
+
+        // No retry within the backoff window, even once the path exists
+        std::fs::create_dir_all(&dir).unwrap();
+        clock.advance(WATCH_RETRY_INTERVAL - Duration::from_millis(1));
+        driver.sync(&sub);
+        assert_eq!(driver.watcher_count(), 0);
+
+        clock.advance(Duration::from_millis(1));
+        driver.sync(&sub);
+        assert_eq!(driver.watcher_count(), 1);
+        assert!(driver.failed_watchers.is_empty());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn test_intervals_iterator() {
         let sub = Sub::batch([
@@ -234,7 +811,7 @@ mod tests {
             Sub::interval("b", Duration::from_secs(2), 20),
         ]);
 
-        let intervals = sub.intervals();
+        let intervals = sub.intervals(&mut Vec::new());
         assert_eq!(intervals.len(), 2);
         assert_eq!(intervals[0], ("a", Duration::from_secs(1), 10));
         assert_eq!(intervals[1], ("b", Duration::from_secs(2), 20));
@@ -16,14 +16,30 @@
 //!     assert_eq!(runner.model().count, 1);
 //! }
 //! ```
+//!
+//! To drive a whole app (init → subscriptions → view → update) on a virtual
+//! clock, use [`run_headless`]:
+//!
+//! ```ignore
+//! let harness = run_headless::<CounterApp>(|h| {
+//!     h.send(Msg::IncrementLater); // returns Cmd::delay(1s, Msg::Increment)
+//!     h.advance(Duration::from_secs(1));
+//! });
+//! assert_eq!(harness.model().count, 1);
+//! ```
 
+use crate::cmd::CmdTimers;
+use crate::error::{catch_panic, ErrorSource};
 use crate::helpers::Clock;
-use crate::{App, Cmd};
+use crate::sub::SubDriver;
+use crate::{App, Cmd, ViewCtx};
 use std::cell::Cell;
+use std::collections::VecDeque;
 use std::future::Future;
 use std::pin::Pin;
 use std::rc::Rc;
-use std::time::Duration;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+use std::time::{Duration, Instant};
 
 /// A boxed future for async tasks
 type BoxFuture<Msg> = Pin<Box<dyn Future<Output = Msg> + Send + 'static>>;
@@ -101,6 +117,86 @@ impl Clock for FakeClock {
     }
 }
 
+/// A controllable clock anchored at a real [`Instant`]
+///
+/// Like [`FakeClock`], time only moves when the test says so, but the
+/// current time can also be read and set as an `Instant`. Use it to drive
+/// [`DebouncerWithClock`](crate::helpers::DebouncerWithClock) and
+/// [`ThrottlerWithClock`](crate::helpers::ThrottlerWithClock) when the
+/// code under test also deals in `Instant`s.
+///
+/// # Example
+/// ```ignore
+/// use egui_cha::test_prelude::*;
+/// use egui_cha::helpers::DebouncerWithClock;
+/// use std::time::Duration;
+///
+/// let clock = MockClock::new();
+/// let mut debouncer = DebouncerWithClock::new(clock.clone());
+///
+/// debouncer.mark_trigger(Duration::from_millis(300));
+/// clock.advance(Duration::from_millis(299));
+/// assert!(!debouncer.should_fire());
+///
+/// clock.advance(Duration::from_millis(1));
+/// assert!(debouncer.should_fire());
+/// ```
+#[derive(Clone)]
+pub struct MockClock {
+    epoch: Instant,
+    elapsed: FakeClock,
+}
+
+impl MockClock {
+    /// Create a mock clock frozen at the current instant
+    pub fn new() -> Self {
+        Self::starting_at(Instant::now())
+    }
+
+    /// Create a mock clock frozen at `epoch`
+    pub fn starting_at(epoch: Instant) -> Self {
+        Self {
+            epoch,
+            elapsed: FakeClock::new(),
+        }
+    }
+
+    /// Advance the clock by the specified duration
+    pub fn advance(&self, duration: Duration) {
+        self.elapsed.advance(duration);
+    }
+
+    /// Set the clock to `instant`
+    ///
+    /// Instants before the clock's epoch are clamped to the epoch.
+    pub fn set(&self, instant: Instant) {
+        self.elapsed
+            .set(instant.saturating_duration_since(self.epoch));
+    }
+
+    /// The current mock instant
+    pub fn instant(&self) -> Instant {
+        self.epoch + self.elapsed.get()
+    }
+
+    /// Time elapsed since the clock's epoch
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed.get()
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Duration {
+        self.elapsed.get()
+    }
+}
+
 /// A test runner for TEA applications
 ///
 /// Provides a convenient way to test update logic without running the UI.
@@ -117,6 +213,12 @@ pub enum CmdRecord<Msg> {
     Task,
     Msg(Msg),
     Batch(usize),
+    /// Delayed message (not executed by the runner)
+    Delay(Duration),
+    /// Keyed debounce (not executed by the runner)
+    Debounce(String),
+    /// Keyed throttle (not executed by the runner)
+    Throttle(String),
 }
 
 impl<A: App> TestRunner<A> {
@@ -204,6 +306,9 @@ impl<A: App> TestRunner<A> {
             Some(CmdRecord::Task) => "Task",
             Some(CmdRecord::Msg(_)) => "Msg",
             Some(CmdRecord::Batch(_)) => "Batch",
+            Some(CmdRecord::Delay(_)) => "Delay",
+            Some(CmdRecord::Debounce(_)) => "Debounce",
+            Some(CmdRecord::Throttle(_)) => "Throttle",
             None => "<no command>",
         }
     }
@@ -224,6 +329,9 @@ impl<A: App> TestRunner<A> {
                 }
                 CmdRecord::Batch(len)
             }
+            Cmd::Delay { delay, .. } => CmdRecord::Delay(delay),
+            Cmd::Debounce { key, .. } => CmdRecord::Debounce(key),
+            Cmd::Throttle { key, .. } => CmdRecord::Throttle(key),
         };
         self.commands.push(record);
     }
@@ -231,7 +339,11 @@ impl<A: App> TestRunner<A> {
     /// Extract tasks from a command (recursively for batches)
     fn extract_tasks(&mut self, cmd: Cmd<A::Msg>) {
         match cmd {
-            Cmd::None | Cmd::Msg(_) => {}
+            Cmd::None
+            | Cmd::Msg(_)
+            | Cmd::Delay { .. }
+            | Cmd::Debounce { .. }
+            | Cmd::Throttle { .. } => {}
             Cmd::Task(future) => {
                 self.pending_tasks.push(future);
             }
@@ -522,10 +634,275 @@ impl<A: App> ModelAssert<A::Model> for TestRunner<A> {
     }
 }
 
+// ============================================
+// Headless runtime
+// ============================================
+
+/// Upper bound on update rounds in one settle, to catch message loops
+const MAX_SETTLE_ROUNDS: usize = 1000;
+
+/// Run a whole app without a window, returning the harness for assertions
+///
+/// The app is initialized via `App::init`, then `steps` drives it by sending
+/// messages and advancing the virtual clock.
+///
+/// # Example
+/// ```ignore
+/// let harness = run_headless::<CounterApp>(|h| {
+///     h.send(Msg::Increment).send(Msg::Increment);
+///     h.advance(Duration::from_millis(500));
+/// });
+/// assert_eq!(harness.model().count, 2);
+/// ```
+pub fn run_headless<A: App>(steps: impl FnOnce(&mut Harness<A>)) -> Harness<A> {
+    let mut harness = Harness::new();
+    steps(&mut harness);
+    harness
+}
+
+/// Headless runtime driving a full app loop on a [`FakeClock`]
+///
+/// Mirrors the eframe runtime: commands are executed, subscriptions are
+/// started and stopped from `App::subscriptions`, and `App::view` is rendered
+/// into an offscreen egui context each frame.
+///
+/// `Cmd::Task` futures are polled with a no-op waker, so they complete only
+/// if they don't wait on real I/O or tokio timers. `Cmd::delay`,
+/// `Cmd::debounce`, `Cmd::throttle` and `Sub::interval` follow the virtual clock.
+pub struct Harness<A: App> {
+    model: A::Model,
+    clock: FakeClock,
+    egui_ctx: egui::Context,
+    queue: VecDeque<A::Msg>,
+    tasks: Vec<BoxFuture<A::Msg>>,
+    subs: SubDriver<A::Msg, FakeClock>,
+    timers: CmdTimers<A::Msg, FakeClock>,
+    catch_panics: bool,
+    exited: bool,
+}
+
+impl<A: App> Harness<A> {
+    /// Initialize the app via `App::init` and render the first frame
+    pub fn new() -> Self {
+        let (model, init_cmd) = A::init();
+        let clock = FakeClock::new();
+        let mut harness = Self {
+            model,
+            subs: SubDriver::with_clock(clock.clone()),
+            timers: CmdTimers::with_clock(clock.clone()),
+            clock,
+            egui_ctx: egui::Context::default(),
+            queue: VecDeque::new(),
+            tasks: Vec::new(),
+            catch_panics: false,
+            exited: false,
+        };
+        harness.execute_cmd(init_cmd);
+        harness.frame();
+        harness
+    }
+
+    /// Deliver a message and process everything it triggers
+    pub fn send(&mut self, msg: A::Msg) -> &mut Self {
+        self.queue.push_back(msg);
+        self.settle();
+        self
+    }
+
+    /// Deliver several messages in order
+    pub fn send_all(&mut self, msgs: impl IntoIterator<Item = A::Msg>) -> &mut Self {
+        for msg in msgs {
+            self.send(msg);
+        }
+        self
+    }
+
+    /// Advance the virtual clock and run one frame
+    pub fn advance(&mut self, duration: Duration) -> &mut Self {
+        self.clock.advance(duration);
+        self.frame()
+    }
+
+    /// Run one frame without advancing the clock
+    ///
+    /// Fires due timers and subscriptions, renders the view and
+    /// processes the resulting messages.
+    pub fn frame(&mut self) -> &mut Self {
+        self.sync_subscriptions();
+        self.queue.extend(self.subs.poll());
+        self.queue.extend(self.timers.poll());
+        self.settle();
+
+        let mut view_msgs = Vec::new();
+        let input = egui::RawInput {
+            time: Some(self.clock.now().as_secs_f64()),
+            ..Default::default()
+        };
+        let mut view_panic = None;
+        let model = &self.model;
+        let catch_panics = self.catch_panics;
+        let _ = self.egui_ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let mut view_ctx = ViewCtx::new(ui, &mut view_msgs);
+                if catch_panics {
+                    view_panic =
+                        catch_panic(ErrorSource::View, || A::view(model, &mut view_ctx)).err();
+                } else {
+                    A::view(model, &mut view_ctx);
+                }
+            });
+        });
+        if let Some(err) = view_panic {
+            view_msgs.clear();
+            let cmd = A::on_panic(&mut self.model, err);
+            self.execute_cmd(cmd);
+        }
+        self.queue.extend(view_msgs);
+        self.settle();
+        self
+    }
+
+    /// Catch panics in `update`/`view` and route them to `App::on_panic`
+    ///
+    /// Mirrors `RunConfig::with_catch_panics`. Off by default, so panics fail the test.
+    pub fn with_catch_panics(&mut self, catch: bool) -> &mut Self {
+        self.catch_panics = catch;
+        self
+    }
+
+    /// Shut the app down like closing the window, calling `App::on_exit`
+    ///
+    /// `on_exit` runs only on the first call.
+    pub fn shutdown(&mut self) -> &mut Self {
+        if !self.exited {
+            self.exited = true;
+            A::on_exit(&mut self.model);
+        }
+        self
+    }
+
+    /// Get the current model
+    pub fn model(&self) -> &A::Model {
+        &self.model
+    }
+
+    /// Get the virtual clock
+    pub fn clock(&self) -> &FakeClock {
+        &self.clock
+    }
+
+    /// Get the offscreen egui context the view renders into
+    pub fn egui_ctx(&self) -> &egui::Context {
+        &self.egui_ctx
+    }
+
+    /// Number of tasks that have not completed yet
+    pub fn pending_task_count(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Process queued messages and ready tasks until nothing is left
+    fn settle(&mut self) {
+        for _ in 0..MAX_SETTLE_ROUNDS {
+            while let Some(msg) = self.queue.pop_front() {
+                let cmd = if self.catch_panics {
+                    catch_panic(ErrorSource::Update, || A::update(&mut self.model, msg))
+                        .unwrap_or_else(|err| A::on_panic(&mut self.model, err))
+                } else {
+                    A::update(&mut self.model, msg)
+                };
+                self.execute_cmd(cmd);
+            }
+            self.poll_tasks();
+            if self.queue.is_empty() {
+                self.sync_subscriptions();
+                return;
+            }
+        }
+        panic!(
+            "Harness: messages still queued after {} update rounds (message loop?)",
+            MAX_SETTLE_ROUNDS
+        );
+    }
+
+    fn sync_subscriptions(&mut self) {
+        let sub = A::subscriptions(&self.model);
+        self.subs.sync(&sub);
+    }
+
+    fn poll_tasks(&mut self) {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let tasks = std::mem::take(&mut self.tasks);
+        for mut task in tasks {
+            match task.as_mut().poll(&mut cx) {
+                Poll::Ready(msg) => self.queue.push_back(msg),
+                Poll::Pending => self.tasks.push(task),
+            }
+        }
+    }
+
+    fn execute_cmd(&mut self, cmd: Cmd<A::Msg>) {
+        match cmd {
+            Cmd::None => {}
+            Cmd::Batch(cmds) => {
+                for cmd in cmds {
+                    self.execute_cmd(cmd);
+                }
+            }
+            Cmd::Task(future) => self.tasks.push(future),
+            Cmd::Msg(msg) => self.queue.push_back(msg),
+            Cmd::Delay { delay, msg } => self.timers.delay(delay, msg),
+            Cmd::Debounce { key, delay, msg } => self.timers.debounce(key, delay, msg),
+            Cmd::Throttle { key, interval, msg } => {
+                if let Some(msg) = self.timers.throttle(key, interval, msg) {
+                    self.queue.push_back(msg);
+                }
+            }
+        }
+    }
+}
+
+impl<A: App> Default for Harness<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Waker that does nothing; the harness re-polls pending tasks every round
+fn noop_waker() -> Waker {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(
+        |_| RawWaker::new(std::ptr::null(), &VTABLE),
+        |_| {},
+        |_| {},
+        |_| {},
+    );
+    // SAFETY: every vtable function ignores the (null) data pointer
+    unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_mock_clock_set_and_advance() {
+        let epoch = Instant::now();
+        let clock = MockClock::starting_at(epoch);
+        assert_eq!(clock.now(), Duration::ZERO);
+
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(clock.instant(), epoch + Duration::from_millis(250));
+
+        clock.set(epoch + Duration::from_secs(2));
+        assert_eq!(clock.elapsed(), Duration::from_secs(2));
+
+        // Clones share the same time
+        let other = clock.clone();
+        other.advance(Duration::from_secs(1));
+        assert_eq!(clock.now(), Duration::from_secs(3));
+    }
+
     // Simple test app for testing the test runner
     struct TestApp;
 
@@ -543,6 +920,11 @@ mod tests {
         MultiBatch,
         AsyncFetch,
         FetchResult(i32),
+        Perform,
+        PerformResult(bool),
+        Got(i32),
+        Failed(String),
+        IncLater,
     }
 
     impl App for TestApp {
@@ -568,6 +950,27 @@ mod tests {
                     return Cmd::task(async { TestMsg::FetchResult(42) });
                 }
                 TestMsg::FetchResult(v) => model.value = v,
+                TestMsg::Perform => {
+                    return Cmd::perform(async { 7 }, TestMsg::Got);
+                }
+                TestMsg::PerformResult(ok) => {
+                    return Cmd::perform_result(
+                        async move {
+                            if ok {
+                                Ok(8)
+                            } else {
+                                Err("boom")
+                            }
+                        },
+                        TestMsg::Got,
+                        |err| TestMsg::Failed(err.to_string()),
+                    );
+                }
+                TestMsg::Got(v) => model.value = v,
+                TestMsg::Failed(_) => model.value = -1,
+                TestMsg::IncLater => {
+                    return Cmd::delay(Duration::from_millis(500), TestMsg::Inc);
+                }
             }
             Cmd::none()
         }
@@ -747,6 +1150,204 @@ mod tests {
         });
     }
 
+    #[test]
+    #[cfg(feature = "tokio")]
+    fn test_perform() {
+        block_on(async {
+            let mut runner = TestRunner::<TestApp>::new();
+
+            runner.send(TestMsg::Perform).expect_cmd_task();
+            let task = runner.pending_tasks.pop().unwrap();
+            assert_eq!(task.await, TestMsg::Got(7));
+
+            runner.send(TestMsg::Perform).process_tasks().await;
+            runner.expect_model(|m| m.value == 7);
+
+            runner
+                .send(TestMsg::PerformResult(true))
+                .process_tasks()
+                .await;
+            runner.expect_model(|m| m.value == 8);
+
+            runner
+                .send(TestMsg::PerformResult(false))
+                .process_tasks()
+                .await;
+            runner.expect_model(|m| m.value == -1);
+        });
+    }
+
+    #[test]
+    fn test_headless_counter_with_delay() {
+        let harness = run_headless::<TestApp>(|h| {
+            h.send(TestMsg::Inc).send(TestMsg::Inc).send(TestMsg::Inc);
+            assert_eq!(h.model().value, 3);
+
+            h.send(TestMsg::IncLater);
+            h.advance(Duration::from_millis(499));
+            assert_eq!(h.model().value, 3);
+
+            h.advance(Duration::from_millis(1));
+            assert_eq!(h.model().value, 4);
+
+            // Ready tasks and Cmd::msg chains complete within send
+            h.send(TestMsg::Perform);
+            assert_eq!(h.model().value, 7);
+            h.send(TestMsg::MultiBatch);
+        });
+
+        assert_eq!(harness.model().value, 9);
+        assert_eq!(harness.clock().get(), Duration::from_millis(500));
+        assert_eq!(harness.pending_task_count(), 0);
+    }
+
+    #[test]
+    fn test_headless_subscriptions_follow_model() {
+        struct Ticker;
+
+        impl App for Ticker {
+            type Model = (bool, u32);
+            type Msg = Option<()>;
+
+            fn init() -> (Self::Model, Cmd<Self::Msg>) {
+                ((true, 0), Cmd::none())
+            }
+
+            fn update(model: &mut Self::Model, msg: Self::Msg) -> Cmd<Self::Msg> {
+                match msg {
+                    Some(()) => model.1 += 1,
+                    None => model.0 = false,
+                }
+                Cmd::none()
+            }
+
+            fn view(_model: &Self::Model, _ctx: &mut ViewCtx<Self::Msg>) {}
+
+            fn subscriptions(model: &Self::Model) -> crate::Sub<Self::Msg> {
+                if model.0 {
+                    crate::Sub::interval("tick", Duration::from_millis(100), Some(()))
+                } else {
+                    crate::Sub::none()
+                }
+            }
+        }
+
+        let harness = run_headless::<Ticker>(|h| {
+            for _ in 0..5 {
+                h.advance(Duration::from_millis(100));
+            }
+            assert_eq!(h.model().1, 5);
+
+            // Stopping the subscription stops the ticks
+            h.send(None);
+            h.advance(Duration::from_secs(1));
+        });
+        assert_eq!(harness.model().1, 5);
+    }
+
+    #[test]
+    fn test_headless_caught_view_panic_keeps_running() {
+        struct Flaky;
+
+        #[derive(Default)]
+        struct FlakyModel {
+            armed: bool,
+            count: i32,
+            panics: Vec<crate::FrameworkError>,
+        }
+
+        #[derive(Clone)]
+        enum FlakyMsg {
+            Arm,
+            Inc,
+            Explode,
+        }
+
+        impl App for Flaky {
+            type Model = FlakyModel;
+            type Msg = FlakyMsg;
+
+            fn init() -> (Self::Model, Cmd<Self::Msg>) {
+                (FlakyModel::default(), Cmd::none())
+            }
+
+            fn update(model: &mut Self::Model, msg: Self::Msg) -> Cmd<Self::Msg> {
+                match msg {
+                    FlakyMsg::Arm => model.armed = true,
+                    FlakyMsg::Inc => model.count += 1,
+                    FlakyMsg::Explode => panic!("update exploded"),
+                }
+                Cmd::none()
+            }
+
+            fn view(model: &Self::Model, ctx: &mut ViewCtx<Self::Msg>) {
+                ctx.emit(FlakyMsg::Inc);
+                if model.armed {
+                    panic!("view exploded");
+                }
+            }
+
+            fn on_panic(model: &mut Self::Model, err: crate::FrameworkError) -> Cmd<Self::Msg> {
+                model.armed = false;
+                model.panics.push(err);
+                Cmd::none()
+            }
+        }
+
+        let harness = run_headless::<Flaky>(|h| {
+            h.with_catch_panics(true);
+            let start = h.model().count;
+
+            // The panicking frame's messages are dropped, later frames run normally
+            h.send(FlakyMsg::Arm).frame();
+            assert_eq!(h.model().count, start);
+            h.frame().send(FlakyMsg::Inc);
+            assert_eq!(h.model().count, start + 2);
+
+            h.send(FlakyMsg::Explode).send(FlakyMsg::Inc);
+            assert_eq!(h.model().count, start + 3);
+        });
+
+        let sources: Vec<_> = harness.model().panics.iter().map(|e| e.source).collect();
+        assert_eq!(sources, vec![ErrorSource::View, ErrorSource::Update]);
+        assert_eq!(
+            harness.model().panics[0].message,
+            "View panicked: view exploded"
+        );
+    }
+
+    #[test]
+    fn test_headless_shutdown_calls_on_exit_once() {
+        struct Exiting;
+
+        impl App for Exiting {
+            type Model = u32;
+            type Msg = ();
+
+            fn init() -> (Self::Model, Cmd<Self::Msg>) {
+                (0, Cmd::none())
+            }
+
+            fn update(_model: &mut Self::Model, _msg: Self::Msg) -> Cmd<Self::Msg> {
+                Cmd::none()
+            }
+
+            fn view(_model: &Self::Model, _ctx: &mut ViewCtx<Self::Msg>) {}
+
+            fn on_exit(model: &mut Self::Model) {
+                *model += 1;
+            }
+        }
+
+        let harness = run_headless::<Exiting>(|h| {
+            h.send(()).advance(Duration::from_millis(100));
+            assert_eq!(*h.model(), 0);
+
+            h.shutdown().shutdown();
+        });
+        assert_eq!(*harness.model(), 1);
+    }
+
     // ========================================
     // FakeClock tests
     // ========================================
@@ -39,7 +39,20 @@ impl<'a, Msg> ViewCtx<'a, Msg> {
         self.emitter.push(msg);
     }
 
-    /// Emit multiple messages
+    /// Emit a message only when `cond` holds
+    ///
+    /// # Example
+    /// ```ignore
+    /// ctx.emit_if(response.changed(), Msg::Dirty);
+    /// ```
+    #[inline]
+    pub fn emit_if(&mut self, cond: bool, msg: Msg) {
+        if cond {
+            self.emit(msg);
+        }
+    }
+
+    /// Emit multiple messages, in order
     pub fn emit_all(&mut self, msgs: impl IntoIterator<Item = Msg>) {
         self.emitter.extend(msgs);
     }
@@ -421,6 +434,47 @@ impl<'a, Msg> ViewCtx<'a, Msg> {
         }
     }
 
+    /// Grid layout wrapping `egui::Grid`, for label/value forms
+    ///
+    /// Cells are added in order; call [`end_row`](Self::end_row) to start the
+    /// next row.
+    ///
+    /// # Example
+    /// ```ignore
+    /// ctx.grid("patch_grid", 2, |ctx| {
+    ///     ctx.ui.label("Name");
+    ///     ctx.ui.label(&model.name);
+    ///     ctx.end_row();
+    ///
+    ///     ctx.ui.label("Enabled");
+    ///     ctx.button("Toggle", Msg::ToggleEnabled);
+    ///     ctx.end_row();
+    /// });
+    /// ```
+    pub fn grid<R>(
+        &mut self,
+        id: impl std::hash::Hash,
+        columns: usize,
+        f: impl FnOnce(&mut ViewCtx<'_, Msg>) -> R,
+    ) -> R {
+        let mut child_msgs = Vec::new();
+        let result = egui::Grid::new(id)
+            .num_columns(columns)
+            .show(self.ui, |ui| {
+                let mut child_ctx = ViewCtx::new(ui, &mut child_msgs);
+                f(&mut child_ctx)
+            })
+            .inner;
+        self.emitter.extend(child_msgs);
+        result
+    }
+
+    /// End the current row inside [`grid`](Self::grid)
+    #[inline]
+    pub fn end_row(&mut self) {
+        self.ui.end_row();
+    }
+
     /// Internal helper for N-column layout
     fn columns_n<const N: usize>(
         &mut self,
@@ -605,7 +659,8 @@ impl<'a, Msg> ViewCtx<'a, Msg> {
     /// Check if an input binding was triggered and emit a message.
     ///
     /// Works with any type implementing `InputBinding`, including
-    /// `KeyboardShortcut`, `DynamicShortcut`, and `ShortcutGroup`.
+    /// `KeyboardShortcut`, `DynamicShortcut`, `ShortcutGroup`, and
+    /// `ShortcutSequence`.
     ///
     /// # Example
     /// ```ignore
@@ -651,8 +706,15 @@ impl<'a, Msg> ViewCtx<'a, Msg> {
     where
         A: Eq + Hash + Clone,
     {
-        if let Some(shortcut) = bindings.get(action) {
-            self.on_binding(shortcut, msg)
+        let ctx = self.ui.ctx().clone();
+        let shortcut = bindings.get(action).is_some_and(|s| s.consume(&ctx));
+        // Always feed the sequence so its progress tracks every frame
+        let sequence = bindings
+            .get_sequence(action)
+            .is_some_and(|s| s.consume(&ctx));
+        if shortcut || sequence {
+            self.emit(msg);
+            true
         } else {
             false
         }
@@ -763,3 +825,58 @@ impl<'a, Msg> ViewCtx<'a, Msg> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run `f` with a fresh ViewCtx and return what it emitted
+    fn emitted<Msg>(f: impl FnOnce(&mut ViewCtx<'_, Msg>)) -> Vec<Msg> {
+        let mut msgs = Vec::new();
+        let mut f = Some(f);
+        let _ = egui::Context::default().run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                if let Some(f) = f.take() {
+                    f(&mut ViewCtx::new(ui, &mut msgs));
+                }
+            });
+        });
+        msgs
+    }
+
+    #[test]
+    fn emit_if_respects_condition() {
+        assert!(emitted(|ctx| ctx.emit_if(false, 1)).is_empty());
+        assert_eq!(emitted(|ctx| ctx.emit_if(true, 1)), vec![1]);
+    }
+
+    #[test]
+    fn grid_collects_cell_msgs_and_end_row_advances() {
+        let mut rects = Vec::new();
+        let msgs = emitted(|ctx| {
+            ctx.grid("form", 2, |ctx| {
+                rects.push(ctx.ui.label("Name").rect);
+                ctx.ui.label("value");
+                ctx.end_row();
+
+                rects.push(ctx.ui.label("Enabled").rect);
+                ctx.emit("toggled");
+                ctx.end_row();
+            });
+        });
+
+        assert_eq!(msgs, vec!["toggled"]);
+        let (first, second) = (rects[0], rects[1]);
+        assert_eq!(first.left(), second.left());
+        assert!(second.top() >= first.bottom(), "{first:?} {second:?}");
+    }
+
+    #[test]
+    fn emit_all_keeps_order() {
+        let msgs = emitted(|ctx| {
+            ctx.emit("first");
+            ctx.emit_all(["a", "b"]);
+        });
+        assert_eq!(msgs, vec!["first", "a", "b"]);
+    }
+}
@@ -77,7 +77,7 @@ impl App for CounterApp {
     fn init() -> (Model, Cmd<Msg>) {
         (
             Model {
-                router: Router::new(Page::Home),
+                router: Router::new(Page::Home).with_transition(Transition::SlideLeft),
                 theme: Theme::light(),
                 errors: ErrorConsoleState::new().with_max_entries(3),
                 ..Default::default()
@@ -116,10 +116,7 @@ impl App for CounterApp {
                     ThemeVariant::Dark => Theme::light(),
                 };
             }
-            Msg::ErrorConsole(console_msg) => match console_msg {
-                ErrorConsoleMsg::Dismiss(i) => model.errors.dismiss(i),
-                ErrorConsoleMsg::DismissAll => model.errors.clear(),
-            },
+            Msg::ErrorConsole(console_msg) => model.errors.handle_msg(console_msg),
         }
         Cmd::none()
     }
@@ -159,13 +156,22 @@ impl App for CounterApp {
             ctx.ui.add_space(8.0);
         }
 
-        // Page content
-        match model.router.current() {
+        // Breadcrumb trail of visited pages
+        Breadcrumb::new().show(
+            ctx,
+            &model.router,
+            |page| format!("{:?}", page),
+            Msg::Router,
+        );
+        ctx.ui.add_space(8.0);
+
+        // Page content (slides in on navigation)
+        model.router.show(ctx, |ctx, page| match page {
             Page::Home => home_page(model, ctx),
             Page::Counter => counter_page(model, ctx),
             Page::Settings => settings_page(model, ctx),
             Page::About => about_page(model, ctx),
-        }
+        });
 
         // Footer
         ctx.ui.add_space(16.0);
@@ -860,8 +860,10 @@ fn render_live_area_ui(model: &Model, ui: &mut egui::Ui, msgs: &mut Vec<Msg>) {
                 let time = (model.bar as f32 + model.beat / 4.0) * 0.5;
                 let level_l = -60.0 + (time.sin() * 30.0 + 30.0);
                 let level_r = -60.0 + ((time + 0.5).sin() * 30.0 + 30.0);
-                LevelMeter::new().size(20.0, 80.0).show(ui, level_l);
-                LevelMeter::new().size(20.0, 80.0).show(ui, level_r);
+                LevelMeter::new()
+                    .size(16.0, 80.0)
+                    .stereo_pair(level_l, level_r)
+                    .show(ui);
             });
         });
     }